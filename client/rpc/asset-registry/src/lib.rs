@@ -0,0 +1,145 @@
+// Copyright 2025 Moonbeam Foundation.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObjectOwned, INTERNAL_ERROR_CODE},
+};
+use moonbeam_rpc_primitives_asset_registry::{
+	AssetKind, AssetRegistryApi as AssetRegistryRuntimeApi, AssetRegistryEntry, AssetStatus,
+};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H160;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+use xcm::VersionedLocation;
+
+/// An asset of the asset registries, as returned by the RPC
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo {
+	/// Registry of the asset: "legacyForeignAsset", "foreignAsset" or "erc20"
+	pub kind: String,
+	pub location: VersionedLocation,
+	pub address: H160,
+	pub decimals: u8,
+	pub symbol: String,
+	/// "active", "frozenXcmDepositAllowed" or "frozenXcmDepositForbidden"
+	pub status: String,
+	/// Whether the asset can be used to pay xcm execution fees
+	pub fee_payment_supported: bool,
+}
+
+impl From<AssetRegistryEntry> for AssetInfo {
+	fn from(entry: AssetRegistryEntry) -> Self {
+		let kind = match entry.kind {
+			AssetKind::LegacyForeignAsset => "legacyForeignAsset",
+			AssetKind::ForeignAsset => "foreignAsset",
+			AssetKind::Erc20 => "erc20",
+		};
+		let status = match entry.status {
+			AssetStatus::Active => "active",
+			AssetStatus::FrozenXcmDepositAllowed => "frozenXcmDepositAllowed",
+			AssetStatus::FrozenXcmDepositForbidden => "frozenXcmDepositForbidden",
+		};
+		Self {
+			kind: kind.to_string(),
+			location: entry.location,
+			address: entry.address,
+			decimals: entry.decimals,
+			symbol: String::from_utf8_lossy(&entry.symbol).into_owned(),
+			status: status.to_string(),
+			fee_payment_supported: entry.fee_payment_supported,
+		}
+	}
+}
+
+/// An RPC endpoint to resolve the assets of the legacy asset manager, the evm foreign assets
+/// and the bridged erc20s through a single lookup
+#[rpc(server)]
+pub trait AssetRegistryApi<BlockHash> {
+	/// Resolve an asset from its xcm location.
+	/// Returns null if the location doesn't match any known asset.
+	#[method(name = "moon_getAssetByLocation")]
+	fn asset_by_location(
+		&self,
+		location: VersionedLocation,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetInfo>>;
+
+	/// Resolve an asset from its evm address.
+	/// Returns null if the address doesn't match any known asset.
+	#[method(name = "moon_getAssetByAddress")]
+	fn asset_by_address(
+		&self,
+		address: H160,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetInfo>>;
+}
+
+pub struct AssetRegistry<B: BlockT, C> {
+	pub client: Arc<C>,
+	_phdata: PhantomData<B>,
+}
+
+impl<B: BlockT, C> AssetRegistry<B, C> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+impl<B, C> AssetRegistryApiServer<B::Hash> for AssetRegistry<B, C>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: AssetRegistryRuntimeApi<B>,
+{
+	fn asset_by_location(
+		&self,
+		location: VersionedLocation,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AssetInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.asset_by_location(at, location)
+			.map(|entry| entry.map(Into::into))
+			.map_err(runtime_api_error)
+	}
+
+	fn asset_by_address(&self, address: H160, at: Option<B::Hash>) -> RpcResult<Option<AssetInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.asset_by_address(at, address)
+			.map(|entry| entry.map(Into::into))
+			.map_err(runtime_api_error)
+	}
+}
+
+fn runtime_api_error(err: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(
+		INTERNAL_ERROR_CODE,
+		"Unable to query the asset registry",
+		Some(err.to_string()),
+	)
+}
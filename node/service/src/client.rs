@@ -48,6 +48,7 @@ pub trait RuntimeApiCollection:
 	+ session_keys_primitives::VrfApi<Block>
 	+ async_backing_primitives::UnincludedSegmentApi<Block>
 	+ xcm_runtime_apis::fees::XcmPaymentApi<Block>
+	+ moonbeam_rpc_primitives_asset_registry::AssetRegistryApi<Block>
 {
 }
 
@@ -69,6 +70,7 @@ impl<Api> RuntimeApiCollection for Api where
 		+ session_keys_primitives::VrfApi<Block>
 		+ async_backing_primitives::UnincludedSegmentApi<Block>
 		+ xcm_runtime_apis::fees::XcmPaymentApi<Block>
+		+ moonbeam_rpc_primitives_asset_registry::AssetRegistryApi<Block>
 {
 }
 
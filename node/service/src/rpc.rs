@@ -174,6 +174,7 @@ where
 		Eth, EthApiServer, EthFilter, EthFilterApiServer, EthPubSub, EthPubSubApiServer, Net,
 		NetApiServer, TxPool, TxPoolApiServer, Web3, Web3ApiServer,
 	};
+	use moonbeam_asset_registry_rpc::{AssetRegistry, AssetRegistryApiServer};
 	use moonbeam_dev_rpc::{DevApiServer, DevRpc};
 	use moonbeam_finality_rpc::{MoonbeamFinality, MoonbeamFinalityApiServer};
 	use moonbeam_rpc_debug::{Debug, DebugServer};
@@ -309,6 +310,7 @@ where
 	}
 
 	io.merge(MoonbeamFinality::new(client.clone(), frontier_backend.clone()).into_rpc())?;
+	io.merge(AssetRegistry::new(client.clone()).into_rpc())?;
 
 	if let Some(command_sink) = command_sink {
 		io.merge(
@@ -0,0 +1,230 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarking
+
+use crate::{BalanceOf, Call, Config, Pallet, PoolId, Pools, UnbondingRequests};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+const USER_SEED: u32 = 999666;
+
+fn min_candidate_stk<T: Config>() -> BalanceOf<T> {
+	<<T as pallet_parachain_staking::Config>::MinCandidateStk as Get<BalanceOf<T>>>::get()
+}
+
+fn min_delegation<T: Config>() -> BalanceOf<T> {
+	<<T as pallet_parachain_staking::Config>::MinDelegation as Get<BalanceOf<T>>>::get()
+}
+
+/// Create a funded user.
+fn create_funded_user<T: Config>(
+	string: &'static str,
+	n: u32,
+	balance: BalanceOf<T>,
+) -> T::AccountId {
+	let user = account(string, n, USER_SEED);
+	let _ = <T as pallet_parachain_staking::Config>::Currency::make_free_balance_be(&user, balance);
+	let _ = <T as pallet_parachain_staking::Config>::Currency::issue(balance);
+	user
+}
+
+/// Create `count` funded collator candidates
+fn create_candidates<T: Config>(count: u32) -> Vec<T::AccountId> {
+	let mut candidates = Vec::new();
+	let candidate_count = pallet_parachain_staking::Pallet::<T>::candidate_pool()
+		.0
+		.len() as u32;
+	for n in 0..count {
+		let candidate =
+			create_funded_user::<T>("candidate", n, min_candidate_stk::<T>() * 2u32.into());
+		pallet_parachain_staking::Pallet::<T>::join_candidates(
+			RawOrigin::Signed(candidate.clone()).into(),
+			min_candidate_stk::<T>(),
+			candidate_count + n,
+		)
+		.expect("fail to register candidate");
+		candidates.push(candidate);
+	}
+	candidates
+}
+
+/// Create a pool delegating `amount` to each of `count` candidates
+fn create_pool<T: Config>(count: u32, amount: BalanceOf<T>) -> (PoolId, T::AccountId) {
+	let candidates = create_candidates::<T>(count);
+	let total = (amount * count.into()).max(<T as Config>::MinCreateAmount::get());
+	let manager = create_funded_user::<T>("manager", 0, total * 2u32.into());
+	let pool_id = crate::NextPoolId::<T>::get();
+	Pallet::<T>::create_pool(RawOrigin::Signed(manager.clone()).into(), candidates, total)
+		.expect("fail to create pool");
+	(pool_id, manager)
+}
+
+/// Move the staking round forward by `rounds`
+fn skip_rounds<T: Config>(rounds: u32) {
+	pallet_parachain_staking::Round::<T>::mutate(|round| round.current += rounds);
+}
+
+benchmarks! {
+	create_pool {
+		let x in 1..(<T as Config>::MaxPoolCandidates::get());
+
+		let candidates = create_candidates::<T>(x);
+		let amount = (min_delegation::<T>() * x.into())
+			.max(<T as Config>::MinCreateAmount::get());
+		let manager = create_funded_user::<T>("manager", 0, amount * 2u32.into());
+	}: _(RawOrigin::Signed(manager.clone()), candidates, amount)
+	verify {
+		assert!(Pools::<T>::get(0).is_some());
+	}
+
+	join {
+		let x = <T as Config>::MaxPoolCandidates::get();
+		let (pool_id, _) = create_pool::<T>(x, min_delegation::<T>());
+		let amount = <T as Config>::MinJoinAmount::get();
+		let member = create_funded_user::<T>("member", 0, amount * 2u32.into());
+	}: _(RawOrigin::Signed(member.clone()), pool_id, amount)
+	verify {
+		assert!(!Pallet::<T>::shares(pool_id, &member).is_zero());
+	}
+
+	unbond {
+		let x = <T as Config>::MaxPoolCandidates::get();
+		let (pool_id, manager) = create_pool::<T>(x, min_delegation::<T>() * 2u32.into());
+		// the member delegation makes the first candidate the most backed one, so the unbond
+		// of the manager is merged with the decrease scheduled by the member in the same round
+		let member = create_funded_user::<T>("member", 0, min_delegation::<T>() * 8u32.into());
+		Pallet::<T>::join(
+			RawOrigin::Signed(member.clone()).into(),
+			pool_id,
+			min_delegation::<T>() * 4u32.into(),
+		)
+		.expect("fail to join pool");
+		Pallet::<T>::unbond(
+			RawOrigin::Signed(member.clone()).into(),
+			pool_id,
+			min_delegation::<T>(),
+		)
+		.expect("fail to unbond");
+		let shares = min_delegation::<T>();
+	}: _(RawOrigin::Signed(manager.clone()), pool_id, shares)
+	verify {
+		assert!(UnbondingRequests::<T>::get(pool_id, &manager).is_some());
+	}
+
+	withdraw_unbonded {
+		let x in 1..(<T as pallet_parachain_staking::Config>::MaxScheduledRequestsPerDelegation::get());
+
+		// a single candidate so all the decreases of the pool target the same delegation
+		let (pool_id, _) = create_pool::<T>(1, min_delegation::<T>() * (x + 1).into());
+		let mut members = Vec::new();
+		for n in 0..x {
+			let member = create_funded_user::<T>("member", n, min_delegation::<T>() * 2u32.into());
+			Pallet::<T>::join(
+				RawOrigin::Signed(member.clone()).into(),
+				pool_id,
+				min_delegation::<T>(),
+			)
+			.expect("fail to join pool");
+			members.push(member);
+		}
+		// the members unbond in distinct rounds so each of them schedules its own request, the
+		// first withdrawal executes the due requests of all the members
+		for member in members.iter() {
+			let shares = Pallet::<T>::shares(pool_id, member);
+			Pallet::<T>::unbond(RawOrigin::Signed(member.clone()).into(), pool_id, shares)
+				.expect("fail to unbond");
+			skip_rounds::<T>(1);
+		}
+		skip_rounds::<T>(
+			<T as pallet_parachain_staking::Config>::DelegationBondLessDelay::get()
+		);
+		let caller = members[0].clone();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id)
+	verify {
+		assert!(UnbondingRequests::<T>::get(pool_id, &caller).is_none());
+	}
+
+	add_candidate {
+		let (pool_id, manager) = create_pool::<T>(1, min_delegation::<T>());
+		let candidate = create_funded_user::<T>("new_candidate", 0, 0u32.into());
+	}: _(RawOrigin::Signed(manager.clone()), pool_id, candidate.clone())
+	verify {
+		assert!(Pools::<T>::get(pool_id)
+			.expect("pool exists")
+			.candidates
+			.contains(&candidate));
+	}
+
+	remove_candidate {
+		let (pool_id, manager) = create_pool::<T>(1, min_delegation::<T>());
+		let candidate = create_funded_user::<T>("new_candidate", 0, 0u32.into());
+		Pallet::<T>::add_candidate(
+			RawOrigin::Signed(manager.clone()).into(),
+			pool_id,
+			candidate.clone(),
+		)
+		.expect("fail to add candidate");
+	}: _(RawOrigin::Signed(manager.clone()), pool_id, candidate.clone())
+	verify {
+		assert!(!Pools::<T>::get(pool_id)
+			.expect("pool exists")
+			.candidates
+			.contains(&candidate));
+	}
+
+	bond_idle_funds {
+		// the idle funds open a new delegation, which is heavier than increasing an existing one
+		let (pool_id, manager) = create_pool::<T>(1, min_delegation::<T>());
+		let candidate =
+			create_funded_user::<T>("new_candidate", 0, min_candidate_stk::<T>() * 2u32.into());
+		pallet_parachain_staking::Pallet::<T>::join_candidates(
+			RawOrigin::Signed(candidate.clone()).into(),
+			min_candidate_stk::<T>(),
+			pallet_parachain_staking::Pallet::<T>::candidate_pool().0.len() as u32,
+		)
+		.expect("fail to register candidate");
+		Pallet::<T>::add_candidate(
+			RawOrigin::Signed(manager.clone()).into(),
+			pool_id,
+			candidate.clone(),
+		)
+		.expect("fail to add candidate");
+		let pool_account = Pallet::<T>::pool_account(pool_id);
+		let _ = <T as pallet_parachain_staking::Config>::Currency::deposit_creating(
+			&pool_account,
+			min_delegation::<T>(),
+		);
+		let caller = create_funded_user::<T>("caller", 0, 0u32.into());
+	}: _(RawOrigin::Signed(caller), pool_id)
+	verify {
+		assert!(pallet_parachain_staking::Pallet::<T>::delegator_state(&pool_account)
+			.expect("pool delegates")
+			.get_bond_amount(&candidate)
+			.is_some());
+	}
+}
+
+impl_benchmark_test_suite!(
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
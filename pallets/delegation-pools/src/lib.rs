@@ -0,0 +1,781 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Pallet delegation-pools
+//!
+//! This pallet allows accounts to stake together through pools built on top of
+//! `pallet-parachain-staking`.
+//!
+//! Each pool owns a keyless account that delegates the pooled funds across a set of collator
+//! candidates chosen by the pool manager. Delegations are fully auto-compounding, so staking
+//! rewards increase the value of the pool.
+//! Members join with any amount above `MinJoinAmount` (which can be lower than the staking
+//! `MinDelegation`) and receive non-transferable shares proportional to their contribution.
+//! The value of the shares is derived from the funds held by the pool account, except the funds
+//! owed to unbonding members. Funds that are not delegated anymore, such as the stake of a
+//! delegation kicked or revoked by its candidate or rewards that could not be compounded, keep
+//! their value and can be delegated again by anyone with `bond_idle_funds`. The pool manager seeds
+//! the pool with at least `MinCreateAmount` and keeps as many shares, so the pool always has a
+//! value and funds transferred to the pool account cannot be used to inflate the share price.
+//! Leaving is a two step process that follows the staking delays: `unbond` burns shares and
+//! schedules a bond decrease, then `withdraw_unbonded` pays the member once the decrease is due.
+//! The unbonds of a round towards a candidate are merged into a single scheduled decrease, so the
+//! pool uses at most one scheduled request per round of each of its delegations.
+//!
+//! The pallet is only part of the Moonbase runtime for now, where it is also blocked in
+//! maintenance mode. Its weights are estimates until the benchmarks are run on reference hardware.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_support::traits::{Currency, ExistenceRequirement};
+	use frame_support::PalletId;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero};
+	use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Percent, Rounding};
+	use sp_std::vec::Vec;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	pub type BalanceOf<T> = pallet_parachain_staking::BalanceOf<T>;
+
+	type StakingPallet<T> = pallet_parachain_staking::Pallet<T>;
+
+	/// The delegation pools's pallet id, used to derive the pool accounts
+	pub const PALLET_ID: PalletId = PalletId(*b"dlgpools");
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_parachain_staking::Config {
+		/// Overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		#[pallet::constant]
+		/// Maximum number of candidates a pool can delegate to.
+		type MaxPoolCandidates: Get<u32>;
+
+		#[pallet::constant]
+		/// Minimum amount to join a pool.
+		type MinJoinAmount: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// Minimum amount to create a pool. The manager keeps at least as many shares.
+		type MinCreateAmount: Get<BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// Identifier of the next created pool
+	pub(crate) type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	/// Delegation pools
+	pub type Pools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, PoolInfo<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn shares)]
+	/// Shares owned by each member of a pool
+	pub type PoolMembers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding_request)]
+	/// Pending withdrawals of the pool members
+	pub type UnbondingRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		UnbondingRequest<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// An error that can occur while executing this pallet's extrinsics.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The candidate is already part of the pool.
+		CandidateAlreadyInPool,
+		/// The amount is below the minimum required to create a pool.
+		CreateAmountBelowMin,
+		/// The candidate is not part of the pool.
+		CandidateNotInPool,
+		/// The pool still delegates to this candidate.
+		CandidateStillDelegated,
+		/// The same candidate was provided more than once.
+		DuplicateCandidate,
+		/// The member does not own enough shares.
+		InsufficientShares,
+		/// The amount is below the minimum required to join a pool.
+		JoinAmountBelowMin,
+		/// No candidate of the pool can be delegated to with the provided amount.
+		NoCandidateToDelegateTo,
+		/// The pool manager must keep at least `MinCreateAmount` shares.
+		ManagerSharesBelowMin,
+		/// No candidate of the pool has enough stake to unbond the requested amount.
+		NoCandidateToUnbondFrom,
+		/// A pool must delegate to at least one candidate.
+		NoCandidates,
+		/// The caller is not the pool manager.
+		NotPoolManager,
+		/// The pool does not exist.
+		PoolNotFound,
+		/// The pool has no value.
+		PoolValueZero,
+		/// There are too many candidates in the pool.
+		TooManyCandidates,
+		/// The member already has a pending unbonding request for this pool.
+		UnbondingRequestAlreadyExists,
+		/// The member has no pending unbonding request for this pool.
+		UnbondingRequestNotFound,
+		/// The unbonding request is not due yet.
+		UnbondingRequestNotDueYet,
+		/// The amount converts to zero shares.
+		ZeroShares,
+		/// The pool has no funds left to delegate.
+		NoIdleFunds,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool has been created
+		PoolCreated {
+			pool_id: PoolId,
+			manager: T::AccountId,
+			pool_account: T::AccountId,
+		},
+		/// A member joined a pool
+		Joined {
+			pool_id: PoolId,
+			member: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			shares: BalanceOf<T>,
+		},
+		/// A member burnt shares, the corresponding amount can be withdrawn from the given round
+		Unbonded {
+			pool_id: PoolId,
+			member: T::AccountId,
+			candidate: T::AccountId,
+			shares: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			when_executable: pallet_parachain_staking::RoundIndex,
+		},
+		/// A member withdrew unbonded funds
+		Withdrawn {
+			pool_id: PoolId,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A candidate was added to a pool
+		CandidateAdded {
+			pool_id: PoolId,
+			candidate: T::AccountId,
+		},
+		/// A candidate was removed from a pool
+		CandidateRemoved {
+			pool_id: PoolId,
+			candidate: T::AccountId,
+		},
+		/// Funds of the pool that were not delegated have been delegated to a candidate
+		IdleFundsBonded {
+			pool_id: PoolId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a pool managed by the caller, delegating `amount` evenly across `candidates`.
+		/// The caller receives the initial shares of the pool.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool(candidates.len() as u32))]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			candidates: Vec<T::AccountId>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+
+			ensure!(
+				amount >= T::MinCreateAmount::get(),
+				Error::<T>::CreateAmountBelowMin
+			);
+			ensure!(!candidates.is_empty(), Error::<T>::NoCandidates);
+			ensure!(
+				candidates.len() as u32 <= T::MaxPoolCandidates::get(),
+				Error::<T>::TooManyCandidates
+			);
+			for (index, candidate) in candidates.iter().enumerate() {
+				ensure!(
+					!candidates[..index].contains(candidate),
+					Error::<T>::DuplicateCandidate
+				);
+			}
+
+			let pool_id = NextPoolId::<T>::get();
+			let pool_account = Self::pool_account(pool_id);
+			<T as pallet_parachain_staking::Config>::Currency::transfer(
+				&manager,
+				&pool_account,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			// split the amount evenly, the last candidate receives the remainder
+			let count: BalanceOf<T> = (candidates.len() as u32).into();
+			let share = amount / count;
+			let mut remaining = amount;
+			for (index, candidate) in candidates.iter().enumerate() {
+				let delegated = if index == candidates.len() - 1 {
+					remaining
+				} else {
+					share
+				};
+				remaining = remaining.saturating_sub(delegated);
+				Self::delegate(&pool_account, candidate.clone(), delegated)?;
+			}
+
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo {
+					manager: manager.clone(),
+					candidates,
+					total_shares: amount,
+					unbonding: Zero::zero(),
+				},
+			);
+			PoolMembers::<T>::insert(pool_id, &manager, amount);
+			NextPoolId::<T>::put(pool_id.saturating_add(1));
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				manager,
+				pool_account,
+			});
+
+			Ok(())
+		}
+
+		/// Join a pool with `amount`, delegated to the least backed candidate of the pool.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::join())]
+		pub fn join(origin: OriginFor<T>, pool_id: PoolId, amount: BalanceOf<T>) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+			ensure!(
+				amount >= T::MinJoinAmount::get(),
+				Error::<T>::JoinAmountBelowMin
+			);
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pool_id);
+
+			// shares are computed before the pool value is increased by the new funds
+			let pool_value = Self::pool_value(&pool_account, &pool);
+			ensure!(
+				!pool_value.is_zero() && !pool.total_shares.is_zero(),
+				Error::<T>::PoolValueZero
+			);
+			let shares = Self::convert(amount, pool.total_shares, pool_value)?;
+			ensure!(!shares.is_zero(), Error::<T>::ZeroShares);
+
+			let candidate = Self::join_target(&pool_account, &pool, amount)
+				.ok_or(Error::<T>::NoCandidateToDelegateTo)?;
+
+			<T as pallet_parachain_staking::Config>::Currency::transfer(
+				&member,
+				&pool_account,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::bond(&pool_account, candidate.clone(), amount)?;
+
+			pool.total_shares = pool.total_shares.saturating_add(shares);
+			Pools::<T>::insert(pool_id, pool);
+			PoolMembers::<T>::mutate(pool_id, &member, |member_shares| {
+				*member_shares = member_shares.saturating_add(shares)
+			});
+
+			Self::deposit_event(Event::Joined {
+				pool_id,
+				member,
+				candidate,
+				amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Burn `shares` of the caller and schedule the decrease of the corresponding stake.
+		/// The funds can be withdrawn with `withdraw_unbonded` once the decrease is due.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::unbond())]
+		pub fn unbond(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+			ensure!(!shares.is_zero(), Error::<T>::ZeroShares);
+			ensure!(
+				!UnbondingRequests::<T>::contains_key(pool_id, &member),
+				Error::<T>::UnbondingRequestAlreadyExists
+			);
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let member_shares = PoolMembers::<T>::get(pool_id, &member);
+			ensure!(member_shares >= shares, Error::<T>::InsufficientShares);
+			if member == pool.manager {
+				ensure!(
+					member_shares.saturating_sub(shares) >= T::MinCreateAmount::get(),
+					Error::<T>::ManagerSharesBelowMin
+				);
+			}
+
+			let pool_account = Self::pool_account(pool_id);
+			let pool_value = Self::pool_value(&pool_account, &pool);
+			let amount = Self::convert(shares, pool_value, pool.total_shares)?;
+
+			let when_executable = StakingPallet::<T>::round().current.saturating_add(
+				<T as pallet_parachain_staking::Config>::DelegationBondLessDelay::get(),
+			);
+			let candidate = Self::unbond_source(&pool_account, &pool, amount, when_executable)
+				.ok_or(Error::<T>::NoCandidateToUnbondFrom)?;
+			Self::schedule_decrease(&pool_account, &candidate, amount, when_executable)?;
+
+			pool.total_shares = pool.total_shares.saturating_sub(shares);
+			pool.unbonding = pool.unbonding.saturating_add(amount);
+			Pools::<T>::insert(pool_id, pool);
+			if member_shares == shares {
+				PoolMembers::<T>::remove(pool_id, &member);
+			} else {
+				PoolMembers::<T>::insert(pool_id, &member, member_shares.saturating_sub(shares));
+			}
+			UnbondingRequests::<T>::insert(
+				pool_id,
+				&member,
+				UnbondingRequest {
+					candidate: candidate.clone(),
+					amount,
+					when_executable,
+				},
+			);
+
+			Self::deposit_event(Event::Unbonded {
+				pool_id,
+				member,
+				candidate,
+				shares,
+				amount,
+				when_executable,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw the funds of the caller's due unbonding request.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded(
+			<T as pallet_parachain_staking::Config>::MaxScheduledRequestsPerDelegation::get()
+		))]
+		pub fn withdraw_unbonded(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let request = UnbondingRequests::<T>::get(pool_id, &member)
+				.ok_or(Error::<T>::UnbondingRequestNotFound)?;
+			let now = StakingPallet::<T>::round().current;
+			ensure!(
+				request.when_executable <= now,
+				Error::<T>::UnbondingRequestNotDueYet
+			);
+
+			// Execute all the due decreases of the pool towards the candidate. Requests are
+			// executed oldest first, so this always includes the one of the member.
+			let pool_account = Self::pool_account(pool_id);
+			let due_requests =
+				StakingPallet::<T>::delegation_scheduled_requests(&request.candidate)
+					.iter()
+					.filter(|scheduled| {
+						scheduled.delegator == pool_account && scheduled.when_executable <= now
+					})
+					.count();
+			for _ in 0..due_requests {
+				StakingPallet::<T>::execute_delegation_request(
+					frame_system::RawOrigin::Signed(pool_account.clone()).into(),
+					pool_account.clone(),
+					request.candidate.clone(),
+				)
+				.map_err(|e| e.error)?;
+			}
+
+			<T as pallet_parachain_staking::Config>::Currency::transfer(
+				&pool_account,
+				&member,
+				request.amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			pool.unbonding = pool.unbonding.saturating_sub(request.amount);
+			Pools::<T>::insert(pool_id, pool);
+			UnbondingRequests::<T>::remove(pool_id, &member);
+
+			Self::deposit_event(Event::Withdrawn {
+				pool_id,
+				member,
+				amount: request.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Add a candidate to a pool. The candidate receives the funds of the next joins that
+		/// reach the staking minimum delegation.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_candidate())]
+		pub fn add_candidate(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			candidate: T::AccountId,
+		) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.manager == manager, Error::<T>::NotPoolManager);
+			ensure!(
+				!pool.candidates.contains(&candidate),
+				Error::<T>::CandidateAlreadyInPool
+			);
+			ensure!(
+				(pool.candidates.len() as u32) < T::MaxPoolCandidates::get(),
+				Error::<T>::TooManyCandidates
+			);
+
+			pool.candidates.push(candidate.clone());
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::CandidateAdded { pool_id, candidate });
+
+			Ok(())
+		}
+
+		/// Remove a candidate the pool no longer delegates to (e.g. the candidate left).
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_candidate())]
+		pub fn remove_candidate(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			candidate: T::AccountId,
+		) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.manager == manager, Error::<T>::NotPoolManager);
+			let index = pool
+				.candidates
+				.iter()
+				.position(|c| c == &candidate)
+				.ok_or(Error::<T>::CandidateNotInPool)?;
+			ensure!(
+				Self::delegated_amount(&Self::pool_account(pool_id), &candidate).is_none(),
+				Error::<T>::CandidateStillDelegated
+			);
+			ensure!(pool.candidates.len() > 1, Error::<T>::NoCandidates);
+
+			pool.candidates.remove(index);
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::CandidateRemoved { pool_id, candidate });
+
+			Ok(())
+		}
+
+		/// Delegate the funds of a pool that are not delegated anymore, such as the stake of a
+		/// delegation kicked or revoked by its candidate, or rewards that could not be compounded.
+		/// Can be called by any signed account.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::bond_idle_funds())]
+		pub fn bond_idle_funds(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pool_id);
+			let amount = Self::idle_funds(&pool_account, &pool);
+			ensure!(!amount.is_zero(), Error::<T>::NoIdleFunds);
+
+			let candidate = Self::join_target(&pool_account, &pool, amount)
+				.ok_or(Error::<T>::NoCandidateToDelegateTo)?;
+			Self::bond(&pool_account, candidate.clone(), amount)?;
+
+			Self::deposit_event(Event::IdleFundsBonded {
+				pool_id,
+				candidate,
+				amount,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding and delegating the funds of a pool
+		pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+			PALLET_ID.into_sub_account_truncating(pool_id)
+		}
+
+		/// Value of a pool owned by its members: the funds held by the pool account, delegated or
+		/// not, excluding the funds owed to unbonding members.
+		pub fn pool_value(
+			pool_account: &T::AccountId,
+			pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+		) -> BalanceOf<T> {
+			<T as pallet_parachain_staking::Config>::Currency::free_balance(pool_account)
+				.saturating_sub(pool.unbonding)
+		}
+
+		/// Part of the pool value that is not delegated
+		fn idle_funds(
+			pool_account: &T::AccountId,
+			pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+		) -> BalanceOf<T> {
+			let bonded = StakingPallet::<T>::delegator_state(pool_account)
+				.map(|state| state.total().saturating_sub(state.less_total))
+				.unwrap_or_default();
+			Self::pool_value(pool_account, pool).saturating_sub(bonded)
+		}
+
+		/// Amount delegated by the pool towards `candidate`, if any
+		fn delegated_amount(
+			pool_account: &T::AccountId,
+			candidate: &T::AccountId,
+		) -> Option<BalanceOf<T>> {
+			StakingPallet::<T>::delegator_state(pool_account)
+				.and_then(|state| state.get_bond_amount(candidate))
+		}
+
+		/// Computes `amount * numerator / denominator`, rounding down
+		fn convert(
+			amount: BalanceOf<T>,
+			numerator: BalanceOf<T>,
+			denominator: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			multiply_by_rational_with_rounding(
+				amount.unique_saturated_into(),
+				numerator.unique_saturated_into(),
+				denominator.unique_saturated_into(),
+				Rounding::Down,
+			)
+			.map(|value| value.unique_saturated_into())
+			.ok_or(ArithmeticError::Overflow.into())
+		}
+
+		/// Increases the delegation of the pool towards `candidate`, creating it if needed
+		fn bond(
+			pool_account: &T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			if Self::delegated_amount(pool_account, &candidate).is_some() {
+				StakingPallet::<T>::delegator_bond_more(
+					frame_system::RawOrigin::Signed(pool_account.clone()).into(),
+					candidate,
+					amount,
+				)
+				.map(|_| ())
+				.map_err(|e| e.error)
+			} else {
+				Self::delegate(pool_account, candidate, amount)
+			}
+		}
+
+		/// Creates a fully auto-compounding delegation of the pool towards `candidate`
+		fn delegate(
+			pool_account: &T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let candidate_delegation_count = StakingPallet::<T>::candidate_info(&candidate)
+				.map(|info| info.delegation_count)
+				.unwrap_or_default();
+			let candidate_auto_compounding_delegation_count =
+				StakingPallet::<T>::auto_compounding_delegations(&candidate).len() as u32;
+			let delegation_count = StakingPallet::<T>::delegator_state(pool_account)
+				.map(|state| state.delegations.0.len() as u32)
+				.unwrap_or_default();
+
+			StakingPallet::<T>::delegate_with_auto_compound(
+				frame_system::RawOrigin::Signed(pool_account.clone()).into(),
+				candidate,
+				amount,
+				Percent::from_percent(100),
+				candidate_delegation_count,
+				candidate_auto_compounding_delegation_count,
+				delegation_count,
+			)
+			.map(|_| ())
+			.map_err(|e| e.error)
+		}
+
+		/// Candidate receiving the funds of a join: a candidate the pool does not delegate to
+		/// yet if the amount allows creating a delegation, otherwise the least backed one.
+		fn join_target(
+			pool_account: &T::AccountId,
+			pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+			amount: BalanceOf<T>,
+		) -> Option<T::AccountId> {
+			let mut least_backed: Option<(T::AccountId, BalanceOf<T>)> = None;
+			for candidate in pool.candidates.iter() {
+				match Self::delegated_amount(pool_account, candidate) {
+					None => {
+						if amount >= <T as pallet_parachain_staking::Config>::MinDelegation::get()
+							&& StakingPallet::<T>::is_candidate(candidate)
+						{
+							return Some(candidate.clone());
+						}
+					}
+					Some(delegated) => {
+						if least_backed
+							.as_ref()
+							.map_or(true, |(_, lowest)| delegated < *lowest)
+						{
+							least_backed = Some((candidate.clone(), delegated));
+						}
+					}
+				}
+			}
+			least_backed.map(|(candidate, _)| candidate)
+		}
+
+		/// Candidate to unbond `amount` from: the candidate with the most stake left after its
+		/// pending decreases, provided it stays above the staking minimum delegation and the
+		/// decrease can be scheduled, either merged with the decrease of the current round
+		/// (executable at `when_executable`) or in a free scheduled request slot.
+		fn unbond_source(
+			pool_account: &T::AccountId,
+			pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+			amount: BalanceOf<T>,
+			when_executable: pallet_parachain_staking::RoundIndex,
+		) -> Option<T::AccountId> {
+			let min_delegation = <T as pallet_parachain_staking::Config>::MinDelegation::get();
+			let max_requests =
+				<T as pallet_parachain_staking::Config>::MaxScheduledRequestsPerDelegation::get();
+			let mut most_backed: Option<(T::AccountId, BalanceOf<T>)> = None;
+			for candidate in pool.candidates.iter() {
+				let delegated = match Self::delegated_amount(pool_account, candidate) {
+					Some(delegated) => delegated,
+					None => continue,
+				};
+				let mut pending_requests = 0u32;
+				let mut mergeable = false;
+				let mut pending_decrease = BalanceOf::<T>::zero();
+				for request in StakingPallet::<T>::delegation_scheduled_requests(candidate)
+					.iter()
+					.filter(|request| &request.delegator == pool_account)
+				{
+					pending_requests = pending_requests.saturating_add(1);
+					mergeable = mergeable || request.when_executable == when_executable;
+					pending_decrease = pending_decrease.saturating_add(request.action.amount());
+				}
+				if !mergeable && pending_requests >= max_requests {
+					continue;
+				}
+				let available = delegated.saturating_sub(pending_decrease);
+				if available.saturating_sub(amount) < min_delegation || available <= amount {
+					continue;
+				}
+				if most_backed
+					.as_ref()
+					.map_or(true, |(_, highest)| available > *highest)
+				{
+					most_backed = Some((candidate.clone(), available));
+				}
+			}
+			most_backed.map(|(candidate, _)| candidate)
+		}
+
+		/// Schedules the decrease of the pool delegation towards `candidate`. A decrease already
+		/// scheduled in the current round is cancelled and merged into the new one, so that the
+		/// unbonds of all the members only use one scheduled request per round.
+		fn schedule_decrease(
+			pool_account: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+			when_executable: pallet_parachain_staking::RoundIndex,
+		) -> DispatchResult {
+			let merged = StakingPallet::<T>::delegation_scheduled_requests(candidate)
+				.iter()
+				.filter(|request| &request.delegator == pool_account)
+				.enumerate()
+				.find(|(_, request)| request.when_executable == when_executable)
+				.map(|(index, request)| (index as u32, request.action.amount()));
+
+			let amount = match merged {
+				Some((index, merged_amount)) => {
+					StakingPallet::<T>::cancel_single_delegation_request(
+						frame_system::RawOrigin::Signed(pool_account.clone()).into(),
+						candidate.clone(),
+						index,
+					)
+					.map_err(|e| e.error)?;
+					amount.saturating_add(merged_amount)
+				}
+				None => amount,
+			};
+
+			StakingPallet::<T>::schedule_delegator_bond_less(
+				frame_system::RawOrigin::Signed(pool_account.clone()).into(),
+				candidate.clone(),
+				amount,
+			)
+			.map(|_| ())
+			.map_err(|e| e.error)
+		}
+	}
+}
@@ -0,0 +1,296 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A minimal runtime including the delegation-pools pallet
+
+use crate as pallet_delegation_pools;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{Everything, Get, OnFinalize, OnInitialize},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_parachain_staking::{InflationInfo, Range};
+use sp_consensus_slots::Slot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill, Percent,
+};
+
+// Pool accounts are derived from the pallet id, a 128 bits account id keeps them distinct
+pub type AccountId = u128;
+pub type Balance = u128;
+pub type BlockNumber = BlockNumberFor<Test>;
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		ParachainStaking: pallet_parachain_staking,
+		DelegationPools: pallet_delegation_pools,
+	}
+);
+
+// Pallet system configuration
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+// Pallet balances configuration
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+}
+
+// Pallet parachain-staking configuration
+
+const GENESIS_BLOCKS_PER_ROUND: u32 = 5;
+const GENESIS_COLLATOR_COMMISSION: Perbill = Perbill::from_percent(20);
+const GENESIS_PARACHAIN_BOND_RESERVE_PERCENT: Percent = Percent::from_percent(30);
+const GENESIS_NUM_SELECTED_CANDIDATES: u32 = 5;
+
+parameter_types! {
+	pub const MinBlocksPerRound: u32 = 3;
+	pub const MaxOfflineRounds: u32 = 2;
+	pub const LeaveCandidatesDelay: u32 = 2;
+	pub const CandidateBondLessDelay: u32 = 2;
+	pub const LeaveDelegatorsDelay: u32 = 2;
+	pub const RevokeDelegationDelay: u32 = 2;
+	pub const DelegationBondLessDelay: u32 = 2;
+	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxRoundArchive: u32 = 4;
+	pub const MinSelectedCandidates: u32 = GENESIS_NUM_SELECTED_CANDIDATES;
+	pub const MaxTopDelegationsPerCandidate: u32 = 4;
+	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
+	pub const MaxDelegationsPerDelegator: u32 = 4;
+	pub const MaxScheduledRequestsPerDelegation: u32 = 3;
+	pub const MinCandidateStk: u128 = 10;
+	pub const MinDelegation: u128 = 3;
+	pub const MaxCandidates: u32 = 10;
+	pub const BlockAuthor: AccountId = 1;
+}
+
+pub struct StakingRoundSlotProvider;
+impl Get<Slot> for StakingRoundSlotProvider {
+	fn get() -> Slot {
+		let block_number: u64 = System::block_number().into();
+		Slot::from(block_number)
+	}
+}
+
+impl pallet_parachain_staking::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MinBlocksPerRound = MinBlocksPerRound;
+	type MaxOfflineRounds = MaxOfflineRounds;
+	type LeaveCandidatesDelay = LeaveCandidatesDelay;
+	type CandidateBondLessDelay = CandidateBondLessDelay;
+	type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
+	type RevokeDelegationDelay = RevokeDelegationDelay;
+	type DelegationBondLessDelay = DelegationBondLessDelay;
+	type RewardPaymentDelay = RewardPaymentDelay;
+	type MaxRoundArchive = MaxRoundArchive;
+	type MinSelectedCandidates = MinSelectedCandidates;
+	type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
+	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
+	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+	type MaxScheduledRequestsPerDelegation = MaxScheduledRequestsPerDelegation;
+	type MinCandidateStk = MinCandidateStk;
+	type MinDelegation = MinDelegation;
+	type BlockAuthor = BlockAuthor;
+	type PayoutCollatorReward = ();
+	type OnCollatorPayout = ();
+	type OnInactiveCollator = ();
+	type OnNewRound = ();
+	type CandidateSelection = ();
+	type SlotProvider = StakingRoundSlotProvider;
+	type WeightInfo = ();
+	type MaxCandidates = MaxCandidates;
+	type SlotDuration = frame_support::traits::ConstU64<6_000>;
+	type BlockTime = frame_support::traits::ConstU64<6_000>;
+}
+
+// Pallet delegation-pools configuration
+
+parameter_types! {
+	pub const MaxPoolCandidates: u32 = 3;
+	pub const MinJoinAmount: u128 = 1;
+	pub const MinCreateAmount: u128 = 5;
+}
+
+impl pallet_delegation_pools::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxPoolCandidates = MaxPoolCandidates;
+	type MinJoinAmount = MinJoinAmount;
+	type MinCreateAmount = MinCreateAmount;
+	type WeightInfo = ();
+}
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, Balance)>,
+	// [collator, amount]
+	collators: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder {
+			balances: vec![],
+			collators: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn with_candidates(mut self, collators: Vec<(AccountId, Balance)>) -> Self {
+		self.collators = collators;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Pallet balances storage can be assimilated");
+
+		pallet_parachain_staking::GenesisConfig::<Test> {
+			candidates: self.collators,
+			delegations: vec![],
+			inflation_config: InflationInfo {
+				expect: Range {
+					min: 700,
+					ideal: 700,
+					max: 700,
+				},
+				// not used
+				annual: Range {
+					min: Perbill::from_percent(50),
+					ideal: Perbill::from_percent(50),
+					max: Perbill::from_percent(50),
+				},
+				// unrealistically high parameterization, only for testing
+				round: Range {
+					min: Perbill::from_percent(5),
+					ideal: Perbill::from_percent(5),
+					max: Perbill::from_percent(5),
+				},
+			},
+			collator_commission: GENESIS_COLLATOR_COMMISSION,
+			parachain_bond_reserve_percent: GENESIS_PARACHAIN_BOND_RESERVE_PERCENT,
+			blocks_per_round: GENESIS_BLOCKS_PER_ROUND,
+			num_selected_candidates: GENESIS_NUM_SELECTED_CANDIDATES,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Parachain Staking's storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Rolls to the first block of the given staking round. Returns the number of blocks played.
+pub(crate) fn roll_to_round_begin(round: u32) -> BlockNumber {
+	let mut num_blocks = 0;
+	while ParachainStaking::round().current < round {
+		roll_one_block();
+		num_blocks += 1;
+	}
+	num_blocks
+}
+
+// Rolls forward one block. Returns the new block number.
+fn roll_one_block() -> BlockNumber {
+	ParachainStaking::on_finalize(System::block_number());
+	Balances::on_finalize(System::block_number());
+	System::on_finalize(System::block_number());
+	System::set_block_number(System::block_number() + 1);
+	System::reset_events();
+	System::on_initialize(System::block_number());
+	Balances::on_initialize(System::block_number());
+	ParachainStaking::on_initialize(System::block_number());
+	System::block_number()
+}
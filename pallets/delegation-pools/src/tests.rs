@@ -0,0 +1,462 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit testing
+
+use crate::mock::{
+	roll_to_round_begin, Balances, DelegationPools, ExtBuilder, ParachainStaking, RuntimeOrigin,
+	System, Test,
+};
+use crate::{Error, Event, PoolInfo, Pools, UnbondingRequest};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+
+fn pool_value(pool_id: u32) -> u128 {
+	DelegationPools::pool_value(
+		&DelegationPools::pool_account(pool_id),
+		&Pools::<Test>::get(pool_id).expect("pool exists"),
+	)
+}
+
+fn delegated(pool_id: u32, candidate: u128) -> Option<u128> {
+	ParachainStaking::delegator_state(DelegationPools::pool_account(pool_id))
+		.and_then(|state| state.get_bond_amount(&candidate))
+}
+
+fn default_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(10, 1_000),
+			(11, 1_000),
+			(12, 1_000),
+		])
+		.with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+		.build()
+}
+
+#[test]
+fn test_create_pool_delegates_evenly_across_candidates() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1, 2],
+			11
+		));
+
+		let pool_account = DelegationPools::pool_account(0);
+		System::assert_last_event(
+			Event::<Test>::PoolCreated {
+				pool_id: 0,
+				manager: 10,
+				pool_account,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Pools::<Test>::get(0),
+			Some(PoolInfo {
+				manager: 10,
+				candidates: vec![1, 2],
+				total_shares: 11,
+				unbonding: 0,
+			})
+		);
+		assert_eq!(DelegationPools::shares(0, 10), 11);
+		// the last candidate receives the remainder
+		assert_eq!(delegated(0, 1), Some(5));
+		assert_eq!(delegated(0, 2), Some(6));
+		assert_eq!(Balances::free_balance(10), 989);
+
+		// pools use distinct accounts
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(11),
+			vec![3],
+			5
+		));
+		assert_ne!(DelegationPools::pool_account(1), pool_account);
+		assert_eq!(delegated(1, 3), Some(5));
+	});
+}
+
+#[test]
+fn test_create_pool_checks_candidates() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			DelegationPools::create_pool(RuntimeOrigin::signed(10), vec![1], 4),
+			Error::<Test>::CreateAmountBelowMin
+		);
+		assert_noop!(
+			DelegationPools::create_pool(RuntimeOrigin::signed(10), vec![], 10),
+			Error::<Test>::NoCandidates
+		);
+		assert_noop!(
+			DelegationPools::create_pool(RuntimeOrigin::signed(10), vec![1, 2, 3, 4], 20),
+			Error::<Test>::TooManyCandidates
+		);
+		assert_noop!(
+			DelegationPools::create_pool(RuntimeOrigin::signed(10), vec![1, 2, 1], 20),
+			Error::<Test>::DuplicateCandidate
+		);
+	});
+}
+
+#[test]
+fn test_join_below_min_delegation_bonds_more_to_least_backed_candidate() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1, 2],
+			11
+		));
+
+		// 2 is below the staking minimum delegation of 3
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 2));
+		System::assert_last_event(
+			Event::<Test>::Joined {
+				pool_id: 0,
+				member: 11,
+				candidate: 1,
+				amount: 2,
+				shares: 2,
+			}
+			.into(),
+		);
+		assert_eq!(delegated(0, 1), Some(7));
+		assert_eq!(delegated(0, 2), Some(6));
+		assert_eq!(DelegationPools::shares(0, 11), 2);
+		assert_eq!(Pools::<Test>::get(0).expect("pool exists").total_shares, 13);
+	});
+}
+
+#[test]
+fn test_join_delegates_to_new_candidate_of_the_pool() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1],
+			10
+		));
+		assert_ok!(DelegationPools::add_candidate(
+			RuntimeOrigin::signed(10),
+			0,
+			2
+		));
+
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 3));
+		assert_eq!(delegated(0, 1), Some(10));
+		assert_eq!(delegated(0, 2), Some(3));
+		assert_eq!(DelegationPools::shares(0, 11), 3);
+	});
+}
+
+#[test]
+fn test_join_checks_amount_and_pool() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			DelegationPools::join(RuntimeOrigin::signed(11), 0, 10),
+			Error::<Test>::PoolNotFound
+		);
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1],
+			10
+		));
+		assert_noop!(
+			DelegationPools::join(RuntimeOrigin::signed(11), 0, 0),
+			Error::<Test>::JoinAmountBelowMin
+		);
+	});
+}
+
+#[test]
+fn test_staking_rewards_increase_the_value_of_shares() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1],
+			10
+		));
+
+		// collator 1 authors all the blocks, rewards are compounded in the pool delegation
+		roll_to_round_begin(6);
+		let value = pool_value(0);
+		assert!(value > 10);
+		assert_eq!(delegated(0, 1), Some(value));
+
+		// later members receive less shares for the same amount
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 10));
+		let shares = DelegationPools::shares(0, 11);
+		assert_eq!(shares, 10 * 10 / value);
+		assert!(shares < 10);
+	});
+}
+
+#[test]
+fn test_unbond_and_withdraw_unbonded() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1, 2],
+			20
+		));
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 4));
+		assert_eq!(delegated(0, 1), Some(14));
+		assert_eq!(Balances::free_balance(11), 996);
+
+		// the decrease is scheduled on the most backed candidate
+		assert_ok!(DelegationPools::unbond(RuntimeOrigin::signed(11), 0, 4));
+		System::assert_last_event(
+			Event::<Test>::Unbonded {
+				pool_id: 0,
+				member: 11,
+				candidate: 1,
+				shares: 4,
+				amount: 4,
+				when_executable: 3,
+			}
+			.into(),
+		);
+		assert_eq!(DelegationPools::shares(0, 11), 0);
+		assert_eq!(
+			DelegationPools::unbonding_request(0, 11),
+			Some(UnbondingRequest {
+				candidate: 1,
+				amount: 4,
+				when_executable: 3,
+			})
+		);
+		let pool = Pools::<Test>::get(0).expect("pool exists");
+		assert_eq!(pool.total_shares, 20);
+		assert_eq!(pool.unbonding, 4);
+		assert_eq!(pool_value(0), 20);
+
+		assert_noop!(
+			DelegationPools::withdraw_unbonded(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::UnbondingRequestNotDueYet
+		);
+
+		roll_to_round_begin(3);
+		assert_ok!(DelegationPools::withdraw_unbonded(
+			RuntimeOrigin::signed(11),
+			0
+		));
+		System::assert_last_event(
+			Event::<Test>::Withdrawn {
+				pool_id: 0,
+				member: 11,
+				amount: 4,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(11), 1_000);
+		assert_eq!(DelegationPools::unbonding_request(0, 11), None);
+		assert_eq!(Pools::<Test>::get(0).expect("pool exists").unbonding, 0);
+		assert!(ParachainStaking::delegation_scheduled_requests(1).is_empty());
+	});
+}
+
+#[test]
+fn test_unbond_checks() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1, 2],
+			10
+		));
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 14));
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 14));
+		assert_eq!(delegated(0, 1), Some(19));
+		assert_eq!(delegated(0, 2), Some(19));
+
+		assert_noop!(
+			DelegationPools::unbond(RuntimeOrigin::signed(10), 0, 0),
+			Error::<Test>::ZeroShares
+		);
+		assert_noop!(
+			DelegationPools::unbond(RuntimeOrigin::signed(12), 0, 1),
+			Error::<Test>::InsufficientShares
+		);
+		// the manager keeps the minimum amount to create a pool
+		assert_noop!(
+			DelegationPools::unbond(RuntimeOrigin::signed(10), 0, 6),
+			Error::<Test>::ManagerSharesBelowMin
+		);
+		// the pool delegations cannot go below the staking minimum delegation
+		assert_noop!(
+			DelegationPools::unbond(RuntimeOrigin::signed(11), 0, 17),
+			Error::<Test>::NoCandidateToUnbondFrom
+		);
+		assert_noop!(
+			DelegationPools::withdraw_unbonded(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::UnbondingRequestNotFound
+		);
+
+		assert_ok!(DelegationPools::unbond(RuntimeOrigin::signed(10), 0, 2));
+		assert_noop!(
+			DelegationPools::unbond(RuntimeOrigin::signed(10), 0, 2),
+			Error::<Test>::UnbondingRequestAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn test_unbonds_of_a_round_are_merged_in_a_single_scheduled_request() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1],
+			10
+		));
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(11), 0, 4));
+		assert_ok!(DelegationPools::join(RuntimeOrigin::signed(12), 0, 4));
+
+		assert_ok!(DelegationPools::unbond(RuntimeOrigin::signed(11), 0, 4));
+		assert_ok!(DelegationPools::unbond(RuntimeOrigin::signed(12), 0, 4));
+		let requests = ParachainStaking::delegation_scheduled_requests(1);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].action.amount(), 8);
+		assert_eq!(requests[0].when_executable, 3);
+		assert_eq!(pool_value(0), 10);
+
+		// the unbonds of a later round use a new request
+		roll_to_round_begin(2);
+		assert_ok!(DelegationPools::unbond(RuntimeOrigin::signed(10), 0, 2));
+		assert_eq!(ParachainStaking::delegation_scheduled_requests(1).len(), 2);
+
+		roll_to_round_begin(3);
+		assert_ok!(DelegationPools::withdraw_unbonded(
+			RuntimeOrigin::signed(11),
+			0
+		));
+		assert_ok!(DelegationPools::withdraw_unbonded(
+			RuntimeOrigin::signed(12),
+			0
+		));
+		assert_eq!(Balances::free_balance(11), 1_000);
+		assert_eq!(Balances::free_balance(12), 1_000);
+		assert_eq!(ParachainStaking::delegation_scheduled_requests(1).len(), 1);
+	});
+}
+
+#[test]
+fn test_revoked_delegation_keeps_its_value_and_can_be_bonded_again() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1, 2],
+			20
+		));
+		assert_noop!(
+			DelegationPools::bond_idle_funds(RuntimeOrigin::signed(12), 0),
+			Error::<Test>::NoIdleFunds
+		);
+		assert_noop!(
+			DelegationPools::bond_idle_funds(RuntimeOrigin::signed(12), 1),
+			Error::<Test>::PoolNotFound
+		);
+
+		// candidate 2 leaves, which revokes the delegation of the pool
+		assert_ok!(ParachainStaking::schedule_leave_candidates(
+			RuntimeOrigin::signed(2),
+			3
+		));
+		roll_to_round_begin(3);
+		let value_before = pool_value(0);
+		assert_ok!(ParachainStaking::execute_leave_candidates(
+			RuntimeOrigin::signed(2),
+			2,
+			1
+		));
+		assert_eq!(delegated(0, 2), None);
+		assert_eq!(pool_value(0), value_before);
+
+		// the candidate that left is skipped, the funds are bonded to the remaining one
+		let delegated_before = delegated(0, 1).expect("pool delegates to candidate 1");
+		assert_ok!(DelegationPools::bond_idle_funds(
+			RuntimeOrigin::signed(12),
+			0
+		));
+		System::assert_last_event(
+			Event::<Test>::IdleFundsBonded {
+				pool_id: 0,
+				candidate: 1,
+				amount: 10,
+			}
+			.into(),
+		);
+		assert_eq!(delegated(0, 1), Some(delegated_before + 10));
+		assert_eq!(pool_value(0), value_before);
+	});
+}
+
+#[test]
+fn test_manage_pool_candidates() {
+	default_ext().execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(10),
+			vec![1],
+			10
+		));
+
+		assert_noop!(
+			DelegationPools::add_candidate(RuntimeOrigin::signed(11), 0, 2),
+			Error::<Test>::NotPoolManager
+		);
+		assert_noop!(
+			DelegationPools::add_candidate(RuntimeOrigin::signed(10), 0, 1),
+			Error::<Test>::CandidateAlreadyInPool
+		);
+		assert_noop!(
+			DelegationPools::remove_candidate(RuntimeOrigin::signed(10), 0, 1),
+			Error::<Test>::CandidateStillDelegated
+		);
+
+		assert_ok!(DelegationPools::add_candidate(
+			RuntimeOrigin::signed(10),
+			0,
+			2
+		));
+		System::assert_last_event(
+			Event::<Test>::CandidateAdded {
+				pool_id: 0,
+				candidate: 2,
+			}
+			.into(),
+		);
+		assert_ok!(DelegationPools::remove_candidate(
+			RuntimeOrigin::signed(10),
+			0,
+			2
+		));
+		System::assert_last_event(
+			Event::<Test>::CandidateRemoved {
+				pool_id: 0,
+				candidate: 2,
+			}
+			.into(),
+		);
+		assert_noop!(
+			DelegationPools::remove_candidate(RuntimeOrigin::signed(10), 0, 2),
+			Error::<Test>::CandidateNotInPool
+		);
+		assert_eq!(
+			Pools::<Test>::get(0).expect("pool exists").candidates,
+			vec![1]
+		);
+	});
+}
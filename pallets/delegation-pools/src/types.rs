@@ -0,0 +1,52 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Types for delegation-pools
+
+use pallet_parachain_staking::RoundIndex;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	codec::{Decode, Encode},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Identifier of a delegation pool
+pub type PoolId = u32;
+
+#[derive(Clone, PartialEq, Eq, Decode, Encode, RuntimeDebug, TypeInfo)]
+/// A delegation pool, delegating its funds across a set of collator candidates
+pub struct PoolInfo<AccountId, Balance> {
+	/// Account allowed to manage the candidates of the pool
+	pub manager: AccountId,
+	/// Candidates the pool funds are delegated to
+	pub candidates: Vec<AccountId>,
+	/// Total shares issued to the pool members
+	pub total_shares: Balance,
+	/// Funds owed to members that unbonded but did not withdraw yet
+	pub unbonding: Balance,
+}
+
+#[derive(Clone, PartialEq, Eq, Decode, Encode, RuntimeDebug, TypeInfo)]
+/// A pending withdrawal of a pool member
+pub struct UnbondingRequest<AccountId, Balance> {
+	/// Candidate the funds are unbonded from
+	pub candidate: AccountId,
+	/// Amount owed to the member
+	pub amount: Balance,
+	/// Round from which the funds can be withdrawn
+	pub when_executable: RoundIndex,
+}
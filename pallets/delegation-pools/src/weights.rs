@@ -0,0 +1,354 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Weights for `pallet_delegation_pools`
+//!
+//! These weights were not produced by a bencher run: they are estimated from the storage
+//! accessed by each call and must be regenerated with the benchmarks of the pallet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_delegation_pools.
+pub trait WeightInfo {
+	fn create_pool(x: u32, ) -> Weight;
+	fn join() -> Weight;
+	fn unbond() -> Weight;
+	fn withdraw_unbonded(x: u32, ) -> Weight;
+	fn add_candidate() -> Weight;
+	fn remove_candidate() -> Weight;
+	fn bond_idle_funds() -> Weight;
+}
+
+/// Weights for pallet_delegation_pools using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DelegationPools NextPoolId (r:1 w:1)
+	/// Proof Skipped: DelegationPools NextPoolId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools Pools (r:0 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:0 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		Weight::from_parts(96_802_417, 6104)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(68_115_904, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4560).saturating_mul(x.into()))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:1 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	fn join() -> Weight {
+		Weight::from_parts(130_417_000, 8088)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: DelegationPools UnbondingRequests (r:1 w:1)
+	/// Proof Skipped: DelegationPools UnbondingRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:1 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: None, max_size: None, mode: Measured)
+	fn unbond() -> Weight {
+		Weight::from_parts(72_990_000, 6078)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools UnbondingRequests (r:1 w:1)
+	/// Proof Skipped: DelegationPools UnbondingRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 10]`.
+	fn withdraw_unbonded(x: u32, ) -> Weight {
+		Weight::from_parts(110_251_983, 8350)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(12_944_120, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 79).saturating_mul(x.into()))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(18_396_000, 3812)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(22_511_000, 4277)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:0)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	fn bond_idle_funds() -> Weight {
+		Weight::from_parts(118_000_000, 8088)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DelegationPools NextPoolId (r:1 w:1)
+	/// Proof Skipped: DelegationPools NextPoolId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools Pools (r:0 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:0 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		Weight::from_parts(96_802_417, 6104)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(68_115_904, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4560).saturating_mul(x.into()))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:1 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	fn join() -> Weight {
+		Weight::from_parts(130_417_000, 8088)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: DelegationPools UnbondingRequests (r:1 w:1)
+	/// Proof Skipped: DelegationPools UnbondingRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools PoolMembers (r:1 w:1)
+	/// Proof Skipped: DelegationPools PoolMembers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: None, max_size: None, mode: Measured)
+	fn unbond() -> Weight {
+		Weight::from_parts(72_990_000, 6078)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DelegationPools UnbondingRequests (r:1 w:1)
+	/// Proof Skipped: DelegationPools UnbondingRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Round (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 10]`.
+	fn withdraw_unbonded(x: u32, ) -> Weight {
+		Weight::from_parts(110_251_983, 8350)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(12_944_120, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 79).saturating_mul(x.into()))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(18_396_000, 3812)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:1)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(22_511_000, 4277)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DelegationPools Pools (r:1 w:0)
+	/// Proof Skipped: DelegationPools Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof Skipped: System Account (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof Skipped: Balances Freezes (max_values: None, max_size: None, mode: Measured)
+	fn bond_idle_funds() -> Weight {
+		Weight::from_parts(118_000_000, 8088)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
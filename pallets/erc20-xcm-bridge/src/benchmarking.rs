@@ -0,0 +1,74 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{v2::*, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use frame_system::EventRecord;
+use sp_core::H160;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+	// compare to the last event record
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_erc20_transfer_gas_limit() -> Result<(), BenchmarkError> {
+		let origin =
+			T::GasLimitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		let contract_address = H160::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract_address, 100_000);
+
+		assert_last_event::<T>(
+			Event::Erc20TransferGasLimitSet {
+				contract_address,
+				gas_limit: 100_000,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_erc20_transfer_gas_limit() -> Result<(), BenchmarkError> {
+		let contract_address = H160::repeat_byte(1);
+		Erc20TransferGasLimits::<T>::insert(contract_address, 100_000);
+
+		let origin =
+			T::GasLimitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract_address);
+
+		assert_last_event::<T>(Event::Erc20TransferGasLimitRemoved { contract_address }.into());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
+}
@@ -48,7 +48,7 @@ impl<Erc20MultilocationPrefix: Get<Location>> Erc20Matcher<Erc20MultilocationPre
 			_ => false,
 		}
 	}
-	fn matches_erc20_multilocation(multilocation: &Location) -> Result<H160, ()> {
+	pub(crate) fn matches_erc20_multilocation(multilocation: &Location) -> Result<H160, ()> {
 		let prefix = Erc20MultilocationPrefix::get();
 		if prefix.parent_count() != multilocation.parent_count()
 			|| prefix
@@ -18,10 +18,10 @@ use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
 use xcm_executor::traits::DropAssets;
 
-/// Morph a given `DropAssets` implementation into one which filter out erc20 assets.
+/// Morph a given `DropAssets` implementation into one which filter out erc20 and erc721 assets.
 pub struct AssetTrapWrapper<AssetTrap, T>(core::marker::PhantomData<(AssetTrap, T)>);
 
-// Morph a given `DropAssets` implementation into one which filter out erc20 assets.
+// Morph a given `DropAssets` implementation into one which filter out erc20 and erc721 assets.
 impl<AssetTrap: DropAssets, T: crate::Config> DropAssets for AssetTrapWrapper<AssetTrap, T> {
 	fn drop_assets(
 		origin: &xcm::latest::Location,
@@ -43,6 +43,21 @@ impl<AssetTrap: DropAssets, T: crate::Config> DropAssets for AssetTrapWrapper<As
 				},
 			));
 		}
+		// Remove all erc721 assets
+		let assets_to_remove: Vec<_> = assets
+			.non_fungible_assets_iter()
+			.filter_map(|multiasset| {
+				crate::Pallet::<T>::is_erc721_asset(&multiasset).then_some(multiasset.id)
+			})
+			.collect();
+		for id in assets_to_remove {
+			assets.saturating_take(xcm::latest::AssetFilter::Wild(
+				xcm::latest::WildAsset::AllOf {
+					fun: WildNonFungible,
+					id,
+				},
+			));
+		}
 		AssetTrap::drop_assets(origin, assets, context)
 	}
 }
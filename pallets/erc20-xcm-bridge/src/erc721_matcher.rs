@@ -0,0 +1,176 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module that provides types to match erc721 assets.
+
+use sp_core::{Get, H160, U256};
+use xcm::latest::prelude::*;
+use xcm::latest::{Junction, Location};
+use xcm_executor::traits::{Error as MatchError, MatchesNonFungibles};
+
+/// Match erc721 tokens identified by the prefix + AccountKey20(contractAddress) location,
+/// the token id being the asset instance (`Index` or `Array32`).
+pub(crate) struct Erc721Matcher<Erc721MultilocationPrefix>(
+	core::marker::PhantomData<Erc721MultilocationPrefix>,
+);
+
+impl<Erc721MultilocationPrefix: Get<Location>> MatchesNonFungibles<H160, U256>
+	for Erc721Matcher<Erc721MultilocationPrefix>
+{
+	fn matches_nonfungibles(multiasset: &Asset) -> Result<(H160, U256), MatchError> {
+		let (instance, id) = match (&multiasset.fun, &multiasset.id) {
+			(NonFungible(ref instance), AssetId(ref id)) => (instance, id),
+			_ => return Err(MatchError::AssetNotHandled),
+		};
+		let contract_address = Self::matches_erc721_multilocation(id)
+			.map_err(|_| MatchError::AssetIdConversionFailed)?;
+		let token_id =
+			Self::matches_token_id(instance).map_err(|_| MatchError::InstanceConversionFailed)?;
+
+		Ok((contract_address, token_id))
+	}
+}
+
+impl<Erc721MultilocationPrefix: Get<Location>> Erc721Matcher<Erc721MultilocationPrefix> {
+	pub(crate) fn is_erc721_asset(multiasset: &Asset) -> bool {
+		match (&multiasset.fun, &multiasset.id) {
+			(NonFungible(_), AssetId(ref id)) => Self::matches_erc721_multilocation(id).is_ok(),
+			_ => false,
+		}
+	}
+	fn matches_erc721_multilocation(multilocation: &Location) -> Result<H160, ()> {
+		let prefix = Erc721MultilocationPrefix::get();
+		if prefix.parent_count() != multilocation.parent_count()
+			|| prefix
+				.interior()
+				.iter()
+				.enumerate()
+				.any(|(index, junction)| multilocation.interior().at(index) != Some(junction))
+		{
+			return Err(());
+		}
+		match multilocation.interior().at(prefix.interior().len()) {
+			Some(Junction::AccountKey20 {
+				key: contract_address,
+				..
+			}) => Ok(H160(*contract_address)),
+			_ => Err(()),
+		}
+	}
+	fn matches_token_id(instance: &AssetInstance) -> Result<U256, ()> {
+		match instance {
+			AssetInstance::Index(index) => Ok(U256::from(*index)),
+			AssetInstance::Array32(bytes) => Ok(U256::from_big_endian(bytes)),
+			_ => Err(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	macro_rules! assert_ok {
+		( $x:expr, $y:expr $(,)? ) => {
+			let is = $x;
+			match is {
+				Ok(ok) => assert_eq!(ok, $y),
+				_ => assert!(false, "Expected Ok(_). Got Err(_)"),
+			}
+		};
+	}
+
+	frame_support::parameter_types! {
+		pub Erc721MultilocationPrefix: Location = Location {
+			parents:0,
+			interior: [PalletInstance(42u8)].into()
+		};
+	}
+
+	fn erc721_location() -> Location {
+		Location {
+			parents: 0,
+			interior: [
+				PalletInstance(42u8),
+				AccountKey20 {
+					key: [1; 20],
+					network: None,
+				},
+			]
+			.into(),
+		}
+	}
+
+	#[test]
+	fn should_match_valid_erc721_location_with_index_instance() {
+		assert_ok!(
+			Erc721Matcher::<Erc721MultilocationPrefix>::matches_nonfungibles(&Asset::from((
+				erc721_location(),
+				AssetInstance::Index(7)
+			))),
+			(H160([1; 20]), U256::from(7))
+		);
+	}
+
+	#[test]
+	fn should_match_valid_erc721_location_with_array32_instance() {
+		let mut token_id = [0u8; 32];
+		token_id[0] = 1;
+		token_id[31] = 2;
+
+		assert_ok!(
+			Erc721Matcher::<Erc721MultilocationPrefix>::matches_nonfungibles(&Asset::from((
+				erc721_location(),
+				AssetInstance::Array32(token_id)
+			))),
+			(H160([1; 20]), (U256::from(1) << 248) + U256::from(2))
+		);
+	}
+
+	#[test]
+	fn should_not_match_unsupported_instance() {
+		assert!(
+			Erc721Matcher::<Erc721MultilocationPrefix>::matches_nonfungibles(&Asset::from((
+				erc721_location(),
+				AssetInstance::Array4([0; 4])
+			)))
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn should_not_match_fungible_or_invalid_erc721_location() {
+		assert!(
+			Erc721Matcher::<Erc721MultilocationPrefix>::matches_nonfungibles(&Asset::from((
+				erc721_location(),
+				100u128
+			)))
+			.is_err()
+		);
+
+		let invalid_location = Location {
+			parents: 0,
+			interior: [PalletInstance(42u8), GeneralIndex(0)].into(),
+		};
+		assert!(
+			Erc721Matcher::<Erc721MultilocationPrefix>::matches_nonfungibles(&Asset::from((
+				invalid_location,
+				AssetInstance::Index(7)
+			)))
+			.is_err()
+		);
+	}
+}
@@ -49,3 +49,44 @@ impl From<Erc20TransferError> for XcmError {
 		}
 	}
 }
+
+pub(crate) enum Erc721TransferError {
+	ContractNotFound,
+	ContractReturnInvalidValue,
+	ContractTransferFail,
+	DispatchError(DispatchError),
+	EvmCallFail,
+	NotTokenOwner,
+}
+
+impl From<DispatchError> for Erc721TransferError {
+	fn from(e: DispatchError) -> Self {
+		Self::DispatchError(e)
+	}
+}
+
+impl From<Erc721TransferError> for XcmError {
+	fn from(error: Erc721TransferError) -> XcmError {
+		match error {
+			Erc721TransferError::ContractNotFound => {
+				XcmError::FailedToTransactAsset("Erc721 contract not found")
+			}
+			Erc721TransferError::ContractReturnInvalidValue => {
+				XcmError::FailedToTransactAsset("Erc721 contract return invalid value")
+			}
+			Erc721TransferError::ContractTransferFail => {
+				XcmError::FailedToTransactAsset("Erc721 contract transfer fail")
+			}
+			Erc721TransferError::DispatchError(err) => {
+				log::debug!("dispatch error: {:?}", err);
+				Self::FailedToTransactAsset("storage layer error")
+			}
+			Erc721TransferError::EvmCallFail => {
+				XcmError::FailedToTransactAsset("Fail to call erc721 contract")
+			}
+			Erc721TransferError::NotTokenOwner => {
+				XcmError::FailedToTransactAsset("Erc721 token not owned by the origin")
+			}
+		}
+	}
+}
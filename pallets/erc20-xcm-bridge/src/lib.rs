@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Pallet that allow to transact erc20 tokens trought xcm directly.
+//! Pallet that allow to transact erc20 and erc721 tokens trought xcm directly.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -25,36 +27,51 @@ mod tests;
 
 mod erc20_matcher;
 mod erc20_trap;
+mod erc721_matcher;
 mod errors;
 mod xcm_holding_ext;
 
+pub mod weights;
+
 use frame_support::pallet;
 
 pub use erc20_trap::AssetTrapWrapper;
 pub use pallet::*;
+pub use weights::WeightInfo;
 pub use xcm_holding_ext::XcmExecutorWrapper;
 
 #[pallet]
 pub mod pallet {
 
 	use crate::erc20_matcher::*;
+	use crate::erc721_matcher::*;
 	use crate::errors::*;
+	use crate::weights::WeightInfo;
 	use crate::xcm_holding_ext::*;
 	use ethereum_types::BigEndianHash;
 	use fp_evm::{ExitReason, ExitSucceed};
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 	use pallet_evm::{GasWeightMapping, Runner};
 	use sp_core::{H160, H256, U256};
 	use sp_std::vec::Vec;
 	use xcm::latest::{
-		Asset, AssetId, Error as XcmError, Junction, Location, Result as XcmResult, XcmContext,
+		Asset, AssetId, Error as XcmError, Fungibility, Junction, Location, Result as XcmResult,
+		XcmContext,
 	};
 	use xcm_executor::traits::ConvertLocation;
-	use xcm_executor::traits::{Error as MatchError, MatchesFungibles};
+	use xcm_executor::traits::{Error as MatchError, MatchesFungibles, MatchesNonFungibles};
 	use xcm_executor::AssetsInHolding;
 
 	const ERC20_TRANSFER_CALL_DATA_SIZE: usize = 4 + 32 + 32; // selector + from + amount
 	const ERC20_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+	const ERC721_TRANSFER_FROM_CALL_DATA_SIZE: usize = 4 + 32 + 32 + 32; // selector + from + to + tokenId
+	const ERC721_TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+	const ERC721_OWNER_OF_CALL_DATA_SIZE: usize = 4 + 32; // selector + tokenId
+	const ERC721_OWNER_OF_SELECTOR: [u8; 4] = [0x63, 0x52, 0x21, 0x1e];
+	const ERC20_DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+	const ERC20_SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+	const ERC20_VIEW_CALL_GAS_LIMIT: u64 = 100_000;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(PhantomData<T>);
@@ -64,15 +81,112 @@ pub mod pallet {
 		type AccountIdConverter: ConvertLocation<H160>;
 		type Erc20MultilocationPrefix: Get<Location>;
 		type Erc20TransferGasLimit: Get<u64>;
+		/// Erc721 tokens are identified by this prefix + AccountKey20(contractAddress),
+		/// the token id being the asset instance.
+		type Erc721MultilocationPrefix: Get<Location>;
+		type Erc721TransferGasLimit: Get<u64>;
 		type EvmRunner: Runner<Self>;
+		/// Origin that is allowed to set the gas limit of erc20 transfers per contract
+		type GasLimitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Gas limit of xcm erc20 transfers per erc20 contract address.
+	/// Takes precedence over the gas limit encoded in the asset location.
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_transfer_gas_limit)]
+	pub type Erc20TransferGasLimits<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The gas limit cannot be zero
+		GasLimitCannotBeZero,
+		/// The gas limit cannot exceed the block gas limit
+		GasLimitTooHigh,
+		/// No gas limit is registered for the given erc20 contract
+		GasLimitNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The gas limit of xcm transfers of an erc20 contract has been set
+		Erc20TransferGasLimitSet {
+			contract_address: H160,
+			gas_limit: u64,
+		},
+		/// The gas limit of xcm transfers of an erc20 contract has been removed
+		Erc20TransferGasLimitRemoved { contract_address: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_erc20_transfer_gas_limit())]
+		pub fn set_erc20_transfer_gas_limit(
+			origin: OriginFor<T>,
+			contract_address: H160,
+			gas_limit: u64,
+		) -> DispatchResult {
+			T::GasLimitOrigin::ensure_origin(origin)?;
+
+			ensure!(gas_limit != 0, Error::<T>::GasLimitCannotBeZero);
+			ensure!(
+				U256::from(gas_limit) <= <T as pallet_evm::Config>::BlockGasLimit::get(),
+				Error::<T>::GasLimitTooHigh
+			);
+
+			Erc20TransferGasLimits::<T>::insert(contract_address, gas_limit);
+
+			Self::deposit_event(Event::Erc20TransferGasLimitSet {
+				contract_address,
+				gas_limit,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_erc20_transfer_gas_limit())]
+		pub fn remove_erc20_transfer_gas_limit(
+			origin: OriginFor<T>,
+			contract_address: H160,
+		) -> DispatchResult {
+			T::GasLimitOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Erc20TransferGasLimits::<T>::contains_key(contract_address),
+				Error::<T>::GasLimitNotFound
+			);
+
+			Erc20TransferGasLimits::<T>::remove(contract_address);
+
+			Self::deposit_event(Event::Erc20TransferGasLimitRemoved { contract_address });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn is_erc20_asset(asset: &Asset) -> bool {
 			Erc20Matcher::<T::Erc20MultilocationPrefix>::is_erc20_asset(asset)
 		}
+		pub fn is_erc721_asset(asset: &Asset) -> bool {
+			Erc721Matcher::<T::Erc721MultilocationPrefix>::is_erc721_asset(asset)
+		}
 		pub fn gas_limit_of_erc20_transfer(asset_id: &AssetId) -> u64 {
 			let location = &asset_id.0;
+			if let Some(gas_limit) =
+				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_erc20_multilocation(location)
+					.ok()
+					.and_then(Erc20TransferGasLimits::<T>::get)
+			{
+				return gas_limit;
+			}
+			// Legacy form: the gas limit is encoded in a trailing GeneralKey of the asset location
 			if let Some(Junction::GeneralKey {
 				length: _,
 				ref data,
@@ -95,6 +209,67 @@ pub mod pallet {
 		}
 		pub fn weight_of_erc20_transfer(asset_id: &AssetId) -> Weight {
 			T::GasWeightMapping::gas_to_weight(Self::gas_limit_of_erc20_transfer(asset_id), true)
+				// Erc20TransferGasLimits read
+				.saturating_add(T::DbWeight::get().reads(1))
+		}
+		pub fn weight_of_erc721_transfer() -> Weight {
+			T::GasWeightMapping::gas_to_weight(T::Erc721TransferGasLimit::get(), true)
+		}
+		/// Weight of the ownerOf call checking the owner of a withdrawn erc721 token
+		pub fn weight_of_erc721_owner_of() -> Weight {
+			T::GasWeightMapping::gas_to_weight(ERC20_VIEW_CALL_GAS_LIMIT, true)
+		}
+		/// Contract address of the erc20 token identified by the given location, if any
+		pub fn erc20_contract_address(location: &Location) -> Option<H160> {
+			Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_erc20_multilocation(location).ok()
+		}
+		/// Location identifying the given erc20 contract in xcm
+		pub fn erc20_location(contract_address: H160) -> Option<Location> {
+			T::Erc20MultilocationPrefix::get()
+				.pushed_with_interior(Junction::AccountKey20 {
+					network: None,
+					key: contract_address.0,
+				})
+				.ok()
+		}
+		/// Decimals and symbol of an erc20 contract, `None` if the contract doesn't implement
+		/// these optional methods of the erc20 standard
+		pub fn erc20_metadata(contract_address: H160) -> Option<(u8, Vec<u8>)> {
+			let decimals = Self::erc20_view_call(contract_address, ERC20_DECIMALS_SELECTOR)?;
+			let symbol = Self::erc20_view_call(contract_address, ERC20_SYMBOL_SELECTOR)?;
+
+			Some((
+				decode_erc20_decimals(&decimals)?,
+				decode_erc20_symbol(&symbol)?,
+			))
+		}
+		fn erc20_view_call(contract_address: H160, selector: [u8; 4]) -> Option<Vec<u8>> {
+			let weight_limit: Weight =
+				T::GasWeightMapping::gas_to_weight(ERC20_VIEW_CALL_GAS_LIMIT, true);
+
+			let exec_info = T::EvmRunner::call(
+				H160::zero(),
+				contract_address,
+				selector.to_vec(),
+				U256::default(),
+				ERC20_VIEW_CALL_GAS_LIMIT,
+				None,
+				None,
+				None,
+				Default::default(),
+				false,
+				false,
+				Some(weight_limit),
+				Some(0),
+				&<T as pallet_evm::Config>::config(),
+			)
+			.ok()?;
+
+			matches!(
+				exec_info.exit_reason,
+				ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
+			)
+			.then_some(exec_info.value)
 		}
 		fn erc20_transfer(
 			erc20_contract_address: H160,
@@ -151,6 +326,172 @@ pub mod pallet {
 
 			Ok(())
 		}
+		fn erc721_transfer_from(
+			erc721_contract_address: H160,
+			from: H160,
+			to: H160,
+			token_id: U256,
+		) -> Result<(), Erc721TransferError> {
+			// ERC721.transferFrom doesn't return any value, so we make sure to not call an empty
+			// contract, which would always succeed.
+			ensure!(
+				pallet_evm::AccountCodes::<T>::contains_key(erc721_contract_address),
+				Erc721TransferError::ContractNotFound
+			);
+
+			let mut input = Vec::with_capacity(ERC721_TRANSFER_FROM_CALL_DATA_SIZE);
+			// ERC721.transferFrom method hash
+			input.extend_from_slice(&ERC721_TRANSFER_FROM_SELECTOR);
+			// append owner address
+			input.extend_from_slice(H256::from(from).as_bytes());
+			// append receiver address
+			input.extend_from_slice(H256::from(to).as_bytes());
+			// append token id to be transferred
+			input.extend_from_slice(H256::from_uint(&token_id).as_bytes());
+
+			let gas_limit = T::Erc721TransferGasLimit::get();
+			let weight_limit: Weight = T::GasWeightMapping::gas_to_weight(gas_limit, true);
+
+			// The call is made on behalf of the owner, which is always allowed to transfer
+			// its own tokens.
+			let exec_info = T::EvmRunner::call(
+				from,
+				erc721_contract_address,
+				input,
+				U256::default(),
+				gas_limit,
+				None,
+				None,
+				None,
+				Default::default(),
+				false,
+				false,
+				Some(weight_limit),
+				Some(0),
+				&<T as pallet_evm::Config>::config(),
+			)
+			.map_err(|_| Erc721TransferError::EvmCallFail)?;
+
+			ensure!(
+				matches!(
+					exec_info.exit_reason,
+					ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
+				),
+				Erc721TransferError::ContractTransferFail
+			);
+
+			Ok(())
+		}
+		fn erc721_owner_of(
+			erc721_contract_address: H160,
+			token_id: U256,
+		) -> Result<H160, Erc721TransferError> {
+			let mut input = Vec::with_capacity(ERC721_OWNER_OF_CALL_DATA_SIZE);
+			// ERC721.ownerOf method hash
+			input.extend_from_slice(&ERC721_OWNER_OF_SELECTOR);
+			// append token id
+			input.extend_from_slice(H256::from_uint(&token_id).as_bytes());
+
+			let weight_limit: Weight =
+				T::GasWeightMapping::gas_to_weight(ERC20_VIEW_CALL_GAS_LIMIT, true);
+
+			let exec_info = T::EvmRunner::call(
+				H160::zero(),
+				erc721_contract_address,
+				input,
+				U256::default(),
+				ERC20_VIEW_CALL_GAS_LIMIT,
+				None,
+				None,
+				None,
+				Default::default(),
+				false,
+				false,
+				Some(weight_limit),
+				Some(0),
+				&<T as pallet_evm::Config>::config(),
+			)
+			.map_err(|_| Erc721TransferError::EvmCallFail)?;
+
+			ensure!(
+				matches!(
+					exec_info.exit_reason,
+					ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
+				),
+				Erc721TransferError::ContractTransferFail
+			);
+			// The owner is returned as an abi encoded address, an empty contract returns nothing.
+			ensure!(
+				exec_info.value.len() == 32,
+				Erc721TransferError::ContractReturnInvalidValue
+			);
+
+			Ok(H160::from(H256::from_slice(&exec_info.value)))
+		}
+		fn deposit_erc721(what: &Asset, who: &Location) -> XcmResult {
+			let (contract_address, token_id) =
+				Erc721Matcher::<T::Erc721MultilocationPrefix>::matches_nonfungibles(what)?;
+
+			let beneficiary = T::AccountIdConverter::convert_location(who)
+				.ok_or(MatchError::AccountIdConversionFailed)?;
+
+			// Get the global context to recover the token owner.
+			let owner = XcmHoldingErc721sOrigins::with(|erc721s_origins| {
+				erc721s_origins.take(contract_address, token_id)
+			})
+			.ok_or(XcmError::FailedToTransactAsset(
+				"missing erc721 executor context",
+			))?
+			.ok_or(XcmError::AssetNotFound)?;
+
+			frame_support::storage::with_storage_layer(|| {
+				Self::erc721_transfer_from(contract_address, owner, beneficiary, token_id)
+			})
+			.map_err(Into::into)
+		}
+		fn withdraw_erc721(what: &Asset, who: &Location) -> Result<AssetsInHolding, XcmError> {
+			let (contract_address, token_id) =
+				Erc721Matcher::<T::Erc721MultilocationPrefix>::matches_nonfungibles(what)?;
+			let who = T::AccountIdConverter::convert_location(who)
+				.ok_or(MatchError::AccountIdConversionFailed)?;
+
+			// The token is only transferred when deposited, so we make sure now that the origin
+			// owns it, otherwise the holding would contain a token that cannot be deposited.
+			ensure!(
+				Self::erc721_owner_of(contract_address, token_id)? == who,
+				Erc721TransferError::NotTokenOwner
+			);
+
+			XcmHoldingErc721sOrigins::with(|erc721s_origins| {
+				erc721s_origins.insert(contract_address, token_id, who)
+			})
+			.ok_or(XcmError::FailedToTransactAsset(
+				"missing erc721 executor context",
+			))?
+			.map_err(|_| XcmError::FailedToTransactAsset("erc721 token already in xcm holding"))?;
+
+			Ok(what.clone().into())
+		}
+		fn internal_transfer_erc721(
+			asset: &Asset,
+			from: &Location,
+			to: &Location,
+		) -> Result<AssetsInHolding, XcmError> {
+			let (contract_address, token_id) =
+				Erc721Matcher::<T::Erc721MultilocationPrefix>::matches_nonfungibles(asset)?;
+
+			let from = T::AccountIdConverter::convert_location(from)
+				.ok_or(MatchError::AccountIdConversionFailed)?;
+
+			let to = T::AccountIdConverter::convert_location(to)
+				.ok_or(MatchError::AccountIdConversionFailed)?;
+
+			frame_support::storage::with_storage_layer(|| {
+				Self::erc721_transfer_from(contract_address, from, to, token_id)
+			})?;
+
+			Ok(asset.clone().into())
+		}
 	}
 
 	impl<T: Config> xcm_executor::traits::TransactAsset for Pallet<T> {
@@ -158,6 +499,10 @@ pub mod pallet {
 		// we have just traced from which account it should have been withdrawn.
 		// So we will retrieve these information and make the transfer from the origin account.
 		fn deposit_asset(what: &Asset, who: &Location, _context: Option<&XcmContext>) -> XcmResult {
+			if let Fungibility::NonFungible(_) = what.fun {
+				return Self::deposit_erc721(what, who);
+			}
+
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
 
@@ -205,6 +550,10 @@ pub mod pallet {
 			to: &Location,
 			_context: &XcmContext,
 		) -> Result<AssetsInHolding, XcmError> {
+			if let Fungibility::NonFungible(_) = asset.fun {
+				return Self::internal_transfer_erc721(asset, from, to);
+			}
+
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(asset)?;
 
@@ -237,6 +586,10 @@ pub mod pallet {
 			who: &Location,
 			_context: Option<&XcmContext>,
 		) -> Result<AssetsInHolding, XcmError> {
+			if let Fungibility::NonFungible(_) = what.fun {
+				return Self::withdraw_erc721(what, who);
+			}
+
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
 			let who = T::AccountIdConverter::convert_location(who)
@@ -252,4 +605,27 @@ pub mod pallet {
 			Ok(what.clone().into())
 		}
 	}
+
+	/// Decode the return value of `decimals()`, an abi encoded uint8
+	pub(crate) fn decode_erc20_decimals(value: &[u8]) -> Option<u8> {
+		if value.len() != 32 {
+			return None;
+		}
+		u8::try_from(U256::from_big_endian(value)).ok()
+	}
+
+	/// Decode the return value of `symbol()`, an abi encoded string (or a bytes32 for some
+	/// older tokens)
+	pub(crate) fn decode_erc20_symbol(value: &[u8]) -> Option<Vec<u8>> {
+		if value.len() == 32 {
+			let len = value.iter().position(|byte| *byte == 0).unwrap_or(32);
+			return Some(value[..len].to_vec());
+		}
+		let offset = usize::try_from(U256::from_big_endian(value.get(0..32)?)).ok()?;
+		let data_start = offset.checked_add(32)?;
+		let len = usize::try_from(U256::from_big_endian(value.get(offset..data_start)?)).ok()?;
+		value
+			.get(data_start..data_start.checked_add(len)?)
+			.map(|symbol| symbol.to_vec())
+	}
 }
@@ -25,6 +25,9 @@ use pallet_evm::{
 use sp_core::{H160, H256, U256};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::AccountId32;
+use sp_runtime::BuildStorage;
+use xcm::latest::{Junction, Location};
+use xcm_executor::traits::ConvertLocation;
 
 pub type Balance = u128;
 
@@ -107,23 +110,31 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
-const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block storage limit in bytes. Set to 40 KB.
 const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
 
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(u64::MAX);
 	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
-	pub GasLimitPovSizeRatio: u64 = {
-		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
-		block_gas_limit.saturating_div(MAX_POV_SIZE)
-	};
+	pub GasLimitPovSizeRatio: u64 = 16;
 	pub GasLimitStorageGrowthRatio: u64 = {
 		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
 		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
 	};
 }
 
+/// Convert the `AccountKey20` locations of this chain to their evm address
+pub struct AccountKey20Converter;
+
+impl ConvertLocation<H160> for AccountKey20Converter {
+	fn convert_location(location: &Location) -> Option<H160> {
+		match location.unpack() {
+			(0, [Junction::AccountKey20 { key, .. }]) => Some(H160(*key)),
+			_ => None,
+		}
+	}
+}
+
 pub struct HashedAddressMapping;
 
 impl AddressMapping<AccountId32> for HashedAddressMapping {
@@ -162,10 +173,26 @@ impl pallet_evm::Config for Test {
 
 parameter_types! {
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+	pub Erc721XcmBridgeTransferGasLimit: u64 = 200_000;
 }
 impl crate::Config for Test {
-	type AccountIdConverter = ();
+	type AccountIdConverter = AccountKey20Converter;
 	type Erc20MultilocationPrefix = ();
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
+	type Erc721MultilocationPrefix = ();
+	type Erc721TransferGasLimit = Erc721XcmBridgeTransferGasLimit;
 	type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+	type GasLimitOrigin = frame_system::EnsureRoot<AccountId32>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.expect("Frame system builds valid default genesis config");
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
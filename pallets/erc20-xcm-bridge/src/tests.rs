@@ -15,10 +15,71 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Unit testing
-use sp_runtime::BoundedVec;
-use xcm::latest::Junction;
+use ethereum_types::BigEndianHash;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{BoundedVec, DispatchError};
+use xcm::latest::{Asset, AssetInstance, Error as XcmError, Junction, Location};
+use xcm_executor::traits::TransactAsset;
 
-use crate::mock::{Erc20XcmBridge, Erc20XcmBridgeTransferGasLimit};
+use crate::mock::{
+	new_test_ext, Erc20XcmBridge, Erc20XcmBridgeTransferGasLimit, RuntimeOrigin, System, Test,
+};
+use crate::xcm_holding_ext::with_xcm_holding_ext;
+use crate::{Error, Event};
+
+/// Runtime code of a minimal erc721 contract, the owner of each token id is stored in the slot
+/// of the same index:
+/// - `ownerOf(uint256 id)` returns `sload(id)`, reverts if it is zero
+/// - `transferFrom(address from, address to, uint256 id)` requires `sload(id) == from` and
+///   `caller == from`, then stores `to` in the slot `id`
+const ERC721_RUNTIME_CODE: &str = "60003560e01c80636352211e1461002057806323b872dd146100335760\
+	0080fd5b6004355480156100505760005260206000f35b6044358054600435803314156100505714156100\
+	50576024359055005b600080fd";
+
+fn deploy_erc721(contract_address: H160, tokens: &[(U256, H160)]) {
+	let code: Vec<u8> = (0..ERC721_RUNTIME_CODE.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&ERC721_RUNTIME_CODE[i..i + 2], 16).expect("valid hex"))
+		.collect();
+	pallet_evm::AccountCodes::<Test>::insert(contract_address, code);
+	for (token_id, owner) in tokens {
+		pallet_evm::AccountStorages::<Test>::insert(
+			contract_address,
+			H256::from_uint(token_id),
+			H256::from(*owner),
+		);
+	}
+}
+
+fn erc721_owner(contract_address: H160, token_id: U256) -> H160 {
+	H160::from(pallet_evm::AccountStorages::<Test>::get(
+		contract_address,
+		H256::from_uint(&token_id),
+	))
+}
+
+fn account_location(account: H160) -> Location {
+	Location {
+		parents: 0,
+		interior: [Junction::AccountKey20 {
+			key: account.0,
+			network: None,
+		}]
+		.into(),
+	}
+}
+
+fn erc20_location(contract_address: H160) -> Location {
+	Location {
+		parents: 0,
+		interior: [Junction::AccountKey20 {
+			key: contract_address.0,
+			network: None,
+		}]
+		.into(),
+	}
+}
 
 #[test]
 fn general_key_data_size_32() {
@@ -63,3 +124,210 @@ fn gas_limit_override_typo() {
 		Erc20XcmBridgeTransferGasLimit::get()
 	)
 }
+
+#[test]
+fn erc721_assets_are_non_fungible_erc20_bridge_assets() {
+	let location = erc20_location(H160::repeat_byte(1));
+
+	let erc721_asset = Asset::from((location.clone(), AssetInstance::Index(1)));
+	assert!(Erc20XcmBridge::is_erc721_asset(&erc721_asset));
+	assert!(!Erc20XcmBridge::is_erc20_asset(&erc721_asset));
+
+	let erc20_asset = Asset::from((location, 1u128));
+	assert!(Erc20XcmBridge::is_erc20_asset(&erc20_asset));
+	assert!(!Erc20XcmBridge::is_erc721_asset(&erc20_asset));
+}
+
+#[test]
+fn withdraw_erc721_checks_the_token_owner() {
+	new_test_ext().execute_with(|| {
+		let contract_address = H160::repeat_byte(0x72);
+		let owner = H160::repeat_byte(1);
+		let beneficiary = H160::repeat_byte(2);
+		deploy_erc721(contract_address, &[(U256::from(7), owner)]);
+		let token = Asset::from((erc20_location(contract_address), AssetInstance::Index(7)));
+		let missing_token =
+			Asset::from((erc20_location(contract_address), AssetInstance::Index(8)));
+
+		with_xcm_holding_ext(|| {
+			assert_eq!(
+				Erc20XcmBridge::withdraw_asset(&token, &account_location(beneficiary), None)
+					.map(|_| ()),
+				Err(XcmError::FailedToTransactAsset(
+					"Erc721 token not owned by the origin"
+				))
+			);
+			assert_eq!(
+				Erc20XcmBridge::withdraw_asset(&missing_token, &account_location(owner), None)
+					.map(|_| ()),
+				Err(XcmError::FailedToTransactAsset(
+					"Erc721 contract transfer fail"
+				))
+			);
+
+			assert_ok!(Erc20XcmBridge::withdraw_asset(
+				&token,
+				&account_location(owner),
+				None
+			));
+			// the token is only transferred on deposit
+			assert_eq!(erc721_owner(contract_address, U256::from(7)), owner);
+			assert_ok!(Erc20XcmBridge::deposit_asset(
+				&token,
+				&account_location(beneficiary),
+				None
+			));
+		});
+		assert_eq!(erc721_owner(contract_address, U256::from(7)), beneficiary);
+	});
+}
+
+#[test]
+fn set_and_remove_erc20_transfer_gas_limit() {
+	new_test_ext().execute_with(|| {
+		let contract_address = H160::repeat_byte(1);
+
+		assert_noop!(
+			Erc20XcmBridge::set_erc20_transfer_gas_limit(
+				RuntimeOrigin::signed([1u8; 32].into()),
+				contract_address,
+				100_000
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Erc20XcmBridge::set_erc20_transfer_gas_limit(
+				RuntimeOrigin::root(),
+				contract_address,
+				0
+			),
+			Error::<Test>::GasLimitCannotBeZero
+		);
+		assert_noop!(
+			Erc20XcmBridge::remove_erc20_transfer_gas_limit(
+				RuntimeOrigin::root(),
+				contract_address
+			),
+			Error::<Test>::GasLimitNotFound
+		);
+
+		assert_ok!(Erc20XcmBridge::set_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			contract_address,
+			100_000
+		));
+		System::assert_last_event(
+			Event::<Test>::Erc20TransferGasLimitSet {
+				contract_address,
+				gas_limit: 100_000,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Erc20XcmBridge::erc20_transfer_gas_limit(contract_address),
+			Some(100_000)
+		);
+
+		assert_ok!(Erc20XcmBridge::remove_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			contract_address
+		));
+		System::assert_last_event(
+			Event::<Test>::Erc20TransferGasLimitRemoved { contract_address }.into(),
+		);
+		assert_eq!(
+			Erc20XcmBridge::erc20_transfer_gas_limit(contract_address),
+			None
+		);
+	});
+}
+
+#[test]
+fn registered_gas_limit_takes_precedence_over_location() {
+	new_test_ext().execute_with(|| {
+		let contract_address = H160::repeat_byte(1);
+
+		// Legacy form, with the gas limit encoded in the asset location
+		let text = "gas_limit:".as_bytes();
+		let limit = 300_000u64;
+		let data = [text, &limit.to_le_bytes()].concat();
+		let vec = BoundedVec::try_from(data).expect("vec should convert");
+		let mut legacy_location = erc20_location(contract_address);
+		legacy_location
+			.push_interior(Junction::from(vec))
+			.expect("location should not be full");
+
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&legacy_location.clone().into()),
+			limit
+		);
+
+		assert_ok!(Erc20XcmBridge::set_erc20_transfer_gas_limit(
+			RuntimeOrigin::root(),
+			contract_address,
+			500_000
+		));
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&legacy_location.into()),
+			500_000
+		);
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&erc20_location(contract_address).into()),
+			500_000
+		);
+
+		// Other contracts still use the default gas limit
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(
+				&erc20_location(H160::repeat_byte(2)).into()
+			),
+			Erc20XcmBridgeTransferGasLimit::get()
+		);
+	});
+}
+
+#[test]
+fn erc20_location_and_contract_address_roundtrip() {
+	let contract_address = H160::repeat_byte(1);
+	let location = Erc20XcmBridge::erc20_location(contract_address).expect("valid location");
+
+	assert_eq!(location, erc20_location(contract_address));
+	assert_eq!(
+		Erc20XcmBridge::erc20_contract_address(&location),
+		Some(contract_address)
+	);
+	assert_eq!(
+		Erc20XcmBridge::erc20_contract_address(&Location::parent()),
+		None
+	);
+}
+
+#[test]
+fn decode_erc20_metadata_return_values() {
+	use crate::pallet::{decode_erc20_decimals, decode_erc20_symbol};
+
+	let mut decimals = [0u8; 32];
+	decimals[31] = 18;
+	assert_eq!(decode_erc20_decimals(&decimals), Some(18));
+	decimals[30] = 1;
+	assert_eq!(decode_erc20_decimals(&decimals), None);
+	assert_eq!(decode_erc20_decimals(&[]), None);
+
+	// abi encoded string
+	let mut symbol = vec![0u8; 96];
+	symbol[31] = 32;
+	symbol[63] = 4;
+	symbol[64..68].copy_from_slice(b"USDC");
+	assert_eq!(decode_erc20_symbol(&symbol), Some(b"USDC".to_vec()));
+
+	// bytes32
+	let mut symbol = [0u8; 32];
+	symbol[0..3].copy_from_slice(b"MKR");
+	assert_eq!(decode_erc20_symbol(&symbol), Some(b"MKR".to_vec()));
+
+	// truncated data
+	let mut symbol = vec![0u8; 64];
+	symbol[31] = 32;
+	symbol[63] = 10;
+	assert_eq!(decode_erc20_symbol(&symbol), None);
+}
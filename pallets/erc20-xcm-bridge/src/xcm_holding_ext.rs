@@ -18,11 +18,12 @@
 
 use core::marker::PhantomData;
 use sp_core::{H160, U256};
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_map::{BTreeMap, Entry};
 use sp_std::vec::Vec;
 use xcm_executor::traits::XcmAssetTransfers;
 
 environmental::environmental!(XCM_HOLDING_ERC20_ORIGINS: XcmHoldingErc20sOrigins);
+environmental::environmental!(XCM_HOLDING_ERC721_ORIGINS: XcmHoldingErc721sOrigins);
 
 #[cfg_attr(test, derive(PartialEq, Debug))]
 pub(crate) enum DrainError {
@@ -95,7 +96,52 @@ impl XcmHoldingErc20sOrigins {
 	}
 }
 
-/// Xcm executor wrapper that inject xcm holding extension "XcmHoldingErc20sOrigins"
+/// Xcm holding erc721 origins extension.
+/// This extension track down the owner of all erc721 tokens in the xcm holding.
+#[derive(Default)]
+pub(crate) struct XcmHoldingErc721sOrigins {
+	map: BTreeMap<(H160, U256), H160>,
+}
+impl XcmHoldingErc721sOrigins {
+	/// Take and remove a given erc721 token from the XCM holding, returning its owner.
+	pub(crate) fn take(&mut self, contract_address: H160, token_id: U256) -> Option<H160> {
+		self.map.remove(&(contract_address, token_id))
+	}
+	/// Track the owner of an erc721 token, fails if the token is already in the XCM holding.
+	pub(crate) fn insert(
+		&mut self,
+		contract_address: H160,
+		token_id: U256,
+		who: H160,
+	) -> Result<(), ()> {
+		match self.map.entry((contract_address, token_id)) {
+			Entry::Vacant(entry) => {
+				entry.insert(who);
+				Ok(())
+			}
+			Entry::Occupied(_) => Err(()),
+		}
+	}
+	pub(crate) fn with<R, F>(f: F) -> Option<R>
+	where
+		F: FnOnce(&mut Self) -> R,
+	{
+		XCM_HOLDING_ERC721_ORIGINS::with(|erc721s_origins| f(erc721s_origins))
+	}
+}
+
+/// Run `f` with the xcm holding extensions injected, as the executor wrapper does.
+#[cfg(test)]
+pub(crate) fn with_xcm_holding_ext<R>(f: impl FnOnce() -> R) -> R {
+	let mut erc20s_origins = Default::default();
+	let mut erc721s_origins = Default::default();
+	XCM_HOLDING_ERC20_ORIGINS::using(&mut erc20s_origins, || {
+		XCM_HOLDING_ERC721_ORIGINS::using(&mut erc721s_origins, f)
+	})
+}
+
+/// Xcm executor wrapper that inject xcm holding extensions "XcmHoldingErc20sOrigins"
+/// and "XcmHoldingErc721sOrigins"
 pub struct XcmExecutorWrapper<Config, InnerXcmExecutor>(PhantomData<(Config, InnerXcmExecutor)>);
 impl<Config, InnerXcmExecutor> xcm::latest::ExecuteXcm<Config::RuntimeCall>
 	for XcmExecutorWrapper<Config, InnerXcmExecutor>
@@ -118,8 +164,11 @@ where
 		weight_credit: xcm::latest::Weight,
 	) -> xcm::latest::Outcome {
 		let mut erc20s_origins = Default::default();
+		let mut erc721s_origins = Default::default();
 		XCM_HOLDING_ERC20_ORIGINS::using(&mut erc20s_origins, || {
-			InnerXcmExecutor::execute(origin, pre, hash, weight_credit)
+			XCM_HOLDING_ERC721_ORIGINS::using(&mut erc721s_origins, || {
+				InnerXcmExecutor::execute(origin, pre, hash, weight_credit)
+			})
 		})
 	}
 
@@ -192,4 +241,28 @@ mod tests {
 			})
 		});
 	}
+
+	#[test]
+	fn test_xcm_holding_ext_erc721s_origins() {
+		const TOKEN1: H160 = H160([1; 20]);
+		const USER1: H160 = H160([3; 20]);
+		const USER2: H160 = H160([4; 20]);
+
+		let mut erc721s_origins_ = Default::default();
+		XCM_HOLDING_ERC721_ORIGINS::using(&mut erc721s_origins_, || {
+			XcmHoldingErc721sOrigins::with(|erc721s_origins| {
+				assert_eq!(erc721s_origins.insert(TOKEN1, U256::from(1), USER1), Ok(()));
+				assert_eq!(erc721s_origins.insert(TOKEN1, U256::from(2), USER2), Ok(()));
+				// The same token can't be withdrawn twice
+				assert_eq!(
+					erc721s_origins.insert(TOKEN1, U256::from(1), USER2),
+					Err(())
+				);
+				assert_eq!(erc721s_origins.take(TOKEN1, U256::from(3)), None);
+				assert_eq!(erc721s_origins.take(TOKEN1, U256::from(2)), Some(USER2));
+				assert_eq!(erc721s_origins.take(TOKEN1, U256::from(2)), None);
+				assert_eq!(erc721s_origins.take(TOKEN1, U256::from(1)), Some(USER1));
+			})
+		});
+	}
 }
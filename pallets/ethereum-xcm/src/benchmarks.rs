@@ -0,0 +1,106 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{BalanceOf, Call, Config, Pallet, XcmProxies};
+use ethereum_types::H160;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use pallet_evm::AddressMapping;
+use sp_runtime::traits::{Bounded, One, Saturating};
+
+/// Get an origin passing `XcmProxyOrigin` along with its address, funded for the deposit
+fn funded_delegator<T: Config>() -> Result<(T::RuntimeOrigin, H160), BenchmarkError> {
+	let origin =
+		T::XcmProxyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let delegator =
+		T::XcmProxyOrigin::try_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+	T::XcmProxyCurrency::make_free_balance_be(
+		&T::AddressMapping::into_account_id(delegator),
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	Ok((origin, delegator))
+}
+
+#[benchmarks(
+	where T::XcmProxyType: Default
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn add_xcm_proxy() -> Result<(), BenchmarkError> {
+		let (origin, delegator) = funded_delegator::<T>()?;
+		let delegate = H160::repeat_byte(0xaa);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			delegate,
+			T::XcmProxyType::default(),
+			T::MaxXcmProxyDuration::get(),
+		);
+
+		assert!(XcmProxies::<T>::contains_key(delegator, delegate));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_xcm_proxy() -> Result<(), BenchmarkError> {
+		let (origin, delegator) = funded_delegator::<T>()?;
+		let delegate = H160::repeat_byte(0xaa);
+		Pallet::<T>::add_xcm_proxy(
+			origin.clone(),
+			delegate,
+			T::XcmProxyType::default(),
+			T::MaxXcmProxyDuration::get(),
+		)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, delegate);
+
+		assert!(!XcmProxies::<T>::contains_key(delegator, delegate));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_xcm_proxy() -> Result<(), BenchmarkError> {
+		let (origin, delegator) = funded_delegator::<T>()?;
+		let delegate = H160::repeat_byte(0xaa);
+		let duration = T::MaxXcmProxyDuration::get();
+		Pallet::<T>::add_xcm_proxy(origin, delegate, T::XcmProxyType::default(), duration)?;
+
+		// Move past the expiry of the proxy
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(
+			now.saturating_add(duration).saturating_add(One::one()),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), delegator, delegate);
+
+		assert!(!XcmProxies::<T>::contains_key(delegator, delegate));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(1).1, crate::mock::Test);
+}
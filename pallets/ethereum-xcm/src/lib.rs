@@ -22,24 +22,35 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::comparison_chain, clippy::large_enum_variant)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
 #[cfg(all(feature = "std", test))]
 mod mock;
 #[cfg(all(feature = "std", test))]
 mod tests;
+pub mod weights;
 
 use ethereum_types::{H160, H256, U256};
 use fp_ethereum::{TransactionData, ValidatedTransaction};
-use fp_evm::{CheckEvmTransaction, CheckEvmTransactionConfig, TransactionValidationError};
+use fp_evm::{
+	CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitReason,
+	TransactionValidationError,
+};
 use frame_support::{
 	dispatch::{DispatchResultWithPostInfo, Pays, PostDispatchInfo},
-	traits::{EnsureOrigin, Get, ProcessMessage},
+	ensure,
+	traits::{ConstU32, Currency, EnsureOrigin, Get, ProcessMessage, ReservableCurrency},
 	weights::Weight,
+	BoundedVec,
 };
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use pallet_evm::{AddressMapping, GasWeightMapping};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::UniqueSaturatedInto, DispatchErrorWithPostInfo, RuntimeDebug};
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	DispatchErrorWithPostInfo, DispatchResult, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 
 pub use ethereum::{
@@ -47,13 +58,31 @@ pub use ethereum::{
 	TransactionAction, TransactionV2 as Transaction,
 };
 pub use fp_rpc::TransactionStatus;
-pub use xcm_primitives::{EnsureProxy, EthereumXcmTransaction, XcmToEthereum};
+pub use weights::WeightInfo;
+pub use xcm_primitives::{
+	EnsureProxy, EthereumXcmTransaction, ManageXcmProxies, XcmProxyCallFilter, XcmToEthereum,
+};
+
+type AccountIdOf<T> =
+	<<T as pallet_evm::Config>::AccountProvider as fp_evm::AccountProvider>::AccountId;
+type BalanceOf<T> = <<T as Config>::XcmProxyCurrency as Currency<AccountIdOf<T>>>::Balance;
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum RawOrigin {
 	XcmEthereumTransaction(H160),
 }
 
+/// A time-limited proxy registered through `add_xcm_proxy`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct XcmProxyDefinition<ProxyType, BlockNumber, Balance> {
+	/// The Ethereum transactions the delegate is allowed to execute
+	pub proxy_type: ProxyType,
+	/// Last block at which the proxy can be used
+	pub expiry: BlockNumber,
+	/// Amount reserved from the delegator
+	pub deposit: Balance,
+}
+
 pub fn ensure_xcm_ethereum_transaction<OuterOrigin>(o: OuterOrigin) -> Result<H160, &'static str>
 where
 	OuterOrigin: Into<Result<RawOrigin, OuterOrigin>>,
@@ -130,6 +159,18 @@ pub mod pallet {
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// An origin that can submit a create tx type
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin that is allowed to register and remove its own XCM proxies
+		type XcmProxyOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = H160>;
+		/// The kinds of Ethereum transactions an XCM proxy can be allowed to execute
+		type XcmProxyType: Parameter + Member + MaxEncodedLen + XcmProxyCallFilter;
+		/// The currency in which the XCM proxy deposits are reserved
+		type XcmProxyCurrency: ReservableCurrency<AccountIdOf<Self>>;
+		/// The amount reserved from the delegator for each XCM proxy
+		type XcmProxyDeposit: Get<BalanceOf<Self>>;
+		/// The maximum number of blocks an XCM proxy can be registered for
+		type MaxXcmProxyDuration: Get<BlockNumberFor<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -146,6 +187,19 @@ pub mod pallet {
 	#[pallet::getter(fn ethereum_xcm_suspended)]
 	pub(super) type EthereumXcmSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Time-limited proxies registered through xcm, by delegator and delegate.
+	#[pallet::storage]
+	#[pallet::getter(fn xcm_proxies)]
+	pub type XcmProxies<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Blake2_128Concat,
+		H160,
+		XcmProxyDefinition<T::XcmProxyType, BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::origin]
 	pub type Origin = RawOrigin;
 
@@ -153,6 +207,26 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Xcm to Ethereum execution is suspended
 		EthereumXcmExecutionSuspended,
+		/// The transaction deadline block has already passed
+		TransactionDeadlineExceeded,
+		/// The transaction nonce does not match the sender's current nonce
+		InvalidTransactionNonce,
+		/// The EVM execution reverted and the transaction asked to fail on failure
+		EthereumExecutionReverted,
+		/// The EVM execution errored and the transaction asked to fail on failure
+		EthereumExecutionFailed,
+		/// The XCM proxy duration is zero or above `MaxXcmProxyDuration`
+		InvalidXcmProxyDuration,
+		/// An account can't be registered as its own XCM proxy
+		NoSelfXcmProxy,
+		/// The XCM proxy was not found
+		XcmProxyNotFound,
+		/// The XCM proxy has expired
+		XcmProxyExpired,
+		/// The XCM proxy has not expired yet
+		XcmProxyNotExpired,
+		/// The transaction is not allowed by the XCM proxy type
+		XcmProxyCallFiltered,
 	}
 
 	#[pallet::event]
@@ -162,7 +236,28 @@ pub mod pallet {
 		ExecutedFromXcm {
 			xcm_msg_hash: H256,
 			eth_tx_hash: H256,
+			/// Address of the deployed contract, if the transaction created one
+			contract_address: Option<H160>,
+		},
+		/// Outcome of the EVM execution of an Ethereum transaction from XCM
+		ExecutionResultFromXcm {
+			eth_tx_hash: H256,
+			exit_reason: ExitReason,
+			used_gas: U256,
+			/// Leading bytes of the data returned by the execution
+			return_data: BoundedVec<u8, ConstU32<MAX_EXECUTION_RESULT_DATA_SIZE>>,
+			/// Decoded revert string, if the execution reverted
+			revert_reason: Option<BoundedVec<u8, ConstU32<MAX_EXECUTION_RESULT_DATA_SIZE>>>,
+		},
+		/// A time-limited proxy has been registered
+		XcmProxyAdded {
+			delegator: H160,
+			delegate: H160,
+			proxy_type: T::XcmProxyType,
+			expiry: BlockNumberFor<T>,
 		},
+		/// A time-limited proxy has been removed and its deposit released
+		XcmProxyRemoved { delegator: H160, delegate: H160 },
 	}
 
 	#[pallet::call]
@@ -172,15 +267,10 @@ pub mod pallet {
 	{
 		/// Xcm Transact an Ethereum transaction.
 		/// Weight: Gas limit plus the db read involving the suspension check
-		#[pallet::weight({
-			let without_base_extrinsic_weight = false;
-			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight({
-				match xcm_transaction {
-					EthereumXcmTransaction::V1(v1_tx) =>  v1_tx.gas_limit.unique_saturated_into(),
-					EthereumXcmTransaction::V2(v2_tx) =>  v2_tx.gas_limit.unique_saturated_into()
-				}
-			}, without_base_extrinsic_weight).saturating_add(T::DbWeight::get().reads(1))
-		})]
+		#[pallet::weight(
+			Pallet::<T>::xcm_transaction_weight(xcm_transaction)
+				.saturating_add(T::DbWeight::get().reads(1))
+		)]
 		pub fn transact(
 			origin: OriginFor<T>,
 			xcm_transaction: EthereumXcmTransaction,
@@ -196,20 +286,17 @@ pub mod pallet {
 					}
 				}
 			);
-			Self::validate_and_apply(source, xcm_transaction, false, None)
+			Self::validate_and_apply(source, xcm_transaction, None)
 		}
 
 		/// Xcm Transact an Ethereum transaction through proxy.
+		/// The proxy is either a time-limited one registered through `add_xcm_proxy`, or an
+		/// `EnsureProxy` one, which is checked when the former is missing, expired or filtered.
 		/// Weight: Gas limit plus the db reads involving the suspension and proxy checks
-		#[pallet::weight({
-			let without_base_extrinsic_weight = false;
-			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight({
-				match xcm_transaction {
-					EthereumXcmTransaction::V1(v1_tx) =>  v1_tx.gas_limit.unique_saturated_into(),
-					EthereumXcmTransaction::V2(v2_tx) =>  v2_tx.gas_limit.unique_saturated_into()
-				}
-			}, without_base_extrinsic_weight).saturating_add(T::DbWeight::get().reads(2))
-		})]
+		#[pallet::weight(
+			Pallet::<T>::xcm_transaction_weight(xcm_transaction)
+				.saturating_add(T::DbWeight::get().reads(3))
+		)]
 		pub fn transact_through_proxy(
 			origin: OriginFor<T>,
 			transact_as: H160,
@@ -226,19 +313,30 @@ pub mod pallet {
 					}
 				}
 			);
-			let _ = T::EnsureProxy::ensure_ok(
-				T::AddressMapping::into_account_id(transact_as),
-				T::AddressMapping::into_account_id(source),
-			)
-			.map_err(|e| sp_runtime::DispatchErrorWithPostInfo {
-				post_info: PostDispatchInfo {
-					actual_weight: Some(T::DbWeight::get().reads(2)),
-					pays_fee: Pays::Yes,
-				},
-				error: sp_runtime::DispatchError::Other(e),
-			})?;
-
-			Self::validate_and_apply(transact_as, xcm_transaction, false, None)
+			// An expired or filtered xcm proxy doesn't prevent the use of an `EnsureProxy` one
+			let xcm_proxy_error = match XcmProxies::<T>::get(transact_as, source) {
+				Some(proxy) => Self::ensure_xcm_proxy_allowed(&proxy, &xcm_transaction).err(),
+				None => Some(Error::<T>::XcmProxyNotFound),
+			};
+			if let Some(xcm_proxy_error) = xcm_proxy_error {
+				T::EnsureProxy::ensure_ok(
+					T::AddressMapping::into_account_id(transact_as),
+					T::AddressMapping::into_account_id(source),
+				)
+				.map_err(|e| sp_runtime::DispatchErrorWithPostInfo {
+					post_info: PostDispatchInfo {
+						actual_weight: Some(T::DbWeight::get().reads(3)),
+						pays_fee: Pays::Yes,
+					},
+					// Report why the existing xcm proxy can't be used, if any
+					error: match xcm_proxy_error {
+						Error::<T>::XcmProxyNotFound => sp_runtime::DispatchError::Other(e),
+						xcm_proxy_error => xcm_proxy_error.into(),
+					},
+				})?;
+			}
+
+			Self::validate_and_apply(transact_as, xcm_transaction, None)
 		}
 
 		/// Suspends all Ethereum executions from XCM.
@@ -266,17 +364,13 @@ pub mod pallet {
 		}
 
 		/// Xcm Transact an Ethereum transaction, but allow to force the caller and create address.
+		/// A forced create address takes precedence over the CREATE2 derived one.
 		/// This call should be restricted (callable only by the runtime or governance).
 		/// Weight: Gas limit plus the db reads involving the suspension and proxy checks
-		#[pallet::weight({
-			let without_base_extrinsic_weight = false;
-			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight({
-				match xcm_transaction {
-					EthereumXcmTransaction::V1(v1_tx) =>  v1_tx.gas_limit.unique_saturated_into(),
-					EthereumXcmTransaction::V2(v2_tx) =>  v2_tx.gas_limit.unique_saturated_into()
-				}
-			}, without_base_extrinsic_weight).saturating_add(T::DbWeight::get().reads(1))
-		})]
+		#[pallet::weight(
+			Pallet::<T>::xcm_transaction_weight(xcm_transaction)
+				.saturating_add(T::DbWeight::get().reads(1))
+		)]
 		pub fn force_transact_as(
 			origin: OriginFor<T>,
 			transact_as: H160,
@@ -295,12 +389,172 @@ pub mod pallet {
 				}
 			);
 
-			Self::validate_and_apply(transact_as, xcm_transaction, true, force_create_address)
+			Self::validate_and_apply(transact_as, xcm_transaction, force_create_address)
+		}
+
+		/// Register `delegate` as a proxy of the origin account for `duration` blocks, allowing
+		/// it to execute through `transact_through_proxy` the Ethereum transactions permitted by
+		/// `proxy_type`. A deposit is reserved from the origin account until the proxy is removed.
+		/// An existing proxy between both accounts is replaced.
+		///
+		/// - `origin`: Must pass `XcmProxyOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::add_xcm_proxy())]
+		pub fn add_xcm_proxy(
+			origin: OriginFor<T>,
+			delegate: H160,
+			proxy_type: T::XcmProxyType,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let delegator = T::XcmProxyOrigin::ensure_origin(origin)?;
+
+			<Self as ManageXcmProxies<_, _>>::add_xcm_proxy(
+				delegator, delegate, proxy_type, duration,
+			)
+		}
+
+		/// Unregister `delegate` as a proxy of the origin account and release the deposit.
+		///
+		/// - `origin`: Must pass `XcmProxyOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_xcm_proxy())]
+		pub fn remove_xcm_proxy(origin: OriginFor<T>, delegate: H160) -> DispatchResult {
+			let delegator = T::XcmProxyOrigin::ensure_origin(origin)?;
+
+			<Self as ManageXcmProxies<_, _>>::remove_xcm_proxy(delegator, delegate)
+		}
+
+		/// Remove an expired proxy and release the deposit to its delegator.
+		/// Can be called by any signed account.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_expired_xcm_proxy())]
+		pub fn remove_expired_xcm_proxy(
+			origin: OriginFor<T>,
+			delegator: H160,
+			delegate: H160,
+		) -> DispatchResult {
+			frame_system::ensure_signed(origin)?;
+
+			let proxy =
+				XcmProxies::<T>::get(delegator, delegate).ok_or(Error::<T>::XcmProxyNotFound)?;
+			ensure!(
+				proxy.expiry < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::XcmProxyNotExpired
+			);
+
+			<Self as ManageXcmProxies<_, _>>::remove_xcm_proxy(delegator, delegate)
+		}
+	}
+}
+
+impl<T: Config> ManageXcmProxies<T::XcmProxyType, BlockNumberFor<T>> for Pallet<T> {
+	fn add_xcm_proxy(
+		delegator: H160,
+		delegate: H160,
+		proxy_type: T::XcmProxyType,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(
+			!duration.is_zero() && duration <= T::MaxXcmProxyDuration::get(),
+			Error::<T>::InvalidXcmProxyDuration
+		);
+		ensure!(delegator != delegate, Error::<T>::NoSelfXcmProxy);
+
+		// The deposit of a replaced proxy is adjusted to the current one
+		let who = T::AddressMapping::into_account_id(delegator);
+		let deposit = T::XcmProxyDeposit::get();
+		let old_deposit = XcmProxies::<T>::get(delegator, delegate)
+			.map(|proxy| proxy.deposit)
+			.unwrap_or_else(Zero::zero);
+		if deposit > old_deposit {
+			T::XcmProxyCurrency::reserve(&who, deposit.saturating_sub(old_deposit))?;
+		} else {
+			T::XcmProxyCurrency::unreserve(&who, old_deposit.saturating_sub(deposit));
 		}
+
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+		XcmProxies::<T>::insert(
+			delegator,
+			delegate,
+			XcmProxyDefinition {
+				proxy_type: proxy_type.clone(),
+				expiry,
+				deposit,
+			},
+		);
+
+		Self::deposit_event(Event::XcmProxyAdded {
+			delegator,
+			delegate,
+			proxy_type,
+			expiry,
+		});
+
+		Ok(())
+	}
+
+	fn remove_xcm_proxy(delegator: H160, delegate: H160) -> DispatchResult {
+		let proxy =
+			XcmProxies::<T>::take(delegator, delegate).ok_or(Error::<T>::XcmProxyNotFound)?;
+		T::XcmProxyCurrency::unreserve(
+			&T::AddressMapping::into_account_id(delegator),
+			proxy.deposit,
+		);
+
+		Self::deposit_event(Event::XcmProxyRemoved {
+			delegator,
+			delegate,
+		});
+
+		Ok(())
 	}
 }
 
+/// Max. size of the return data and revert reason reported in `ExecutionResultFromXcm`.
+pub const MAX_EXECUTION_RESULT_DATA_SIZE: u32 = 256;
+
+/// Gas charged per 32 bytes word of init code (EIP-3860).
+pub const INIT_CODE_WORD_GAS: u64 = 2;
+/// Gas charged per 32 bytes word of init code hashed to derive a CREATE2 address.
+pub const INIT_CODE_HASH_WORD_GAS: u64 = 6;
+
 impl<T: Config> Pallet<T> {
+	/// Ensure that `proxy` has not expired and allows to execute `xcm_transaction`.
+	fn ensure_xcm_proxy_allowed(
+		proxy: &XcmProxyDefinition<T::XcmProxyType, BlockNumberFor<T>, BalanceOf<T>>,
+		xcm_transaction: &EthereumXcmTransaction,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= proxy.expiry,
+			Error::<T>::XcmProxyExpired
+		);
+		ensure!(
+			proxy.proxy_type.is_xcm_proxy_call_allowed(xcm_transaction),
+			Error::<T>::XcmProxyCallFiltered
+		);
+		Ok(())
+	}
+
+	/// Weight of the gas limit, plus the init code processing for contract creations.
+	fn xcm_transaction_weight(xcm_transaction: &EthereumXcmTransaction) -> Weight {
+		let without_base_extrinsic_weight = false;
+		let gas_limit: u64 = xcm_transaction.gas_limit().unique_saturated_into();
+		let init_code_gas = match xcm_transaction.init_code_len() {
+			Some(init_code_len) => {
+				let words = (init_code_len as u64).saturating_add(31) / 32;
+				let word_gas = match xcm_transaction {
+					EthereumXcmTransaction::Create2(_) => {
+						INIT_CODE_WORD_GAS.saturating_add(INIT_CODE_HASH_WORD_GAS)
+					}
+					_ => INIT_CODE_WORD_GAS,
+				};
+				words.saturating_mul(word_gas)
+			}
+			None => 0,
+		};
+		<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+			gas_limit.saturating_add(init_code_gas),
+			without_base_extrinsic_weight,
+		)
+	}
+
 	fn transaction_len(transaction: &Transaction) -> u64 {
 		transaction
 			.encode()
@@ -312,7 +566,6 @@ impl<T: Config> Pallet<T> {
 	fn validate_and_apply(
 		source: H160,
 		xcm_transaction: EthereumXcmTransaction,
-		allow_create: bool,
 		maybe_force_create_address: Option<H160>,
 	) -> DispatchResultWithPostInfo {
 		// The lack of a real signature where different callers with the
@@ -321,10 +574,52 @@ impl<T: Config> Pallet<T> {
 		// We use a global nonce instead the user nonce for all Xcm->Ethereum transactions to avoid
 		// this.
 		let current_nonce = Self::nonce();
-		let error_weight = T::DbWeight::get().reads(1);
+		let mut error_weight = T::DbWeight::get().reads(1);
+
+		if let EthereumXcmTransaction::V3(ref v3_tx) = xcm_transaction {
+			if let Some(deadline) = v3_tx.deadline {
+				let now: u32 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+				ensure!(
+					now <= deadline,
+					DispatchErrorWithPostInfo {
+						error: Error::<T>::TransactionDeadlineExceeded.into(),
+						post_info: PostDispatchInfo {
+							actual_weight: Some(error_weight),
+							pays_fee: Pays::Yes
+						}
+					}
+				);
+			}
+			// The explicit nonce is checked against the sender's own nonce, the transaction
+			// payload still uses the global nonce to avoid hash collisions.
+			if let Some(nonce) = v3_tx.nonce {
+				error_weight = T::DbWeight::get().reads(2);
+				let (account, _) = pallet_evm::Pallet::<T>::account_basic(&source);
+				ensure!(
+					account.nonce == nonce,
+					DispatchErrorWithPostInfo {
+						error: Error::<T>::InvalidTransactionNonce.into(),
+						post_info: PostDispatchInfo {
+							actual_weight: Some(error_weight),
+							pays_fee: Pays::Yes
+						}
+					}
+				);
+			}
+		}
 
 		let transaction: Option<Transaction> =
-			xcm_transaction.into_transaction_v2(current_nonce, T::ChainId::get(), allow_create);
+			xcm_transaction.into_transaction_v2(current_nonce, T::ChainId::get());
+		let maybe_force_create_address = match xcm_transaction {
+			EthereumXcmTransaction::Create2(ref create2_tx) => {
+				maybe_force_create_address.or(Some(create2_tx.contract_address(source)))
+			}
+			_ => maybe_force_create_address,
+		};
+		let revert_on_failure = matches!(
+			xcm_transaction,
+			EthereumXcmTransaction::V3(ref v3_tx) if v3_tx.revert_on_failure
+		);
 		if let Some(transaction) = transaction {
 			let tx_hash = transaction.hash();
 			let transaction_data: TransactionData = (&transaction).into();
@@ -372,13 +667,63 @@ impl<T: Config> Pallet<T> {
 			// transaction on chain - we increase the global nonce.
 			<Nonce<T>>::put(current_nonce.saturating_add(U256::one()));
 
-			let (dispatch_info, _) =
+			let (dispatch_info, execution_info) =
 				T::ValidatedTransaction::apply(source, transaction, maybe_force_create_address)?;
 
+			let (exit_reason, used_gas, return_data, contract_address) = match execution_info {
+				CallOrCreateInfo::Call(call_info) => (
+					call_info.exit_reason,
+					call_info.used_gas.standard,
+					call_info.value,
+					None,
+				),
+				CallOrCreateInfo::Create(create_info) => {
+					let contract_address = if create_info.exit_reason.is_succeed() {
+						Some(create_info.value)
+					} else {
+						None
+					};
+					(
+						create_info.exit_reason,
+						create_info.used_gas.standard,
+						Vec::new(),
+						contract_address,
+					)
+				}
+			};
+
+			// Failing the call rolls back the execution, the dispatch error is what gets
+			// reported back to the origin by `ReportTransactStatus`.
+			if revert_on_failure && !exit_reason.is_succeed() {
+				let error = match exit_reason {
+					ExitReason::Revert(_) => Error::<T>::EthereumExecutionReverted,
+					_ => Error::<T>::EthereumExecutionFailed,
+				};
+				return Err(DispatchErrorWithPostInfo {
+					post_info: dispatch_info,
+					error: error.into(),
+				});
+			}
+
+			let revert_reason = match exit_reason {
+				ExitReason::Revert(_) => Some(BoundedVec::truncate_from(
+					xcm_primitives::extract_revert_message(&return_data).into_bytes(),
+				)),
+				_ => None,
+			};
+			Self::deposit_event(Event::ExecutionResultFromXcm {
+				eth_tx_hash: tx_hash,
+				exit_reason,
+				used_gas,
+				return_data: BoundedVec::truncate_from(return_data),
+				revert_reason,
+			});
+
 			XCM_MESSAGE_HASH::with(|xcm_msg_hash| {
 				Self::deposit_event(Event::ExecutedFromXcm {
 					xcm_msg_hash: *xcm_msg_hash,
 					eth_tx_hash: tx_hash,
+					contract_address,
 				});
 			});
 
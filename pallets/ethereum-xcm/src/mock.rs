@@ -228,6 +228,15 @@ pub enum ProxyType {
 
 impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {}
 
+impl crate::XcmProxyCallFilter for ProxyType {
+	fn is_xcm_proxy_call_allowed(&self, _transaction: &EthereumXcmTransaction) -> bool {
+		match self {
+			ProxyType::NotAllowed => false,
+			ProxyType::Any => true,
+		}
+	}
+}
+
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, _c: &RuntimeCall) -> bool {
 		match self {
@@ -280,6 +289,11 @@ impl xcm_primitives::EnsureProxy<AccountId32> for EthereumXcmEnsureProxy {
 	}
 }
 
+parameter_types! {
+	pub const XcmProxyDeposit: u64 = 100;
+	pub const MaxXcmProxyDuration: BlockNumber = 10;
+}
+
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
@@ -289,6 +303,12 @@ impl crate::Config for Test {
 	type EnsureProxy = EthereumXcmEnsureProxy;
 	type ControllerOrigin = EnsureRoot<AccountId32>;
 	type ForceOrigin = EnsureRoot<AccountId32>;
+	type XcmProxyOrigin = crate::EnsureXcmEthereumTransaction;
+	type XcmProxyType = ProxyType;
+	type XcmProxyCurrency = Balances;
+	type XcmProxyDeposit = XcmProxyDeposit;
+	type MaxXcmProxyDuration = MaxXcmProxyDuration;
+	type WeightInfo = ();
 }
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -0,0 +1,193 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+use crate::{mock::*, RawOrigin};
+use ethereum_types::{H160, H256, U256};
+use frame_support::{
+	assert_ok,
+	dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+	traits::ConstU32,
+	BoundedVec,
+};
+use xcm_primitives::{
+	EthereumXcmCreate2Transaction, EthereumXcmTransaction, EthereumXcmTransactionV2,
+};
+
+// 	pragma solidity ^0.6.6;
+// 	contract Test {
+// 		function foo() external pure returns (bool) {
+// 			return true;
+// 		}
+// 		function bar() external pure {
+// 			require(false, "error_msg");
+// 		}
+// 	}
+const CONTRACT: &str = "608060405234801561001057600080fd5b50610113806100206000396000f3fe6080604052\
+						348015600f57600080fd5b506004361060325760003560e01c8063c2985578146037578063\
+						febb0f7e146057575b600080fd5b603d605f565b6040518082151515158152602001915050\
+						60405180910390f35b605d6068565b005b60006001905090565b600060db576040517f08c3\
+						79a00000000000000000000000000000000000000000000000000000000081526004018080\
+						602001828103825260098152602001807f6572726f725f6d73670000000000000000000000\
+						00000000000000000000000081525060200191505060405180910390fd5b56fea264697066\
+						7358221220fde68a3968e0e99b16fabf9b2997a78218b32214031f8e07e2c502daf603a69e\
+						64736f6c63430006060033";
+
+fn xcm_create2_transaction(salt: H256) -> EthereumXcmCreate2Transaction {
+	EthereumXcmCreate2Transaction {
+		gas_limit: U256::from(0x100000),
+		value: U256::zero(),
+		salt,
+		init_code:
+			BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::try_from(
+				hex::decode(CONTRACT).unwrap(),
+			)
+			.unwrap(),
+		access_list: None,
+	}
+}
+
+// `ExecutedFromXcm` is only deposited while processing an xcm message.
+fn transact_from_xcm(
+	source: H160,
+	xcm_transaction: EthereumXcmTransaction,
+) -> DispatchResultWithPostInfo {
+	crate::XCM_MESSAGE_HASH::using(&mut H256::zero(), || {
+		EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(source).into(),
+			xcm_transaction,
+		)
+	})
+}
+
+fn last_contract_address() -> Option<H160> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|r| match r.event {
+			RuntimeEvent::EthereumXcm(crate::Event::ExecutedFromXcm {
+				contract_address, ..
+			}) => Some(contract_address),
+			_ => None,
+		})
+		.expect("ExecutedFromXcm event not found")
+}
+
+#[test]
+fn test_transact_xcm_create2() {
+	let (pairs, mut ext) = new_test_ext(1);
+	let alice = &pairs[0];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let create2_tx = xcm_create2_transaction(H256::repeat_byte(1));
+		let expected_address = create2_tx.contract_address(alice.address);
+
+		assert_ok!(transact_from_xcm(
+			alice.address,
+			EthereumXcmTransaction::Create2(create2_tx),
+		));
+
+		assert!(pallet_evm::AccountCodes::<Test>::contains_key(
+			expected_address
+		));
+		assert_eq!(last_contract_address(), Some(expected_address));
+	});
+}
+
+#[test]
+fn test_transact_xcm_create2_through_proxy_uses_transact_as() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let _ =
+			Proxy::add_proxy_delegate(&bob.account_id, alice.account_id.clone(), ProxyType::Any, 0);
+		let create2_tx = xcm_create2_transaction(H256::repeat_byte(1));
+		let expected_address = create2_tx.contract_address(bob.address);
+
+		assert_ok!(crate::XCM_MESSAGE_HASH::using(&mut H256::zero(), || {
+			EthereumXcm::transact_through_proxy(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				bob.address,
+				EthereumXcmTransaction::Create2(create2_tx),
+			)
+		}));
+
+		assert!(pallet_evm::AccountCodes::<Test>::contains_key(
+			expected_address
+		));
+		assert_eq!(last_contract_address(), Some(expected_address));
+	});
+}
+
+#[test]
+fn test_transact_xcm_create2_same_salt_collides() {
+	let (pairs, mut ext) = new_test_ext(1);
+	let alice = &pairs[0];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(transact_from_xcm(
+			alice.address,
+			EthereumXcmTransaction::Create2(xcm_create2_transaction(H256::repeat_byte(1))),
+		));
+
+		// Evm create failing still succesfully dispatched, but no contract is reported
+		assert_ok!(transact_from_xcm(
+			alice.address,
+			EthereumXcmTransaction::Create2(xcm_create2_transaction(H256::repeat_byte(1))),
+		));
+		assert_eq!(last_contract_address(), None);
+
+		// A different salt deploys a new contract
+		let create2_tx = xcm_create2_transaction(H256::repeat_byte(2));
+		let expected_address = create2_tx.contract_address(alice.address);
+		assert_ok!(transact_from_xcm(
+			alice.address,
+			EthereumXcmTransaction::Create2(create2_tx),
+		));
+		assert_eq!(last_contract_address(), Some(expected_address));
+	});
+}
+
+#[test]
+fn test_transact_xcm_create2_weight_accounts_init_code() {
+	let create2_tx = xcm_create2_transaction(H256::zero());
+	let call_tx = EthereumXcmTransactionV2 {
+		gas_limit: create2_tx.gas_limit,
+		action: ethereum::TransactionAction::Call(H160::default()),
+		value: U256::zero(),
+		input: create2_tx.init_code.clone(),
+		access_list: None,
+	};
+	let create_tx = EthereumXcmTransactionV2 {
+		action: ethereum::TransactionAction::Create,
+		..call_tx.clone()
+	};
+
+	let weight_of = |xcm_transaction| {
+		crate::Call::<Test>::transact { xcm_transaction }
+			.get_dispatch_info()
+			.weight
+	};
+	let call_weight = weight_of(EthereumXcmTransaction::V2(call_tx));
+	let create_weight = weight_of(EthereumXcmTransaction::V2(create_tx));
+	let create2_weight = weight_of(EthereumXcmTransaction::Create2(create2_tx));
+
+	assert!(create_weight.ref_time() > call_weight.ref_time());
+	assert!(create2_weight.ref_time() > create_weight.ref_time());
+}
@@ -14,5 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
+mod create2;
 mod v1;
 mod v2;
+mod v3;
+mod xcm_proxy;
@@ -113,19 +113,16 @@ fn test_transact_xcm_create() {
 	let alice = &pairs[0];
 
 	ext.execute_with(|| {
-		assert_noop!(
-			EthereumXcm::transact(
-				RawOrigin::XcmEthereumTransaction(alice.address).into(),
-				xcm_erc20_creation_eip_1559_transaction()
-			),
-			DispatchErrorWithPostInfo {
-				post_info: PostDispatchInfo {
-					actual_weight: Some(Weight::zero()),
-					pays_fee: Pays::Yes,
-				},
-				error: DispatchError::Other("Cannot convert xcm payload to known type"),
-			}
-		);
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_erc20_creation_eip_1559_transaction()
+		));
+
+		let contract_address =
+			H160::from_slice(&hex::decode("32dcab0ef3fb2de2fce1d2e0799d36239671f04a").unwrap());
+		assert!(pallet_evm::AccountCodes::<Test>::contains_key(
+			contract_address
+		));
 	});
 }
 
@@ -0,0 +1,396 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+use crate::{mock::*, Error, RawOrigin};
+use ethereum_types::{H160, U256};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{Pays, PostDispatchInfo},
+	traits::{ConstU32, Get},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{traits::Dispatchable, DispatchError, DispatchErrorWithPostInfo};
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV3};
+
+// 	pragma solidity ^0.6.6;
+// 	contract Test {
+// 		function foo() external pure returns (bool) {
+// 			return true;
+// 		}
+// 		function bar() external pure {
+// 			require(false, "error_msg");
+// 		}
+// 	}
+const CONTRACT: &str = "608060405234801561001057600080fd5b50610113806100206000396000f3fe6080604052\
+						348015600f57600080fd5b506004361060325760003560e01c8063c2985578146037578063\
+						febb0f7e146057575b600080fd5b603d605f565b6040518082151515158152602001915050\
+						60405180910390f35b605d6068565b005b60006001905090565b600060db576040517f08c3\
+						79a00000000000000000000000000000000000000000000000000000000081526004018080\
+						602001828103825260098152602001807f6572726f725f6d73670000000000000000000000\
+						00000000000000000000000081525060200191505060405180910390fd5b56fea264697066\
+						7358221220fde68a3968e0e99b16fabf9b2997a78218b32214031f8e07e2c502daf603a69e\
+						64736f6c63430006060033";
+
+fn xcm_evm_transfer_v3_transaction(
+	destination: H160,
+	value: U256,
+	nonce: Option<U256>,
+	deadline: Option<u32>,
+) -> EthereumXcmTransaction {
+	EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+		gas_limit: U256::from(0x5208),
+		action: ethereum::TransactionAction::Call(destination),
+		value,
+		input:
+			BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::try_from(
+				vec![],
+			)
+			.unwrap(),
+		access_list: None,
+		max_fee_per_gas: U256::zero(),
+		max_priority_fee_per_gas: U256::zero(),
+		nonce,
+		deadline,
+		revert_on_failure: false,
+	})
+}
+
+fn xcm_evm_call_v3_transaction(
+	destination: H160,
+	input: Vec<u8>,
+	revert_on_failure: bool,
+) -> EthereumXcmTransaction {
+	EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+		gas_limit: U256::from(0x100000),
+		action: ethereum::TransactionAction::Call(destination),
+		value: U256::zero(),
+		input:
+			BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::try_from(
+				input,
+			)
+			.unwrap(),
+		access_list: None,
+		max_fee_per_gas: U256::zero(),
+		max_priority_fee_per_gas: U256::zero(),
+		nonce: None,
+		deadline: None,
+		revert_on_failure,
+	})
+}
+
+fn deploy_contract(deployer: &AccountInfo) -> H160 {
+	let t = EIP1559UnsignedTransaction {
+		nonce: U256::zero(),
+		max_priority_fee_per_gas: U256::one(),
+		max_fee_per_gas: U256::one(),
+		gas_limit: U256::from(0x100000),
+		action: ethereum::TransactionAction::Create,
+		value: U256::zero(),
+		input: hex::decode(CONTRACT).unwrap(),
+	}
+	.sign(&deployer.private_key, None);
+	assert_ok!(Ethereum::execute(deployer.address, &t, None, None));
+
+	H160::from_slice(&hex::decode("32dcab0ef3fb2de2fce1d2e0799d36239671f04a").unwrap())
+}
+
+fn last_execution_result() -> Option<crate::Event<Test>> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|r| match r.event {
+			RuntimeEvent::EthereumXcm(event @ crate::Event::ExecutionResultFromXcm { .. }) => {
+				Some(event)
+			}
+			_ => None,
+		})
+}
+
+#[test]
+fn test_transact_xcm_evm_transfer() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		let balances_before = System::account(&bob.account_id);
+		EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_evm_transfer_v3_transaction(bob.address, U256::from(100), None, None),
+		)
+		.expect("Failed to execute transaction");
+
+		assert_eq!(
+			System::account(&bob.account_id).data.free,
+			balances_before.data.free + 100
+		);
+	});
+}
+
+#[test]
+fn test_transact_xcm_before_deadline_works() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(10);
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_evm_transfer_v3_transaction(bob.address, U256::from(100), None, Some(10)),
+		));
+	});
+}
+
+#[test]
+fn test_transact_xcm_after_deadline_fails() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	let db_weights: frame_support::weights::RuntimeDbWeight =
+		<Test as frame_system::Config>::DbWeight::get();
+
+	ext.execute_with(|| {
+		System::set_block_number(11);
+		assert_noop!(
+			EthereumXcm::transact(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				xcm_evm_transfer_v3_transaction(bob.address, U256::from(100), None, Some(10)),
+			),
+			DispatchErrorWithPostInfo {
+				error: Error::<Test>::TransactionDeadlineExceeded.into(),
+				post_info: PostDispatchInfo {
+					actual_weight: Some(db_weights.reads(1)),
+					pays_fee: Pays::Yes
+				}
+			}
+		);
+	});
+}
+
+#[test]
+fn test_transact_xcm_with_explicit_nonce_works() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_evm_transfer_v3_transaction(bob.address, U256::one(), Some(U256::zero()), None),
+		));
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_evm_transfer_v3_transaction(bob.address, U256::one(), Some(U256::one()), None),
+		));
+
+		// The global nonce is still used for the transaction payload.
+		assert_eq!(EthereumXcm::nonce(), U256::from(2));
+	});
+}
+
+#[test]
+fn test_transact_xcm_with_wrong_nonce_fails() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	let db_weights: frame_support::weights::RuntimeDbWeight =
+		<Test as frame_system::Config>::DbWeight::get();
+
+	ext.execute_with(|| {
+		assert_noop!(
+			EthereumXcm::transact(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				xcm_evm_transfer_v3_transaction(bob.address, U256::one(), Some(U256::one()), None),
+			),
+			DispatchErrorWithPostInfo {
+				error: Error::<Test>::InvalidTransactionNonce.into(),
+				post_info: PostDispatchInfo {
+					actual_weight: Some(db_weights.reads(2)),
+					pays_fee: Pays::Yes
+				}
+			}
+		);
+	});
+}
+
+#[test]
+fn test_transact_xcm_through_proxy_checks_transact_as_nonce() {
+	let (pairs, mut ext) = new_test_ext(3);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+	let charlie = &pairs[2];
+
+	ext.execute_with(|| {
+		let _ =
+			Proxy::add_proxy_delegate(&bob.account_id, alice.account_id.clone(), ProxyType::Any, 0);
+
+		// Alice's nonce is irrelevant, the transaction is applied as Bob.
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			xcm_evm_transfer_v3_transaction(charlie.address, U256::one(), None, None),
+		));
+
+		assert_ok!(EthereumXcm::transact_through_proxy(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			bob.address,
+			xcm_evm_transfer_v3_transaction(
+				charlie.address,
+				U256::from(100),
+				Some(U256::zero()),
+				None
+			),
+		));
+	});
+}
+
+#[test]
+fn test_transact_xcm_invalid_priority_fee() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		assert_noop!(
+			EthereumXcm::transact(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+					gas_limit: U256::from(0x5208),
+					action: ethereum::TransactionAction::Call(bob.address),
+					value: U256::one(),
+					input: BoundedVec::<
+						u8,
+						ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>,
+					>::try_from(vec![])
+					.unwrap(),
+					access_list: None,
+					max_fee_per_gas: U256::zero(),
+					max_priority_fee_per_gas: U256::one(),
+					nonce: None,
+					deadline: None,
+					revert_on_failure: false,
+				}),
+			),
+			DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo {
+					actual_weight: Some(Weight::zero()),
+					pays_fee: Pays::Yes,
+				},
+				error: DispatchError::Other("Cannot convert xcm payload to known type"),
+			}
+		);
+	});
+}
+
+#[test]
+fn test_transact_xcm_execution_result_return_data() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let contract_address = deploy_contract(alice);
+		let foo = hex::decode("c2985578").unwrap();
+
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			xcm_evm_call_v3_transaction(contract_address, foo, false),
+		));
+
+		match last_execution_result() {
+			Some(crate::Event::ExecutionResultFromXcm {
+				exit_reason,
+				used_gas,
+				return_data,
+				revert_reason,
+				..
+			}) => {
+				assert!(exit_reason.is_succeed());
+				assert!(!used_gas.is_zero());
+				// `foo` returns true, abi encoded as a 32 bytes word
+				let mut expected = vec![0u8; 32];
+				expected[31] = 1;
+				assert_eq!(return_data.into_inner(), expected);
+				assert_eq!(revert_reason, None);
+			}
+			_ => panic!("ExecutionResultFromXcm event not found"),
+		}
+	});
+}
+
+#[test]
+fn test_transact_xcm_execution_result_revert_reason() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let contract_address = deploy_contract(alice);
+		let bar = hex::decode("febb0f7e").unwrap();
+
+		// Evm call failing still succesfully dispatched
+		assert_ok!(EthereumXcm::transact(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			xcm_evm_call_v3_transaction(contract_address, bar, false),
+		));
+
+		match last_execution_result() {
+			Some(crate::Event::ExecutionResultFromXcm {
+				exit_reason,
+				revert_reason,
+				..
+			}) => {
+				assert!(matches!(exit_reason, fp_evm::ExitReason::Revert(_)));
+				assert_eq!(
+					revert_reason.map(|reason| reason.into_inner()),
+					Some(b"VM Exception while processing transaction: revert error_msg".to_vec())
+				);
+			}
+			_ => panic!("ExecutionResultFromXcm event not found"),
+		}
+	});
+}
+
+#[test]
+fn test_transact_xcm_revert_on_failure() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let contract_address = deploy_contract(alice);
+		let bar = hex::decode("febb0f7e").unwrap();
+		let nonce_before = EthereumXcm::nonce();
+
+		// Dispatched as the xcm executor does, so the failed call is rolled back
+		let call = RuntimeCall::EthereumXcm(crate::Call::transact {
+			xcm_transaction: xcm_evm_call_v3_transaction(contract_address, bar, true),
+		});
+		let result = call.dispatch(RawOrigin::XcmEthereumTransaction(bob.address).into());
+
+		assert_eq!(
+			result.unwrap_err().error,
+			Error::<Test>::EthereumExecutionReverted.into()
+		);
+		assert_eq!(EthereumXcm::nonce(), nonce_before);
+		assert_eq!(last_execution_result(), None);
+	});
+}
@@ -0,0 +1,254 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+use crate::{mock::*, Error, RawOrigin, XcmProxies};
+use ethereum_types::{H160, U256};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{ConstU32, Get},
+	BoundedVec,
+};
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2};
+
+fn xcm_evm_transfer_eip_1559_transaction(destination: H160, value: U256) -> EthereumXcmTransaction {
+	EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+		gas_limit: U256::from(0x5208),
+		action: ethereum::TransactionAction::Call(destination),
+		value,
+		input:
+			BoundedVec::<u8, ConstU32<{ xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE }>>::try_from(
+				vec![],
+			)
+			.unwrap(),
+		access_list: None,
+	})
+}
+
+#[test]
+fn test_add_and_remove_xcm_proxy() {
+	let (pairs, mut ext) = new_test_ext(2);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			EthereumXcm::add_xcm_proxy(
+				RawOrigin::XcmEthereumTransaction(bob.address).into(),
+				alice.address,
+				ProxyType::Any,
+				MaxXcmProxyDuration::get() + 1,
+			),
+			Error::<Test>::InvalidXcmProxyDuration
+		);
+		assert_noop!(
+			EthereumXcm::add_xcm_proxy(
+				RawOrigin::XcmEthereumTransaction(bob.address).into(),
+				bob.address,
+				ProxyType::Any,
+				5,
+			),
+			Error::<Test>::NoSelfXcmProxy
+		);
+
+		assert_ok!(EthereumXcm::add_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+			ProxyType::Any,
+			5,
+		));
+		let proxy = XcmProxies::<Test>::get(bob.address, alice.address).expect("proxy registered");
+		assert_eq!(proxy.expiry, 6);
+		assert_eq!(proxy.deposit, XcmProxyDeposit::get());
+		assert_eq!(
+			Balances::reserved_balance(&bob.account_id),
+			XcmProxyDeposit::get()
+		);
+
+		// Replacing the proxy doesn't reserve a second deposit
+		assert_ok!(EthereumXcm::add_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+			ProxyType::NotAllowed,
+			10,
+		));
+		assert_eq!(
+			Balances::reserved_balance(&bob.account_id),
+			XcmProxyDeposit::get()
+		);
+
+		assert_ok!(EthereumXcm::remove_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+		));
+		assert!(XcmProxies::<Test>::get(bob.address, alice.address).is_none());
+		assert_eq!(Balances::reserved_balance(&bob.account_id), 0);
+
+		assert_noop!(
+			EthereumXcm::remove_xcm_proxy(
+				RawOrigin::XcmEthereumTransaction(bob.address).into(),
+				alice.address,
+			),
+			Error::<Test>::XcmProxyNotFound
+		);
+	});
+}
+
+#[test]
+fn test_transact_through_xcm_proxy() {
+	let (pairs, mut ext) = new_test_ext(3);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+	let charlie = &pairs[2];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		// Bob allows Alice to execute his transactions for 5 blocks
+		assert_ok!(EthereumXcm::add_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+			ProxyType::Any,
+			5,
+		));
+
+		let bob_before = System::account(&bob.account_id);
+		let charlie_before = System::account(&charlie.account_id);
+
+		assert_ok!(EthereumXcm::transact_through_proxy(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			bob.address,
+			xcm_evm_transfer_eip_1559_transaction(charlie.address, U256::from(100)),
+		));
+		assert_eq!(
+			System::account(&bob.account_id).data.free,
+			bob_before.data.free - 100
+		);
+		assert_eq!(
+			System::account(&charlie.account_id).data.free,
+			charlie_before.data.free + 100
+		);
+
+		// The expired proxy can't be used anymore
+		System::set_block_number(7);
+		assert_eq!(
+			EthereumXcm::transact_through_proxy(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				bob.address,
+				xcm_evm_transfer_eip_1559_transaction(charlie.address, U256::from(100)),
+			)
+			.unwrap_err()
+			.error,
+			Error::<Test>::XcmProxyExpired.into()
+		);
+
+		// Anyone can remove the expired proxy, releasing the deposit to Bob
+		assert_ok!(EthereumXcm::remove_expired_xcm_proxy(
+			RuntimeOrigin::signed(charlie.account_id.clone()),
+			bob.address,
+			alice.address,
+		));
+		assert!(XcmProxies::<Test>::get(bob.address, alice.address).is_none());
+		assert_eq!(Balances::reserved_balance(&bob.account_id), 0);
+	});
+}
+
+#[test]
+fn test_transact_through_xcm_proxy_filtered() {
+	let (pairs, mut ext) = new_test_ext(3);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+	let charlie = &pairs[2];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(EthereumXcm::add_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+			ProxyType::NotAllowed,
+			5,
+		));
+
+		assert_eq!(
+			EthereumXcm::transact_through_proxy(
+				RawOrigin::XcmEthereumTransaction(alice.address).into(),
+				bob.address,
+				xcm_evm_transfer_eip_1559_transaction(charlie.address, U256::from(100)),
+			)
+			.unwrap_err()
+			.error,
+			Error::<Test>::XcmProxyCallFiltered.into()
+		);
+
+		// The proxy has not expired yet
+		assert_noop!(
+			EthereumXcm::remove_expired_xcm_proxy(
+				RuntimeOrigin::signed(charlie.account_id.clone()),
+				bob.address,
+				alice.address,
+			),
+			Error::<Test>::XcmProxyNotExpired
+		);
+	});
+}
+
+#[test]
+fn test_transact_through_proxy_falls_back_to_ensure_proxy() {
+	let (pairs, mut ext) = new_test_ext(3);
+	let alice = &pairs[0];
+	let bob = &pairs[1];
+	let charlie = &pairs[2];
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(EthereumXcm::add_xcm_proxy(
+			RawOrigin::XcmEthereumTransaction(bob.address).into(),
+			alice.address,
+			ProxyType::NotAllowed,
+			5,
+		));
+		assert_ok!(Proxy::add_proxy_delegate(
+			&bob.account_id,
+			alice.account_id.clone(),
+			ProxyType::Any,
+			0
+		));
+
+		let bob_before = System::account(&bob.account_id);
+
+		// The filtered xcm proxy doesn't prevent the use of the regular proxy
+		assert_ok!(EthereumXcm::transact_through_proxy(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			bob.address,
+			xcm_evm_transfer_eip_1559_transaction(charlie.address, U256::from(100)),
+		));
+
+		// Neither does the expired one
+		System::set_block_number(7);
+		assert_ok!(EthereumXcm::transact_through_proxy(
+			RawOrigin::XcmEthereumTransaction(alice.address).into(),
+			bob.address,
+			xcm_evm_transfer_eip_1559_transaction(charlie.address, U256::from(100)),
+		));
+
+		assert_eq!(
+			System::account(&bob.account_id).data.free,
+			bob_before.data.free - 200
+		);
+	});
+}
@@ -0,0 +1,100 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Weights for pallet_ethereum_xcm
+//!
+//! The xcm proxy calls have benchmarks in `benchmarks.rs`, but this file was written by hand and
+//! not generated from them. Each call reads and writes one `XcmProxies` entry and the account
+//! holding the proxy deposit. The base weights are estimates and should be replaced by the output
+//! of the bencher.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ethereum_xcm.
+pub trait WeightInfo {
+	fn add_xcm_proxy() -> Weight;
+	fn remove_xcm_proxy() -> Weight;
+	fn remove_expired_xcm_proxy() -> Weight;
+}
+
+/// Weights for pallet_ethereum_xcm using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn add_xcm_proxy() -> Weight {
+		Weight::from_parts(30_000_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn remove_xcm_proxy() -> Weight {
+		Weight::from_parts(27_000_000, 3727)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn remove_expired_xcm_proxy() -> Weight {
+		Weight::from_parts(28_000_000, 3727)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn add_xcm_proxy() -> Weight {
+		Weight::from_parts(30_000_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn remove_xcm_proxy() -> Weight {
+		Weight::from_parts(27_000_000, 3727)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumXcm::XcmProxies` (r:1 w:1)
+	/// Proof: `EthereumXcm::XcmProxies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn remove_expired_xcm_proxy() -> Weight {
+		Weight::from_parts(28_000_000, 3727)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
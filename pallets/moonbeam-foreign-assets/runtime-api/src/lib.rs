@@ -0,0 +1,41 @@
+// Copyright 2025 Moonbeam Foundation.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to list the foreign assets managed by pallet-moonbeam-foreign-assets,
+//! with their contract address, xcm location, status and creation deposit.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ForeignAssetsApi<AccountId, ForeignAssetInfo>
+	where
+		AccountId: Codec,
+		ForeignAssetInfo: Codec,
+	{
+		/// List all foreign assets
+		fn foreign_assets() -> Vec<ForeignAssetInfo>;
+		/// Get a foreign asset by its asset id
+		fn foreign_asset(asset_id: u128) -> Option<ForeignAssetInfo>;
+		/// List the foreign assets whose creation deposit was locked by the given account
+		fn foreign_assets_by_owner(owner: AccountId) -> Vec<ForeignAssetInfo>;
+	}
+}
@@ -16,13 +16,20 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{AssetStatus, Call, Config, Pallet};
+use crate::evm::EvmCaller;
+use crate::{
+	AssetStatus, AssetsPendingStorageClearing, Call, Config, OriginType, Pallet,
+	PendingOwnershipTransfers, DESTROY_ASSET_STORAGE_LIMIT, FOREIGN_ERC20_TEMPLATE_VERSION,
+};
+use ethereum_types::H256;
 use frame_benchmarking::v2::*;
 use frame_support::pallet_prelude::*;
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::BoundedVec;
 use xcm::latest::prelude::*;
+use xcm_executor::traits::ConvertLocation;
 
 fn location_of(n: u128) -> Location {
 	Location::new(0, [Junction::GeneralIndex(n)])
@@ -32,6 +39,18 @@ fn str_to_bv(str_: &str) -> BoundedVec<u8, ConstU32<256>> {
 	str_.as_bytes().to_vec().try_into().expect("too long")
 }
 
+/// Fill the storage of the contract of the given asset id with `n` extra entries
+fn fill_contract_storage<T: Config>(asset_id: u128, n: u32) {
+	let contract_address = Pallet::<T>::contract_address_from_asset_id(asset_id);
+	for i in 0..n {
+		pallet_evm::AccountStorages::<T>::insert(
+			contract_address,
+			H256::from_low_u64_be(u64::MAX - i as u64),
+			H256::from_low_u64_be(1),
+		);
+	}
+}
+
 #[benchmarks(
 	where T: Config + pallet_ethereum::Config
 )]
@@ -163,6 +182,192 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn update_metadata() -> Result<(), BenchmarkError> {
+		let max_assets = T::MaxForeignAssets::get() as u128;
+		for i in 1..=max_assets {
+			let symbol = sp_runtime::format!("MT{}", i);
+			let name = sp_runtime::format!("Mytoken{}", i);
+			Pallet::<T>::create_foreign_asset(
+				RawOrigin::Root.into(),
+				i,
+				location_of(i),
+				18,
+				str_to_bv(&symbol),
+				str_to_bv(&name),
+			)?;
+		}
+
+		let asset_id = max_assets;
+		// Worst case: replace the longest symbol and name by new ones of the same length
+		let symbol = BoundedVec::truncate_from(sp_std::vec![b'S'; 256]);
+		let name = BoundedVec::truncate_from(sp_std::vec![b'N'; 256]);
+		Pallet::<T>::update_metadata(
+			RawOrigin::Root.into(),
+			asset_id,
+			18,
+			symbol.clone(),
+			name.clone(),
+		)?;
+		let symbol = BoundedVec::truncate_from(sp_std::vec![b'T'; 256]);
+		let name = BoundedVec::truncate_from(sp_std::vec![b'O'; 256]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset_id, 12, symbol, name);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn upgrade_asset_template() -> Result<(), BenchmarkError> {
+		let max_assets = T::MaxForeignAssets::get() as u128;
+		for i in 1..=max_assets {
+			let symbol = sp_runtime::format!("MT{}", i);
+			let name = sp_runtime::format!("Mytoken{}", i);
+			Pallet::<T>::create_foreign_asset(
+				RawOrigin::Root.into(),
+				i,
+				location_of(i),
+				18,
+				str_to_bv(&symbol),
+				str_to_bv(&name),
+			)?;
+		}
+
+		let asset_id = max_assets;
+
+		// The runtime ships a single template, so there is no asset to upgrade: measure the
+		// swap of the contract code, the template version bookkeeping is negligible.
+		#[block]
+		{
+			EvmCaller::<T>::erc20_upgrade_template(asset_id).expect("fail to upgrade template");
+		}
+
+		assert_eq!(
+			Pallet::<T>::assets_template_version(asset_id),
+			FOREIGN_ERC20_TEMPLATE_VERSION
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_foreign_asset() -> Result<(), BenchmarkError> {
+		let max_assets = T::MaxForeignAssets::get() as u128;
+		for i in 1..=max_assets {
+			let symbol = sp_runtime::format!("MT{}", i);
+			let name = sp_runtime::format!("Mytoken{}", i);
+			Pallet::<T>::create_foreign_asset(
+				RawOrigin::Root.into(),
+				i,
+				location_of(i),
+				18,
+				str_to_bv(&symbol),
+				str_to_bv(&name),
+			)?;
+		}
+
+		let asset_id = max_assets;
+		fill_contract_storage::<T>(asset_id, DESTROY_ASSET_STORAGE_LIMIT);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset_id);
+
+		assert_eq!(Pallet::<T>::assets_by_id(asset_id), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_ownership() -> Result<(), BenchmarkError> {
+		let max_assets = T::MaxForeignAssets::get() as u128;
+		for i in 1..=max_assets {
+			let symbol = sp_runtime::format!("MT{}", i);
+			let name = sp_runtime::format!("Mytoken{}", i);
+			Pallet::<T>::create_foreign_asset(
+				RawOrigin::Root.into(),
+				i,
+				location_of(i),
+				18,
+				str_to_bv(&symbol),
+				str_to_bv(&name),
+			)?;
+		}
+
+		let asset_id = max_assets;
+		let new_owner = Location::new(1, [Junction::Parachain(1000)]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset_id, new_owner.clone());
+
+		assert_eq!(
+			Pallet::<T>::pending_ownership_transfers(asset_id),
+			Some(new_owner)
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_ownership() -> Result<(), BenchmarkError> {
+		let origin = T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let new_owner = match T::ForeignAssetModifierOrigin::try_origin(origin.clone()) {
+			Ok(OriginType::XCM(location)) => location,
+			_ => return Err(BenchmarkError::Weightless),
+		};
+		let new_owner_account =
+			T::ConvertLocation::convert_location(&new_owner).ok_or(BenchmarkError::Weightless)?;
+		let deposit = T::ForeignAssetCreationDeposit::get();
+		<T as Config>::Currency::make_free_balance_be(&new_owner_account, deposit + deposit);
+
+		let max_assets = T::MaxForeignAssets::get() as u128;
+		for i in 1..=max_assets {
+			let symbol = sp_runtime::format!("MT{}", i);
+			let name = sp_runtime::format!("Mytoken{}", i);
+			Pallet::<T>::create_foreign_asset(
+				RawOrigin::Root.into(),
+				i,
+				location_of(i),
+				18,
+				str_to_bv(&symbol),
+				str_to_bv(&name),
+			)?;
+		}
+
+		let asset_id = max_assets;
+		Pallet::<T>::transfer_ownership(RawOrigin::Root.into(), asset_id, new_owner)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset_id);
+
+		assert!(!PendingOwnershipTransfers::<T>::contains_key(asset_id));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_destroyed_asset_storage(n: Linear<1, 1000>) -> Result<(), BenchmarkError> {
+		// Storage left behind by a destroyed asset, the clearing resumes from its first entry
+		let asset_id = 1;
+		fill_contract_storage::<T>(asset_id, n);
+		let contract_address = Pallet::<T>::contract_address_from_asset_id(asset_id);
+		let cursor = pallet_evm::AccountStorages::<T>::iter_key_prefix(contract_address)
+			.map(|key| pallet_evm::AccountStorages::<T>::hashed_key_for(contract_address, key))
+			.min()
+			.ok_or(BenchmarkError::Weightless)?;
+		AssetsPendingStorageClearing::<T>::insert(asset_id, cursor);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset_id, n);
+
+		assert!(!AssetsPendingStorageClearing::<T>::contains_key(asset_id));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		crate::benchmarks::tests::new_test_ext(),
@@ -21,17 +21,22 @@ use frame_support::ensure;
 use frame_support::pallet_prelude::Weight;
 use pallet_evm::{GasWeightMapping, Runner};
 use precompile_utils::prelude::*;
-use precompile_utils::solidity::codec::{Address, BoundedString};
+use precompile_utils::solidity::codec::{Address, BoundedString, Reader};
 use precompile_utils::solidity::Codec;
 use precompile_utils_macro::keccak256;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{format, DispatchError, SaturatedConversion};
+use sp_runtime::{format, DispatchError};
 use sp_std::vec::Vec;
 use xcm::latest::Error as XcmError;
 
 const ERC20_CALL_MAX_CALLDATA_SIZE: usize = 4 + 32 + 32; // selector + address + uint256
 const ERC20_CREATE_MAX_CALLDATA_SIZE: usize = 16 * 1024; // 16Ko
 
+// Storage slots of the erc20 template (foreign_erc20.sol compiled with OpenZeppelin 5.0.2)
+const ERC20_NAME_SLOT: u64 = 3;
+const ERC20_SYMBOL_SLOT: u64 = 4;
+const ERC20_DECIMALS_SLOT: u64 = 9;
+
 // Hardcoded gas limits (from manual binary search)
 const ERC20_CREATE_GAS_LIMIT: u64 = 3_600_000; // highest failure: 3_600_000
 pub(crate) const ERC20_BURN_FROM_GAS_LIMIT: u64 = 160_000; // highest failure: 154_000
@@ -41,6 +46,10 @@ pub(crate) const ERC20_TRANSFER_GAS_LIMIT: u64 = 160_000; // highest failure: 15
 pub(crate) const ERC20_APPROVE_GAS_LIMIT: u64 = 160_000; // highest failure: 153_000
 const ERC20_UNPAUSE_GAS_LIMIT: u64 = 160_000; // highest failure: 149_500
 
+// Hardcoded gas limits (upper bounds for the largest metadata allowed by the pallet)
+const ERC20_EIP712_DOMAIN_GAS_LIMIT: u64 = 100_000;
+const ERC20_TOTAL_SUPPLY_GAS_LIMIT: u64 = 50_000;
+
 #[derive(Debug)]
 pub enum EvmError {
 	BurnFromFail(String),
@@ -106,25 +115,93 @@ impl<T: crate::Config> EvmCaller<T> {
 		symbol: &str,
 		token_name: &str,
 	) -> Result<H160, Error<T>> {
+		let contract_adress = Pallet::<T>::contract_address_from_asset_id(asset_id);
+
+		Self::erc20_deploy(
+			contract_adress,
+			ForeignErc20ConstructorArgs {
+				owner: Pallet::<T>::account_id().into(),
+				decimals,
+				symbol: symbol.into(),
+				token_name: token_name.into(),
+			},
+		)
+		.map_err(|err| {
+			log::debug!("erc20_create (error): {:?}", err);
+			Error::<T>::Erc20ContractCreationFail
+		})?;
+
+		Ok(contract_adress)
+	}
+
+	/// Replace the code of an existing foreign asset erc20 contract by the code of the current
+	/// template, without touching the contract storage (balances, allowances, permit nonces...).
+	///
+	/// The new code is obtained by deploying the current template on a temporary address, which
+	/// is removed afterwards. The templates must only append state variables to the storage
+	/// layout of their predecessors.
+	pub(crate) fn erc20_upgrade_template(asset_id: AssetId) -> Result<(), Error<T>> {
+		let contract_address = Pallet::<T>::contract_address_from_asset_id(asset_id);
+		ensure!(
+			pallet_evm::AccountCodes::<T>::contains_key(contract_address),
+			Error::<T>::Erc20TemplateUpgradeFail
+		);
+
+		// The token name is part of the EIP-712 domain, which is stored in the contract code.
+		// The new code is built with the name of the current domain rather than the one exposed
+		// by `name()`, which may have been updated since, so that existing permits stay valid.
+		let token_name = Self::erc20_eip712_name(contract_address)?;
+
+		let temporary_address = Self::template_deployment_address(asset_id);
+		Self::erc20_deploy(
+			temporary_address,
+			ForeignErc20ConstructorArgs {
+				owner: Pallet::<T>::account_id().into(),
+				decimals: 0,
+				symbol: "".into(),
+				token_name,
+			},
+		)
+		.map_err(|err| {
+			log::debug!("erc20_upgrade_template (error): {:?}", err);
+			Error::<T>::Erc20TemplateUpgradeFail
+		})?;
+
+		let code = pallet_evm::AccountCodes::<T>::get(temporary_address);
+		pallet_evm::Pallet::<T>::remove_account(&temporary_address);
+
+		pallet_evm::AccountCodesMetadata::<T>::insert(
+			contract_address,
+			pallet_evm::CodeMetadata {
+				size: code.len() as u64,
+				hash: H256::from(sp_io::hashing::keccak_256(&code)),
+			},
+		);
+		pallet_evm::AccountCodes::<T>::insert(contract_address, code);
+
+		Ok(())
+	}
+
+	/// Address used to deploy the template when upgrading the contract of the given asset
+	fn template_deployment_address(asset_id: AssetId) -> H160 {
+		let mut preimage = Vec::with_capacity(8 + 16);
+		preimage.extend_from_slice(&crate::PALLET_ID.0);
+		preimage.extend_from_slice(&asset_id.to_be_bytes());
+		H160::from(H256::from(sp_io::hashing::keccak_256(&preimage)))
+	}
+
+	fn erc20_deploy(address: H160, args: ForeignErc20ConstructorArgs) -> Result<(), String> {
 		// Get init code
 		let mut init = Vec::with_capacity(ERC20_CREATE_MAX_CALLDATA_SIZE);
 		init.extend_from_slice(include_bytes!("../resources/foreign_erc20_initcode.bin"));
 
 		// Add constructor parameters
-		let args = ForeignErc20ConstructorArgs {
-			owner: Pallet::<T>::account_id().into(),
-			decimals,
-			symbol: symbol.into(),
-			token_name: token_name.into(),
-		};
 		let encoded_args = precompile_utils::solidity::codec::Writer::new()
 			.write(args)
 			.build();
 		// Skip size of constructor args (32 bytes)
 		init.extend_from_slice(&encoded_args[32..]);
 
-		let contract_adress = Pallet::<T>::contract_address_from_asset_id(asset_id);
-
 		let exec_info = T::EvmRunner::create_force_address(
 			Pallet::<T>::account_id(),
 			init,
@@ -139,11 +216,49 @@ impl<T: crate::Config> EvmCaller<T> {
 			None,
 			None,
 			&<T as pallet_evm::Config>::config(),
-			contract_adress,
+			address,
+		)
+		.map_err(|err| format!("{:?}", err.error.into()))?;
+
+		ensure!(
+			matches!(
+				exec_info.exit_reason,
+				ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
+			),
+			error_on_execution_failure(&exec_info.exit_reason, &exec_info.value)
+		);
+
+		Ok(())
+	}
+
+	// Call contract selector "eip712Domain" and return the name of the domain
+	fn erc20_eip712_name(
+		erc20_contract_address: H160,
+	) -> Result<BoundedString<ConstU32<256>>, Error<T>> {
+		let input = keccak256!("eip712Domain()")[..4].to_vec();
+
+		let weight_limit: Weight =
+			T::GasWeightMapping::gas_to_weight(ERC20_EIP712_DOMAIN_GAS_LIMIT, true);
+
+		let exec_info = T::EvmRunner::call(
+			Pallet::<T>::account_id(),
+			erc20_contract_address,
+			input,
+			U256::default(),
+			ERC20_EIP712_DOMAIN_GAS_LIMIT,
+			None,
+			None,
+			None,
+			Default::default(),
+			false,
+			false,
+			Some(weight_limit),
+			Some(0),
+			&<T as pallet_evm::Config>::config(),
 		)
 		.map_err(|err| {
-			log::debug!("erc20_create (error): {:?}", err.error.into());
-			Error::<T>::Erc20ContractCreationFail
+			log::debug!("erc20_eip712_name (error): {:?}", err.error.into());
+			Error::<T>::EvmInternalError
 		})?;
 
 		ensure!(
@@ -151,10 +266,21 @@ impl<T: crate::Config> EvmCaller<T> {
 				exec_info.exit_reason,
 				ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
 			),
-			Error::Erc20ContractCreationFail
+			{
+				let err = error_on_execution_failure(&exec_info.exit_reason, &exec_info.value);
+				log::debug!("erc20_eip712_name (error): {:?}", err);
+				Error::<T>::Erc20TemplateUpgradeFail
+			}
 		);
 
-		Ok(contract_adress)
+		// Returns (bytes1 fields, string name, string version, uint256 chainId, ...)
+		let mut reader = Reader::new(&exec_info.value);
+		let _fields: H256 = reader
+			.read()
+			.map_err(|_| Error::<T>::Erc20TemplateUpgradeFail)?;
+		reader
+			.read()
+			.map_err(|_| Error::<T>::Erc20TemplateUpgradeFail)
 	}
 
 	pub(crate) fn erc20_mint_into(
@@ -448,6 +574,129 @@ impl<T: crate::Config> EvmCaller<T> {
 
 		Ok(())
 	}
+
+	// Call contract selector "totalSupply"
+	pub(crate) fn erc20_total_supply(asset_id: AssetId) -> Result<U256, Error<T>> {
+		let input = keccak256!("totalSupply()")[..4].to_vec();
+
+		let weight_limit: Weight =
+			T::GasWeightMapping::gas_to_weight(ERC20_TOTAL_SUPPLY_GAS_LIMIT, true);
+
+		let exec_info = T::EvmRunner::call(
+			Pallet::<T>::account_id(),
+			Pallet::<T>::contract_address_from_asset_id(asset_id),
+			input,
+			U256::default(),
+			ERC20_TOTAL_SUPPLY_GAS_LIMIT,
+			None,
+			None,
+			None,
+			Default::default(),
+			false,
+			false,
+			Some(weight_limit),
+			Some(0),
+			&<T as pallet_evm::Config>::config(),
+		)
+		.map_err(|err| {
+			log::debug!("erc20_total_supply (error): {:?}", err.error.into());
+			Error::<T>::EvmInternalError
+		})?;
+
+		ensure!(
+			matches!(
+				exec_info.exit_reason,
+				ExitReason::Succeed(ExitSucceed::Returned | ExitSucceed::Stopped)
+			),
+			{
+				let err = error_on_execution_failure(&exec_info.exit_reason, &exec_info.value);
+				log::debug!("erc20_total_supply (error): {:?}", err);
+				Error::<T>::EvmCallTotalSupplyFail
+			}
+		);
+
+		ensure!(
+			exec_info.value.len() == 32,
+			Error::<T>::EvmCallTotalSupplyFail
+		);
+
+		Ok(U256::from_big_endian(&exec_info.value))
+	}
+
+	/// Overwrite the decimals, symbol and name stored by the erc20 contract of a given asset.
+	///
+	/// The template has no setter for them, so the new values are written in the storage slots
+	/// that its getters read. The name of the EIP-712 domain used by permits is not affected,
+	/// it keeps the name given when the contract was deployed.
+	pub(crate) fn erc20_update_metadata(
+		asset_id: AssetId,
+		decimals: u8,
+		symbol: &[u8],
+		token_name: &[u8],
+	) {
+		let contract_address = Pallet::<T>::contract_address_from_asset_id(asset_id);
+
+		Self::set_storage(
+			contract_address,
+			H256::from_low_u64_be(ERC20_DECIMALS_SLOT),
+			H256::from_low_u64_be(decimals as u64),
+		);
+		Self::set_storage_string(
+			contract_address,
+			H256::from_low_u64_be(ERC20_SYMBOL_SLOT),
+			symbol,
+		);
+		Self::set_storage_string(
+			contract_address,
+			H256::from_low_u64_be(ERC20_NAME_SLOT),
+			token_name,
+		);
+	}
+
+	/// Store a string the way solidity does: strings shorter than 32 bytes are stored in the
+	/// slot along with their length, longer ones in consecutive slots starting at the hash of
+	/// the slot, which then only contains the length.
+	fn set_storage_string(address: H160, slot: H256, value: &[u8]) {
+		let data_slot = |index: usize| {
+			let base = H256::from(sp_io::hashing::keccak_256(slot.as_bytes())).into_uint();
+			H256::from_uint(&base.overflowing_add(U256::from(index)).0)
+		};
+
+		// Clear the data slots of the previous value if it was a long string
+		let previous = pallet_evm::AccountStorages::<T>::get(address, slot).into_uint();
+		if previous.bit(0) {
+			let previous_len = (previous >> 1).low_u64() as usize;
+			for index in 0..(previous_len + 31) / 32 {
+				pallet_evm::AccountStorages::<T>::remove(address, data_slot(index));
+			}
+		}
+
+		if value.len() < 32 {
+			let mut word = [0u8; 32];
+			word[..value.len()].copy_from_slice(value);
+			word[31] = (value.len() * 2) as u8;
+			Self::set_storage(address, slot, H256::from(word));
+		} else {
+			Self::set_storage(
+				address,
+				slot,
+				H256::from_low_u64_be((value.len() * 2 + 1) as u64),
+			);
+			for (index, chunk) in value.chunks(32).enumerate() {
+				let mut word = [0u8; 32];
+				word[..chunk.len()].copy_from_slice(chunk);
+				Self::set_storage(address, data_slot(index), H256::from(word));
+			}
+		}
+	}
+
+	fn set_storage(address: H160, key: H256, value: H256) {
+		if value.is_zero() {
+			pallet_evm::AccountStorages::<T>::remove(address, key);
+		} else {
+			pallet_evm::AccountStorages::<T>::insert(address, key, value);
+		}
+	}
 }
 
 fn error_on_execution_failure(reason: &ExitReason, data: &[u8]) -> String {
@@ -455,31 +704,6 @@ fn error_on_execution_failure(reason: &ExitReason, data: &[u8]) -> String {
 		ExitReason::Succeed(_) => String::new(),
 		ExitReason::Error(err) => format!("evm error: {err:?}"),
 		ExitReason::Fatal(err) => format!("evm fatal: {err:?}"),
-		ExitReason::Revert(_) => extract_revert_message(data),
-	}
-}
-
-/// The data should contain a UTF-8 encoded revert reason with a minimum size consisting of:
-/// error function selector (4 bytes) + offset (32 bytes) + reason string length (32 bytes)
-fn extract_revert_message(data: &[u8]) -> String {
-	const LEN_START: usize = 36;
-	const MESSAGE_START: usize = 68;
-	const BASE_MESSAGE: &str = "VM Exception while processing transaction: revert";
-	// Return base message if data is too short
-	if data.len() <= MESSAGE_START {
-		return BASE_MESSAGE.into();
-	}
-	// Extract message length and calculate end position
-	let message_len = U256::from(&data[LEN_START..MESSAGE_START]).saturated_into::<usize>();
-	let message_end = MESSAGE_START.saturating_add(message_len);
-	// Return base message if data is shorter than expected message end
-	if data.len() < message_end {
-		return BASE_MESSAGE.into();
-	}
-	// Extract and decode the message
-	let body = &data[MESSAGE_START..message_end];
-	match core::str::from_utf8(body) {
-		Ok(reason) => format!("{BASE_MESSAGE} {reason}"),
-		Err(_) => BASE_MESSAGE.into(),
+		ExitReason::Revert(_) => xcm_primitives::extract_revert_message(data),
 	}
 }
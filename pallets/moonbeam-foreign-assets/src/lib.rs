@@ -56,6 +56,7 @@ use frame_support::pallet;
 use frame_support::pallet_prelude::*;
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
 use xcm::latest::{
 	Asset, AssetId as XcmAssetId, Error as XcmError, Fungibility, Location, Result as XcmResult,
 	XcmContext,
@@ -66,6 +67,14 @@ use xcm_executor::traits::Error as MatchError;
 
 const FOREIGN_ASSETS_PREFIX: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
 
+/// Version of the erc20 template deployed by this pallet (`foreign_erc20.sol`).
+/// Must be incremented each time the template and its initcode are modified.
+pub const FOREIGN_ERC20_TEMPLATE_VERSION: u32 = 0;
+
+/// Maximum number of contract storage entries removed when destroying an asset. The remaining
+/// entries are removed with `clear_destroyed_asset_storage`.
+pub const DESTROY_ASSET_STORAGE_LIMIT: u32 = 64;
+
 /// Trait for the OnForeignAssetRegistered hook
 pub trait ForeignAssetCreatedHook<ForeignAsset> {
 	fn on_asset_created(foreign_asset: &ForeignAsset, asset_id: &AssetId);
@@ -144,7 +153,7 @@ impl<T: crate::Config> ForeignAssetsMatcher<T> {
 	}
 }
 
-#[derive(Decode, Debug, Encode, PartialEq, TypeInfo)]
+#[derive(Clone, Decode, Debug, Encode, Eq, PartialEq, TypeInfo)]
 pub enum AssetStatus {
 	/// All operations are enabled
 	Active,
@@ -158,7 +167,7 @@ pub enum AssetStatus {
 pub mod pallet {
 	use super::*;
 	use frame_support::traits::{Currency, ReservableCurrency};
-	use pallet_evm::{GasWeightMapping, Runner};
+	use pallet_evm::{AddressMapping, GasWeightMapping, Runner};
 	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, Convert};
 	use xcm_executor::traits::ConvertLocation;
 	use xcm_executor::traits::Error as MatchError;
@@ -229,7 +238,7 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>;
 	}
 
-	type BalanceOf<T> =
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	pub type AssetBalance = U256;
@@ -248,6 +257,20 @@ pub mod pallet {
 		EvmCallPauseFail,
 		EvmCallUnpauseFail,
 		EvmInternalError,
+		Erc20TemplateUpgradeFail,
+		/// The asset contract already runs the current template
+		AssetTemplateUpToDate,
+		/// The asset can't be destroyed while some tokens are still in circulation
+		AssetSupplyNotZero,
+		/// The storage of a destroyed asset contract must be cleared before reusing its asset id
+		AssetStorageNotCleared,
+		/// There is no destroyed asset contract storage to clear for this asset id
+		NoAssetStorageToClear,
+		EvmCallTotalSupplyFail,
+		/// The origin is not the owner of the asset
+		NotAssetOwner,
+		/// There is no pending ownership transfer for this asset and origin
+		NoPendingOwnershipTransfer,
 		/// Account has insufficient balance for locking
 		InsufficientBalance,
 		CannotConvertLocationToAccount,
@@ -287,6 +310,43 @@ pub mod pallet {
 		},
 		/// Tokens have been locked for asset creation
 		TokensLocked(T::AccountId, AssetId, AssetBalance),
+		/// The metadata of the erc20 contract of a given asset id have been updated
+		ForeignAssetMetadataUpdated {
+			asset_id: AssetId,
+			decimals: u8,
+			symbol: BoundedVec<u8, ConstU32<256>>,
+			name: BoundedVec<u8, ConstU32<256>>,
+		},
+		/// The erc20 contract of a given asset id has been upgraded to a new template version
+		ForeignAssetTemplateUpgraded {
+			asset_id: AssetId,
+			previous_version: u32,
+			new_version: u32,
+		},
+		/// An asset with no tokens in circulation has been destroyed, and its creation deposit
+		/// unreserved
+		ForeignAssetDestroyed {
+			asset_id: AssetId,
+			xcm_location: Location,
+			deposit: Option<BalanceOf<T>>,
+		},
+		/// The storage of a destroyed asset contract has been partially cleared, the remaining
+		/// entries can be removed with `clear_destroyed_asset_storage`
+		ForeignAssetStorageClearingPending { asset_id: AssetId },
+		/// The storage of a destroyed asset contract has been fully cleared
+		ForeignAssetStorageCleared { asset_id: AssetId },
+		/// The owner of an asset proposed to transfer its ownership
+		ForeignAssetOwnershipTransferProposed {
+			asset_id: AssetId,
+			new_owner: Location,
+		},
+		/// The ownership of an asset (and its creation deposit) has been transferred
+		ForeignAssetOwnershipTransferred {
+			asset_id: AssetId,
+			previous_owner: Option<T::AccountId>,
+			new_owner: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
 	}
 
 	/// Mapping from an asset id to a Foreign asset type.
@@ -311,12 +371,46 @@ pub mod pallet {
 	pub type AssetsCreationDetails<T: Config> =
 		StorageMap<_, Blake2_128Concat, AssetId, AssetDepositDetails<T>>;
 
+	/// Mapping from an asset id to the version of the erc20 template its contract runs.
+	/// Assets created before the introduction of template versions run the version 0.
+	#[pallet::storage]
+	#[pallet::getter(fn assets_template_version)]
+	pub type AssetsTemplateVersion<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, u32, ValueQuery>;
+
+	/// Mapping from an asset id to the location its ownership is being transferred to.
+	/// The transfer is completed once this location accepts the ownership.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_ownership_transfers)]
+	pub type PendingOwnershipTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, Location>;
+
+	/// Mapping from the id of a destroyed asset whose contract storage has not been fully
+	/// cleared yet to the storage key the clearing resumes from.
+	/// These asset ids can't be reused until `clear_destroyed_asset_storage` cleared it.
+	#[pallet::storage]
+	#[pallet::getter(fn assets_pending_storage_clearing)]
+	pub type AssetsPendingStorageClearing<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, Vec<u8>>;
+
 	#[derive(Clone, Decode, Encode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
 	pub struct AssetDepositDetails<T: Config> {
 		pub deposit_account: T::AccountId,
 		pub deposit: BalanceOf<T>,
 	}
 
+	/// Foreign asset information, as exposed by the runtime api
+	#[derive(Clone, Decode, Encode, Eq, PartialEq, Debug, TypeInfo)]
+	pub struct ForeignAssetInfo<AccountId, Balance> {
+		pub asset_id: AssetId,
+		pub contract_address: H160,
+		pub xcm_location: Location,
+		pub status: AssetStatus,
+		/// The account that locked the creation deposit, if any
+		pub owner: Option<AccountId>,
+		pub deposit: Option<Balance>,
+	}
+
 	impl<T: Config> Pallet<T> {
 		/// The account ID of this pallet
 		#[inline]
@@ -391,6 +485,43 @@ pub mod pallet {
 		pub fn weight_of_erc20_transfer() -> Weight {
 			T::GasWeightMapping::gas_to_weight(evm::ERC20_TRANSFER_GAS_LIMIT, true)
 		}
+		/// Information about a given foreign asset
+		pub fn foreign_asset_info(
+			asset_id: AssetId,
+		) -> Option<ForeignAssetInfo<T::AccountId, BalanceOf<T>>> {
+			let xcm_location = AssetsById::<T>::get(&asset_id)?;
+			let (_asset_id, status) = AssetsByLocation::<T>::get(&xcm_location)?;
+			let deposit_details = AssetsCreationDetails::<T>::get(&asset_id);
+
+			Some(ForeignAssetInfo {
+				asset_id,
+				contract_address: Self::contract_address_from_asset_id(asset_id),
+				xcm_location,
+				status,
+				owner: deposit_details
+					.as_ref()
+					.map(|details| details.deposit_account.clone()),
+				deposit: deposit_details.map(|details| details.deposit),
+			})
+		}
+
+		/// Information about all foreign assets
+		pub fn foreign_assets_info() -> Vec<ForeignAssetInfo<T::AccountId, BalanceOf<T>>> {
+			AssetsById::<T>::iter_keys()
+				.filter_map(Self::foreign_asset_info)
+				.collect()
+		}
+
+		/// Information about all foreign assets owned by a given account
+		pub fn foreign_assets_info_by_owner(
+			owner: T::AccountId,
+		) -> Vec<ForeignAssetInfo<T::AccountId, BalanceOf<T>>> {
+			AssetsCreationDetails::<T>::iter()
+				.filter(|(_, details)| details.deposit_account == owner)
+				.filter_map(|(asset_id, _)| Self::foreign_asset_info(asset_id))
+				.collect()
+		}
+
 		#[cfg(feature = "runtime-benchmarks")]
 		pub fn set_asset(asset_location: Location, asset_id: AssetId) {
 			AssetsByLocation::<T>::insert(&asset_location, (asset_id, AssetStatus::Active));
@@ -479,6 +610,138 @@ pub mod pallet {
 
 			Self::do_unfreeze_asset(asset_id, xcm_location)
 		}
+
+		/// Update the decimals, symbol and name of a given foreign assetId.
+		/// The EIP-712 domain used by permits keeps the name given at creation.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_metadata())]
+		pub fn update_metadata(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			decimals: u8,
+			symbol: BoundedVec<u8, ConstU32<256>>,
+			name: BoundedVec<u8, ConstU32<256>>,
+		) -> DispatchResult {
+			let origin_type = T::ForeignAssetModifierOrigin::ensure_origin(origin.clone())?;
+
+			let xcm_location =
+				AssetsById::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+
+			Self::ensure_origin_can_modify_location(origin_type, &xcm_location)?;
+
+			Self::do_update_metadata(asset_id, decimals, symbol, name)
+		}
+
+		/// Redeploy the erc20 contract of a given foreign assetId on the current template,
+		/// preserving its storage (balances, allowances, metadata...)
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::upgrade_asset_template())]
+		pub fn upgrade_asset_template(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin_type = T::ForeignAssetModifierOrigin::ensure_origin(origin.clone())?;
+
+			// The template is part of the runtime, only governance can decide to roll it out
+			ensure!(
+				origin_type == OriginType::Governance,
+				DispatchError::BadOrigin
+			);
+
+			ensure!(
+				AssetsById::<T>::contains_key(&asset_id),
+				Error::<T>::AssetDoesNotExist
+			);
+
+			Self::do_upgrade_asset_template(asset_id)
+		}
+
+		/// Destroy a given foreign assetId once it has no tokens in circulation,
+		/// and unreserve its creation deposit
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_foreign_asset())]
+		pub fn destroy_foreign_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin_type = T::ForeignAssetCreatorOrigin::ensure_origin(origin.clone())?;
+
+			let xcm_location =
+				AssetsById::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+
+			Self::ensure_origin_is_asset_owner(origin_type, asset_id)?;
+
+			Self::do_destroy_asset(asset_id, xcm_location)
+		}
+
+		/// Propose to transfer the ownership of a given foreign assetId to another location.
+		/// The new owner must accept the ownership with `accept_ownership`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			new_owner: Location,
+		) -> DispatchResult {
+			let origin_type = T::ForeignAssetModifierOrigin::ensure_origin(origin.clone())?;
+
+			ensure!(
+				AssetsById::<T>::contains_key(&asset_id),
+				Error::<T>::AssetDoesNotExist
+			);
+
+			Self::ensure_origin_is_asset_owner(origin_type, asset_id)?;
+
+			// Ensure the new owner is a sibling that will be able to lock the deposit
+			convert_location::<T>(&new_owner)?;
+
+			PendingOwnershipTransfers::<T>::insert(&asset_id, &new_owner);
+
+			Self::deposit_event(Event::ForeignAssetOwnershipTransferProposed {
+				asset_id,
+				new_owner,
+			});
+			Ok(())
+		}
+
+		/// Accept the ownership of a given foreign assetId, the creation deposit is locked from
+		/// the new owner and unreserved to the previous owner
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let origin_type = T::ForeignAssetModifierOrigin::ensure_origin(origin.clone())?;
+
+			let new_owner = PendingOwnershipTransfers::<T>::get(&asset_id)
+				.ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+
+			ensure!(
+				origin_type == OriginType::XCM(new_owner.clone()),
+				Error::<T>::NoPendingOwnershipTransfer
+			);
+
+			Self::do_transfer_ownership(asset_id, convert_location::<T>(&new_owner)?)
+		}
+
+		/// Remove up to `limit` entries from the remaining contract storage of a destroyed
+		/// foreign assetId. Anyone can call it, the fee is refunded once the storage is cleared.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_destroyed_asset_storage(*limit))]
+		pub fn clear_destroyed_asset_storage(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			ensure!(
+				AssetsPendingStorageClearing::<T>::contains_key(&asset_id),
+				Error::<T>::NoAssetStorageToClear
+			);
+
+			let (visited, cleared) = Self::clear_asset_storage(asset_id, limit);
+
+			Ok((
+				Some(<T as Config>::WeightInfo::clear_destroyed_asset_storage(
+					visited,
+				)),
+				if cleared { Pays::No } else { Pays::Yes },
+			)
+				.into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -501,6 +764,27 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Ensure that the caller origin owns the asset, governance owns all assets
+		fn ensure_origin_is_asset_owner(
+			origin_type: OriginType,
+			asset_id: AssetId,
+		) -> DispatchResult {
+			match origin_type {
+				OriginType::XCM(origin_location) => {
+					let origin_account = convert_location::<T>(&origin_location)?;
+					ensure!(
+						AssetsCreationDetails::<T>::get(&asset_id)
+							.is_some_and(|details| details.deposit_account == origin_account),
+						Error::<T>::NotAssetOwner
+					);
+				}
+				OriginType::Governance => {
+					// nothing to check Governance can manage any asset
+				}
+			};
+			Ok(())
+		}
+
 		fn get_deposit_account(
 			origin_type: OriginType,
 		) -> Result<Option<T::AccountId>, DispatchError> {
@@ -526,6 +810,12 @@ pub mod pallet {
 				Error::<T>::AssetAlreadyExists
 			);
 
+			// The contract of a destroyed asset with the same id would inherit its storage
+			ensure!(
+				!AssetsPendingStorageClearing::<T>::contains_key(&asset_id),
+				Error::<T>::AssetStorageNotCleared
+			);
+
 			ensure!(
 				!AssetsByLocation::<T>::contains_key(&asset_xcm_location),
 				Error::<T>::LocationAlreadyExists
@@ -569,6 +859,7 @@ pub mod pallet {
 			// Insert the association foreigAsset->assetId
 			AssetsById::<T>::insert(&asset_id, &asset_xcm_location);
 			AssetsByLocation::<T>::insert(&asset_xcm_location, (asset_id, AssetStatus::Active));
+			AssetsTemplateVersion::<T>::insert(&asset_id, FOREIGN_ERC20_TEMPLATE_VERSION);
 
 			T::OnForeignAssetCreated::on_asset_created(&asset_xcm_location, &asset_id);
 
@@ -657,6 +948,145 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		pub fn do_update_metadata(
+			asset_id: AssetId,
+			decimals: u8,
+			symbol: BoundedVec<u8, ConstU32<256>>,
+			name: BoundedVec<u8, ConstU32<256>>,
+		) -> DispatchResult {
+			core::str::from_utf8(&symbol).map_err(|_| Error::<T>::InvalidSymbol)?;
+			core::str::from_utf8(&name).map_err(|_| Error::<T>::InvalidTokenName)?;
+
+			EvmCaller::<T>::erc20_update_metadata(asset_id, decimals, &symbol, &name);
+
+			Self::deposit_event(Event::ForeignAssetMetadataUpdated {
+				asset_id,
+				decimals,
+				symbol,
+				name,
+			});
+			Ok(())
+		}
+
+		pub fn do_upgrade_asset_template(asset_id: AssetId) -> DispatchResult {
+			let previous_version = AssetsTemplateVersion::<T>::get(&asset_id);
+			ensure!(
+				previous_version < FOREIGN_ERC20_TEMPLATE_VERSION,
+				Error::<T>::AssetTemplateUpToDate
+			);
+
+			// We perform the evm calls in a storage transaction to ensure that if it fail
+			// any contract storage changes are rolled back.
+			frame_support::storage::with_storage_layer::<(), DispatchError, _>(|| {
+				EvmCaller::<T>::erc20_upgrade_template(asset_id).map_err(Into::into)
+			})?;
+
+			AssetsTemplateVersion::<T>::insert(&asset_id, FOREIGN_ERC20_TEMPLATE_VERSION);
+
+			Self::deposit_event(Event::ForeignAssetTemplateUpgraded {
+				asset_id,
+				previous_version,
+				new_version: FOREIGN_ERC20_TEMPLATE_VERSION,
+			});
+			Ok(())
+		}
+
+		pub fn do_destroy_asset(asset_id: AssetId, xcm_location: Location) -> DispatchResult {
+			ensure!(
+				EvmCaller::<T>::erc20_total_supply(asset_id)?.is_zero(),
+				Error::<T>::AssetSupplyNotZero
+			);
+
+			// Remove the erc20 contract code, like `pallet_evm::Pallet::remove_account` does, so
+			// that its storage (allowances...) can't grow anymore while it's being cleared.
+			let contract_address = Self::contract_address_from_asset_id(asset_id);
+			if pallet_evm::AccountCodes::<T>::contains_key(contract_address) {
+				let _ = frame_system::Pallet::<T>::dec_sufficients(
+					&<T as pallet_evm::Config>::AddressMapping::into_account_id(contract_address),
+				);
+			}
+			pallet_evm::AccountCodes::<T>::remove(contract_address);
+			pallet_evm::AccountCodesMetadata::<T>::remove(contract_address);
+
+			// The contract storage is unbounded, so only a limited part of it is removed here
+			let (_, cleared) = Self::clear_asset_storage(asset_id, DESTROY_ASSET_STORAGE_LIMIT);
+			if !cleared {
+				Self::deposit_event(Event::ForeignAssetStorageClearingPending { asset_id });
+			}
+
+			AssetsById::<T>::remove(&asset_id);
+			AssetsByLocation::<T>::remove(&xcm_location);
+			AssetsTemplateVersion::<T>::remove(&asset_id);
+			PendingOwnershipTransfers::<T>::remove(&asset_id);
+
+			let deposit = AssetsCreationDetails::<T>::take(&asset_id).map(|details| {
+				<T as Config>::Currency::unreserve(&details.deposit_account, details.deposit);
+				details.deposit
+			});
+
+			Self::deposit_event(Event::ForeignAssetDestroyed {
+				asset_id,
+				xcm_location,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Remove up to `limit` entries from the storage of the contract of the given asset id,
+		/// resuming from where the previous call stopped.
+		/// Returns the number of visited entries and whether the storage has been fully cleared.
+		fn clear_asset_storage(asset_id: AssetId, limit: u32) -> (u32, bool) {
+			let contract_address = Self::contract_address_from_asset_id(asset_id);
+			let cursor = AssetsPendingStorageClearing::<T>::get(&asset_id);
+
+			let result = pallet_evm::AccountStorages::<T>::clear_prefix(
+				contract_address,
+				limit,
+				cursor.as_deref(),
+			);
+			match result.maybe_cursor {
+				Some(cursor) => {
+					AssetsPendingStorageClearing::<T>::insert(&asset_id, cursor);
+					(result.loops, false)
+				}
+				None => {
+					if AssetsPendingStorageClearing::<T>::take(&asset_id).is_some() {
+						Self::deposit_event(Event::ForeignAssetStorageCleared { asset_id });
+					}
+					(result.loops, true)
+				}
+			}
+		}
+
+		pub fn do_transfer_ownership(asset_id: AssetId, new_owner: T::AccountId) -> DispatchResult {
+			let deposit = T::ForeignAssetCreationDeposit::get();
+
+			// Reserve _deposit_ amount of funds from the new owner
+			<T as Config>::Currency::reserve(&new_owner, deposit)?;
+
+			let previous_owner = AssetsCreationDetails::<T>::get(&asset_id).map(|details| {
+				<T as Config>::Currency::unreserve(&details.deposit_account, details.deposit);
+				details.deposit_account
+			});
+
+			AssetsCreationDetails::<T>::insert(
+				&asset_id,
+				AssetDepositDetails {
+					deposit_account: new_owner.clone(),
+					deposit,
+				},
+			);
+			PendingOwnershipTransfers::<T>::remove(&asset_id);
+
+			Self::deposit_event(Event::ForeignAssetOwnershipTransferred {
+				asset_id,
+				previous_owner,
+				new_owner,
+				deposit,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> xcm_executor::traits::TransactAsset for Pallet<T> {
@@ -17,8 +17,10 @@
 use crate::*;
 use mock::*;
 
+use ethereum_types::H256;
 use frame_support::traits::Currency;
 use frame_support::{assert_noop, assert_ok};
+use precompile_utils::solidity::codec::UnboundedBytes;
 use precompile_utils::testing::Bob;
 use xcm::latest::prelude::*;
 
@@ -30,6 +32,31 @@ fn encode_token_name(str_: &str) -> BoundedVec<u8, ConstU32<256>> {
 	BoundedVec::try_from(str_.as_bytes().to_vec()).expect("too long")
 }
 
+fn erc20_view<R: precompile_utils::solidity::Codec>(asset_id: AssetId, signature: &str) -> R {
+	use pallet_evm::Runner;
+
+	let exec_info = <Test as Config>::EvmRunner::call(
+		EvmForeignAssets::account_id(),
+		EvmForeignAssets::contract_address_from_asset_id(asset_id),
+		sp_io::hashing::keccak_256(signature.as_bytes())[..4].to_vec(),
+		U256::default(),
+		100_000,
+		None,
+		None,
+		None,
+		Default::default(),
+		false,
+		false,
+		None,
+		None,
+		&<Test as pallet_evm::Config>::config(),
+	)
+	.expect("view call must succeed");
+
+	precompile_utils::solidity::decode_return_value(&exec_info.value)
+		.expect("view call must return a valid value")
+}
+
 #[test]
 fn create_foreign_and_freeze_unfreeze_using_xcm() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -422,3 +449,503 @@ fn test_governance_can_change_any_asset_location() {
 		));
 	});
 }
+
+#[test]
+fn test_update_metadata_origin_checks() {
+	ExtBuilder::default().build().execute_with(|| {
+		let deposit = ForeignAssetCreationDeposit::get();
+
+		Balances::make_free_balance_be(&PARA_C, deposit);
+
+		let asset_location: Location = (Parent, Parachain(3), PalletInstance(22)).into();
+		let asset_id = 5;
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_C),
+			asset_id,
+			asset_location,
+			10,
+			encode_ticker("PARC"),
+			encode_token_name("Parachain C Token"),
+		));
+
+		assert_noop!(
+			EvmForeignAssets::update_metadata(
+				RuntimeOrigin::root(),
+				6,
+				10,
+				encode_ticker("PARC"),
+				encode_token_name("Parachain C Token"),
+			),
+			Error::<Test>::AssetDoesNotExist,
+		);
+
+		// This asset doesn't belong to PARA A
+		assert_noop!(
+			EvmForeignAssets::update_metadata(
+				RuntimeOrigin::signed(PARA_A),
+				asset_id,
+				10,
+				encode_ticker("PARC"),
+				encode_token_name("Parachain C Token"),
+			),
+			Error::<Test>::LocationOutsideOfOrigin,
+		);
+	});
+}
+
+#[test]
+fn test_update_metadata_is_read_back_from_the_contract() {
+	ExtBuilder::default().build().execute_with(|| {
+		let deposit = ForeignAssetCreationDeposit::get();
+
+		Balances::make_free_balance_be(&PARA_C, deposit);
+
+		let asset_location: Location = (Parent, Parachain(3), PalletInstance(22)).into();
+		let asset_id = 5;
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_C),
+			asset_id,
+			asset_location,
+			10,
+			encode_ticker("PARC"),
+			encode_token_name("Parachain C Token"),
+		));
+
+		assert_ok!(EvmForeignAssets::update_metadata(
+			RuntimeOrigin::signed(PARA_C),
+			asset_id,
+			12,
+			encode_ticker("xPARC"),
+			encode_token_name("Parachain C Token (renamed)"),
+		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::ForeignAssetMetadataUpdated {
+				asset_id,
+				decimals: 12,
+				symbol: encode_ticker("xPARC"),
+				name: encode_token_name("Parachain C Token (renamed)"),
+			})
+		);
+
+		assert_eq!(erc20_view::<u8>(asset_id, "decimals()"), 12);
+		assert_eq!(
+			erc20_view::<UnboundedBytes>(asset_id, "symbol()").as_bytes(),
+			b"xPARC"
+		);
+		assert_eq!(
+			erc20_view::<UnboundedBytes>(asset_id, "name()").as_bytes(),
+			b"Parachain C Token (renamed)"
+		);
+
+		// Long strings are stored out of their slot, shrinking them must clear the extra slots
+		let contract_address = EvmForeignAssets::contract_address_from_asset_id(asset_id);
+		let storage_size =
+			|| pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).count();
+		let initial_storage_size = storage_size();
+		let long_name = "Parachain C Token with a name longer than one storage slot";
+		assert_ok!(EvmForeignAssets::update_metadata(
+			RuntimeOrigin::signed(PARA_C),
+			asset_id,
+			12,
+			encode_ticker("xPARC"),
+			encode_token_name(long_name),
+		));
+		assert_eq!(
+			erc20_view::<UnboundedBytes>(asset_id, "name()").as_bytes(),
+			long_name.as_bytes()
+		);
+		assert_eq!(storage_size(), initial_storage_size + 2);
+
+		assert_ok!(EvmForeignAssets::update_metadata(
+			RuntimeOrigin::signed(PARA_C),
+			asset_id,
+			12,
+			encode_ticker("xPARC"),
+			encode_token_name("Parachain C Token"),
+		));
+		assert_eq!(
+			erc20_view::<UnboundedBytes>(asset_id, "name()").as_bytes(),
+			b"Parachain C Token"
+		);
+		assert_eq!(storage_size(), initial_storage_size);
+	});
+}
+
+#[test]
+fn test_upgrade_asset_template_preserves_contract_storage() {
+	ExtBuilder::default().build().execute_with(|| {
+		let deposit = ForeignAssetCreationDeposit::get();
+
+		Balances::make_free_balance_be(&PARA_A, deposit);
+
+		let asset_location: Location = (Parent, Parachain(1), PalletInstance(13)).into();
+		let asset_id = 1;
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id,
+			asset_location,
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+
+		assert_ok!(EvmForeignAssets::mint_into(
+			asset_id,
+			PARA_A,
+			U256::from(100)
+		));
+		assert_ok!(EvmForeignAssets::approve(
+			asset_id,
+			PARA_A,
+			Bob.into(),
+			U256::from(50)
+		));
+
+		// Only governance can roll out a new template
+		assert_noop!(
+			EvmForeignAssets::upgrade_asset_template(RuntimeOrigin::signed(PARA_A), asset_id),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			EvmForeignAssets::upgrade_asset_template(RuntimeOrigin::root(), 2),
+			Error::<Test>::AssetDoesNotExist,
+		);
+		// The runtime ships the template the asset was created with
+		assert_eq!(
+			EvmForeignAssets::assets_template_version(asset_id),
+			FOREIGN_ERC20_TEMPLATE_VERSION
+		);
+		assert_noop!(
+			EvmForeignAssets::upgrade_asset_template(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetTemplateUpToDate,
+		);
+
+		// A renamed asset keeps the name of its EIP-712 domain across upgrades
+		assert_ok!(EvmForeignAssets::update_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken (renamed)"),
+		));
+		let domain_separator = erc20_view::<H256>(asset_id, "DOMAIN_SEPARATOR()");
+
+		let contract_address = EvmForeignAssets::contract_address_from_asset_id(asset_id);
+		let mut storage_before: Vec<_> =
+			pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).collect();
+		assert!(!storage_before.is_empty());
+
+		// Exercise the code swap done by upgrade_asset_template, which has nothing to upgrade to
+		assert_ok!(EvmCaller::<Test>::erc20_upgrade_template(asset_id));
+
+		assert_eq!(
+			erc20_view::<H256>(asset_id, "DOMAIN_SEPARATOR()"),
+			domain_separator
+		);
+		assert_eq!(
+			erc20_view::<UnboundedBytes>(asset_id, "name()").as_bytes(),
+			b"Mytoken (renamed)"
+		);
+
+		// Balances and allowances are untouched
+		let mut storage_after: Vec<_> =
+			pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).collect();
+		storage_before.sort();
+		storage_after.sort();
+		assert_eq!(storage_before, storage_after);
+		assert!(!pallet_evm::AccountCodes::<Test>::get(contract_address).is_empty());
+
+		// The asset keeps working after the upgrade
+		assert_ok!(EvmForeignAssets::mint_into(
+			asset_id,
+			PARA_A,
+			U256::from(100)
+		));
+	});
+}
+
+#[test]
+fn test_destroy_foreign_asset_unreserves_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let deposit = ForeignAssetCreationDeposit::get();
+
+		Balances::make_free_balance_be(&PARA_A, deposit);
+
+		let asset_location: Location = (Parent, Parachain(1), PalletInstance(13)).into();
+		let asset_id = 1;
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id,
+			asset_location.clone(),
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+		assert_eq!(Balances::reserved_balance(&PARA_A), deposit);
+
+		assert_ok!(EvmForeignAssets::mint_into(
+			asset_id,
+			PARA_A,
+			U256::from(100)
+		));
+
+		// Only the owner can destroy the asset
+		assert_noop!(
+			EvmForeignAssets::destroy_foreign_asset(RuntimeOrigin::signed(PARA_B), asset_id),
+			Error::<Test>::NotAssetOwner,
+		);
+		assert_noop!(
+			EvmForeignAssets::destroy_foreign_asset(RuntimeOrigin::signed(PARA_A), asset_id),
+			Error::<Test>::AssetSupplyNotZero,
+		);
+
+		assert_ok!(evm::EvmCaller::<Test>::erc20_burn_from(
+			EvmForeignAssets::contract_address_from_asset_id(asset_id),
+			PARA_A.into(),
+			U256::from(100)
+		));
+
+		assert_ok!(EvmForeignAssets::destroy_foreign_asset(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id
+		));
+
+		assert_eq!(Balances::reserved_balance(&PARA_A), 0);
+		assert_eq!(Balances::free_balance(&PARA_A), deposit);
+		assert_eq!(EvmForeignAssets::assets_by_id(asset_id), None);
+		assert_eq!(EvmForeignAssets::assets_by_location(&asset_location), None);
+		assert_eq!(EvmForeignAssets::assets_creation_details(asset_id), None);
+		assert!(!pallet_evm::AccountCodes::<Test>::contains_key(
+			EvmForeignAssets::contract_address_from_asset_id(asset_id)
+		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::ForeignAssetDestroyed {
+				asset_id,
+				xcm_location: asset_location.clone(),
+				deposit: Some(deposit),
+			})
+		);
+
+		// The asset id and location can be reused
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id,
+			asset_location,
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+	});
+}
+
+#[test]
+fn test_destroyed_asset_storage_is_cleared_lazily() {
+	let mut ext = ExtBuilder::default().build();
+	let asset_location: Location = (Parent, Parachain(1), PalletInstance(13)).into();
+	let asset_id = 1;
+	let contract_address = EvmForeignAssets::contract_address_from_asset_id(asset_id);
+
+	ext.execute_with(|| {
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::root(),
+			asset_id,
+			asset_location.clone(),
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+
+		// Simulate allowances left behind by the token holders
+		for i in 0..DESTROY_ASSET_STORAGE_LIMIT + 2 {
+			pallet_evm::AccountStorages::<Test>::insert(
+				contract_address,
+				ethereum_types::H256::from_low_u64_be(u64::MAX - i as u64),
+				ethereum_types::H256::from_low_u64_be(1),
+			);
+		}
+	});
+	// Entries written in the current block are all removed at once, only the committed ones are
+	// limited
+	ext.commit_all()
+		.expect("failed to commit the contract storage");
+
+	ext.execute_with(|| {
+		assert_noop!(
+			EvmForeignAssets::clear_destroyed_asset_storage(
+				RuntimeOrigin::signed(PARA_B),
+				asset_id,
+				10
+			),
+			Error::<Test>::NoAssetStorageToClear,
+		);
+
+		assert_ok!(EvmForeignAssets::destroy_foreign_asset(
+			RuntimeOrigin::root(),
+			asset_id
+		));
+		assert_eq!(EvmForeignAssets::assets_by_id(asset_id), None);
+		assert!(EvmForeignAssets::assets_pending_storage_clearing(asset_id).is_some());
+		assert!(events().contains(&Event::ForeignAssetStorageClearingPending { asset_id }));
+		// The contract can't be called anymore while its storage is being cleared
+		assert!(!pallet_evm::AccountCodes::<Test>::contains_key(
+			contract_address
+		));
+
+		// The asset id can't be reused until the storage is cleared
+		assert_noop!(
+			EvmForeignAssets::create_foreign_asset(
+				RuntimeOrigin::root(),
+				asset_id,
+				asset_location.clone(),
+				18,
+				encode_ticker("MTT"),
+				encode_token_name("Mytoken"),
+			),
+			Error::<Test>::AssetStorageNotCleared,
+		);
+
+		// Anyone can clear the remaining storage
+		let remaining = pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).count();
+		assert!(remaining > 1);
+		assert_ok!(EvmForeignAssets::clear_destroyed_asset_storage(
+			RuntimeOrigin::signed(PARA_B),
+			asset_id,
+			1
+		));
+		assert!(EvmForeignAssets::assets_pending_storage_clearing(asset_id).is_some());
+		assert_eq!(
+			pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).count(),
+			remaining - 1
+		);
+
+		assert_ok!(EvmForeignAssets::clear_destroyed_asset_storage(
+			RuntimeOrigin::signed(PARA_B),
+			asset_id,
+			remaining as u32
+		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::ForeignAssetStorageCleared { asset_id })
+		);
+		assert_eq!(
+			EvmForeignAssets::assets_pending_storage_clearing(asset_id),
+			None
+		);
+		assert_eq!(
+			pallet_evm::AccountStorages::<Test>::iter_prefix(contract_address).count(),
+			0
+		);
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::root(),
+			asset_id,
+			asset_location,
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+	});
+}
+
+#[test]
+fn test_foreign_asset_ownership_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let deposit = ForeignAssetCreationDeposit::get();
+
+		Balances::make_free_balance_be(&PARA_A, deposit);
+		Balances::make_free_balance_be(&PARA_B, deposit);
+
+		let asset_location: Location = (Parent, Parachain(1), PalletInstance(13)).into();
+		let para_b_location: Location = (Parent, Parachain(2)).into();
+		let asset_id = 1;
+
+		assert_ok!(EvmForeignAssets::create_foreign_asset(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id,
+			asset_location.clone(),
+			18,
+			encode_ticker("MTT"),
+			encode_token_name("Mytoken"),
+		));
+
+		assert_noop!(
+			EvmForeignAssets::transfer_ownership(
+				RuntimeOrigin::signed(PARA_B),
+				asset_id,
+				para_b_location.clone(),
+			),
+			Error::<Test>::NotAssetOwner,
+		);
+		assert_noop!(
+			EvmForeignAssets::accept_ownership(RuntimeOrigin::signed(PARA_B), asset_id),
+			Error::<Test>::NoPendingOwnershipTransfer,
+		);
+
+		assert_ok!(EvmForeignAssets::transfer_ownership(
+			RuntimeOrigin::signed(PARA_A),
+			asset_id,
+			para_b_location.clone(),
+		));
+		assert_eq!(
+			EvmForeignAssets::pending_ownership_transfers(asset_id),
+			Some(para_b_location.clone())
+		);
+
+		// Only the proposed owner can accept
+		assert_noop!(
+			EvmForeignAssets::accept_ownership(RuntimeOrigin::signed(PARA_C), asset_id),
+			Error::<Test>::NoPendingOwnershipTransfer,
+		);
+
+		assert_ok!(EvmForeignAssets::accept_ownership(
+			RuntimeOrigin::signed(PARA_B),
+			asset_id
+		));
+
+		assert_eq!(Balances::reserved_balance(&PARA_A), 0);
+		assert_eq!(Balances::reserved_balance(&PARA_B), deposit);
+		assert_eq!(
+			EvmForeignAssets::pending_ownership_transfers(asset_id),
+			None
+		);
+		assert_eq!(
+			events().last(),
+			Some(&Event::ForeignAssetOwnershipTransferred {
+				asset_id,
+				previous_owner: Some(PARA_A),
+				new_owner: PARA_B,
+				deposit,
+			})
+		);
+
+		assert_eq!(
+			EvmForeignAssets::foreign_assets_info_by_owner(PARA_B),
+			vec![ForeignAssetInfo {
+				asset_id,
+				contract_address: EvmForeignAssets::contract_address_from_asset_id(asset_id),
+				xcm_location: asset_location,
+				status: AssetStatus::Active,
+				owner: Some(PARA_B),
+				deposit: Some(deposit),
+			}]
+		);
+		assert!(EvmForeignAssets::foreign_assets_info_by_owner(PARA_A).is_empty());
+
+		// The previous owner can't destroy the asset anymore
+		assert_noop!(
+			EvmForeignAssets::destroy_foreign_asset(RuntimeOrigin::signed(PARA_A), asset_id),
+			Error::<Test>::NotAssetOwner,
+		);
+		assert_ok!(EvmForeignAssets::destroy_foreign_asset(
+			RuntimeOrigin::signed(PARA_B),
+			asset_id
+		));
+		assert_eq!(Balances::reserved_balance(&PARA_B), 0);
+	});
+}
@@ -21,6 +21,15 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `girazoki-XPS-15-9530`, CPU: `13th Gen Intel(R) Core(TM) i9-13900H`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! The weights of `update_metadata` and `upgrade_asset_template` are written by hand, they
+//! postdate this run. `update_metadata` is bounded by 35 writes to the contract storage, the
+//! slots of a 256 bytes symbol and name and of the strings they replace. `upgrade_asset_template`
+//! costs a template deployment plus the copy of the resulting code.
+//!
+//! Same for the calls handling the end of life and the ownership of an asset:
+//! `destroy_foreign_asset`, `clear_destroyed_asset_storage`, `transfer_ownership` and
+//! `accept_ownership` are priced from the storage they access.
 
 // Executed Command:
 // ./target/release/moonbeam
@@ -55,6 +64,12 @@ pub trait WeightInfo {
 	fn change_xcm_location() -> Weight;
 	fn freeze_foreign_asset() -> Weight;
 	fn unfreeze_foreign_asset() -> Weight;
+	fn update_metadata() -> Weight;
+	fn upgrade_asset_template() -> Weight;
+	fn destroy_foreign_asset() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn clear_destroyed_asset_storage(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_foreign_asset_creator using the Substrate node and recommended hardware.
@@ -118,6 +133,93 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:2 w:35)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_metadata() -> Weight {
+		Weight::from_parts(48_000_000, 9042)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:2)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:2 w:2)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:4 w:4)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn upgrade_asset_template() -> Weight {
+		Weight::from_parts(86_203_000, 30277)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:65 w:64)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::CounterForAssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::CounterForAssetsById` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsByLocation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn destroy_foreign_asset() -> Weight {
+		Weight::from_parts(97_105_000, 14134)
+			.saturating_add(T::DbWeight::get().reads(73_u64))
+			.saturating_add(T::DbWeight::get().writes(75_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(12_981_000, 3654)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(25_418_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:1000 w:1000)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn clear_destroyed_asset_storage(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3600)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -178,4 +280,91 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:2 w:35)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_metadata() -> Weight {
+		Weight::from_parts(48_000_000, 9042)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:2)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:2 w:2)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:4 w:4)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn upgrade_asset_template() -> Weight {
+		Weight::from_parts(86_203_000, 30277)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:65 w:64)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::CounterForAssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::CounterForAssetsById` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsByLocation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn destroy_foreign_asset() -> Weight {
+		Weight::from_parts(97_105_000, 14134)
+			.saturating_add(RocksDbWeight::get().reads(73_u64))
+			.saturating_add(RocksDbWeight::get().writes(75_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(12_981_000, 3654)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(25_418_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:1000 w:1000)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn clear_destroyed_asset_storage(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3600)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(n.into()))
+	}
 }
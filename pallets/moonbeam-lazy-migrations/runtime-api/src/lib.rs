@@ -0,0 +1,39 @@
+// Copyright 2025 Moonbeam Foundation.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API allowing to query the progress of the lazy migrations registered in
+//! pallet-moonbeam-lazy-migrations.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait LazyMigrationsApi<MigrationId, MigrationStatus>
+	where
+		MigrationId: Codec,
+		MigrationStatus: Codec,
+	{
+		/// List the registered lazy migrations with their status
+		fn lazy_migrations() -> Vec<(MigrationId, MigrationStatus)>;
+		/// Get the status of a lazy migration, `None` if it isn't registered
+		fn lazy_migration_status(id: MigrationId) -> Option<MigrationStatus>;
+	}
+}
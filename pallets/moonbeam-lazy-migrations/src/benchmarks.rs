@@ -15,7 +15,13 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 // #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{foreign_asset::ForeignAssetMigrationStatus, Call, Config, Pallet};
+use crate::lazy_migration::{
+	MAX_MIGRATION_CURSOR_LEN, MAX_MIGRATION_ERROR_LEN, MAX_MIGRATION_ID_LEN,
+};
+use crate::{
+	foreign_asset::ForeignAssetMigrationStatus, Call, Config, LazyMigrationStatus, MigrationCursor,
+	MigrationId, Pallet,
+};
 use frame_benchmarking::{account, benchmarks};
 use frame_support::traits::Currency;
 use frame_support::BoundedVec;
@@ -231,4 +237,24 @@ benchmarks! {
 			ForeignAssetMigrationStatus::Idle
 		);
 	}
+
+	reset_lazy_migration {
+		let id: MigrationId = BoundedVec::truncate_from(vec![1u8; MAX_MIGRATION_ID_LEN as usize]);
+		let cursor: MigrationCursor =
+			BoundedVec::truncate_from(vec![1u8; MAX_MIGRATION_CURSOR_LEN as usize]);
+		crate::pallet::LazyMigrationStatuses::<T>::insert(
+			&id,
+			LazyMigrationStatus::Failed {
+				cursor: Some(cursor.clone()),
+				steps: 1,
+				error: BoundedVec::truncate_from(vec![1u8; MAX_MIGRATION_ERROR_LEN as usize]),
+			},
+		);
+	}: _(RawOrigin::Root, id.clone())
+	verify {
+		assert_eq!(
+			Pallet::<T>::lazy_migration_status(&id),
+			LazyMigrationStatus::InProgress { cursor, steps: 1 }
+		);
+	}
 }
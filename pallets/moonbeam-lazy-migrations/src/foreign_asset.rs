@@ -19,6 +19,7 @@
 use super::*;
 use frame_support::sp_runtime::Saturating;
 use frame_support::traits::{fungibles::metadata::Inspect, ReservableCurrency};
+use frame_support::weights::WeightMeter;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_core::{H160, U256};
 
@@ -79,6 +80,10 @@ where
 					.map_err(|_| Error::<T>::NameTooLong)?;
 				let asset_type = pallet_asset_manager::AssetIdType::<T>::take(asset_id)
 					.ok_or(Error::<T>::AssetTypeNotFound)?;
+				// Also remove the reverse mapping, otherwise the xcm location would keep being
+				// resolved to the old asset by pallet-asset-manager and its storage could never
+				// be cleaned once all the assets are migrated.
+				pallet_asset_manager::AssetTypeId::<T>::remove(&asset_type);
 				let xcm_location: Location =
 					asset_type.into().ok_or(Error::<T>::LocationNotFound)?;
 
@@ -111,12 +116,14 @@ where
 		})
 	}
 
-	pub(super) fn do_migrate_foreign_asset_balances(limit: u32) -> DispatchResult {
+	/// Migrate up to `limit` balances of the asset being migrated.
+	/// Returns the number of balances that remain to be migrated.
+	pub(super) fn do_migrate_foreign_asset_balances(limit: u32) -> Result<u32, DispatchError> {
 		use pallet_assets::ExistenceReason::*;
 
 		ensure!(limit != 0, Error::<T>::LimitCannotBeZero);
 
-		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> DispatchResult {
+		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> Result<u32, DispatchError> {
 			let info = match status {
 				ForeignAssetMigrationStatus::Migrating(info) => info,
 				_ => return Err(Error::<T>::NoMigrationInProgress.into()),
@@ -159,14 +166,16 @@ where
 					Ok::<(), Error<T>>(())
 				})?;
 
-			Ok(())
+			Ok(info.remaining_balances)
 		})
 	}
 
-	pub(super) fn do_migrate_foreign_asset_approvals(limit: u32) -> DispatchResult {
+	/// Migrate up to `limit` approvals of the asset being migrated.
+	/// Returns the number of approvals that remain to be migrated.
+	pub(super) fn do_migrate_foreign_asset_approvals(limit: u32) -> Result<u32, DispatchError> {
 		ensure!(limit != 0, Error::<T>::LimitCannotBeZero);
 
-		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> DispatchResult {
+		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> Result<u32, DispatchError> {
 			let info = match status {
 				ForeignAssetMigrationStatus::Migrating(info) => info,
 				_ => return Err(Error::<T>::NoMigrationInProgress.into()),
@@ -206,13 +215,14 @@ where
 					Ok::<(), Error<T>>(())
 				})?;
 
-			Ok(())
+			Ok(info.remaining_approvals)
 		})
 	}
 
 	/// Finish Migration
-	pub(super) fn do_finish_foreign_asset_migration() -> DispatchResult {
-		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> DispatchResult {
+	/// Returns the id of the migrated asset.
+	pub(super) fn do_finish_foreign_asset_migration() -> Result<u128, DispatchError> {
+		ForeignAssetMigrationStatusValue::<T>::try_mutate(|status| -> Result<u128, DispatchError> {
 			let migration_info = match status {
 				ForeignAssetMigrationStatus::Migrating(info) => info,
 				_ => return Err(Error::<T>::NoMigrationInProgress.into()),
@@ -238,9 +248,135 @@ where
 				},
 			)?;
 
-			ApprovedForeignAssets::<T>::remove(migration_info.asset_id);
+			let asset_id = migration_info.asset_id;
+			ApprovedForeignAssets::<T>::remove(asset_id);
 			*status = ForeignAssetMigrationStatus::Idle;
-			Ok(())
+			Ok(asset_id)
 		})
 	}
 }
+
+/// Lazy migration moving the approved foreign assets from `pallet-assets` to the moonbeam
+/// foreign assets pallet, one asset at a time.
+///
+/// The migration state is kept in `ForeignAssetMigrationStatusValue`, so it can be resumed
+/// across runtime upgrades and interleaved with the manual extrinsics. It waits for governance
+/// approvals as long as some assets remain in `pallet-assets`, and completes once all of them
+/// have been migrated, at which point the `pallet-assets` and `pallet-asset-manager` storage
+/// can be safely removed.
+pub struct ForeignAssetsLazyMigration<T>(PhantomData<T>);
+
+impl<T: Config> LazyMigration for ForeignAssetsLazyMigration<T>
+where
+	<T as pallet_assets::Config>::Balance: Into<U256>,
+	<T as pallet_asset_manager::Config>::ForeignAssetType: Into<Option<Location>>,
+	<T as frame_system::Config>::AccountId: Into<H160> + From<H160>,
+{
+	fn id() -> MigrationId {
+		BoundedVec::truncate_from(b"foreign-assets".to_vec())
+	}
+
+	fn step(
+		_cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, LazyMigrationError> {
+		// The progress is tracked in storage, the cursor is only used to keep the migration going
+		let cursor = MigrationCursor::default();
+
+		// Read of the migration status
+		consume(meter, T::DbWeight::get().reads(1))?;
+		let info = match ForeignAssetMigrationStatusValue::<T>::get() {
+			ForeignAssetMigrationStatus::Migrating(info) => info,
+			ForeignAssetMigrationStatus::Idle => {
+				// Read of the next approved asset and of the remaining assets
+				consume(meter, T::DbWeight::get().reads(2))?;
+
+				let asset_id = match ApprovedForeignAssets::<T>::iter_keys().next() {
+					Some(asset_id) => asset_id,
+					None if pallet_assets::Asset::<T>::iter_keys().next().is_none() => {
+						return Ok(None);
+					}
+					None => return Err(LazyMigrationError::Waiting),
+				};
+
+				consume(
+					meter,
+					<T as Config>::WeightInfo::start_foreign_assets_migration(),
+				)?;
+				Pallet::<T>::do_start_foreign_asset_migration(asset_id)?;
+				Pallet::<T>::deposit_event(Event::ForeignAssetMigrationStarted { asset_id });
+
+				return Ok(Some(cursor));
+			}
+		};
+
+		if info.remaining_balances > 0 {
+			let limit = max_items(
+				meter,
+				info.remaining_balances,
+				<T as Config>::WeightInfo::migrate_foreign_asset_balances,
+			)?;
+			let remaining = Pallet::<T>::do_migrate_foreign_asset_balances(limit)?;
+			Pallet::<T>::deposit_event(Event::ForeignAssetBalancesMigrated {
+				asset_id: info.asset_id,
+				remaining,
+			});
+		} else if info.remaining_approvals > 0 {
+			let limit = max_items(
+				meter,
+				info.remaining_approvals,
+				<T as Config>::WeightInfo::migrate_foreign_asset_approvals,
+			)?;
+			let remaining = Pallet::<T>::do_migrate_foreign_asset_approvals(limit)?;
+			Pallet::<T>::deposit_event(Event::ForeignAssetApprovalsMigrated {
+				asset_id: info.asset_id,
+				remaining,
+			});
+		} else {
+			consume(
+				meter,
+				<T as Config>::WeightInfo::finish_foreign_assets_migration(),
+			)?;
+			let asset_id = Pallet::<T>::do_finish_foreign_asset_migration()?;
+			Pallet::<T>::deposit_event(Event::ForeignAssetMigrationFinished { asset_id });
+		}
+
+		Ok(Some(cursor))
+	}
+}
+
+fn consume(meter: &mut WeightMeter, weight: Weight) -> Result<(), LazyMigrationError> {
+	meter
+		.try_consume(weight)
+		.map_err(|_| LazyMigrationError::InsufficientWeight)
+}
+
+/// Consume the weight of processing as many items as possible, up to `remaining_items`,
+/// and return the number of items to process.
+fn max_items(
+	meter: &mut WeightMeter,
+	remaining_items: u32,
+	weight_of: impl Fn(u32) -> Weight,
+) -> Result<u32, LazyMigrationError> {
+	let base = weight_of(0);
+	let per_item = weight_of(1).saturating_sub(base);
+	let available = meter
+		.remaining()
+		.checked_sub(&base)
+		.ok_or(LazyMigrationError::InsufficientWeight)?;
+
+	let mut items = remaining_items.min(ARRAY_LIMIT);
+	if per_item.ref_time() > 0 {
+		items = items.min((available.ref_time() / per_item.ref_time()).min(u32::MAX as u64) as u32);
+	}
+	if per_item.proof_size() > 0 {
+		items =
+			items.min((available.proof_size() / per_item.proof_size()).min(u32::MAX as u64) as u32);
+	}
+	if items == 0 {
+		return Err(LazyMigrationError::InsufficientWeight);
+	}
+
+	consume(meter, weight_of(items))?;
+	Ok(items)
+}
@@ -0,0 +1,232 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Generic cursor based lazy migrations
+//!
+//! A lazy migration is applied over several blocks: each call to [`LazyMigration::step`]
+//! processes a bounded amount of items, starting from the cursor returned by the previous step.
+//! The migrations registered in the pallet configuration are progressed in order, either in
+//! `on_idle` or through the permissionless `step_lazy_migrations` extrinsic.
+
+use super::*;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use frame_support::weights::WeightMeter;
+use sp_std::vec::Vec;
+
+/// Maximum length of a lazy migration identifier
+pub const MAX_MIGRATION_ID_LEN: u32 = 32;
+/// Maximum length of a lazy migration cursor
+pub const MAX_MIGRATION_CURSOR_LEN: u32 = 1024;
+/// Maximum length of the encoded error of a failed lazy migration
+pub const MAX_MIGRATION_ERROR_LEN: u32 = 256;
+
+pub type MigrationId = BoundedVec<u8, ConstU32<MAX_MIGRATION_ID_LEN>>;
+pub type MigrationCursor = BoundedVec<u8, ConstU32<MAX_MIGRATION_CURSOR_LEN>>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LazyMigrationError {
+	/// There was not enough weight left to make any progress, the step will be retried later
+	InsufficientWeight,
+	/// The migration has nothing to process until an external condition is met, the following
+	/// migrations are progressed meanwhile
+	Waiting,
+	/// The migration can't make progress anymore
+	Failed(DispatchError),
+}
+
+impl From<DispatchError> for LazyMigrationError {
+	fn from(e: DispatchError) -> Self {
+		Self::Failed(e)
+	}
+}
+
+/// A migration that is applied lazily, over several blocks.
+pub trait LazyMigration {
+	/// Unique identifier of the migration, used as key of its status in storage.
+	fn id() -> MigrationId;
+
+	/// Process a bounded amount of items, starting from `cursor` (`None` for the first step),
+	/// without consuming more than the weight left in `meter`.
+	///
+	/// Returns the cursor to resume from, or `None` once the migration is complete.
+	/// The storage changes of a failed step are rolled back.
+	fn step(
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, LazyMigrationError>;
+}
+
+/// Registry of lazy migrations, implemented for tuples of [`LazyMigration`].
+/// Migrations are progressed in the order of the tuple.
+pub trait LazyMigrations {
+	/// Identifiers of the registered migrations
+	fn ids() -> Vec<MigrationId>;
+
+	/// Step the migration with the given identifier, `None` if it isn't registered
+	fn step(
+		id: &MigrationId,
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Option<Result<Option<MigrationCursor>, LazyMigrationError>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+#[tuple_types_custom_trait_bound(LazyMigration)]
+impl LazyMigrations for Tuple {
+	fn ids() -> Vec<MigrationId> {
+		let mut ids = Vec::new();
+		for_tuples!( #( ids.push(Tuple::id()); )* );
+		ids
+	}
+
+	fn step(
+		id: &MigrationId,
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Option<Result<Option<MigrationCursor>, LazyMigrationError>> {
+		for_tuples!( #(
+			if Tuple::id() == *id {
+				return Some(Tuple::step(cursor, meter));
+			}
+		)* );
+		None
+	}
+}
+
+#[derive(Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+pub enum LazyMigrationStatus {
+	/// No step has been performed yet
+	Pending,
+	/// Some steps have been performed, the next one will resume from `cursor`
+	InProgress { cursor: MigrationCursor, steps: u32 },
+	/// All items have been migrated
+	Completed { steps: u32 },
+	/// The migration failed and won't be progressed until it is reset, it then resumes from
+	/// `cursor`. `error` is the SCALE encoded `DispatchError` returned by the failed step.
+	Failed {
+		cursor: Option<MigrationCursor>,
+		steps: u32,
+		error: BoundedVec<u8, ConstU32<MAX_MIGRATION_ERROR_LEN>>,
+	},
+}
+
+impl<T: Config> Pallet<T> {
+	/// Status of all the registered lazy migrations
+	pub fn lazy_migrations_status() -> Vec<(MigrationId, LazyMigrationStatus)> {
+		T::LazyMigrations::ids()
+			.into_iter()
+			.map(|id| {
+				let status = Self::lazy_migration_status(&id);
+				(id, status)
+			})
+			.collect()
+	}
+
+	/// Status of a given lazy migration
+	pub fn lazy_migration_status(id: &MigrationId) -> LazyMigrationStatus {
+		LazyMigrationStatuses::<T>::get(id).unwrap_or(LazyMigrationStatus::Pending)
+	}
+
+	/// Progress the pending lazy migrations, in order, within the weight left in `meter`.
+	/// Returns whether any step has been performed.
+	pub(crate) fn progress_lazy_migrations(meter: &mut WeightMeter) -> bool {
+		// Read and write of the migration status
+		let status_weight = T::DbWeight::get().reads_writes(1, 1);
+		let mut progressed = false;
+
+		for id in T::LazyMigrations::ids() {
+			loop {
+				if meter.try_consume(status_weight).is_err() {
+					return progressed;
+				}
+
+				let (cursor, steps) = match Self::lazy_migration_status(&id) {
+					LazyMigrationStatus::Pending => (None, 0),
+					LazyMigrationStatus::InProgress { cursor, steps } => (Some(cursor), steps),
+					LazyMigrationStatus::Completed { .. } | LazyMigrationStatus::Failed { .. } => {
+						break;
+					}
+				};
+
+				let result = with_transaction(|| {
+					let result =
+						T::LazyMigrations::step(&id, cursor.clone(), meter).unwrap_or(Ok(None));
+					if result.is_ok() {
+						TransactionOutcome::Commit(result)
+					} else {
+						TransactionOutcome::Rollback(result)
+					}
+				});
+
+				let steps = steps.saturating_add(1);
+				match result {
+					Ok(Some(cursor)) => {
+						if steps == 1 {
+							Self::deposit_event(Event::LazyMigrationStarted { id: id.clone() });
+						}
+						LazyMigrationStatuses::<T>::insert(
+							&id,
+							LazyMigrationStatus::InProgress { cursor, steps },
+						);
+						progressed = true;
+					}
+					Ok(None) => {
+						LazyMigrationStatuses::<T>::insert(
+							&id,
+							LazyMigrationStatus::Completed { steps },
+						);
+						Self::deposit_event(Event::LazyMigrationCompleted { id, steps });
+						progressed = true;
+						break;
+					}
+					Err(LazyMigrationError::InsufficientWeight) => return progressed,
+					Err(LazyMigrationError::Waiting) => break,
+					Err(LazyMigrationError::Failed(err)) => {
+						let error = BoundedVec::truncate_from(err.encode());
+						log::warn!("Lazy migration {:?} failed: {:?}", id, err);
+						LazyMigrationStatuses::<T>::insert(
+							&id,
+							LazyMigrationStatus::Failed {
+								cursor,
+								steps,
+								error: error.clone(),
+							},
+						);
+						Self::deposit_event(Event::LazyMigrationFailed { id, error });
+						progressed = true;
+						break;
+					}
+				}
+			}
+		}
+
+		progressed
+	}
+
+	/// Reset a failed lazy migration, so that it resumes from the step that failed
+	pub(crate) fn do_reset_lazy_migration(id: &MigrationId) -> DispatchResult {
+		LazyMigrationStatuses::<T>::try_mutate_exists(id, |maybe_status| match maybe_status {
+			Some(LazyMigrationStatus::Failed { cursor, steps, .. }) => {
+				let (cursor, steps) = (cursor.take(), *steps);
+				// A migration that failed on its first step starts over
+				*maybe_status =
+					cursor.map(|cursor| LazyMigrationStatus::InProgress { cursor, steps });
+				Ok(())
+			}
+			_ => Err(Error::<T>::LazyMigrationNotFailed.into()),
+		})
+	}
+}
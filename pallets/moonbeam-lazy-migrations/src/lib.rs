@@ -28,23 +28,37 @@ mod tests;
 mod benchmarks;
 
 mod foreign_asset;
+pub mod lazy_migration;
+pub use foreign_asset::ForeignAssetsLazyMigration;
 pub mod weights;
 pub use weights::WeightInfo;
 
 use frame_support::pallet;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
+pub use lazy_migration::{
+	LazyMigration, LazyMigrationError, LazyMigrationStatus, LazyMigrations, MigrationCursor,
+	MigrationId,
+};
 pub use pallet::*;
+use sp_runtime::Perbill;
 use xcm::latest::Location;
 
 const MAX_CONTRACT_CODE_SIZE: u64 = 25 * 1024;
 
+/// Share of the maximum weight of a normal extrinsic that `step_lazy_migrations` can use
+const STEP_LAZY_MIGRATIONS_MAX_WEIGHT: Perbill = Perbill::from_percent(25);
+
+/// Share of the maximum weight of `step_lazy_migrations` that a call must consume to be free
+const STEP_LAZY_MIGRATIONS_MIN_FREE_WEIGHT: Perbill = Perbill::from_percent(10);
+
 environmental::environmental!(MIGRATING_FOREIGN_ASSETS: bool);
 
 #[pallet]
 pub mod pallet {
 	use super::*;
 	use crate::foreign_asset::ForeignAssetMigrationStatus;
+	use frame_support::weights::WeightMeter;
 	use sp_core::{H160, U256};
 
 	pub const ARRAY_LIMIT: u32 = 1000;
@@ -54,6 +68,11 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	/// Status of the lazy migrations that have been stepped at least once
+	#[pallet::storage]
+	pub(crate) type LazyMigrationStatuses<T: Config> =
+		StorageMap<_, Twox64Concat, MigrationId, LazyMigrationStatus, OptionQuery>;
+
 	#[pallet::storage]
 	pub(crate) type StateMigrationStatusValue<T: Config> =
 		StorageValue<_, (StateMigrationStatus, u64), ValueQuery>;
@@ -94,9 +113,46 @@ pub mod pallet {
 	{
 		// Origin that is allowed to start foreign assets migration
 		type ForeignAssetMigratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The lazy migrations to apply, progressed in order
+		type LazyMigrations: LazyMigrations;
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The first step of a lazy migration has been performed
+		LazyMigrationStarted { id: MigrationId },
+		/// All the items of a lazy migration have been migrated
+		LazyMigrationCompleted { id: MigrationId, steps: u32 },
+		/// A lazy migration failed and won't be progressed until it is reset
+		LazyMigrationFailed {
+			id: MigrationId,
+			error: BoundedVec<u8, ConstU32<{ lazy_migration::MAX_MIGRATION_ERROR_LEN }>>,
+		},
+		/// A failed lazy migration has been reset and will be retried
+		LazyMigrationReset { id: MigrationId },
+		/// The automatic migration of a foreign asset has started
+		ForeignAssetMigrationStarted { asset_id: u128 },
+		/// Some balances of the foreign asset being migrated have been migrated
+		ForeignAssetBalancesMigrated { asset_id: u128, remaining: u32 },
+		/// Some approvals of the foreign asset being migrated have been migrated
+		ForeignAssetApprovalsMigrated { asset_id: u128, remaining: u32 },
+		/// The automatic migration of a foreign asset is finished
+		ForeignAssetMigrationFinished { asset_id: u128 },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::progress_lazy_migrations(&mut meter);
+			meter.consumed()
+		}
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The limit cannot be zero
@@ -123,6 +179,10 @@ pub mod pallet {
 		MintFailed,
 		/// Fail to add an approval
 		ApprovalFailed,
+		/// No lazy migration could be progressed
+		NoLazyMigrationProgress,
+		/// The lazy migration is not in a failed state
+		LazyMigrationNotFailed,
 	}
 
 	#[pallet::call]
@@ -233,9 +293,66 @@ pub mod pallet {
 			Self::do_finish_foreign_asset_migration()?;
 			Ok(Pays::No.into())
 		}
+
+		/// Progress the pending lazy migrations within the given weight limit, capped to a
+		/// quarter of the maximum weight of an extrinsic.
+		/// Free of charge if the steps performed consume at least a tenth of that cap.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Pallet::<T>::step_lazy_migrations_weight_limit(*weight_limit))]
+		pub fn step_lazy_migrations(
+			origin: OriginFor<T>,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let mut meter =
+				WeightMeter::with_limit(Self::step_lazy_migrations_weight_limit(weight_limit));
+			ensure!(
+				Self::progress_lazy_migrations(&mut meter),
+				Error::<T>::NoLazyMigrationProgress
+			);
+
+			// Small steps are paid, so that they can't be used to fill blocks for free
+			let min_free_weight =
+				STEP_LAZY_MIGRATIONS_MIN_FREE_WEIGHT * Self::step_lazy_migrations_max_weight();
+			let pays_fee = if meter.consumed().any_gte(min_free_weight) {
+				Pays::No
+			} else {
+				Pays::Yes
+			};
+
+			Ok((Some(meter.consumed()), pays_fee).into())
+		}
+
+		/// Reset a failed lazy migration, once the cause of the failure has been fixed.
+		/// The migration is retried from the step that failed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reset_lazy_migration())]
+		pub fn reset_lazy_migration(origin: OriginFor<T>, id: MigrationId) -> DispatchResult {
+			T::ForeignAssetMigratorOrigin::ensure_origin(origin)?;
+
+			Self::do_reset_lazy_migration(&id)?;
+
+			Self::deposit_event(Event::LazyMigrationReset { id });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Maximum weight that a call to `step_lazy_migrations` can use
+		pub(crate) fn step_lazy_migrations_max_weight() -> Weight {
+			let block_weights = T::BlockWeights::get();
+			let max_extrinsic = block_weights
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(block_weights.max_block);
+			STEP_LAZY_MIGRATIONS_MAX_WEIGHT * max_extrinsic
+		}
+
+		fn step_lazy_migrations_weight_limit(weight_limit: Weight) -> Weight {
+			weight_limit.min(Self::step_lazy_migrations_max_weight())
+		}
+
 		fn create_contract_metadata_weight(code_size: u64) -> Weight {
 			// max entry size of AccountCodesMetadata (full key + value)
 			const PROOF_SIZE_CODE_METADATA: u64 = 100;
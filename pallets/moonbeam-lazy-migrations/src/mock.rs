@@ -18,8 +18,10 @@
 
 use super::*;
 use crate as pallet_moonbeam_lazy_migrations;
+use frame_support::storage;
 use frame_support::traits::{AsEnsureOriginWithArg, ConstU128, EitherOf};
 use frame_support::weights::constants::RocksDbWeight;
+use frame_support::weights::WeightMeter;
 use frame_support::{construct_runtime, parameter_types, traits::Everything, weights::Weight};
 use frame_system::{EnsureRoot, EnsureSigned, Origin};
 use pallet_asset_manager::AssetRegistrar;
@@ -46,7 +48,7 @@ construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Timestamp: pallet_timestamp,
 		EVM: pallet_evm,
-		LazyMigrations: pallet_moonbeam_lazy_migrations::{Pallet, Call},
+		LazyMigrations: pallet_moonbeam_lazy_migrations::{Pallet, Call, Storage, Event<T>},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>},
 		MoonbeamForeignAssets: pallet_moonbeam_foreign_assets::{Pallet, Call, Storage, Event<T>},
@@ -366,9 +368,51 @@ impl pallet_moonbeam_foreign_assets::Config for Test {
 	type Currency = Balances;
 }
 
+/// Lazy migration moving the `source` items to `target` keys, one item per step
+pub struct MockLazyMigration;
+impl LazyMigration for MockLazyMigration {
+	fn id() -> MigrationId {
+		BoundedVec::truncate_from(b"mock-migration".to_vec())
+	}
+
+	fn step(
+		cursor: Option<MigrationCursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<MigrationCursor>, LazyMigrationError> {
+		let item_weight = RocksDbWeight::get().reads_writes(1, 2);
+		if meter.try_consume(item_weight).is_err() {
+			return Err(LazyMigrationError::InsufficientWeight);
+		}
+
+		let index = cursor
+			.map(|cursor| u32::decode(&mut &cursor[..]).expect("valid cursor"))
+			.unwrap_or(0);
+		let value: u32 = storage::unhashed::take(&mock_migration_key(b"source", index)).ok_or(
+			LazyMigrationError::Failed(DispatchError::Other("missing item")),
+		)?;
+		storage::unhashed::put(&mock_migration_key(b"target", index), &value);
+
+		if index + 1 >= MockMigrationItems::get() {
+			Ok(None)
+		} else {
+			Ok(Some(BoundedVec::truncate_from((index + 1).encode())))
+		}
+	}
+}
+
+pub fn mock_migration_key(prefix: &[u8], index: u32) -> Vec<u8> {
+	[b"mock-migration-".as_ref(), prefix, &index.encode()].concat()
+}
+
+parameter_types! {
+	pub static MockMigrationItems: u32 = 5;
+}
+
 impl Config for Test {
 	type WeightInfo = ();
 	type ForeignAssetMigratorOrigin = EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type LazyMigrations = (MockLazyMigration,);
 }
 
 // Constants for test accounts
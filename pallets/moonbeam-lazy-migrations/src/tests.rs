@@ -968,3 +968,400 @@ fn test_finish_foreign_assets_migration_with_remaining_approvals() {
 		}
 	});
 }
+
+mod lazy_migrations {
+	use super::*;
+	use crate::mock::{
+		mock_migration_key, MockLazyMigration, MockMigrationItems, RuntimeEvent, System,
+	};
+	use crate::{
+		Event, ForeignAssetsLazyMigration, LazyMigration, LazyMigrationError, LazyMigrationStatus,
+	};
+	use frame_support::dispatch::{GetDispatchInfo, Pays};
+	use frame_support::storage;
+	use frame_support::traits::Hooks;
+	use frame_support::weights::constants::RocksDbWeight;
+	use frame_support::weights::{Weight, WeightMeter};
+	use parity_scale_codec::Encode;
+
+	fn insert_items(count: u32) {
+		MockMigrationItems::set(count);
+		for index in 0..count {
+			storage::unhashed::put(&mock_migration_key(b"source", index), &index);
+		}
+	}
+
+	fn migrated_items() -> u32 {
+		(0..MockMigrationItems::get())
+			.filter(|index| storage::unhashed::exists(&mock_migration_key(b"target", *index)))
+			.count() as u32
+	}
+
+	// Weight of one step of the mock migration, including the status read and write
+	fn step_weight() -> Weight {
+		RocksDbWeight::get().reads_writes(2, 3)
+	}
+
+	fn lazy_migration_events() -> Vec<Event<Test>> {
+		System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::LazyMigrations(inner) => Some(inner),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_on_idle_progresses_within_weight() {
+		ExtBuilder::default().build().execute_with(|| {
+			insert_items(5);
+			let id = MockLazyMigration::id();
+
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::Pending
+			);
+
+			// Enough weight for two steps only
+			let consumed = LazyMigrations::on_idle(1, step_weight() * 2 + Weight::from_parts(1, 1));
+			assert_eq!(consumed, step_weight() * 2);
+			assert_eq!(migrated_items(), 2);
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::InProgress {
+					cursor: BoundedVec::truncate_from(2u32.encode()),
+					steps: 2,
+				}
+			);
+
+			LazyMigrations::on_idle(2, Weight::MAX);
+			assert_eq!(migrated_items(), 5);
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::Completed { steps: 5 }
+			);
+			assert_eq!(
+				lazy_migration_events(),
+				vec![
+					Event::LazyMigrationStarted { id: id.clone() },
+					Event::LazyMigrationCompleted { id, steps: 5 },
+				]
+			);
+
+			// Completed migrations are not progressed anymore
+			assert_eq!(LazyMigrations::on_idle(3, Weight::MAX), Weight::zero());
+		});
+	}
+
+	#[test]
+	fn test_step_lazy_migrations_extrinsic() {
+		ExtBuilder::default().build().execute_with(|| {
+			insert_items(3);
+
+			// Not enough weight to perform a step
+			assert_noop!(
+				LazyMigrations::step_lazy_migrations(
+					RuntimeOrigin::signed(ALITH),
+					Weight::from_parts(1, 1)
+				),
+				Error::<Test>::NoLazyMigrationProgress
+			);
+
+			// A single step is too small to be free
+			let post_info =
+				LazyMigrations::step_lazy_migrations(RuntimeOrigin::signed(ALITH), step_weight())
+					.expect("one step can be performed");
+			assert_eq!(post_info.actual_weight, Some(step_weight()));
+			assert_eq!(post_info.pays_fee, Pays::Yes);
+			assert_eq!(migrated_items(), 1);
+
+			assert_ok!(LazyMigrations::step_lazy_migrations(
+				RuntimeOrigin::signed(ALITH),
+				Weight::MAX
+			));
+			assert_eq!(migrated_items(), 3);
+
+			assert_noop!(
+				LazyMigrations::step_lazy_migrations(RuntimeOrigin::signed(ALITH), Weight::MAX),
+				Error::<Test>::NoLazyMigrationProgress
+			);
+		});
+	}
+
+	#[test]
+	fn test_step_lazy_migrations_weight_is_capped() {
+		ExtBuilder::default().build().execute_with(|| {
+			let max_weight = LazyMigrations::step_lazy_migrations_max_weight();
+			let call = crate::Call::<Test>::step_lazy_migrations {
+				weight_limit: Weight::MAX,
+			};
+			assert_eq!(call.get_dispatch_info().weight, max_weight);
+
+			let max_steps = (max_weight.ref_time() / step_weight().ref_time()) as u32;
+			insert_items(max_steps + 1);
+
+			let post_info =
+				LazyMigrations::step_lazy_migrations(RuntimeOrigin::signed(ALITH), Weight::MAX)
+					.expect("steps can be performed");
+			assert!(post_info
+				.actual_weight
+				.expect("actual weight is reported")
+				.all_lte(max_weight));
+			assert_eq!(post_info.pays_fee, Pays::No);
+			assert_eq!(migrated_items(), max_steps);
+		});
+	}
+
+	#[test]
+	fn test_failed_lazy_migration_is_reported() {
+		ExtBuilder::default().build().execute_with(|| {
+			insert_items(3);
+			// The second item is missing
+			storage::unhashed::kill(&mock_migration_key(b"source", 1));
+			let id = MockLazyMigration::id();
+
+			LazyMigrations::on_idle(1, Weight::MAX);
+
+			let error = BoundedVec::truncate_from(DispatchError::Other("missing item").encode());
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::Failed {
+					cursor: Some(BoundedVec::truncate_from(1u32.encode())),
+					steps: 2,
+					error: error.clone(),
+				}
+			);
+			assert_eq!(
+				lazy_migration_events().last(),
+				Some(&Event::LazyMigrationFailed { id, error })
+			);
+			assert_eq!(migrated_items(), 1);
+
+			// Failed migrations are not progressed anymore
+			assert_eq!(LazyMigrations::on_idle(2, Weight::MAX), Weight::zero());
+		});
+	}
+
+	#[test]
+	fn test_reset_failed_lazy_migration() {
+		ExtBuilder::default().build().execute_with(|| {
+			insert_items(3);
+			// The second item is missing
+			storage::unhashed::kill(&mock_migration_key(b"source", 1));
+			let id = MockLazyMigration::id();
+
+			assert_noop!(
+				LazyMigrations::reset_lazy_migration(RuntimeOrigin::root(), id.clone()),
+				Error::<Test>::LazyMigrationNotFailed
+			);
+
+			LazyMigrations::on_idle(1, Weight::MAX);
+			assert!(matches!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::Failed { .. }
+			));
+
+			// Only governance can reset a migration
+			assert_noop!(
+				LazyMigrations::reset_lazy_migration(RuntimeOrigin::signed(ALITH), id.clone()),
+				DispatchError::BadOrigin
+			);
+
+			// Fix the cause of the failure and retry the failed step
+			storage::unhashed::put(&mock_migration_key(b"source", 1), &1u32);
+			assert_ok!(LazyMigrations::reset_lazy_migration(
+				RuntimeOrigin::root(),
+				id.clone()
+			));
+			assert_eq!(
+				lazy_migration_events().last(),
+				Some(&Event::LazyMigrationReset { id: id.clone() })
+			);
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::InProgress {
+					cursor: BoundedVec::truncate_from(1u32.encode()),
+					steps: 2,
+				}
+			);
+
+			LazyMigrations::on_idle(2, Weight::MAX);
+			assert_eq!(migrated_items(), 3);
+			assert_eq!(
+				LazyMigrations::lazy_migration_status(&id),
+				LazyMigrationStatus::Completed { steps: 4 }
+			);
+		});
+	}
+
+	#[test]
+	fn test_foreign_assets_lazy_migration() {
+		ExtBuilder::default().build().execute_with(|| {
+			let location = xcm::latest::Location::new(1, [xcm::latest::Junction::Parachain(1000)]);
+			let asset_id = create_old_foreign_asset(location.clone());
+			let spender = AccountId::from([3; 20]);
+
+			for who in [ALITH, BOB] {
+				assert_ok!(Assets::mint(
+					RuntimeOrigin::signed(AssetManager::account_id()),
+					asset_id.into(),
+					who.into(),
+					100,
+				));
+			}
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(BOB),
+				asset_id.into(),
+				spender.into(),
+				50,
+			));
+
+			// The asset is waiting for the approval of the migration
+			assert_eq!(
+				ForeignAssetsLazyMigration::<Test>::step(None, &mut WeightMeter::new()),
+				Err(LazyMigrationError::Waiting)
+			);
+
+			assert_ok!(LazyMigrations::approve_assets_to_migrate(
+				RuntimeOrigin::root(),
+				BoundedVec::try_from(vec![asset_id]).unwrap(),
+			));
+
+			// Start the migration of the asset
+			let mut cursor =
+				ForeignAssetsLazyMigration::<Test>::step(None, &mut WeightMeter::new())
+					.expect("migration can be started");
+			assert!(cursor.is_some());
+
+			// Only enough weight to migrate a single balance
+			let mut meter = WeightMeter::with_limit(
+				<() as crate::WeightInfo>::migrate_foreign_asset_balances(1)
+					+ RocksDbWeight::get().reads(1),
+			);
+			cursor = ForeignAssetsLazyMigration::<Test>::step(cursor, &mut meter)
+				.expect("one balance can be migrated");
+			assert_eq!(
+				ForeignAssetsLazyMigration::<Test>::step(cursor.clone(), &mut meter),
+				Err(LazyMigrationError::InsufficientWeight)
+			);
+
+			while let Some(next) = cursor {
+				cursor =
+					ForeignAssetsLazyMigration::<Test>::step(Some(next), &mut WeightMeter::new())
+						.expect("migration steps succeed");
+			}
+
+			// Everything has been moved to the foreign assets pallet
+			assert_eq!(
+				crate::pallet::ForeignAssetMigrationStatusValue::<Test>::get(),
+				ForeignAssetMigrationStatus::Idle
+			);
+			assert!(pallet_assets::Asset::<Test>::get(asset_id).is_none());
+			assert_eq!(
+				pallet_assets::Account::<Test>::iter_prefix(asset_id).count(),
+				0
+			);
+			assert!(pallet_asset_manager::AssetIdType::<Test>::get(asset_id).is_none());
+			assert_eq!(pallet_asset_manager::AssetTypeId::<Test>::iter().count(), 0);
+			assert_eq!(
+				pallet_moonbeam_foreign_assets::AssetsById::<Test>::get(asset_id),
+				Some(location)
+			);
+			assert_eq!(Balances::reserved_balance(&BOB), 0);
+
+			assert_eq!(
+				lazy_migration_events(),
+				vec![
+					Event::ForeignAssetMigrationStarted { asset_id },
+					Event::ForeignAssetBalancesMigrated {
+						asset_id,
+						remaining: 1,
+					},
+					Event::ForeignAssetBalancesMigrated {
+						asset_id,
+						remaining: 0,
+					},
+					Event::ForeignAssetApprovalsMigrated {
+						asset_id,
+						remaining: 0,
+					},
+					Event::ForeignAssetMigrationFinished { asset_id },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn test_foreign_assets_lazy_migration_resumes_manual_migration() {
+		ExtBuilder::default().build().execute_with(|| {
+			let location = xcm::latest::Location::new(1, [xcm::latest::Junction::Parachain(1000)]);
+			let asset_id = create_old_foreign_asset(location.clone());
+
+			for who in [ALITH, BOB] {
+				assert_ok!(Assets::mint(
+					RuntimeOrigin::signed(AssetManager::account_id()),
+					asset_id.into(),
+					who.into(),
+					100,
+				));
+			}
+			assert_ok!(LazyMigrations::approve_assets_to_migrate(
+				RuntimeOrigin::root(),
+				BoundedVec::try_from(vec![asset_id]).unwrap(),
+			));
+
+			// The migration is started and partially applied with the manual extrinsics
+			assert_ok!(LazyMigrations::start_foreign_assets_migration(
+				RuntimeOrigin::signed(ALITH),
+				asset_id,
+			));
+			// Both mappings of pallet-asset-manager are removed when the migration starts
+			assert!(pallet_asset_manager::AssetIdType::<Test>::get(asset_id).is_none());
+			assert!(
+				pallet_asset_manager::AssetTypeId::<Test>::get(MockAssetType::from(location))
+					.is_none()
+			);
+			assert_ok!(LazyMigrations::migrate_foreign_asset_balances(
+				RuntimeOrigin::signed(ALITH),
+				1
+			));
+
+			// The lazy migration resumes from the state left by the extrinsics
+			let mut cursor = None;
+			loop {
+				cursor = ForeignAssetsLazyMigration::<Test>::step(cursor, &mut WeightMeter::new())
+					.expect("migration steps succeed");
+				if cursor.is_none() {
+					break;
+				}
+			}
+
+			assert_eq!(
+				crate::pallet::ForeignAssetMigrationStatusValue::<Test>::get(),
+				ForeignAssetMigrationStatus::Idle
+			);
+			assert!(pallet_assets::Asset::<Test>::get(asset_id).is_none());
+			assert_eq!(
+				lazy_migration_events(),
+				vec![
+					Event::ForeignAssetBalancesMigrated {
+						asset_id,
+						remaining: 0,
+					},
+					Event::ForeignAssetApprovalsMigrated {
+						asset_id,
+						remaining: 0,
+					},
+					Event::ForeignAssetMigrationFinished { asset_id },
+				]
+			);
+
+			// Nothing is left to finish manually
+			assert_noop!(
+				LazyMigrations::finish_foreign_assets_migration(RuntimeOrigin::signed(ALITH)),
+				Error::<Test>::NoMigrationInProgress
+			);
+		});
+	}
+}
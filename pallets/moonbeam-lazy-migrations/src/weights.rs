@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `device-46.home`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `reset_lazy_migration` was added after this run and has not been benchmarked yet. It only
+//! updates the status of one lazy migration, so its base weight is an estimate.
 
 // Executed Command:
 // ./target/release/moonbeam
@@ -55,7 +58,8 @@ pub trait WeightInfo {
 	fn start_foreign_assets_migration() -> Weight;
 	fn migrate_foreign_asset_balances(n: u32, ) -> Weight;
 	fn migrate_foreign_asset_approvals(n: u32, ) -> Weight;
-	fn finish_foreign_assets_migration() -> Weight;	
+	fn finish_foreign_assets_migration() -> Weight;
+	fn reset_lazy_migration() -> Weight;
 }
 pub struct SubstrateWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
@@ -222,6 +226,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `MoonbeamLazyMigrations::LazyMigrationStatuses` (r:1 w:1)
+	/// Proof: `MoonbeamLazyMigrations::LazyMigrationStatuses` (`max_values`: None, `max_size`: Some(1331), added: 3806, mode: `MaxEncodedLen`)
+	fn reset_lazy_migration() -> Weight {
+		Weight::from_parts(10_000_000, 4796)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 impl WeightInfo for () {
@@ -388,4 +399,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `MoonbeamLazyMigrations::LazyMigrationStatuses` (r:1 w:1)
+	/// Proof: `MoonbeamLazyMigrations::LazyMigrationStatuses` (`max_values`: None, `max_size`: Some(1331), added: 3806, mode: `MaxEncodedLen`)
+	fn reset_lazy_migration() -> Weight {
+		Weight::from_parts(10_000_000, 4796)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -20,7 +20,8 @@
 
 use crate::{
 	AccountLookupOverride, BalanceOf, Call, CollatorPoolInfo, CollatorsPool, Config, CurrentRound,
-	ForceRotation, MinOrbiterDeposit, OrbiterPerRound, Pallet,
+	ForceRotation, ForfeitedOrbiterRewards, MinOrbiterDeposit, OrbiterPerRound, Pallet,
+	RegisteredOrbiter, SuspendedOrbiters,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnInitialize, ReservableCurrency};
@@ -252,7 +253,7 @@ benchmarks! {
 		collator_pool_info.add_orbiter(old_orbiter.clone());
 		collator_pool_info.add_orbiter(new_orbiter.clone());
 		// To put old_orbiter in place
-		let rotate_result = collator_pool_info.rotate_orbiter();
+		let rotate_result = collator_pool_info.rotate_orbiter(|_| true);
 
 		// Worst case: forced rotation
 		ForceRotation::<T>::put(true);
@@ -278,6 +279,24 @@ benchmarks! {
 		);
 
 	}
+
+	penalize_orbiter {
+		init::<T>();
+
+		let round: T::RoundIndex = 1u32.into();
+		<CurrentRound<T>>::put(round);
+
+		let collator: T::AccountId = create_funded_user::<T>("COLLATOR", USER_SEED, 10_000);
+		// Worst case, the orbiter has a deposit to slash and what remains is below the minimum
+		let orbiter: T::AccountId = create_orbiter::<T>("ORBITER", USER_SEED, 20_000);
+		<OrbiterPerRound<T>>::insert(round, &collator, &orbiter);
+
+	}: { Pallet::<T>::penalize_orbiter(round, collator.clone(), orbiter.clone(), 0, 20); }
+	verify {
+		assert!(<ForfeitedOrbiterRewards<T>>::contains_key(round, &collator));
+		assert!(<SuspendedOrbiters<T>>::contains_key(&orbiter));
+		assert_eq!(<RegisteredOrbiter<T>>::get(&orbiter), Some(false));
+	}
 }
 
 #[cfg(test)]
@@ -371,6 +390,13 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_on_new_round());
 		});
 	}
+
+	#[test]
+	fn bench_penalize_orbiter() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_penalize_orbiter());
+		});
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -25,6 +25,12 @@
 //! In order not to impact the other pallets (notably nimbus and parachain-staking) this pallet
 //! simply redefines the lookup NimbusId-> AccountId, in order to replace the collator by its
 //! currently selected orbiter.
+//!
+//! The block production of the active orbiters is checked at the start of each round, using the
+//! points awarded to their collator during the previous round. An orbiter that authored less than
+//! `MinOrbiterPerformance` of the expected blocks forfeits its rewards for that round, is slashed
+//! of `OrbiterMissedBlocksPenalty` of its deposit and is skipped in the rotations for
+//! `OrbiterSuspensionPeriod` rounds.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -42,9 +48,32 @@ pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
 
-use frame_support::pallet;
+use frame_support::{pallet, weights::Weight};
 use nimbus_primitives::{AccountLookup, NimbusId};
 
+/// Provides the block production performance of the collators, per round
+pub trait CollatorPerformance<AccountId, RoundIndex> {
+	/// Points awarded to the collator for the blocks authored during the given round
+	fn awarded_points(round: RoundIndex, collator: &AccountId) -> u32;
+	/// Points the collator would have been awarded by authoring all the blocks it was expected to
+	/// author during the given round, zero if it was not selected for this round
+	fn expected_points(round: RoundIndex, collator: &AccountId) -> u32;
+	/// Weight of reading both the awarded and the expected points of a collator
+	fn points_weight() -> Weight;
+}
+
+impl<AccountId, RoundIndex> CollatorPerformance<AccountId, RoundIndex> for () {
+	fn awarded_points(_round: RoundIndex, _collator: &AccountId) -> u32 {
+		0
+	}
+	fn expected_points(_round: RoundIndex, _collator: &AccountId) -> u32 {
+		0
+	}
+	fn points_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[pallet]
 pub mod pallet {
 	use super::*;
@@ -52,6 +81,8 @@ pub mod pallet {
 	use frame_support::traits::{Currency, NamedReservableCurrency};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::traits::{CheckedSub, One, Saturating, StaticLookup, Zero};
+	use sp_runtime::Perbill;
+	use sp_std::vec::Vec;
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
@@ -76,6 +107,9 @@ pub mod pallet {
 		/// Origin that is allowed to add a collator in orbiters program.
 		type AddCollatorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Block production performance of the collators, used to check the active orbiters.
+		type CollatorPerformance: CollatorPerformance<Self::AccountId, Self::RoundIndex>;
+
 		/// The currency type.
 		type Currency: NamedReservableCurrency<Self::AccountId>;
 
@@ -90,9 +124,22 @@ pub mod pallet {
 		/// Maximum number of round to keep on storage.
 		type MaxRoundArchive: Get<Self::RoundIndex>;
 
+		#[pallet::constant]
+		/// Minimum part of the expected points an active orbiter must be awarded in a round to
+		/// not be penalized.
+		type MinOrbiterPerformance: Get<Perbill>;
+
+		#[pallet::constant]
+		/// Part of the deposit slashed from an orbiter that did not author enough blocks.
+		type OrbiterMissedBlocksPenalty: Get<Perbill>;
+
 		/// Reserve identifier for this pallet instance.
 		type OrbiterReserveIdentifier: Get<ReserveIdentifierOf<Self>>;
 
+		#[pallet::constant]
+		/// Number of rounds during which a penalized orbiter is skipped in the rotations.
+		type OrbiterSuspensionPeriod: Get<Self::RoundIndex>;
+
 		#[pallet::constant]
 		/// Number of rounds before changing the selected orbiter.
 		/// WARNING: when changing `RotatePeriod`, you need a migration code that sets
@@ -135,6 +182,19 @@ pub mod pallet {
 	/// true to avoid holes in OrbiterPerRound.
 	pub(crate) type ForceRotation<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	#[pallet::storage]
+	/// Rounds for which the active orbiter of a collator pool did not author enough blocks, and
+	/// thus won't be rewarded
+	pub(crate) type ForfeitedOrbiterRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::RoundIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn min_orbiter_deposit)]
 	/// Minimum deposit required to be registered as an orbiter
@@ -154,9 +214,17 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn orbiter)]
-	/// Check if account is an orbiter
+	/// Check if account is an orbiter, `false` if the deposit of the orbiter fell below the minimum
+	/// after a penalty: it is skipped in the rotations until it tops up its deposit by registering
+	/// again.
 	pub type RegisteredOrbiter<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn suspended_orbiter)]
+	/// Penalized orbiters, with the round from which they can be selected again
+	pub type SuspendedOrbiters<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::RoundIndex>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_orbiter_deposit: BalanceOf<T>,
@@ -194,7 +262,9 @@ pub mod pallet {
 				// Otherwise, we should still have a lower limit, and implement a multi-block clear
 				// by using the return value of clear_prefix for subsequent blocks.
 				let result = OrbiterPerRound::<T>::clear_prefix(round_to_prune, u32::MAX, None);
-				T::WeightInfo::on_initialize(result.unique)
+				let forfeited =
+					ForfeitedOrbiterRewards::<T>::clear_prefix(round_to_prune, u32::MAX, None);
+				T::WeightInfo::on_initialize(result.unique.saturating_add(forfeited.unique))
 			} else {
 				T::DbWeight::get().reads(1)
 			}
@@ -243,6 +313,28 @@ pub mod pallet {
 			account: T::AccountId,
 			rewards: BalanceOf<T>,
 		},
+		/// The orbiter did not author enough blocks during the round and won't be rewarded for it.
+		OrbiterRewardForfeited {
+			account: T::AccountId,
+			rewards: BalanceOf<T>,
+		},
+		/// An active orbiter did not author enough blocks during a round, part of its deposit has
+		/// been slashed and it is skipped in the rotations until `suspended_until`.
+		OrbiterPenalized {
+			collator: T::AccountId,
+			orbiter: T::AccountId,
+			round: T::RoundIndex,
+			awarded_points: u32,
+			expected_points: u32,
+			penalty: BalanceOf<T>,
+			suspended_until: T::RoundIndex,
+		},
+		/// The deposit of a penalized orbiter fell below the minimum, it is skipped in the
+		/// rotations until it tops up its deposit by registering again.
+		OrbiterDepositBelowMinimum {
+			account: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
 		OrbiterRotation {
 			collator: T::AccountId,
 			old_orbiter: Option<T::AccountId>,
@@ -375,6 +467,7 @@ pub mod pallet {
 
 			T::Currency::unreserve_all_named(&T::OrbiterReserveIdentifier::get(), &orbiter);
 			RegisteredOrbiter::<T>::remove(&orbiter);
+			SuspendedOrbiters::<T>::remove(&orbiter);
 			Self::deposit_event(Event::OrbiterUnregistered { account: orbiter });
 
 			Ok(())
@@ -453,10 +546,16 @@ pub mod pallet {
 			// Update current orbiter for each pool and edit AccountLookupOverride accordingly.
 			CollatorsPool::<T>::translate::<CollatorPoolInfo<T::AccountId>, _>(
 				|collator, mut pool| {
+					// Penalized orbiters are skipped until the end of their suspension, and until
+					// they top up their deposit if it fell below the minimum
 					let RotateOrbiterResult {
 						maybe_old_orbiter,
 						maybe_next_orbiter,
-					} = pool.rotate_orbiter();
+					} = pool.rotate_orbiter(|orbiter| {
+						SuspendedOrbiters::<T>::get(orbiter)
+							.map_or(true, |suspended_until| suspended_until <= round_index)
+							&& RegisteredOrbiter::<T>::get(orbiter) != Some(false)
+					});
 
 					// remove old orbiter, if any.
 					if let Some(CurrentOrbiter {
@@ -474,6 +573,10 @@ pub mod pallet {
 						writes += 1;
 					}
 					if let Some(next_orbiter) = maybe_next_orbiter {
+						// The suspension of the selected orbiter, if any, has ended
+						SuspendedOrbiters::<T>::remove(&next_orbiter);
+						writes += 1;
+
 						// Forbidding the collator to write blocks, it is now up to its orbiters to do it.
 						AccountLookupOverride::<T>::insert(
 							collator.clone(),
@@ -520,11 +623,96 @@ pub mod pallet {
 			);
 			T::DbWeight::get().reads_writes(1, writes)
 		}
+		/// Check the block production of the orbiters that were active during the given round,
+		/// and penalize the ones that did not author enough blocks.
+		fn check_orbiters_performance(round_index: T::RoundIndex) -> Weight {
+			let active_orbiters: Vec<(T::AccountId, T::AccountId)> =
+				OrbiterPerRound::<T>::iter_prefix(round_index).collect();
+			// reads: OrbiterPerRound entry and collator performance
+			let mut weight = T::DbWeight::get()
+				.reads(active_orbiters.len() as u64)
+				.saturating_add(
+					T::CollatorPerformance::points_weight()
+						.saturating_mul(active_orbiters.len() as u64),
+				);
+
+			for (collator, orbiter) in active_orbiters {
+				let expected_points =
+					T::CollatorPerformance::expected_points(round_index, &collator);
+				if expected_points.is_zero() {
+					continue;
+				}
+				let awarded_points = T::CollatorPerformance::awarded_points(round_index, &collator);
+				if awarded_points < T::MinOrbiterPerformance::get() * expected_points {
+					Self::penalize_orbiter(
+						round_index,
+						collator,
+						orbiter,
+						awarded_points,
+						expected_points,
+					);
+					weight = weight.saturating_add(T::WeightInfo::penalize_orbiter());
+				}
+			}
+
+			weight
+		}
+		/// Slash part of the orbiter deposit, forfeit its rewards for the given round and suspend
+		/// it from the rotations. If the remaining deposit is below the minimum, the orbiter has to
+		/// top it up before being selected again.
+		pub(crate) fn penalize_orbiter(
+			round_index: T::RoundIndex,
+			collator: T::AccountId,
+			orbiter: T::AccountId,
+			awarded_points: u32,
+			expected_points: u32,
+		) {
+			let reserve_identifier = T::OrbiterReserveIdentifier::get();
+			let deposit = T::Currency::reserved_balance_named(&reserve_identifier, &orbiter);
+			let penalty = T::OrbiterMissedBlocksPenalty::get() * deposit;
+			// The slashed amount is burned
+			let (_, not_slashed) =
+				T::Currency::slash_reserved_named(&reserve_identifier, &orbiter, penalty);
+			let penalty = penalty.saturating_sub(not_slashed);
+
+			let remaining_deposit = deposit.saturating_sub(penalty);
+			if let Some(min_orbiter_deposit) = MinOrbiterDeposit::<T>::get() {
+				if remaining_deposit < min_orbiter_deposit {
+					RegisteredOrbiter::<T>::insert(&orbiter, false);
+					Self::deposit_event(Event::OrbiterDepositBelowMinimum {
+						account: orbiter.clone(),
+						deposit: remaining_deposit,
+					});
+				}
+			}
+
+			ForfeitedOrbiterRewards::<T>::insert(round_index, &collator, ());
+
+			let suspended_until =
+				CurrentRound::<T>::get().saturating_add(T::OrbiterSuspensionPeriod::get());
+			SuspendedOrbiters::<T>::insert(&orbiter, suspended_until);
+
+			Self::deposit_event(Event::OrbiterPenalized {
+				collator,
+				orbiter,
+				round: round_index,
+				awarded_points,
+				expected_points,
+				penalty,
+				suspended_until,
+			});
+		}
 		/// Notify this pallet that a new round begin
 		pub fn on_new_round(round_index: T::RoundIndex) -> Weight {
 			CurrentRound::<T>::put(round_index);
 
-			if ForceRotation::<T>::get() {
+			// Check the orbiters that were active during the round that just ended
+			let performance_weight = match round_index.checked_sub(&One::one()) {
+				Some(previous_round) => Self::check_orbiters_performance(previous_round),
+				None => Weight::zero(),
+			};
+
+			let rotation_weight = if ForceRotation::<T>::get() {
 				ForceRotation::<T>::put(false);
 				let _ = Self::on_rotate(round_index);
 				T::WeightInfo::on_new_round()
@@ -533,7 +721,9 @@ pub mod pallet {
 				T::WeightInfo::on_new_round()
 			} else {
 				T::DbWeight::get().writes(1)
-			}
+			};
+
+			rotation_weight.saturating_add(performance_weight)
 		}
 		/// Notify this pallet that a collator received rewards
 		pub fn distribute_rewards(
@@ -542,7 +732,12 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		) -> Weight {
 			if let Some(orbiter) = OrbiterPerRound::<T>::take(pay_for_round, &collator) {
-				if T::Currency::deposit_into_existing(&orbiter, amount).is_ok() {
+				if ForfeitedOrbiterRewards::<T>::take(pay_for_round, &collator).is_some() {
+					Self::deposit_event(Event::OrbiterRewardForfeited {
+						account: orbiter,
+						rewards: amount,
+					});
+				} else if T::Currency::deposit_into_existing(&orbiter, amount).is_ok() {
 					Self::deposit_event(Event::OrbiterRewarded {
 						account: orbiter,
 						rewards: amount,
@@ -112,6 +112,8 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub OrbiterReserveIdentifier: [u8; 4] = [b'o', b'r', b'b', b'i'];
+	pub const MinOrbiterPerformance: Perbill = Perbill::from_percent(50);
+	pub const OrbiterMissedBlocksPenalty: Perbill = Perbill::from_percent(10);
 }
 
 pub struct MockAccountLookup;
@@ -127,17 +129,45 @@ impl AccountLookup<AccountId> for MockAccountLookup {
 	}
 }
 
+parameter_types! {
+	/// Points awarded per (round, collator)
+	pub static AwardedPoints: Vec<((u32, AccountId), u32)> = vec![];
+	/// Points each collator is expected to be awarded per round
+	pub static ExpectedPoints: u32 = 0;
+}
+
+pub struct MockCollatorPerformance;
+impl pallet_moonbeam_orbiters::CollatorPerformance<AccountId, u32> for MockCollatorPerformance {
+	fn awarded_points(round: u32, collator: &AccountId) -> u32 {
+		AwardedPoints::get()
+			.into_iter()
+			.find(|(key, _)| *key == (round, *collator))
+			.map(|(_, points)| points)
+			.unwrap_or_default()
+	}
+	fn expected_points(_round: u32, _collator: &AccountId) -> u32 {
+		ExpectedPoints::get()
+	}
+	fn points_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 impl pallet_moonbeam_orbiters::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AccountLookup = MockAccountLookup;
 	type AddCollatorOrigin = EnsureRoot<AccountId>;
+	type CollatorPerformance = MockCollatorPerformance;
 	type Currency = Balances;
 	type DelCollatorOrigin = EnsureRoot<AccountId>;
 	/// Maximum number of orbiters per collator
 	type MaxPoolSize = ConstU32<2>;
 	/// Maximum number of round to keep on storage
 	type MaxRoundArchive = ConstU32<4>;
+	type MinOrbiterPerformance = MinOrbiterPerformance;
+	type OrbiterMissedBlocksPenalty = OrbiterMissedBlocksPenalty;
 	type OrbiterReserveIdentifier = OrbiterReserveIdentifier;
+	type OrbiterSuspensionPeriod = ConstU32<4>;
 	type RotatePeriod = ConstU32<2>;
 	/// Round index type.
 	type RoundIndex = u32;
@@ -16,8 +16,12 @@
 
 //! Unit testing
 
-use crate::mock::{roll_to, ExtBuilder, MoonbeamOrbiters, RuntimeOrigin, System, Test};
+use crate::mock::{
+	roll_to, AwardedPoints, Balances, ExpectedPoints, ExtBuilder, MoonbeamOrbiters, RuntimeOrigin,
+	System, Test,
+};
 use crate::{Error, Event};
+use frame_support::traits::Currency;
 use frame_support::{assert_noop, assert_ok};
 
 #[test]
@@ -84,6 +88,144 @@ fn test_orbiter_rotation() {
 		});
 }
 
+#[test]
+fn test_underperforming_orbiter_is_penalized_and_skipped() {
+	ExtBuilder::default()
+		.with_balances(vec![(2, 20_000), (3, 20_000)])
+		.with_min_orbiter_deposit(10_000)
+		.build()
+		.execute_with(|| {
+			// Add a collator with two orbiters to the orbiter program
+			assert_ok!(MoonbeamOrbiters::add_collator(RuntimeOrigin::root(), 1),);
+			assert_ok!(MoonbeamOrbiters::orbiter_register(RuntimeOrigin::signed(2)),);
+			assert_ok!(MoonbeamOrbiters::collator_add_orbiter(
+				RuntimeOrigin::signed(1),
+				2
+			),);
+			assert_ok!(MoonbeamOrbiters::orbiter_register(RuntimeOrigin::signed(3)),);
+			assert_ok!(MoonbeamOrbiters::collator_add_orbiter(
+				RuntimeOrigin::signed(1),
+				3
+			),);
+
+			// The orbiter 2 authors all its blocks in round 2 but none in round 3
+			ExpectedPoints::set(40);
+			AwardedPoints::set(vec![
+				((2, 1), 40),
+				((3, 1), 0),
+				((4, 1), 40),
+				((5, 1), 40),
+				((6, 1), 40),
+				((7, 1), 40),
+				((8, 1), 40),
+				((9, 1), 40),
+			]);
+
+			// Orbiter 2 is active for rounds 2 and 3
+			roll_to(4);
+			assert_eq!(crate::OrbiterPerRound::<Test>::get(3, 1), Some(2));
+
+			// Roll to fourth round, the orbiter 2 is penalized for round 3
+			roll_to(8);
+			let events = System::events();
+			assert_eq!(
+				events[events.len() - 3].event,
+				Event::<Test>::OrbiterPenalized {
+					collator: 1,
+					orbiter: 2,
+					round: 3,
+					awarded_points: 0,
+					expected_points: 40,
+					penalty: 1_000,
+					suspended_until: 8,
+				}
+				.into(),
+			);
+			assert_eq!(
+				events[events.len() - 2].event,
+				Event::<Test>::OrbiterDepositBelowMinimum {
+					account: 2,
+					deposit: 9_000,
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRotation {
+					collator: 1,
+					old_orbiter: Some(2),
+					new_orbiter: Some(3),
+				}
+				.into(),
+			);
+			assert_eq!(Balances::reserved_balance(2), 9_000);
+			assert_eq!(Balances::total_balance(&2), 19_000);
+			assert_eq!(MoonbeamOrbiters::suspended_orbiter(2), Some(8));
+
+			// The rewards of round 3 are forfeited
+			MoonbeamOrbiters::distribute_rewards(3, 1, 500);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRewardForfeited {
+					account: 2,
+					rewards: 500,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::total_balance(&2), 19_000);
+
+			// The rewards of round 2 are paid
+			MoonbeamOrbiters::distribute_rewards(2, 1, 500);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRewarded {
+					account: 2,
+					rewards: 500,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::total_balance(&2), 19_500);
+
+			// Roll to sixth round, the suspended orbiter 2 is skipped
+			roll_to(12);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRotation {
+					collator: 1,
+					old_orbiter: Some(3),
+					new_orbiter: Some(3),
+				}
+				.into(),
+			);
+
+			// Roll to eighth round, the suspension of orbiter 2 has ended but its deposit is still
+			// below the minimum
+			roll_to(16);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRotation {
+					collator: 1,
+					old_orbiter: Some(3),
+					new_orbiter: Some(3),
+				}
+				.into(),
+			);
+			assert_eq!(MoonbeamOrbiters::orbiter(2), Some(false));
+
+			// The orbiter 2 tops up its deposit
+			assert_ok!(MoonbeamOrbiters::orbiter_register(RuntimeOrigin::signed(2)),);
+			assert_eq!(Balances::reserved_balance(2), 10_000);
+			assert_eq!(MoonbeamOrbiters::orbiter(2), Some(true));
+
+			// Roll to tenth round, the orbiter 2 is selected again
+			roll_to(20);
+			System::assert_last_event(
+				Event::<Test>::OrbiterRotation {
+					collator: 1,
+					old_orbiter: Some(3),
+					new_orbiter: Some(2),
+				}
+				.into(),
+			);
+			assert_eq!(MoonbeamOrbiters::suspended_orbiter(2), None);
+		});
+}
+
 #[test]
 fn test_collator_add_orbiter() {
 	ExtBuilder::default()
@@ -114,22 +114,30 @@ impl<AccountId: Clone + PartialEq> CollatorPoolInfo<AccountId> {
 			RemoveOrbiterResult::OrbiterNotFound
 		}
 	}
-	pub(super) fn rotate_orbiter(&mut self) -> RotateOrbiterResult<AccountId> {
+	/// Select the next orbiter, skipping the ones that are not eligible
+	pub(super) fn rotate_orbiter(
+		&mut self,
+		is_eligible: impl Fn(&AccountId) -> bool,
+	) -> RotateOrbiterResult<AccountId> {
 		let maybe_old_orbiter = self.maybe_current_orbiter.clone();
-		if self.next_orbiter >= self.orbiters.len() as u32 {
+		let orbiters_count = self.orbiters.len() as u32;
+		if self.next_orbiter >= orbiters_count {
 			self.next_orbiter = 0;
 		}
-		let maybe_next_orbiter =
-			if let Some(next_orbiter) = self.orbiters.get(self.next_orbiter as usize) {
-				self.maybe_current_orbiter = Some(CurrentOrbiter {
-					account_id: next_orbiter.clone(),
-					removed: false,
-				});
-				self.next_orbiter += 1;
-				Some(next_orbiter.clone())
-			} else {
-				None
-			};
+		let maybe_next_index = (0..orbiters_count)
+			.map(|offset| (self.next_orbiter + offset) % orbiters_count)
+			.find(|index| is_eligible(&self.orbiters[*index as usize]));
+		let maybe_next_orbiter = if let Some(next_index) = maybe_next_index {
+			let next_orbiter = self.orbiters[next_index as usize].clone();
+			self.maybe_current_orbiter = Some(CurrentOrbiter {
+				account_id: next_orbiter.clone(),
+				removed: false,
+			});
+			self.next_orbiter = next_index + 1;
+			Some(next_orbiter)
+		} else {
+			None
+		};
 
 		RotateOrbiterResult {
 			maybe_old_orbiter,
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `benchmarker`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `penalize_orbiter` was added after this run and has not been benchmarked yet. Its base weight
+//! is an estimate, and the storage accesses listed below are those of its worst case, where the
+//! remaining deposit of the orbiter falls below the minimum.
 
 // Executed Command:
 // ./target/release/moonbeam
@@ -62,6 +66,7 @@ pub trait WeightInfo {
 	fn on_initialize(x: u32, ) -> Weight;
 	fn distribute_rewards() -> Weight;
 	fn on_new_round() -> Weight;
+	fn penalize_orbiter() -> Weight;
 }
 
 /// Weights for pallet_moonbeam_orbiters using the Substrate node and recommended hardware.
@@ -221,6 +226,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: MoonbeamOrbiters CurrentRound (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters CurrentRound (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters MinOrbiterDeposit (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters MinOrbiterDeposit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters ForfeitedOrbiterRewards (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters ForfeitedOrbiterRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters RegisteredOrbiter (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters RegisteredOrbiter (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters SuspendedOrbiters (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters SuspendedOrbiters (max_values: None, max_size: None, mode: Measured)
+	fn penalize_orbiter() -> Weight {
+		Weight::from_parts(34_687_000, 4502)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -379,4 +403,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: MoonbeamOrbiters CurrentRound (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters CurrentRound (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters MinOrbiterDeposit (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters MinOrbiterDeposit (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters ForfeitedOrbiterRewards (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters ForfeitedOrbiterRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters RegisteredOrbiter (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters RegisteredOrbiter (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters SuspendedOrbiters (r:0 w:1)
+	/// Proof Skipped: MoonbeamOrbiters SuspendedOrbiters (max_values: None, max_size: None, mode: Measured)
+	fn penalize_orbiter() -> Weight {
+		Weight::from_parts(34_687_000, 4502)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
\ No newline at end of file
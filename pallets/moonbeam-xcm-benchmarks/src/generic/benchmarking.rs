@@ -78,6 +78,57 @@ benchmarks! {
 		Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
 	}
 
+	hrmp_new_channel_open_request {
+		// The sibling is automatically accepted and reciprocated, which sends two messages
+		let sibling = 2000u32;
+		T::hrmp_notifications_setup(sibling)?;
+
+		let mut executor = new_executor::<T>(Location::parent());
+		let instruction = Instruction::<XcmCallOf<T>>::HrmpNewChannelOpenRequest {
+			sender: sibling,
+			max_message_size: 1024,
+			max_capacity: 8,
+		};
+		let xcm = Xcm(vec![instruction]);
+	} : {
+		executor.bench_process(xcm)?;
+	}
+
+	hrmp_channel_accepted {
+		let sibling = 2000u32;
+		T::hrmp_notifications_setup(sibling)?;
+
+		let mut executor = new_executor::<T>(Location::parent());
+		let instruction = Instruction::<XcmCallOf<T>>::HrmpChannelAccepted {
+			recipient: sibling,
+		};
+		let xcm = Xcm(vec![instruction]);
+	} : {
+		executor.bench_process(xcm)?;
+	}
+
+	hrmp_channel_closing {
+		let sibling = 2000u32;
+		let self_para_id = T::hrmp_notifications_setup(sibling)?;
+
+		// The closed channel is tracked
+		let mut executor = new_executor::<T>(Location::parent());
+		let accepted = Instruction::<XcmCallOf<T>>::HrmpChannelAccepted {
+			recipient: sibling,
+		};
+		executor.bench_process(Xcm(vec![accepted]))?;
+
+		let mut executor = new_executor::<T>(Location::parent());
+		let instruction = Instruction::<XcmCallOf<T>>::HrmpChannelClosing {
+			initiator: self_para_id,
+			sender: self_para_id,
+			recipient: sibling,
+		};
+		let xcm = Xcm(vec![instruction]);
+	} : {
+		executor.bench_process(xcm)?;
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::generic::mock::new_test_ext(),
@@ -91,9 +142,22 @@ pub struct XcmGenericBenchmarks<T>(sp_std::marker::PhantomData<T>);
 // We only need to implement benchmarks for the runtime-benchmarks feature or testing.
 impl<T: Config> frame_benchmarking::Benchmarking for XcmGenericBenchmarks<T> {
 	fn benchmarks(extra: bool) -> Vec<frame_benchmarking::BenchmarkMetadata> {
-		// Assuming we are overwritting, we only need to return the generics
+		// Assuming we are overwritting, we only need to return the generics, plus the
+		// benchmarks the upstream pallet does not have
 		use pallet_xcm_benchmarks::generic::Pallet as PalletXcmGenericBench;
-		PalletXcmGenericBench::<T>::benchmarks(extra)
+
+		use crate::generic::Pallet as MoonbeamXcmGenericBench;
+		let mut benchmarks = PalletXcmGenericBench::<T>::benchmarks(extra);
+		let moonbeam_only: Vec<_> = MoonbeamXcmGenericBench::<T>::benchmarks(extra)
+			.into_iter()
+			.filter(|moonbeam| {
+				!benchmarks
+					.iter()
+					.any(|upstream| upstream.name == moonbeam.name)
+			})
+			.collect();
+		benchmarks.extend(moonbeam_only);
+		benchmarks
 	}
 	fn run_benchmark(
 		extrinsic: &[u8],
@@ -229,7 +229,11 @@ impl pallet_xcm_benchmarks::generic::Config for Test {
 	}
 }
 
-impl generic::Config for Test {}
+impl generic::Config for Test {
+	fn hrmp_notifications_setup(_sibling: u32) -> Result<u32, BenchmarkError> {
+		Ok(100)
+	}
+}
 impl Config for Test {}
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -23,10 +23,16 @@ pub mod mock;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use frame_benchmarking::BenchmarkError;
+
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>:
 		frame_system::Config + crate::Config + pallet_xcm_benchmarks::generic::Config
 	{
+		/// Prepares the worst case of the HRMP notifications involving `sibling`, whose channel
+		/// open requests are automatically accepted and reciprocated. Returns the para id of
+		/// this chain.
+		fn hrmp_notifications_setup(sibling: u32) -> Result<u32, BenchmarkError>;
 	}
 
 	#[pallet::pallet]
@@ -32,6 +32,10 @@ impl<T: frame_system::Config + pallet_erc20_xcm_bridge::Config + pallet_moonbeam
 	pub(crate) fn withdraw_asset(asset: &Asset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
 			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
+		} else if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc721_asset(asset) {
+			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc721_transfer().saturating_add(
+				pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc721_owner_of(),
+			)
 		} else {
 			pallet_moonbeam_foreign_assets::Pallet::<T>::weight_of_erc20_burn()
 		}
@@ -39,6 +43,8 @@ impl<T: frame_system::Config + pallet_erc20_xcm_bridge::Config + pallet_moonbeam
 	pub(crate) fn transfer_asset(asset: &Asset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
 			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
+		} else if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc721_asset(asset) {
+			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc721_transfer()
 		} else {
 			pallet_moonbeam_foreign_assets::Pallet::<T>::weight_of_erc20_transfer()
 		}
@@ -46,6 +52,8 @@ impl<T: frame_system::Config + pallet_erc20_xcm_bridge::Config + pallet_moonbeam
 	pub(crate) fn transfer_reserve_asset(asset: &Asset) -> Weight {
 		if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc20_asset(asset) {
 			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc20_transfer(&asset.id)
+		} else if pallet_erc20_xcm_bridge::Pallet::<T>::is_erc721_asset(asset) {
+			pallet_erc20_xcm_bridge::Pallet::<T>::weight_of_erc721_transfer()
 		} else {
 			Weight::from_parts(200_000_000 as u64, ASSET_TRANSFER_MAX_PROOF_SIZE)
 		}
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `benchmarker`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `alias_origin` was added after this run: its weight is written by hand from the
+//! dynamic parameter it reads, the trusted siblings of the runtime aliasers.
+//! The same goes for the weights of the hrmp notifications.
 
 // Executed Command:
 // ./target/release/moonbeam
@@ -88,6 +92,10 @@ pub trait WeightInfo {
 	fn note_unlockable() -> Weight;
 	fn request_unlock() -> Weight;
 	fn unpaid_execution() -> Weight;
+	fn alias_origin() -> Weight;
+	fn hrmp_new_channel_open_request() -> Weight;
+	fn hrmp_channel_accepted() -> Weight;
+	fn hrmp_channel_closing() -> Weight;
 }
 
 /// Weights for moonbeam_xcm_benchmarks_generic using the Substrate node and recommended hardware.
@@ -456,6 +464,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 5_249_000 picoseconds.
 		Weight::from_parts(5_371_000, 0)
 	}
+	/// Storage: Parameters Parameters (r:1 w:0)
+	fn alias_origin() -> Weight {
+		Weight::from_parts(12_000_000, 3620)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor HrmpAutoAccept (r:1 w:0)
+	/// Proof Skipped: XcmTransactor HrmpAutoAccept (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor HrmpChannels (r:1 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor DestinationAssetFeePerSecond (r:2 w:0)
+	/// Proof Skipped: XcmTransactor DestinationAssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:2 w:0)
+	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SupportedVersion (r:2 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:2 w:2)
+	/// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:2 w:0)
+	/// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:2 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:2 w:2)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	fn hrmp_new_channel_open_request() -> Weight {
+		// The worst case automatically accepts and reciprocates the request, sending two
+		// messages to the relay.
+		Weight::from_parts(130_000_000, 7880)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	fn hrmp_channel_accepted() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	fn hrmp_channel_closing() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -823,4 +878,51 @@ impl WeightInfo for () {
 		// Minimum execution time: 5_249_000 picoseconds.
 		Weight::from_parts(5_371_000, 0)
 	}
+	/// Storage: Parameters Parameters (r:1 w:0)
+	fn alias_origin() -> Weight {
+		Weight::from_parts(12_000_000, 3620)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor HrmpAutoAccept (r:1 w:0)
+	/// Proof Skipped: XcmTransactor HrmpAutoAccept (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor HrmpChannels (r:1 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor DestinationAssetFeePerSecond (r:2 w:0)
+	/// Proof Skipped: XcmTransactor DestinationAssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactInfoWithWeightLimit (r:2 w:0)
+	/// Proof Skipped: XcmTransactor TransactInfoWithWeightLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SupportedVersion (r:2 w:0)
+	/// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:2 w:2)
+	/// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:2 w:0)
+	/// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem HostConfiguration (r:2 w:0)
+	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:2 w:2)
+	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	fn hrmp_new_channel_open_request() -> Weight {
+		// The worst case automatically accepts and reciprocates the request, sending two
+		// messages to the relay.
+		Weight::from_parts(130_000_000, 7880)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	fn hrmp_channel_accepted() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
+	fn hrmp_channel_closing() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
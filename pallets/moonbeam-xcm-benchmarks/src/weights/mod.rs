@@ -22,6 +22,7 @@ use core::cmp::min;
 use frame_support::weights::Weight;
 use fungible::WeightInfo as XcmFungibleWeight;
 use generic::SubstrateWeight as XcmGeneric;
+use pallet_xcm_asset_exchanger::WeightInfo as XcmAssetExchangerWeight;
 use sp_std::prelude::*;
 use xcm::{
 	latest::{prelude::*, Weight as XCMWeight},
@@ -62,7 +63,8 @@ impl<Runtime, Call> XcmWeightInfo<Call> for XcmWeight<Runtime, Call>
 where
 	Runtime: frame_system::Config
 		+ pallet_erc20_xcm_bridge::Config
-		+ pallet_moonbeam_foreign_assets::Config,
+		+ pallet_moonbeam_foreign_assets::Config
+		+ pallet_xcm_asset_exchanger::Config,
 {
 	fn withdraw_asset(assets: &Assets) -> XCMWeight {
 		assets.inner().iter().fold(Weight::zero(), |acc, asset| {
@@ -106,16 +108,13 @@ where
 		_max_message_size: &u32,
 		_max_capacity: &u32,
 	) -> XCMWeight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::hrmp_new_channel_open_request()
 	}
 	fn hrmp_channel_accepted(_recipient: &u32) -> XCMWeight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::hrmp_channel_accepted()
 	}
 	fn hrmp_channel_closing(_initiator: &u32, _sender: &u32, _recipient: &u32) -> XCMWeight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::hrmp_channel_closing()
 	}
 	fn clear_origin() -> XCMWeight {
 		XcmGeneric::<Runtime>::clear_origin()
@@ -133,7 +132,9 @@ where
 		assets.weigh_multi_assets_filter(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
 	}
 	fn exchange_asset(_give: &AssetFilter, _receive: &Assets, _maximal: &bool) -> XCMWeight {
-		Weight::MAX
+		// A single asset is swapped against a pool, the swap is benchmarked with an EVM foreign
+		// asset, whose transfers into and out of the pool are ERC-20 calls
+		<Runtime as pallet_xcm_asset_exchanger::Config>::WeightInfo::swap()
 	}
 	fn initiate_reserve_withdraw(
 		_assets: &AssetFilter,
@@ -239,8 +240,7 @@ where
 		XcmGeneric::<Runtime>::clear_topic()
 	}
 	fn alias_origin(_: &Location) -> Weight {
-		// XCM Executor does not currently support alias origin operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::alias_origin()
 	}
 	fn unpaid_execution(_: &WeightLimit, _: &Option<Location>) -> Weight {
 		XcmGeneric::<Runtime>::unpaid_execution()
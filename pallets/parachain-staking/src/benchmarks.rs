@@ -20,8 +20,8 @@
 use crate::{
 	AwardedPts, BalanceOf, BottomDelegations, Call, CandidateBondLessRequest, Config,
 	DelegationAction, EnableMarkingOffline, InflationDistributionAccount,
-	InflationDistributionConfig, InflationDistributionInfo, Pallet, Points, Range, RewardPayment,
-	Round, ScheduledRequest, TopDelegations,
+	InflationDistributionConfig, InflationDistributionInfo, Pallet, Points, Range,
+	RewardDestination, RewardPayment, Round, ScheduledRequest, TopDelegations,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
@@ -2207,6 +2207,31 @@ benchmarks! {
 		);
 	}
 
+	set_reward_destination {
+		let mut seed = Seed::new();
+		let collator = create_funded_collator::<T>(
+			"collator",
+			seed.take(),
+			0u32.into(),
+			true,
+			1,
+		)?;
+		let delegator = create_funded_delegator::<T>(
+			"delegator",
+			seed.take(),
+			0u32.into(),
+			collator.clone(),
+			true,
+			0,
+		)?;
+	}: _(RawOrigin::Signed(delegator.clone()), RewardDestination::Candidate(collator.clone()))
+	verify {
+		assert_eq!(
+			Pallet::<T>::reward_destination(&delegator),
+			RewardDestination::Candidate(collator),
+		);
+	}
+
 	mint_collator_reward {
 		let mut seed = Seed::new();
 		let collator = create_funded_collator::<T>(
@@ -2502,6 +2527,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_reward_destination() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_reward_destination());
+		});
+	}
+
 	#[test]
 	fn bench_base_on_initialize() {
 		new_test_ext().execute_with(|| {
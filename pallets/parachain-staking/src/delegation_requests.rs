@@ -17,18 +17,23 @@
 //! Scheduled requests functionality for delegators
 
 use crate::pallet::{
-	BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error, Event,
-	Pallet, Round, RoundIndex, Total,
+	BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorRewardDestination,
+	DelegatorState, Error, Event, MaxScheduledRequestsPerCandidate, Pallet, Round, RoundIndex,
+	Total,
 };
 use crate::weights::WeightInfo;
-use crate::{auto_compound::AutoCompoundDelegations, AddGet, Delegator};
+use crate::{auto_compound::AutoCompoundDelegations, Delegator};
 use frame_support::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo};
 use frame_support::ensure;
 use frame_support::traits::Get;
 use frame_support::BoundedVec;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Saturating, RuntimeDebug};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
 
 /// An action that can be performed upon a delegation
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
@@ -119,7 +124,7 @@ impl<T: Config> Pallet<T> {
 			candidate: collator,
 			scheduled_exit: when,
 		});
-		Ok(().into())
+		Ok(Some(actual_weight).into())
 	}
 
 	/// Schedules a [DelegationAction::Decrease] for the delegator, towards a given collator.
@@ -135,13 +140,28 @@ impl<T: Config> Pallet<T> {
 			scheduled_requests.len() as u32,
 		);
 
+		// bond less requests may be queued, as long as no revoke is pending for the delegation
+		let mut pending_requests = 0u32;
+		let mut pending_decrease = BalanceOf::<T>::zero();
+		for req in scheduled_requests
+			.iter()
+			.filter(|req| req.delegator == delegator)
+		{
+			ensure!(
+				matches!(req.action, DelegationAction::Decrease(_)),
+				DispatchErrorWithPostInfo {
+					post_info: Some(actual_weight).into(),
+					error: <Error<T>>::PendingDelegationRequestAlreadyExists.into(),
+				},
+			);
+			pending_requests = pending_requests.saturating_add(1);
+			pending_decrease = pending_decrease.saturating_add(req.action.amount());
+		}
 		ensure!(
-			!scheduled_requests
-				.iter()
-				.any(|req| req.delegator == delegator),
+			pending_requests < T::MaxScheduledRequestsPerDelegation::get(),
 			DispatchErrorWithPostInfo {
 				post_info: Some(actual_weight).into(),
-				error: <Error<T>>::PendingDelegationRequestAlreadyExists.into(),
+				error: <Error<T>>::ExceedMaxScheduledRequestsPerDelegation.into(),
 			},
 		);
 
@@ -151,14 +171,16 @@ impl<T: Config> Pallet<T> {
 				post_info: Some(actual_weight).into(),
 				error: <Error<T>>::DelegationDNE.into(),
 			})?;
+		// Net bonded amount is the delegation amount after pending decreases are executed
+		let net_bonded_amount = bonded_amount.saturating_sub(pending_decrease);
 		ensure!(
-			bonded_amount > decrease_amount,
+			net_bonded_amount > decrease_amount,
 			DispatchErrorWithPostInfo {
 				post_info: Some(actual_weight).into(),
 				error: <Error<T>>::DelegatorBondBelowMin.into(),
 			},
 		);
-		let new_amount: BalanceOf<T> = (bonded_amount - decrease_amount).into();
+		let new_amount: BalanceOf<T> = (net_bonded_amount - decrease_amount).into();
 		ensure!(
 			new_amount >= T::MinDelegation::get(),
 			DispatchErrorWithPostInfo {
@@ -204,7 +226,7 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(actual_weight).into())
 	}
 
-	/// Cancels the delegator's existing [ScheduledRequest] towards a given collator.
+	/// Cancels the delegator's oldest existing [ScheduledRequest] towards a given collator.
 	pub(crate) fn delegation_cancel_request(
 		collator: T::AccountId,
 		delegator: T::AccountId,
@@ -232,12 +254,49 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(actual_weight).into())
 	}
 
+	/// Cancels a single one of the delegator's existing [ScheduledRequest]s towards a given
+	/// collator, `request_index` being its position among the delegator's pending requests
+	/// ordered from the oldest to the newest.
+	pub(crate) fn delegation_cancel_single_request(
+		collator: T::AccountId,
+		delegator: T::AccountId,
+		request_index: u32,
+	) -> DispatchResultWithPostInfo {
+		let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+		let actual_weight =
+			<T as Config>::WeightInfo::cancel_delegation_request(scheduled_requests.len() as u32);
+
+		let request_idx = scheduled_requests
+			.iter()
+			.enumerate()
+			.filter(|(_, req)| req.delegator == delegator)
+			.nth(request_index as usize)
+			.map(|(idx, _)| idx)
+			.ok_or(DispatchErrorWithPostInfo {
+				post_info: Some(actual_weight).into(),
+				error: <Error<T>>::PendingDelegationRequestDNE.into(),
+			})?;
+
+		let request = scheduled_requests.remove(request_idx);
+		state.less_total = state.less_total.saturating_sub(request.action.amount());
+		<DelegationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
+		<DelegatorState<T>>::insert(delegator.clone(), state);
+
+		Self::deposit_event(Event::CancelledDelegationRequest {
+			delegator,
+			collator,
+			cancelled_request: request.into(),
+		});
+		Ok(Some(actual_weight).into())
+	}
+
 	fn cancel_request_with_state(
 		delegator: &T::AccountId,
 		state: &mut Delegator<T::AccountId, BalanceOf<T>>,
 		scheduled_requests: &mut BoundedVec<
 			ScheduledRequest<T::AccountId, BalanceOf<T>>,
-			AddGet<T::MaxTopDelegationsPerCandidate, T::MaxBottomDelegationsPerCandidate>,
+			MaxScheduledRequestsPerCandidate<T>,
 		>,
 	) -> Option<ScheduledRequest<T::AccountId, BalanceOf<T>>> {
 		let request_idx = scheduled_requests
@@ -250,25 +309,28 @@ impl<T: Config> Pallet<T> {
 		Some(request)
 	}
 
-	/// Executes the delegator's existing [ScheduledRequest] towards a given collator.
+	/// Executes the delegator's oldest due [ScheduledRequest] towards a given collator.
 	pub(crate) fn delegation_execute_scheduled_request(
 		collator: T::AccountId,
 		delegator: T::AccountId,
 	) -> DispatchResultWithPostInfo {
 		let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
 		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+		ensure!(
+			scheduled_requests
+				.iter()
+				.any(|req| req.delegator == delegator),
+			<Error<T>>::PendingDelegationRequestDNE
+		);
+
+		// requests are appended in scheduling order, so the first due request is the oldest one
+		let now = <Round<T>>::get().current;
 		let request_idx = scheduled_requests
 			.iter()
-			.position(|req| req.delegator == delegator)
-			.ok_or(<Error<T>>::PendingDelegationRequestDNE)?;
+			.position(|req| req.delegator == delegator && req.when_executable <= now)
+			.ok_or(<Error<T>>::PendingDelegationRequestNotDueYet)?;
 		let request = &scheduled_requests[request_idx];
 
-		let now = <Round<T>>::get().current;
-		ensure!(
-			request.when_executable <= now,
-			<Error<T>>::PendingDelegationRequestNotDueYet
-		);
-
 		match request.action {
 			DelegationAction::Revoke(amount) => {
 				let actual_weight =
@@ -313,6 +375,7 @@ impl<T: Config> Pallet<T> {
 				<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
 				if leaving {
 					<DelegatorState<T>>::remove(&delegator);
+					<DelegatorRewardDestination<T>>::remove(&delegator);
 					Self::deposit_event(Event::DelegatorLeft {
 						delegator,
 						unstaked_amount: amount,
@@ -403,7 +466,7 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Removes the delegator's existing [ScheduledRequest] towards a given collator, if exists.
+	/// Removes the delegator's existing [ScheduledRequest]s towards a given collator, if any.
 	/// The state needs to be persisted by the caller of this function.
 	pub(crate) fn delegation_remove_request_with_state(
 		collator: &T::AccountId,
@@ -412,19 +475,24 @@ impl<T: Config> Pallet<T> {
 	) {
 		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(collator);
 
-		let maybe_request_idx = scheduled_requests
-			.iter()
-			.position(|req| &req.delegator == delegator);
+		let requests_count = scheduled_requests.len();
+		let mut removed_amount = BalanceOf::<T>::zero();
+		scheduled_requests.retain(|req| {
+			if &req.delegator == delegator {
+				removed_amount = removed_amount.saturating_add(req.action.amount());
+				false
+			} else {
+				true
+			}
+		});
 
-		if let Some(request_idx) = maybe_request_idx {
-			let request = scheduled_requests.remove(request_idx);
-			let amount = request.action.amount();
-			state.less_total = state.less_total.saturating_sub(amount);
+		if scheduled_requests.len() != requests_count {
+			state.less_total = state.less_total.saturating_sub(removed_amount);
 			<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
 		}
 	}
 
-	/// Returns true if a [ScheduledRequest] exists for a given delegation
+	/// Returns true if at least one [ScheduledRequest] exists for a given delegation
 	pub fn delegation_request_exists(collator: &T::AccountId, delegator: &T::AccountId) -> bool {
 		<DelegationScheduledRequests<T>>::get(collator)
 			.iter()
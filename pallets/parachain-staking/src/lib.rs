@@ -108,6 +108,9 @@ pub mod pallet {
 	pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
 	pub const DELEGATOR_LOCK_ID: LockIdentifier = *b"stkngdel";
 
+	/// Points awarded to the author of each block
+	pub const POINTS_PER_BLOCK: RewardPoint = 20;
+
 	/// A hard limit for weight computation purposes for the max candidates that _could_
 	/// theoretically exist.
 	pub const MAX_CANDIDATES: u32 = 200;
@@ -148,6 +151,10 @@ pub mod pallet {
 		/// Number of rounds after which block authors are rewarded
 		#[pallet::constant]
 		type RewardPaymentDelay: Get<RoundIndex>;
+		/// Number of rounds for which reward summaries are kept in `RoundSummaries`.
+		/// Setting it to zero disables the archive.
+		#[pallet::constant]
+		type MaxRoundArchive: Get<RoundIndex>;
 		/// Minimum number of selected candidates every round
 		#[pallet::constant]
 		type MinSelectedCandidates: Get<u32>;
@@ -160,6 +167,9 @@ pub mod pallet {
 		/// Maximum delegations per delegator
 		#[pallet::constant]
 		type MaxDelegationsPerDelegator: Get<u32>;
+		/// Maximum pending scheduled requests per delegation
+		#[pallet::constant]
+		type MaxScheduledRequestsPerDelegation: Get<u32>;
 		/// Minimum stake required for any account to be a collator candidate
 		#[pallet::constant]
 		type MinCandidateStk: Get<BalanceOf<Self>>;
@@ -181,6 +191,9 @@ pub mod pallet {
 		/// Handler to notify the runtime when a new round begin.
 		/// If you don't need it, you can specify the type `()`.
 		type OnNewRound: OnNewRound;
+		/// Policy used to select the collators of the next round among the candidates.
+		/// To use the default selection by total counted stake, specify the type `()`.
+		type CandidateSelection: CandidateSelection<Self>;
 		/// Get the current slot number
 		type SlotProvider: Get<Slot>;
 		/// Get the slot duration in milliseconds
@@ -253,6 +266,7 @@ pub mod pallet {
 		CannotSetAboveMaxCandidates,
 		MarkingOfflineNotEnabled,
 		CurrentRoundTooLow,
+		ExceedMaxScheduledRequestsPerDelegation,
 	}
 
 	#[pallet::event]
@@ -449,6 +463,17 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Reward destination was set for a delegator.
+		RewardDestinationSet {
+			delegator: T::AccountId,
+			destination: RewardDestination<T::AccountId>,
+		},
+		/// Reward destination of a delegator was reset to [RewardDestination::Delegator] because
+		/// it targeted a candidate the delegator no longer delegates to.
+		RewardDestinationReset {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+		},
 	}
 
 	#[pallet::hooks]
@@ -561,6 +586,29 @@ pub mod pallet {
 		}
 	}
 
+	pub struct MulGet<T, R> {
+		_phantom: PhantomData<(T, R)>,
+	}
+	impl<T, R> Get<u32> for MulGet<T, R>
+	where
+		T: Get<u32>,
+		R: Get<u32>,
+	{
+		fn get() -> u32 {
+			T::get().saturating_mul(R::get())
+		}
+	}
+
+	/// Maximum number of scheduled requests stored per collator, that is, up to
+	/// `MaxScheduledRequestsPerDelegation` requests for each of its top and bottom delegations.
+	pub type MaxScheduledRequestsPerCandidate<T> = MulGet<
+		AddGet<
+			<T as Config>::MaxTopDelegationsPerCandidate,
+			<T as Config>::MaxBottomDelegationsPerCandidate,
+		>,
+		<T as Config>::MaxScheduledRequestsPerDelegation,
+	>;
+
 	/// Stores outstanding delegation requests per collator.
 	#[pallet::storage]
 	#[pallet::getter(fn delegation_scheduled_requests)]
@@ -570,7 +618,7 @@ pub mod pallet {
 		T::AccountId,
 		BoundedVec<
 			ScheduledRequest<T::AccountId, BalanceOf<T>>,
-			AddGet<T::MaxTopDelegationsPerCandidate, T::MaxBottomDelegationsPerCandidate>,
+			MaxScheduledRequestsPerCandidate<T>,
 		>,
 		ValueQuery,
 	>;
@@ -589,6 +637,13 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Stores the reward destination per delegator. Delegators without an entry are paid
+	/// according to [RewardDestination::Delegator].
+	#[pallet::storage]
+	#[pallet::getter(fn reward_destination)]
+	pub(crate) type DelegatorRewardDestination<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn top_delegations)]
 	/// Top delegations for collator candidate
@@ -657,6 +712,26 @@ pub mod pallet {
 	pub type DelayedPayouts<T: Config> =
 		StorageMap<_, Twox64Concat, RoundIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn round_summary)]
+	/// Summaries of the staking rewards of the last `MaxRoundArchive` rounds
+	pub type RoundSummaries<T: Config> =
+		StorageMap<_, Twox64Concat, RoundIndex, RoundSummary<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collator_round_summary)]
+	/// Per-collator rewards of the rounds archived in `RoundSummaries`, filled in as the delayed
+	/// payouts of the round are made
+	pub type CollatorRoundSummaries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		Twox64Concat,
+		T::AccountId,
+		CollatorRoundSummary<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn inflation_config)]
 	/// Inflation configuration
@@ -1205,7 +1280,7 @@ pub mod pallet {
 		/// A revoke may not be performed if any other scheduled request is pending.
 		#[pallet::call_index(22)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule_revoke_delegation(
-			T::MaxTopDelegationsPerCandidate::get() + T::MaxBottomDelegationsPerCandidate::get()
+			MaxScheduledRequestsPerCandidate::<T>::get()
 		))]
 		pub fn schedule_revoke_delegation(
 			origin: OriginFor<T>,
@@ -1218,7 +1293,7 @@ pub mod pallet {
 		/// Bond more for delegators wrt a specific collator candidate.
 		#[pallet::call_index(23)]
 		#[pallet::weight(<T as Config>::WeightInfo::delegator_bond_more(
-			T::MaxTopDelegationsPerCandidate::get() + T::MaxBottomDelegationsPerCandidate::get()
+			MaxScheduledRequestsPerCandidate::<T>::get()
 		))]
 		pub fn delegator_bond_more(
 			origin: OriginFor<T>,
@@ -1243,10 +1318,12 @@ pub mod pallet {
 
 		/// Request bond less for delegators wrt a specific collator candidate. The delegation's
 		/// rewards for rounds while the request is pending use the reduced bonded amount.
-		/// A bond less may not be performed if any other scheduled request is pending.
+		/// Up to `MaxScheduledRequestsPerDelegation` bond less requests may be pending at once,
+		/// each one becoming executable on its own once due. A bond less may not be performed if
+		/// a revoke is pending.
 		#[pallet::call_index(24)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule_delegator_bond_less(
-			T::MaxTopDelegationsPerCandidate::get() + T::MaxBottomDelegationsPerCandidate::get()
+			MaxScheduledRequestsPerCandidate::<T>::get()
 		))]
 		pub fn schedule_delegator_bond_less(
 			origin: OriginFor<T>,
@@ -1257,7 +1334,7 @@ pub mod pallet {
 			Self::delegation_schedule_bond_decrease(candidate, delegator, less)
 		}
 
-		/// Execute pending request to change an existing delegation
+		/// Execute the oldest due pending request to change an existing delegation
 		#[pallet::call_index(25)]
 		#[pallet::weight(<T as Config>::WeightInfo::execute_delegator_revoke_delegation_worst())]
 		pub fn execute_delegation_request(
@@ -1269,9 +1346,13 @@ pub mod pallet {
 			Self::delegation_execute_scheduled_request(candidate, delegator)
 		}
 
-		/// Cancel request to change an existing delegation.
+		/// Cancel the oldest pending request to change an existing delegation.
+		/// A delegation may have several pending bond less requests, the other ones stay
+		/// scheduled. Use `cancel_single_delegation_request` to cancel a specific one of them.
 		#[pallet::call_index(26)]
-		#[pallet::weight(<T as Config>::WeightInfo::cancel_delegation_request(350))]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_delegation_request(
+			MaxScheduledRequestsPerCandidate::<T>::get()
+		))]
 		pub fn cancel_delegation_request(
 			origin: OriginFor<T>,
 			candidate: T::AccountId,
@@ -1448,6 +1529,55 @@ pub mod pallet {
 			});
 			Ok(().into())
 		}
+
+		/// Set where the staking rewards of the caller's delegations are paid to.
+		/// - `Candidate` destinations require an existing delegation towards that candidate
+		/// - the setting is cleared once the delegator leaves the set of delegators
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_destination())]
+		pub fn set_reward_destination(
+			origin: OriginFor<T>,
+			destination: RewardDestination<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			let state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+			if let RewardDestination::Candidate(candidate) = &destination {
+				ensure!(
+					state.delegations.0.iter().any(|b| &b.owner == candidate),
+					Error::<T>::DelegationDNE
+				);
+			}
+			ensure!(
+				<DelegatorRewardDestination<T>>::get(&delegator) != destination,
+				Error::<T>::NoWritingSameValue
+			);
+			if destination == RewardDestination::Delegator {
+				<DelegatorRewardDestination<T>>::remove(&delegator);
+			} else {
+				<DelegatorRewardDestination<T>>::insert(&delegator, destination.clone());
+			}
+			Self::deposit_event(Event::RewardDestinationSet {
+				delegator,
+				destination,
+			});
+			Ok(().into())
+		}
+
+		/// Cancel a single pending request to change an existing delegation. `request_index` is
+		/// the position of the request among the caller's pending requests towards `candidate`,
+		/// ordered from the oldest to the newest.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_delegation_request(
+			MaxScheduledRequestsPerCandidate::<T>::get()
+		))]
+		pub fn cancel_single_delegation_request(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			request_index: u32,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::delegation_cancel_single_request(candidate, delegator, request_index)
+		}
 	}
 
 	/// Represents a payout made via `pay_one_collator_reward`.
@@ -1675,6 +1805,7 @@ pub mod pallet {
 						// since it is assumed that they were removed incrementally before only the
 						// last delegation was left.
 						<DelegatorState<T>>::remove(&bond.owner);
+						<DelegatorRewardDestination<T>>::remove(&bond.owner);
 						T::Currency::remove_lock(DELEGATOR_LOCK_ID, &bond.owner);
 					} else {
 						<DelegatorState<T>>::insert(&bond.owner, delegator);
@@ -1812,9 +1943,12 @@ pub mod pallet {
 			// We don't need to saturate here because the genesis round is 1.
 			let prepare_payout_for_round = now - 1;
 
+			// Prune the round summary that falls out of the archive
+			let archive_weight = Self::prune_round_summaries(prepare_payout_for_round);
+
 			// Return early if there is no blocks for this round
 			if <Points<T>>::get(prepare_payout_for_round).is_zero() {
-				return Weight::zero();
+				return archive_weight;
 			}
 
 			// Compute total issuance based on round duration
@@ -1845,9 +1979,45 @@ pub mod pallet {
 				collator_commission: <CollatorCommission<T>>::get(),
 			};
 
+			let mut archive_weight = archive_weight;
+			if !T::MaxRoundArchive::get().is_zero() {
+				<RoundSummaries<T>>::insert(
+					prepare_payout_for_round,
+					RoundSummary {
+						round_issuance: payout.round_issuance,
+						total_staking_reward: payout.total_staking_reward,
+						total_points: <Points<T>>::get(prepare_payout_for_round),
+					},
+				);
+				archive_weight = archive_weight.saturating_add(T::DbWeight::get().writes(1));
+			}
+
 			<DelayedPayouts<T>>::insert(prepare_payout_for_round, payout);
 
-			<T as Config>::WeightInfo::prepare_staking_payouts()
+			<T as Config>::WeightInfo::prepare_staking_payouts().saturating_add(archive_weight)
+		}
+
+		/// Removes the summaries of the round that is `MaxRoundArchive` rounds older than `round`.
+		fn prune_round_summaries(round: RoundIndex) -> Weight {
+			let max_round_archive = T::MaxRoundArchive::get();
+			if max_round_archive.is_zero() {
+				return Weight::zero();
+			}
+			if let Some(round_to_prune) = round.checked_sub(max_round_archive) {
+				<RoundSummaries<T>>::remove(round_to_prune);
+				// at most one collator summary is archived per selected candidate
+				let removed = <CollatorRoundSummaries<T>>::clear_prefix(
+					round_to_prune,
+					T::MaxCandidates::get(),
+					None,
+				);
+				T::DbWeight::get().reads_writes(
+					removed.loops as u64,
+					(removed.unique as u64).saturating_add(1),
+				)
+			} else {
+				Weight::zero()
+			}
 		}
 
 		/// Wrapper around pay_one_collator_reward which handles the following logic:
@@ -1986,13 +2156,31 @@ pub mod pallet {
 					}
 				}
 
-				extra_weight = extra_weight.saturating_add(
-					<T as Config>::WeightInfo::pay_one_collator_reward_best(
+				// record the collator rewards in the round summary, if it is still archived
+				if <RoundSummaries<T>>::contains_key(paid_for_round) {
+					<CollatorRoundSummaries<T>>::insert(
+						paid_for_round,
+						&collator,
+						CollatorRoundSummary {
+							points: pts,
+							total_paid,
+						},
+					);
+				}
+				extra_weight = extra_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				extra_weight = extra_weight
+					.saturating_add(<T as Config>::WeightInfo::pay_one_collator_reward_best(
 						num_paid_delegations,
 						num_auto_compounding,
 						num_scheduled_requests as u32,
-					),
-				);
+					))
+					// read DelegatorRewardDestination and DelegatorState for each paid delegation,
+					// and reset a stale candidate reward destination
+					.saturating_add(T::DbWeight::get().reads_writes(
+						2 * num_paid_delegations as u64,
+						num_paid_delegations as u64,
+					));
 
 				(
 					RewardPayment::Paid,
@@ -2016,27 +2204,36 @@ pub mod pallet {
 				return vec![];
 			}
 
-			let candidates = <CandidatePool<T>>::get().0;
+			let candidates = <CandidatePool<T>>::get().0.into_inner();
+
+			T::CandidateSelection::select_candidates(candidates, top_n)
+		}
+
+		/// Select the `top_n` candidates with the highest amount and return a vec of their
+		/// AccountIds (sorted by AccountId). `candidates` must be sorted by AccountId.
+		pub fn select_top_candidates_by_stake(
+			mut candidates: Vec<Bond<T::AccountId, BalanceOf<T>>>,
+			top_n: usize,
+		) -> Vec<T::AccountId> {
+			if top_n == 0 {
+				return vec![];
+			}
 
 			// If the number of candidates is greater than top_n, select the candidates with higher
 			// amount. Otherwise, return all the candidates.
 			if candidates.len() > top_n {
 				// Partially sort candidates such that element at index `top_n - 1` is sorted, and
 				// all the elements in the range 0..top_n are the top n elements.
-				let sorted_candidates = candidates
-					.try_mutate(|inner| {
-						inner.select_nth_unstable_by(top_n - 1, |a, b| {
-							// Order by amount, then owner. The owner is needed to ensure a stable order
-							// when two accounts have the same amount.
-							a.amount
-								.cmp(&b.amount)
-								.then_with(|| a.owner.cmp(&b.owner))
-								.reverse()
-						});
-					})
-					.expect("sort cannot increase item count; qed");
+				candidates.select_nth_unstable_by(top_n - 1, |a, b| {
+					// Order by amount, then owner. The owner is needed to ensure a stable order
+					// when two accounts have the same amount.
+					a.amount
+						.cmp(&b.amount)
+						.then_with(|| a.owner.cmp(&b.owner))
+						.reverse()
+				});
 
-				let mut collators = sorted_candidates
+				let mut collators = candidates
 					.into_iter()
 					.take(top_n)
 					.map(|x| x.owner)
@@ -2059,6 +2256,8 @@ pub mod pallet {
 				(0u32, 0u32, BalanceOf::<T>::zero());
 			// choose the top TotalSelected qualified candidates, ordered by stake
 			let collators = Self::compute_top_candidates();
+			// account for the selection policy, bounded by the max candidates in the pool
+			let selection_weight = T::CandidateSelection::selection_weight(T::MaxCandidates::get());
 			if collators.is_empty() {
 				// SELECTION FAILED TO SELECT >=1 COLLATOR => select collators from previous round
 				let last_round = now.saturating_sub(1u32);
@@ -2084,7 +2283,8 @@ pub mod pallet {
 						total_exposed_amount: *snapshot_total,
 					})
 				}
-				let weight = <T as Config>::WeightInfo::select_top_candidates(0, 0);
+				let weight = <T as Config>::WeightInfo::select_top_candidates(0, 0)
+					.saturating_add(selection_weight);
 				return (weight, collator_count, delegation_count, total);
 			}
 
@@ -2140,7 +2340,8 @@ pub mod pallet {
 			let weight = <T as Config>::WeightInfo::select_top_candidates(
 				collator_count,
 				avg_delegator_count,
-			);
+			)
+			.saturating_add(selection_weight);
 			(weight, collator_count, delegation_count, total)
 		}
 
@@ -2149,15 +2350,30 @@ pub mod pallet {
 		///
 		/// This will:
 		/// - if [DelegationChange::Revoke] is outstanding, set the bond amount to 0.
-		/// - if [DelegationChange::Decrease] is outstanding, subtract the bond by specified amount.
+		/// - if [DelegationChange::Decrease] is outstanding, subtract the bond by the sum of all
+		/// pending decrease amounts.
 		/// - else, do nothing
 		///
 		/// The intended bond amounts will be used while calculating rewards.
 		pub(crate) fn get_rewardable_delegators(collator: &T::AccountId) -> CountedDelegations<T> {
-			let requests = <DelegationScheduledRequests<T>>::get(collator)
-				.into_iter()
-				.map(|x| (x.delegator, x.action))
-				.collect::<BTreeMap<_, _>>();
+			let mut requests: BTreeMap<T::AccountId, DelegationAction<BalanceOf<T>>> =
+				BTreeMap::new();
+			for request in <DelegationScheduledRequests<T>>::get(collator) {
+				requests
+					.entry(request.delegator)
+					.and_modify(|action| {
+						// a revoke can not be pending alongside other requests, so only decreases
+						// need to be accumulated
+						if let (
+							DelegationAction::Decrease(pending),
+							DelegationAction::Decrease(amount),
+						) = (action.clone(), request.action.clone())
+						{
+							*action = DelegationAction::Decrease(pending.saturating_add(amount));
+						}
+					})
+					.or_insert(request.action);
+			}
 			let mut uncounted_stake = BalanceOf::<T>::zero();
 			let rewardable_delegations = <TopDelegations<T>>::get(collator)
 				.expect("all members of CandidateQ must be candidates")
@@ -2242,11 +2458,81 @@ pub mod pallet {
 			<T as Config>::WeightInfo::mint_collator_reward()
 		}
 
-		/// Mint and compound delegation rewards. The function mints the amount towards the
-		/// delegator and tries to compound a specified percent of it back towards the delegation.
+		/// Mint and compound delegation rewards according to the delegator's
+		/// [RewardDestination]:
+		/// * `Delegator`: the amount is minted towards the delegator and `compound_percent` of it
+		/// is compounded back towards the delegation.
+		/// * `Account`: `compound_percent` of the amount is minted and compounded as above, the
+		/// remainder is minted towards the payee account.
+		/// * `Candidate`: the amount is minted towards the delegator and entirely compounded towards
+		/// the delegation to the target candidate. If the delegator no longer delegates to the
+		/// target candidate, the destination is reset and the amount is handled as for `Delegator`.
+		///
+		/// Emits the [Rewarded] and [Compounded] events.
+		pub fn mint_and_compound(
+			amt: BalanceOf<T>,
+			compound_percent: Percent,
+			candidate: T::AccountId,
+			delegator: T::AccountId,
+		) {
+			match <DelegatorRewardDestination<T>>::get(&delegator) {
+				RewardDestination::Delegator => {
+					Self::mint_and_compound_to_delegation(
+						amt,
+						compound_percent,
+						candidate,
+						delegator,
+					);
+				}
+				RewardDestination::Account(payee) => {
+					let compound_amount = compound_percent.mul_ceil(amt);
+					if !compound_amount.is_zero() {
+						Self::mint_and_compound_to_delegation(
+							compound_amount,
+							Percent::from_percent(100),
+							candidate,
+							delegator,
+						);
+					}
+					let payee_amount = amt.saturating_sub(compound_amount);
+					if !payee_amount.is_zero() {
+						Self::mint(payee_amount, payee);
+					}
+				}
+				RewardDestination::Candidate(target) => {
+					let delegates_to_target = <DelegatorState<T>>::get(&delegator)
+						.map_or(false, |state| {
+							state.delegations.0.iter().any(|b| b.owner == target)
+						});
+					if delegates_to_target {
+						Self::mint_and_compound_to_delegation(
+							amt,
+							Percent::from_percent(100),
+							target,
+							delegator,
+						);
+					} else {
+						<DelegatorRewardDestination<T>>::remove(&delegator);
+						Self::deposit_event(Event::RewardDestinationReset {
+							delegator: delegator.clone(),
+							candidate: target,
+						});
+						Self::mint_and_compound_to_delegation(
+							amt,
+							compound_percent,
+							candidate,
+							delegator,
+						);
+					}
+				}
+			}
+		}
+
+		/// Mint the amount towards the delegator and try to compound a specified percent of it
+		/// back towards the delegation to `candidate`.
 		/// If a scheduled delegation revoke exists, then the amount is only minted, and nothing is
 		/// compounded. Emits the [Compounded] event.
-		pub fn mint_and_compound(
+		fn mint_and_compound_to_delegation(
 			amt: BalanceOf<T>,
 			compound_percent: Percent,
 			candidate: T::AccountId,
@@ -2288,13 +2574,13 @@ pub mod pallet {
 		}
 
 		/// Add reward points to block authors:
-		/// * 20 points to the block producer for producing a block in the chain
+		/// * `POINTS_PER_BLOCK` points to the block producer for producing a block in the chain
 		fn award_points_to_block_author() {
 			let author = T::BlockAuthor::get();
 			let now = <Round<T>>::get().current;
-			let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
-			<AwardedPts<T>>::insert(now, author, score_plus_20);
-			<Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
+			let score = <AwardedPts<T>>::get(now, &author).saturating_add(POINTS_PER_BLOCK);
+			<AwardedPts<T>>::insert(now, author, score);
+			<Points<T>>::mutate(now, |x| *x = x.saturating_add(POINTS_PER_BLOCK));
 		}
 
 		/// Marks collators as inactive for the previous round if they received zero awarded points.
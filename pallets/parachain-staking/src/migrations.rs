@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+	BoundedVec,
+};
 
 use crate::*;
 
@@ -100,3 +104,55 @@ impl<T: Config> OnRuntimeUpgrade for MigrateParachainBondConfig<T> {
 		Ok(())
 	}
 }
+
+/// Re-bound the stored [DelegationScheduledRequests] from one request per delegation to
+/// `MaxScheduledRequestsPerDelegation` requests per delegation. The encoding is unchanged, every
+/// entry is decoded with the previous bound and stored again with the new one.
+pub struct MigrateDelegationScheduledRequestsBound<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateDelegationScheduledRequestsBound<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut migrated = 0u64;
+		<DelegationScheduledRequests<T>>::translate_values::<
+			BoundedVec<
+				ScheduledRequest<T::AccountId, BalanceOf<T>>,
+				AddGet<T::MaxTopDelegationsPerCandidate, T::MaxBottomDelegationsPerCandidate>,
+			>,
+			_,
+		>(|requests| {
+			migrated = migrated.saturating_add(1);
+			// The new bound is a multiple of the previous one, nothing is truncated
+			Some(BoundedVec::truncate_from(requests.into_inner()))
+		});
+
+		T::DbWeight::get().reads_writes(migrated, migrated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		use parity_scale_codec::Encode;
+
+		let requests_count: u64 = <DelegationScheduledRequests<T>>::iter_values()
+			.map(|requests| requests.len() as u64)
+			.sum();
+
+		Ok(requests_count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		use frame_support::ensure;
+
+		let old_requests_count: u64 = parity_scale_codec::Decode::decode(&mut &state[..])
+			.map_err(|_| sp_runtime::DispatchError::Other("Failed to decode old state"))?;
+		let requests_count: u64 = <DelegationScheduledRequests<T>>::iter_values()
+			.map(|requests| requests.len() as u64)
+			.sum();
+
+		ensure!(
+			requests_count == old_requests_count,
+			"Scheduled requests lost during migration"
+		);
+
+		Ok(())
+	}
+}
@@ -129,10 +129,12 @@ parameter_types! {
 	pub const RevokeDelegationDelay: u32 = 2;
 	pub const DelegationBondLessDelay: u32 = 2;
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxRoundArchive: u32 = 4;
 	pub const MinSelectedCandidates: u32 = GENESIS_NUM_SELECTED_CANDIDATES;
 	pub const MaxTopDelegationsPerCandidate: u32 = 4;
 	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
 	pub const MaxDelegationsPerDelegator: u32 = 4;
+	pub const MaxScheduledRequestsPerDelegation: u32 = 3;
 	pub const MinCandidateStk: u128 = 10;
 	pub const MinDelegation: u128 = 3;
 	pub const MaxCandidates: u32 = 200;
@@ -158,10 +160,12 @@ impl Config for Test {
 	type RevokeDelegationDelay = RevokeDelegationDelay;
 	type DelegationBondLessDelay = DelegationBondLessDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
+	type MaxRoundArchive = MaxRoundArchive;
 	type MinSelectedCandidates = MinSelectedCandidates;
 	type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
 	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
 	type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+	type MaxScheduledRequestsPerDelegation = MaxScheduledRequestsPerDelegation;
 	type MinCandidateStk = MinCandidateStk;
 	type MinDelegation = MinDelegation;
 	type BlockAuthor = BlockAuthor;
@@ -169,6 +173,7 @@ impl Config for Test {
 	type PayoutCollatorReward = ();
 	type OnInactiveCollator = ();
 	type OnNewRound = ();
+	type CandidateSelection = ();
 	type SlotProvider = StakingRoundSlotProvider;
 	type WeightInfo = ();
 	type MaxCandidates = MaxCandidates;
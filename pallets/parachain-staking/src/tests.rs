@@ -30,10 +30,12 @@ use crate::mock::{
 	RuntimeOrigin, Test, POINTS_PER_BLOCK, POINTS_PER_ROUND,
 };
 use crate::{
-	assert_events_emitted, assert_events_emitted_match, assert_events_eq, assert_no_events,
-	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded,
-	EnableMarkingOffline, Error, Event, InflationDistributionInfo, Range, WasInactive,
-	DELEGATOR_LOCK_ID,
+	assert_events_emitted, assert_events_emitted_match, assert_events_eq,
+	assert_events_not_emitted_match, assert_no_events, AtStake, Bond, CandidateSelection,
+	CappedCandidateStake, CollatorRoundSummary, CollatorStatus, DelegationScheduledRequests,
+	DelegatorAdded, DelegatorRewardDestination, EnableMarkingOffline, Error, Event,
+	InflationDistributionInfo, MinSelfBondRatio, RandomTieBreak, Range, RewardDestination,
+	WasInactive, DELEGATOR_LOCK_ID,
 };
 use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
@@ -3293,6 +3295,249 @@ fn cannot_delegator_bond_less_below_min_delegation() {
 		});
 }
 
+#[test]
+fn can_schedule_multiple_delegator_bond_less_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			roll_to_round_begin(2);
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				3
+			));
+			assert_eq!(
+				ParachainStaking::delegation_scheduled_requests(&1),
+				vec![
+					ScheduledRequest {
+						delegator: 2,
+						when_executable: 3,
+						action: DelegationAction::Decrease(5),
+					},
+					ScheduledRequest {
+						delegator: 2,
+						when_executable: 4,
+						action: DelegationAction::Decrease(3),
+					},
+				],
+			);
+			assert_eq!(
+				ParachainStaking::delegator_state(&2)
+					.map(|x| x.less_total)
+					.expect("delegator state must exist"),
+				8
+			);
+		});
+}
+
+#[test]
+fn cannot_schedule_more_than_max_delegator_bond_less_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			for _ in 0..crate::mock::MaxScheduledRequestsPerDelegation::get() {
+				assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+					RuntimeOrigin::signed(2),
+					1,
+					1
+				));
+			}
+			assert_noop!(
+				ParachainStaking::schedule_delegator_bond_less(RuntimeOrigin::signed(2), 1, 1)
+					.map_err(|err| err.error),
+				Error::<Test>::ExceedMaxScheduledRequestsPerDelegation
+			);
+		});
+}
+
+#[test]
+fn cancel_delegation_request_only_cancels_the_oldest_pending_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				3
+			));
+			assert_ok!(ParachainStaking::cancel_delegation_request(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_events_emitted!(Event::CancelledDelegationRequest {
+				delegator: 2,
+				collator: 1,
+				cancelled_request: CancelledScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Decrease(5),
+				},
+			});
+			assert_eq!(
+				ParachainStaking::delegation_scheduled_requests(&1),
+				vec![ScheduledRequest {
+					delegator: 2,
+					when_executable: 3,
+					action: DelegationAction::Decrease(3),
+				}],
+			);
+			assert_eq!(
+				ParachainStaking::delegator_state(&2)
+					.map(|x| x.less_total)
+					.expect("delegator state must exist"),
+				3
+			);
+		});
+}
+
+#[test]
+fn cancel_single_delegation_request_only_cancels_the_selected_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			roll_to_round_begin(2);
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				3
+			));
+			assert_noop!(
+				ParachainStaking::cancel_single_delegation_request(RuntimeOrigin::signed(2), 1, 2)
+					.map_err(|err| err.error),
+				Error::<Test>::PendingDelegationRequestDNE
+			);
+			assert_ok!(ParachainStaking::cancel_single_delegation_request(
+				RuntimeOrigin::signed(2),
+				1,
+				0
+			));
+			assert_events_emitted!(Event::CancelledDelegationRequest {
+				delegator: 2,
+				collator: 1,
+				cancelled_request: CancelledScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Decrease(5),
+				},
+			});
+			assert_eq!(
+				ParachainStaking::delegation_scheduled_requests(&1),
+				vec![ScheduledRequest {
+					delegator: 2,
+					when_executable: 4,
+					action: DelegationAction::Decrease(3),
+				}],
+			);
+			assert_eq!(
+				ParachainStaking::delegator_state(&2)
+					.map(|x| x.less_total)
+					.expect("delegator state must exist"),
+				3
+			);
+		});
+}
+
+#[test]
+fn cancel_single_delegation_request_cancels_a_pending_revoke() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_ok!(ParachainStaking::cancel_single_delegation_request(
+				RuntimeOrigin::signed(2),
+				1,
+				0
+			));
+			assert_events_emitted!(Event::CancelledDelegationRequest {
+				delegator: 2,
+				collator: 1,
+				cancelled_request: CancelledScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Revoke(20),
+				},
+			});
+			assert!(ParachainStaking::delegation_scheduled_requests(&1).is_empty());
+		});
+}
+
+#[test]
+fn cannot_delegator_bond_less_below_min_delegation_with_pending_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				10
+			));
+			assert_noop!(
+				ParachainStaking::schedule_delegator_bond_less(RuntimeOrigin::signed(2), 1, 8)
+					.map_err(|err| err.error),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				ParachainStaking::schedule_delegator_bond_less(RuntimeOrigin::signed(2), 1, 10)
+					.map_err(|err| err.error),
+				Error::<Test>::DelegatorBondBelowMin
+			);
+		});
+}
+
+#[test]
+fn cannot_revoke_delegation_with_pending_bond_less_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_noop!(
+				ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 1)
+					.map_err(|err| err.error),
+				Error::<Test>::PendingDelegationRequestAlreadyExists
+			);
+		});
+}
+
 // EXECUTE PENDING DELEGATION REQUEST
 
 // 1. REVOKE DELEGATION
@@ -4107,6 +4352,61 @@ fn cancel_revoke_delegation_updates_delegator_state() {
 		});
 }
 
+#[test]
+fn execute_delegator_bond_less_executes_due_requests_in_order() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			roll_to_round_begin(2);
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				3
+			));
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert_events_emitted!(Event::DelegationDecreased {
+				delegator: 2,
+				candidate: 1,
+				amount: 5,
+				in_top: true,
+			});
+			assert_noop!(
+				ParachainStaking::execute_delegation_request(RuntimeOrigin::signed(2), 2, 1),
+				Error::<Test>::PendingDelegationRequestNotDueYet
+			);
+			roll_to_round_begin(4);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert_eq!(
+				ParachainStaking::delegator_state(&2)
+					.expect("delegator state must exist")
+					.total(),
+				12
+			);
+			assert!(!ParachainStaking::delegation_request_exists(&1, &2));
+			assert_noop!(
+				ParachainStaking::execute_delegation_request(RuntimeOrigin::signed(2), 2, 1),
+				Error::<Test>::PendingDelegationRequestDNE
+			);
+		});
+}
+
 // 2. CANCEL DELEGATOR BOND LESS
 
 #[test]
@@ -8558,6 +8858,283 @@ fn test_rewards_auto_compound_on_payment_as_per_auto_compound_config() {
 		});
 }
 
+#[test]
+fn test_set_reward_destination_fails_if_not_delegator() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_reward_destination(
+					RuntimeOrigin::signed(2),
+					RewardDestination::Account(1),
+				),
+				Error::<Test>::DelegatorDNE,
+			);
+		});
+}
+
+#[test]
+fn test_set_reward_destination_fails_if_target_candidate_not_delegated() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 30), (3, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_reward_destination(
+					RuntimeOrigin::signed(2),
+					RewardDestination::Candidate(3),
+				),
+				Error::<Test>::DelegationDNE,
+			);
+		});
+}
+
+#[test]
+fn test_set_reward_destination_fails_if_unchanged() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_reward_destination(
+					RuntimeOrigin::signed(2),
+					RewardDestination::Delegator,
+				),
+				Error::<Test>::NoWritingSameValue,
+			);
+		});
+}
+
+#[test]
+fn test_set_reward_destination_updates_storage_and_emits_event() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Account(5),
+			));
+			assert_events_emitted!(Event::RewardDestinationSet {
+				delegator: 2,
+				destination: RewardDestination::Account(5),
+			});
+			assert_eq!(
+				RewardDestination::Account(5),
+				ParachainStaking::reward_destination(&2),
+			);
+
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Delegator,
+			));
+			assert!(!DelegatorRewardDestination::<Test>::contains_key(&2));
+		});
+}
+
+#[test]
+fn test_execute_leave_delegators_removes_reward_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Candidate(1),
+			));
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert!(!DelegatorRewardDestination::<Test>::contains_key(&2));
+		});
+}
+
+#[test]
+fn test_rewards_paid_to_payee_as_per_reward_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			(2..=5).for_each(|round| set_author(round, 1, POINTS_PER_ROUND));
+			assert_ok!(ParachainStaking::set_auto_compound(
+				RuntimeOrigin::signed(2),
+				1,
+				Percent::from_percent(50),
+				0,
+				1,
+			));
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Account(3),
+			));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+			assert_events_eq!(
+				Event::Rewarded {
+					account: 1,
+					rewards: 4,
+				},
+				// 50% compounded, remainder paid to payee
+				Event::Rewarded {
+					account: 2,
+					rewards: 2,
+				},
+				Event::Compounded {
+					candidate: 1,
+					delegator: 2,
+					amount: 2,
+				},
+				Event::Rewarded {
+					account: 3,
+					rewards: 2,
+				},
+				// no reward destination
+				Event::Rewarded {
+					account: 3,
+					rewards: 4,
+				},
+			);
+		});
+}
+
+#[test]
+fn test_rewards_compound_towards_target_candidate_as_per_reward_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 300), (3, 200), (4, 20)])
+		.with_candidates(vec![(1, 100), (4, 20)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200), (2, 4, 100)])
+		.build()
+		.execute_with(|| {
+			(2..=5).for_each(|round| set_author(round, 1, POINTS_PER_ROUND));
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Candidate(4),
+			));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+			assert_events_emitted_match!(
+				Event::Rewarded { account: 2, .. },
+				Event::Compounded {
+					candidate: 4,
+					delegator: 2,
+					..
+				},
+			);
+			assert_events_not_emitted_match!(Event::Compounded {
+				candidate: 1,
+				delegator: 2,
+				..
+			});
+			assert!(ParachainStaking::delegator_state(&2)
+				.expect("delegator must exist")
+				.delegations
+				.0
+				.iter()
+				.any(|b| b.owner == 4 && b.amount > 100));
+		});
+}
+
+#[test]
+fn test_reward_destination_is_reset_when_target_candidate_delegation_is_revoked() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 300), (3, 200), (4, 20)])
+		.with_candidates(vec![(1, 100), (4, 20)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200), (2, 4, 100)])
+		.build()
+		.execute_with(|| {
+			(2..=6).for_each(|round| set_author(round, 1, POINTS_PER_ROUND));
+			assert_ok!(ParachainStaking::set_reward_destination(
+				RuntimeOrigin::signed(2),
+				RewardDestination::Candidate(4),
+			));
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				4
+			));
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				4
+			));
+
+			roll_to_round_begin(4);
+			roll_blocks(1);
+			assert_events_emitted!(Event::RewardDestinationReset {
+				delegator: 2,
+				candidate: 4,
+			});
+			assert_events_emitted_match!(Event::Rewarded { account: 2, .. });
+			assert!(!DelegatorRewardDestination::<Test>::contains_key(&2));
+		});
+}
+
+#[test]
+fn test_round_summary_records_rewards_paid_for_round() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			(1..=3).for_each(|round| set_author(round, 1, POINTS_PER_ROUND));
+			roll_to_round_begin(3);
+			let total_points = ParachainStaking::points(1);
+			let collator_points = ParachainStaking::awarded_pts(1, 1);
+			let summary = ParachainStaking::round_summary(1).expect("round 1 must be archived");
+			assert_eq!(summary.total_points, total_points);
+			assert!(ParachainStaking::collator_round_summary(1, 1).is_none());
+
+			roll_blocks(3);
+			assert_eq!(
+				ParachainStaking::collator_round_summary(1, 1),
+				Some(CollatorRoundSummary {
+					points: collator_points,
+					total_paid: Perbill::from_rational(collator_points, total_points)
+						* summary.total_staking_reward,
+				}),
+			);
+		});
+}
+
+#[test]
+fn test_round_summary_is_pruned_after_max_round_archive() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100)])
+		.with_candidates(vec![(1, 100)])
+		.build()
+		.execute_with(|| {
+			(1..=3).for_each(|round| set_author(round, 1, POINTS_PER_ROUND));
+			roll_to_round_begin(5);
+			assert!(ParachainStaking::round_summary(1).is_some());
+			assert!(ParachainStaking::collator_round_summary(1, 1).is_some());
+			roll_to_round_begin(6);
+			assert!(ParachainStaking::round_summary(1).is_none());
+			assert!(ParachainStaking::collator_round_summary(1, 1).is_none());
+			assert!(ParachainStaking::round_summary(2).is_some());
+			assert!(ParachainStaking::collator_round_summary(2, 1).is_some());
+		});
+}
+
 #[test]
 fn test_delegate_with_auto_compound_fails_if_invalid_delegation_hint() {
 	ExtBuilder::default()
@@ -9087,13 +9664,15 @@ fn test_on_initialize_weights() {
 			//
 			// following this assertion, we add individual weights together to show that we can
 			// derive this number independently.
-			let expected_on_init = 2999210735;
+			let expected_on_init = 3099210735;
 			assert_eq!(Weight::from_parts(expected_on_init, 51554), weight);
 
 			// assemble weight manually to ensure it is well understood
 			let mut expected_weight = 0u64;
 			expected_weight += PalletWeights::<Test>::base_on_initialize().ref_time();
 			expected_weight += PalletWeights::<Test>::prepare_staking_payouts().ref_time();
+			// RoundSummaries write, done in prepare_staking_payouts()
+			expected_weight += RocksDbWeight::get().writes(1).ref_time();
 			expected_weight += PalletWeights::<Test>::mark_collators_as_inactive(5).ref_time();
 
 			// TODO: this should be the same as <TotalSelected<Test>>. I believe this relates to
@@ -9133,3 +9712,135 @@ fn test_compute_top_candidates_is_stable() {
 			);
 		});
 }
+
+#[test]
+fn test_min_self_bond_ratio_selection_skips_under_bonded_candidates() {
+	frame_support::parameter_types! {
+		const MinRatio: Perbill = Perbill::from_percent(50);
+	}
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 20),
+			(2, 30),
+			(3, 30),
+			(4, 30),
+			(5, 30),
+			(6, 30),
+			(7, 60),
+		])
+		.with_candidates(vec![(1, 20), (2, 30), (3, 30), (4, 30), (5, 30), (6, 30)])
+		.with_delegations(vec![(7, 1, 60)])
+		.build()
+		.execute_with(|| {
+			let candidates = ParachainStaking::candidate_pool().0.into_inner();
+			// candidate 1 has the highest stake, but only 25% of it is self-bonded
+			assert_eq!(
+				<() as CandidateSelection<Test>>::select_candidates(candidates.clone(), 5),
+				vec![1, 3, 4, 5, 6]
+			);
+			assert_eq!(
+				<MinSelfBondRatio<MinRatio> as CandidateSelection<Test>>::select_candidates(
+					candidates, 5
+				),
+				vec![2, 3, 4, 5, 6]
+			);
+		});
+}
+
+#[test]
+fn test_capped_candidate_stake_selection_limits_counted_stake() {
+	frame_support::parameter_types! {
+		const StakeCap: u128 = 30;
+		static Seed: u8 = 0;
+	}
+	struct MockRandomness;
+	impl frame_support::traits::Randomness<sp_core::H256, BlockNumber> for MockRandomness {
+		fn random(_subject: &[u8]) -> (sp_core::H256, BlockNumber) {
+			(sp_core::H256::repeat_byte(Seed::get()), 0)
+		}
+	}
+
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 30),
+			(2, 30),
+			(3, 30),
+			(4, 30),
+			(5, 30),
+			(6, 30),
+			(7, 60),
+		])
+		.with_candidates(vec![(1, 30), (2, 30), (3, 30), (4, 30), (5, 30), (6, 30)])
+		.with_delegations(vec![(7, 1, 60)])
+		.build()
+		.execute_with(|| {
+			let candidates = ParachainStaking::candidate_pool().0.into_inner();
+			// candidate 1 has the highest stake, so the default policy always selects it
+			let default_selection =
+				<() as CandidateSelection<Test>>::select_candidates(candidates.clone(), 3);
+			assert!(default_selection.contains(&1));
+
+			// the stake of candidate 1 above the cap is not counted, so all candidates tie and
+			// candidate 1 is not always selected
+			let mut different_selections = 0;
+			for seed in 0..32u8 {
+				Seed::set(seed);
+				let selected = <CappedCandidateStake<StakeCap, MockRandomness> as CandidateSelection<
+					Test,
+				>>::select_candidates(candidates.clone(), 3);
+				assert_eq!(selected.len(), 3);
+				assert!(selected.windows(2).all(|pair| pair[0] < pair[1]));
+				if !selected.contains(&1) {
+					assert_ne!(selected, default_selection);
+					different_selections += 1;
+				}
+			}
+			assert!(different_selections > 0);
+		});
+}
+
+#[test]
+fn test_random_tie_break_selection_keeps_ranking_by_stake() {
+	frame_support::parameter_types! {
+		static Seed: u8 = 0;
+	}
+	struct MockRandomness;
+	impl frame_support::traits::Randomness<sp_core::H256, BlockNumber> for MockRandomness {
+		fn random(_subject: &[u8]) -> (sp_core::H256, BlockNumber) {
+			(sp_core::H256::repeat_byte(Seed::get()), 0)
+		}
+	}
+
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 30),
+			(2, 30),
+			(3, 30),
+			(4, 30),
+			(5, 30),
+			(6, 30),
+			(7, 60),
+		])
+		.with_candidates(vec![(1, 30), (2, 30), (3, 30), (4, 30), (5, 30), (6, 30)])
+		.with_delegations(vec![(7, 1, 60)])
+		.build()
+		.execute_with(|| {
+			let candidates = ParachainStaking::candidate_pool().0.into_inner();
+			let mut excluded = std::collections::BTreeSet::new();
+			for seed in 0..16u8 {
+				Seed::set(seed);
+				let selected =
+					<RandomTieBreak<MockRandomness> as CandidateSelection<Test>>::select_candidates(
+						candidates.clone(),
+						4,
+					);
+				assert_eq!(selected.len(), 4);
+				assert!(selected.windows(2).all(|pair| pair[0] < pair[1]));
+				// candidate 1 has the highest stake, so it is always selected
+				assert!(selected.contains(&1));
+				excluded.extend((2..=6).filter(|candidate| !selected.contains(candidate)));
+			}
+			// ties are not always broken in favour of the same candidates
+			assert!(excluded.len() > 2);
+		});
+}
@@ -17,8 +17,14 @@
 //! traits for parachain-staking
 
 use crate::weights::WeightInfo;
-use frame_support::{dispatch::PostDispatchInfo, pallet_prelude::Weight};
-use sp_runtime::DispatchErrorWithPostInfo;
+use frame_support::{
+	dispatch::PostDispatchInfo,
+	pallet_prelude::Weight,
+	traits::{Get, Randomness},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Hash, DispatchErrorWithPostInfo, Perbill};
+use sp_std::{cmp::Reverse, marker::PhantomData, prelude::*};
 
 pub trait OnCollatorPayout<AccountId, Balance> {
 	fn on_collator_payout(
@@ -85,3 +91,141 @@ impl<Runtime: crate::Config> OnInactiveCollator<Runtime> for () {
 		))
 	}
 }
+
+/// Defines how the collators of the next round are selected among the candidate pool.
+pub trait CandidateSelection<Runtime: crate::Config> {
+	/// Returns at most `top_n` candidates to select, sorted by AccountId.
+	/// `candidates` holds the active candidates with their total counted stake, sorted by
+	/// AccountId.
+	fn select_candidates(
+		candidates: Vec<crate::Bond<Runtime::AccountId, crate::BalanceOf<Runtime>>>,
+		top_n: usize,
+	) -> Vec<Runtime::AccountId>;
+
+	/// Weight consumed by `select_candidates` on top of the default selection.
+	fn selection_weight(_candidate_count: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Defines the default selection: the candidates with the highest total counted stake are
+/// selected, ties are broken by AccountId.
+impl<Runtime: crate::Config> CandidateSelection<Runtime> for () {
+	fn select_candidates(
+		candidates: Vec<crate::Bond<Runtime::AccountId, crate::BalanceOf<Runtime>>>,
+		top_n: usize,
+	) -> Vec<Runtime::AccountId> {
+		crate::Pallet::<Runtime>::select_top_candidates_by_stake(candidates, top_n)
+	}
+}
+
+/// Selects candidates by stake, skipping the candidates whose self-bond is below `Ratio` of
+/// their total counted stake.
+pub struct MinSelfBondRatio<Ratio>(PhantomData<Ratio>);
+
+impl<Runtime: crate::Config, Ratio: Get<Perbill>> CandidateSelection<Runtime>
+	for MinSelfBondRatio<Ratio>
+{
+	fn select_candidates(
+		candidates: Vec<crate::Bond<Runtime::AccountId, crate::BalanceOf<Runtime>>>,
+		top_n: usize,
+	) -> Vec<Runtime::AccountId> {
+		let min_ratio = Ratio::get();
+		let candidates = candidates
+			.into_iter()
+			.filter(|candidate| {
+				crate::Pallet::<Runtime>::candidate_info(&candidate.owner).map_or(false, |info| {
+					Perbill::from_rational(info.bond, candidate.amount) >= min_ratio
+				})
+			})
+			.collect();
+		crate::Pallet::<Runtime>::select_top_candidates_by_stake(candidates, top_n)
+	}
+
+	fn selection_weight(candidate_count: u32) -> Weight {
+		// read CandidateInfo of each candidate
+		<Runtime as frame_system::Config>::DbWeight::get().reads(candidate_count as u64)
+	}
+}
+
+/// Selects candidates by stake, counting at most `Cap` of each candidate's total stake.
+/// Candidates with the same counted stake, such as all the candidates above the cap, are ranked
+/// with the randomness `R` rather than by their uncapped stake, so that stake above the cap gives
+/// no advantage.
+pub struct CappedCandidateStake<Cap, R>(PhantomData<(Cap, R)>);
+
+impl<Runtime, Cap, R> CandidateSelection<Runtime> for CappedCandidateStake<Cap, R>
+where
+	Runtime: crate::Config,
+	Cap: Get<crate::BalanceOf<Runtime>>,
+	R: Randomness<Runtime::Hash, BlockNumberFor<Runtime>>,
+{
+	fn select_candidates(
+		mut candidates: Vec<crate::Bond<Runtime::AccountId, crate::BalanceOf<Runtime>>>,
+		top_n: usize,
+	) -> Vec<Runtime::AccountId> {
+		if candidates.len() <= top_n {
+			return crate::Pallet::<Runtime>::select_top_candidates_by_stake(candidates, top_n);
+		}
+
+		let cap = Cap::get();
+		let (seed, _) = R::random(b"parachain-staking-selection");
+		candidates.sort_by_cached_key(|candidate| {
+			(
+				Reverse(candidate.amount.min(cap)),
+				<Runtime as frame_system::Config>::Hashing::hash_of(&(&seed, &candidate.owner)),
+			)
+		});
+		let mut collators = candidates
+			.into_iter()
+			.take(top_n)
+			.map(|candidate| candidate.owner)
+			.collect::<Vec<_>>();
+		collators.sort();
+		collators
+	}
+
+	fn selection_weight(_candidate_count: u32) -> Weight {
+		// read the randomness
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+}
+
+/// Selects candidates by stake, breaking the ties between equal stakes with the randomness `R`
+/// rather than by AccountId, so that the same candidates are not always favoured.
+pub struct RandomTieBreak<R>(PhantomData<R>);
+
+impl<Runtime, R> CandidateSelection<Runtime> for RandomTieBreak<R>
+where
+	Runtime: crate::Config,
+	R: Randomness<Runtime::Hash, BlockNumberFor<Runtime>>,
+{
+	fn select_candidates(
+		mut candidates: Vec<crate::Bond<Runtime::AccountId, crate::BalanceOf<Runtime>>>,
+		top_n: usize,
+	) -> Vec<Runtime::AccountId> {
+		if candidates.len() <= top_n {
+			return crate::Pallet::<Runtime>::select_top_candidates_by_stake(candidates, top_n);
+		}
+
+		let (seed, _) = R::random(b"parachain-staking-selection");
+		candidates.sort_by_cached_key(|candidate| {
+			(
+				Reverse(candidate.amount),
+				<Runtime as frame_system::Config>::Hashing::hash_of(&(&seed, &candidate.owner)),
+			)
+		});
+		let mut collators = candidates
+			.into_iter()
+			.take(top_n)
+			.map(|candidate| candidate.owner)
+			.collect::<Vec<_>>();
+		collators.sort();
+		collators
+	}
+
+	fn selection_weight(_candidate_count: u32) -> Weight {
+		// read the randomness
+		<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+}
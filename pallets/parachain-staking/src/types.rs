@@ -18,8 +18,8 @@
 
 use crate::{
 	auto_compound::AutoCompoundDelegations, set::OrderedSet, BalanceOf, BottomDelegations,
-	CandidateInfo, Config, DelegatorState, Error, Event, Pallet, Round, RoundIndex, TopDelegations,
-	Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
+	CandidateInfo, Config, DelegatorRewardDestination, DelegatorState, Error, Event, Pallet, Round,
+	RoundIndex, TopDelegations, Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -99,6 +99,26 @@ impl Default for CollatorStatus {
 	}
 }
 
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Where the staking rewards of a delegator are paid to
+pub enum RewardDestination<AccountId> {
+	/// Rewards are paid to the delegator account and compounded according to the auto-compound
+	/// config of each delegation
+	Delegator,
+	/// The auto-compounded portion of the rewards is still staked towards the rewarding
+	/// delegation, the remaining portion is paid to the given payee account
+	Account(AccountId),
+	/// Rewards are paid to the delegator account and entirely compounded towards the delegation
+	/// to the given candidate
+	Candidate(AccountId),
+}
+
+impl<AccountId> Default for RewardDestination<AccountId> {
+	fn default() -> RewardDestination<AccountId> {
+		RewardDestination::Delegator
+	}
+}
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct BondWithAutoCompound<AccountId, Balance> {
 	pub owner: AccountId,
@@ -181,6 +201,27 @@ pub struct DelayedPayout<Balance> {
 	pub collator_commission: Perbill,
 }
 
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Rewards paid to a single collator (and its delegators) for a round
+pub struct CollatorRoundSummary<Balance> {
+	/// Points awarded to the collator for block production in the round
+	pub points: u32,
+	/// Total reward paid out for the collator, including the share of its delegators
+	pub total_paid: Balance,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Archived summary of the staking rewards of a round, the per-collator rewards are archived in
+/// `CollatorRoundSummaries`
+pub struct RoundSummary<Balance> {
+	/// Total round reward (result of compute_issuance() at round end)
+	pub round_issuance: Balance,
+	/// The total inflation paid this round to stakers (e.g. less parachain bond fund)
+	pub total_staking_reward: Balance,
+	/// Total points awarded to collators for block production in the round
+	pub total_points: u32,
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// DEPRECATED
 /// Collator state with commission fee, bonded stake, and delegations
@@ -721,6 +762,7 @@ impl<
 			});
 			if leaving {
 				<DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+				<DelegatorRewardDestination<T>>::remove(&lowest_bottom_to_be_kicked.owner);
 				Pallet::<T>::deposit_event(Event::DelegatorLeft {
 					delegator: lowest_bottom_to_be_kicked.owner,
 					unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -87,6 +87,7 @@ pub trait WeightInfo {
 	fn mint_collator_reward() -> Weight;
 	fn notify_inactive_collator() -> Weight;
 	fn mark_collators_as_inactive(x: u32, ) -> Weight;
+	fn set_reward_destination() -> Weight;
 }
 
 /// Weights for `pallet_parachain_staking` using the Substrate node and recommended hardware.
@@ -902,6 +903,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorRewardDestination` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorRewardDestination` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(15_000_000, 4069)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1716,4 +1726,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorRewardDestination` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorRewardDestination` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(15_000_000, 4069)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,140 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{v2::*, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use frame_system::EventRecord;
+
+const LIQUIDITY: u128 = 1_000_000_000_000_000_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+	// compare to the last event record
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+fn setup_pool<T: Config>(liquidity: u128) -> Result<Location, BenchmarkError> {
+	let origin = T::PoolsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+	let location = T::BenchmarkHelper::setup_pool_asset(&T::LiquidityProvider::get(), LIQUIDITY);
+
+	Pallet::<T>::create_pool(origin.clone(), location.clone()).expect("fail to create pool");
+	if liquidity > 0 {
+		Pallet::<T>::add_liquidity(origin, location.clone(), liquidity, liquidity)
+			.expect("fail to add liquidity");
+	}
+
+	Ok(location)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_pool() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PoolsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		let location =
+			T::BenchmarkHelper::setup_pool_asset(&T::LiquidityProvider::get(), LIQUIDITY);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, location.clone());
+
+		assert_last_event::<T>(Event::PoolCreated { location }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_liquidity() -> Result<(), BenchmarkError> {
+		let location = setup_pool::<T>(0)?;
+
+		let origin =
+			T::PoolsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			location.clone(),
+			LIQUIDITY / 2,
+			LIQUIDITY / 2,
+		);
+
+		assert_last_event::<T>(
+			Event::LiquidityAdded {
+				location,
+				asset_amount: LIQUIDITY / 2,
+				native_amount: LIQUIDITY / 2,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_liquidity() -> Result<(), BenchmarkError> {
+		let location = setup_pool::<T>(LIQUIDITY / 2)?;
+
+		let origin =
+			T::PoolsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			location.clone(),
+			LIQUIDITY / 4,
+			LIQUIDITY / 4,
+		);
+
+		assert_last_event::<T>(
+			Event::LiquidityRemoved {
+				location,
+				asset_amount: LIQUIDITY / 4,
+				native_amount: LIQUIDITY / 4,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn swap() -> Result<(), BenchmarkError> {
+		// The pool asset set up by the helper is the worst case: its transfers are EVM calls
+		let location = setup_pool::<T>(LIQUIDITY / 2)?;
+		let give: Asset = (XcmAssetId(location.clone()), LIQUIDITY / 100).into();
+		let want: Asset = (XcmAssetId(T::NativeLocation::get()), LIQUIDITY / 1_000).into();
+
+		#[block]
+		{
+			Pallet::<T>::swap_exact_out(give, want).expect("fail to swap");
+		}
+
+		assert_eq!(
+			Pools::<T>::get(&location).map(|pool| pool.native),
+			Some(LIQUIDITY / 2 - LIQUIDITY / 1_000)
+		);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
+}
@@ -0,0 +1,155 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module that plugs a liquidity source into the XCM executor as `AssetExchanger`.
+
+use frame_support::traits::{Contains, ProcessMessageError};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+use xcm::v4::{Asset, Assets, Fungibility, Instruction, Location, Weight, WeightLimit};
+use xcm_executor::traits::{AssetExchange, Properties, ShouldExecute};
+use xcm_executor::AssetsInHolding;
+
+/// A source of liquidity able to swap a fungible asset for another one.
+/// The assets given are already withdrawn from the holding register, and the assets returned
+/// are put back into it.
+pub trait LiquiditySource {
+	/// Swap all of `give` for at least `min_want`, returns the asset obtained.
+	fn swap_exact_in(give: Asset, min_want: Asset) -> Result<Asset, DispatchError>;
+
+	/// Swap at most `give` for exactly `want`, returns the asset obtained and the unused part
+	/// of `give`.
+	fn swap_exact_out(give: Asset, want: Asset) -> Result<(Asset, Asset), DispatchError>;
+}
+
+/// An `AssetExchange` implementation exchanging a single fungible asset for another one
+/// through the given liquidity source.
+pub struct LiquiditySourceExchanger<Source>(PhantomData<Source>);
+
+impl<Source: LiquiditySource> AssetExchange for LiquiditySourceExchanger<Source> {
+	fn exchange_asset(
+		_origin: Option<&Location>,
+		give: AssetsInHolding,
+		want: &Assets,
+		maximal: bool,
+	) -> Result<AssetsInHolding, AssetsInHolding> {
+		let give_asset = match Self::single_fungible(&give) {
+			Some(asset) => asset,
+			None => return Err(give),
+		};
+		let want_asset = match want.inner() {
+			[asset @ Asset {
+				fun: Fungibility::Fungible(_),
+				..
+			}] => asset.clone(),
+			_ => return Err(give),
+		};
+
+		// A failed swap must not leave any partial change behind
+		let result = frame_support::storage::with_storage_layer(|| {
+			if maximal {
+				Source::swap_exact_in(give_asset, want_asset).map(AssetsInHolding::from)
+			} else {
+				Source::swap_exact_out(give_asset, want_asset).map(|(received, change)| {
+					let mut holding = AssetsInHolding::from(received);
+					if change.fun != Fungibility::Fungible(0) {
+						holding.subsume(change);
+					}
+					holding
+				})
+			}
+		});
+
+		result.map_err(|error| {
+			log::debug!(
+				target: "xcm::exchange_asset",
+				"Failed to exchange {:?} for {:?}: {:?}",
+				give,
+				want,
+				error
+			);
+			give
+		})
+	}
+}
+
+impl<Source> LiquiditySourceExchanger<Source> {
+	fn single_fungible(holding: &AssetsInHolding) -> Option<Asset> {
+		if holding.fungible.len() != 1 || !holding.non_fungible.is_empty() {
+			return None;
+		}
+		holding
+			.fungible
+			.iter()
+			.next()
+			.map(|(id, amount)| (id.clone(), *amount).into())
+	}
+}
+
+/// Allows top level execution from `T` origins of messages that exchange the assets they
+/// withdraw before paying for execution, so the fees can be paid with the exchanged assets.
+/// The messages must start with `WithdrawAsset | ReserveAssetDeposited | ClaimAsset`, followed
+/// by any number of `ClearOrigin`, then `ExchangeAsset` and `BuyExecution`.
+/// The exchange runs before any fee is paid, so `T` should only contain trusted locations.
+pub struct AllowPaidExecutionAfterExchangeFrom<T>(PhantomData<T>);
+
+impl<T: Contains<Location>> ShouldExecute for AllowPaidExecutionAfterExchangeFrom<T> {
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		if !T::contains(origin) {
+			return Err(ProcessMessageError::Unsupported);
+		}
+
+		let mut iter = instructions.iter_mut();
+		match iter.next() {
+			Some(
+				Instruction::WithdrawAsset(..)
+				| Instruction::ReserveAssetDeposited(..)
+				| Instruction::ClaimAsset { .. },
+			) => (),
+			_ => return Err(ProcessMessageError::BadFormat),
+		}
+		let mut next = iter.next();
+		while matches!(next, Some(Instruction::ClearOrigin)) {
+			next = iter.next();
+		}
+		match next {
+			Some(Instruction::ExchangeAsset { .. }) => (),
+			_ => return Err(ProcessMessageError::BadFormat),
+		}
+		match iter.next() {
+			Some(Instruction::BuyExecution {
+				weight_limit: WeightLimit::Limited(ref mut weight),
+				..
+			}) if weight.all_gte(max_weight) => {
+				*weight = max_weight;
+				Ok(())
+			}
+			Some(Instruction::BuyExecution {
+				ref mut weight_limit,
+				..
+			}) if weight_limit == &WeightLimit::Unlimited => {
+				*weight_limit = WeightLimit::Limited(max_weight);
+				Ok(())
+			}
+			_ => Err(ProcessMessageError::Overweight(max_weight)),
+		}
+	}
+}
@@ -0,0 +1,462 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # A pallet providing liquidity to exchange assets during XCM execution
+//!
+//! Governance funds constant product pools pairing the native currency with a foreign asset.
+//! The pools are used as liquidity source by the `AssetExchanger` of the XCM executor, which
+//! allows incoming messages to swap the assets they hold (e.g. to pay for execution fees).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod exchanger;
+pub mod weights;
+
+pub use exchanger::{
+	AllowPaidExecutionAfterExchangeFrom, LiquiditySource, LiquiditySourceExchanger,
+};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::pallet;
+use frame_support::pallet_prelude::*;
+use frame_support::PalletId;
+use frame_system::pallet_prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{AccountIdConversion, Convert};
+use sp_runtime::Permill;
+use xcm::v4::{Asset, AssetId as XcmAssetId, Fungibility, Location, XcmContext};
+use xcm_executor::traits::TransactAsset;
+
+/// Reserves of a pool pairing a foreign asset with the native currency
+#[derive(Clone, Default, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PoolReserves {
+	/// Reserve of the foreign asset
+	pub asset: u128,
+	/// Reserve of the native currency
+	pub native: u128,
+}
+
+impl PoolReserves {
+	/// Returns the (input, output) reserves of a swap in the given direction
+	fn in_out(&self, native_in: bool) -> (u128, u128) {
+		if native_in {
+			(self.native, self.asset)
+		} else {
+			(self.asset, self.native)
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// Returns the location of a foreign asset that can be paired with the native currency,
+	/// after funding `who` with `amount` of this asset and of the native currency.
+	fn setup_pool_asset(who: &AccountId, amount: u128) -> Location;
+}
+
+#[pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// Configuration trait of this pallet.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Convert `T::AccountId` to `Location`.
+		type AccountIdToLocation: Convert<Self::AccountId, Location>;
+
+		/// How to withdraw, deposit and transfer assets.
+		type AssetTransactor: TransactAsset;
+
+		/// Account funding the pools and receiving the removed liquidity
+		type LiquidityProvider: Get<Self::AccountId>;
+
+		/// XCM Location for native curreny
+		type NativeLocation: Get<Location>;
+
+		/// The pallet id, used to derive the account holding the pools reserves
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin that is allowed to create pools and to manage their liquidity
+		type PoolsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Fee taken on the input amount of each swap, kept by the pool
+		#[pallet::constant]
+		type SwapFee: Get<Permill>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The benchmarks need a funded foreign asset to create a pool
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+	}
+
+	/// Reserves of each pool, per XCM Location of the foreign asset paired with the native currency
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, Location, PoolReserves>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The native currency cannot be paired with itself
+		InvalidPoolAsset,
+		/// A pool already exists for the given asset
+		PoolAlreadyExists,
+		/// There is no pool for the given asset
+		PoolNotFound,
+		/// The pool does not hold enough liquidity
+		InsufficientLiquidity,
+		/// The swap would give less than the minimum amount wanted
+		InsufficientAmountOut,
+		/// Only swaps between the native currency and a foreign asset are supported
+		UnsupportedSwap,
+		/// Failed to move the assets with the asset transactor
+		AssetTransactFailed,
+		/// An arithmetic operation overflowed
+		Overflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool pairing the given asset with the native currency has been created
+		PoolCreated { location: Location },
+		/// Liquidity has been added to a pool
+		LiquidityAdded {
+			location: Location,
+			asset_amount: u128,
+			native_amount: u128,
+		},
+		/// Liquidity has been removed from a pool
+		LiquidityRemoved {
+			location: Location,
+			asset_amount: u128,
+			native_amount: u128,
+		},
+		/// Assets have been swapped against a pool
+		Swapped {
+			location: Location,
+			native_in: bool,
+			amount_in: u128,
+			amount_out: u128,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		pub fn create_pool(origin: OriginFor<T>, location: Location) -> DispatchResult {
+			T::PoolsOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				location != T::NativeLocation::get(),
+				Error::<T>::InvalidPoolAsset
+			);
+			ensure!(
+				!Pools::<T>::contains_key(&location),
+				Error::<T>::PoolAlreadyExists
+			);
+
+			Pools::<T>::insert(&location, PoolReserves::default());
+
+			Self::deposit_event(Event::PoolCreated { location });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			location: Location,
+			asset_amount: u128,
+			native_amount: u128,
+		) -> DispatchResult {
+			T::PoolsOrigin::ensure_origin(origin)?;
+
+			let mut pool = Pools::<T>::get(&location).ok_or(Error::<T>::PoolNotFound)?;
+			pool.asset = pool
+				.asset
+				.checked_add(asset_amount)
+				.ok_or(Error::<T>::Overflow)?;
+			pool.native = pool
+				.native
+				.checked_add(native_amount)
+				.ok_or(Error::<T>::Overflow)?;
+
+			let provider = T::AccountIdToLocation::convert(T::LiquidityProvider::get());
+			let pool_account = Self::pool_account_location();
+			Self::transfer(&location, asset_amount, &provider, &pool_account)?;
+			Self::transfer(
+				&T::NativeLocation::get(),
+				native_amount,
+				&provider,
+				&pool_account,
+			)?;
+
+			Pools::<T>::insert(&location, pool);
+
+			Self::deposit_event(Event::LiquidityAdded {
+				location,
+				asset_amount,
+				native_amount,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			location: Location,
+			asset_amount: u128,
+			native_amount: u128,
+		) -> DispatchResult {
+			T::PoolsOrigin::ensure_origin(origin)?;
+
+			let mut pool = Pools::<T>::get(&location).ok_or(Error::<T>::PoolNotFound)?;
+			pool.asset = pool
+				.asset
+				.checked_sub(asset_amount)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+			pool.native = pool
+				.native
+				.checked_sub(native_amount)
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+
+			let provider = T::AccountIdToLocation::convert(T::LiquidityProvider::get());
+			let pool_account = Self::pool_account_location();
+			Self::transfer(&location, asset_amount, &pool_account, &provider)?;
+			Self::transfer(
+				&T::NativeLocation::get(),
+				native_amount,
+				&pool_account,
+				&provider,
+			)?;
+
+			Pools::<T>::insert(&location, pool);
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				location,
+				asset_amount,
+				native_amount,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the reserves of all pools
+		pub fn pool_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+		fn pool_account_location() -> Location {
+			T::AccountIdToLocation::convert(Self::pool_account())
+		}
+		fn transfer(
+			location: &Location,
+			amount: u128,
+			from: &Location,
+			to: &Location,
+		) -> DispatchResult {
+			if amount == 0 {
+				return Ok(());
+			}
+			T::AssetTransactor::transfer_asset(
+				&(XcmAssetId(location.clone()), Fungibility::Fungible(amount)).into(),
+				from,
+				to,
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::AssetTransactFailed)?;
+			Ok(())
+		}
+		/// Returns the location of the pool able to swap `give` for `want`,
+		/// and whether the native currency is the input of the swap
+		fn pool_of_pair(give: &Location, want: &Location) -> Result<(Location, bool), Error<T>> {
+			let native = T::NativeLocation::get();
+			if *give == native && *want != native {
+				Ok((want.clone(), true))
+			} else if *want == native && *give != native {
+				Ok((give.clone(), false))
+			} else {
+				Err(Error::<T>::UnsupportedSwap)
+			}
+		}
+		/// Amount obtained when swapping `amount_in`, after deducting the swap fee
+		pub fn get_amount_out(
+			amount_in: u128,
+			reserve_in: u128,
+			reserve_out: u128,
+		) -> Result<u128, Error<T>> {
+			ensure!(
+				reserve_in > 0 && reserve_out > 0,
+				Error::<T>::InsufficientLiquidity
+			);
+			let amount_in = U256::from(amount_in - T::SwapFee::get().mul_ceil(amount_in));
+			let amount_out =
+				amount_in * U256::from(reserve_out) / (U256::from(reserve_in) + amount_in);
+			// Always lower than reserve_out
+			Ok(amount_out.low_u128())
+		}
+		/// Amount to swap, swap fee included, to obtain `amount_out`
+		pub fn get_amount_in(
+			amount_out: u128,
+			reserve_in: u128,
+			reserve_out: u128,
+		) -> Result<u128, Error<T>> {
+			ensure!(
+				reserve_in > 0 && amount_out < reserve_out,
+				Error::<T>::InsufficientLiquidity
+			);
+			let numerator = U256::from(reserve_in) * U256::from(amount_out);
+			let denominator = U256::from(reserve_out - amount_out);
+			let amount_in_after_fee = (numerator + denominator - 1) / denominator;
+
+			let fee_complement =
+				U256::from(Permill::one().deconstruct() - T::SwapFee::get().deconstruct());
+			ensure!(!fee_complement.is_zero(), Error::<T>::InsufficientLiquidity);
+			let amount_in = (amount_in_after_fee * U256::from(Permill::one().deconstruct())
+				+ fee_complement - 1)
+				/ fee_complement;
+
+			ensure!(amount_in <= U256::from(u128::MAX), Error::<T>::Overflow);
+			Ok(amount_in.low_u128())
+		}
+		/// Move `amount_in` of the input asset into the pool and `amount_out` of the output asset
+		/// out of it, returns the output asset
+		fn do_swap(
+			location: Location,
+			mut pool: PoolReserves,
+			native_in: bool,
+			amount_in: u128,
+			amount_out: u128,
+		) -> Result<Asset, DispatchError> {
+			let (location_in, location_out) = if native_in {
+				(T::NativeLocation::get(), location.clone())
+			} else {
+				(location.clone(), T::NativeLocation::get())
+			};
+			let asset_in: Asset =
+				(XcmAssetId(location_in), Fungibility::Fungible(amount_in)).into();
+			let asset_out: Asset =
+				(XcmAssetId(location_out), Fungibility::Fungible(amount_out)).into();
+
+			let pool_account = Self::pool_account_location();
+			T::AssetTransactor::deposit_asset(&asset_in, &pool_account, None)
+				.map_err(|_| Error::<T>::AssetTransactFailed)?;
+			T::AssetTransactor::withdraw_asset(&asset_out, &pool_account, None)
+				.map_err(|_| Error::<T>::AssetTransactFailed)?;
+
+			if native_in {
+				pool.native = pool
+					.native
+					.checked_add(amount_in)
+					.ok_or(Error::<T>::Overflow)?;
+				pool.asset = pool
+					.asset
+					.checked_sub(amount_out)
+					.ok_or(Error::<T>::InsufficientLiquidity)?;
+			} else {
+				pool.asset = pool
+					.asset
+					.checked_add(amount_in)
+					.ok_or(Error::<T>::Overflow)?;
+				pool.native = pool
+					.native
+					.checked_sub(amount_out)
+					.ok_or(Error::<T>::InsufficientLiquidity)?;
+			}
+			Pools::<T>::insert(&location, pool);
+
+			Self::deposit_event(Event::Swapped {
+				location,
+				native_in,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(asset_out)
+		}
+		fn fungible_of(asset: &Asset) -> Result<(&Location, u128), Error<T>> {
+			match asset {
+				Asset {
+					id: XcmAssetId(location),
+					fun: Fungibility::Fungible(amount),
+				} => Ok((location, *amount)),
+				_ => Err(Error::<T>::UnsupportedSwap),
+			}
+		}
+	}
+
+	impl<T: Config> LiquiditySource for Pallet<T> {
+		fn swap_exact_in(give: Asset, min_want: Asset) -> Result<Asset, DispatchError> {
+			let (give_location, amount_in) = Self::fungible_of(&give)?;
+			let (want_location, min_amount_out) = Self::fungible_of(&min_want)?;
+			let (location, native_in) = Self::pool_of_pair(give_location, want_location)?;
+			let pool = Pools::<T>::get(&location).ok_or(Error::<T>::PoolNotFound)?;
+
+			let (reserve_in, reserve_out) = pool.in_out(native_in);
+			let amount_out = Self::get_amount_out(amount_in, reserve_in, reserve_out)?;
+			ensure!(
+				amount_out > 0 && amount_out >= min_amount_out,
+				Error::<T>::InsufficientAmountOut
+			);
+
+			Self::do_swap(location, pool, native_in, amount_in, amount_out)
+		}
+		fn swap_exact_out(give: Asset, want: Asset) -> Result<(Asset, Asset), DispatchError> {
+			let (give_location, max_amount_in) = Self::fungible_of(&give)?;
+			let (want_location, amount_out) = Self::fungible_of(&want)?;
+			let (location, native_in) = Self::pool_of_pair(give_location, want_location)?;
+			let pool = Pools::<T>::get(&location).ok_or(Error::<T>::PoolNotFound)?;
+
+			let (reserve_in, reserve_out) = pool.in_out(native_in);
+			let amount_in = Self::get_amount_in(amount_out, reserve_in, reserve_out)?;
+			ensure!(
+				amount_in <= max_amount_in,
+				Error::<T>::InsufficientAmountOut
+			);
+
+			let change: Asset = (
+				XcmAssetId(give_location.clone()),
+				Fungibility::Fungible(max_amount_in - amount_in),
+			)
+				.into();
+			let received = Self::do_swap(location, pool, native_in, amount_in, amount_out)?;
+
+			Ok((received, change))
+		}
+	}
+}
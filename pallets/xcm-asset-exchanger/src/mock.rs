@@ -0,0 +1,241 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A minimal runtime including the xcm asset exchanger pallet
+
+use super::*;
+use crate as pallet_xcm_asset_exchanger;
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types, storage,
+	traits::{Currency, Everything, ExistenceRequirement, WithdrawReasons},
+	weights::constants::RocksDbWeight,
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use xcm::v4::{Error as XcmError, Junction, Result as XcmResult};
+use xcm_executor::AssetsInHolding;
+
+type AccountId = u64;
+type Balance = u128;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		XcmAssetExchanger: pallet_xcm_asset_exchanger::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = RocksDbWeight;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+}
+
+pub struct AccountIdToLocation;
+impl Convert<AccountId, Location> for AccountIdToLocation {
+	fn convert(account: AccountId) -> Location {
+		Location::new(
+			0,
+			[Junction::AccountIndex64 {
+				network: None,
+				index: account,
+			}],
+		)
+	}
+}
+
+fn parent_asset_balance_key(who: AccountId) -> Vec<u8> {
+	(b"____parent_asset_balance", who).encode()
+}
+
+pub fn parent_asset_balance(who: AccountId) -> Balance {
+	storage::unhashed::get_or_default(&parent_asset_balance_key(who))
+}
+
+pub fn set_parent_asset_balance(who: AccountId, amount: Balance) {
+	storage::unhashed::put(&parent_asset_balance_key(who), &amount);
+}
+
+/// Native currency handled by pallet balances, the parent asset by a raw storage balance
+pub struct MockAssetTransactor;
+impl MockAssetTransactor {
+	fn account_of(who: &Location) -> Result<AccountId, XcmError> {
+		match who.interior.iter().next() {
+			Some(Junction::AccountIndex64 { index, .. }) => Ok(*index),
+			_ => Err(XcmError::FailedToTransactAsset("invalid location")),
+		}
+	}
+}
+impl TransactAsset for MockAssetTransactor {
+	fn deposit_asset(asset: &Asset, who: &Location, _context: Option<&XcmContext>) -> XcmResult {
+		let who = Self::account_of(who)?;
+		match (asset.id.clone(), asset.fun.clone()) {
+			(XcmAssetId(location), Fungibility::Fungible(amount)) => {
+				if location == <Test as Config>::NativeLocation::get() {
+					let _ = Balances::deposit_creating(&who, amount);
+					Ok(())
+				} else if location == Location::parent() {
+					set_parent_asset_balance(who, parent_asset_balance(who) + amount);
+					Ok(())
+				} else {
+					Err(XcmError::AssetNotFound)
+				}
+			}
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+	fn withdraw_asset(
+		asset: &Asset,
+		who: &Location,
+		_context: Option<&XcmContext>,
+	) -> Result<AssetsInHolding, XcmError> {
+		let who = Self::account_of(who)?;
+		match (asset.id.clone(), asset.fun.clone()) {
+			(XcmAssetId(location), Fungibility::Fungible(amount)) => {
+				if location == <Test as Config>::NativeLocation::get() {
+					Balances::withdraw(
+						&who,
+						amount,
+						WithdrawReasons::TRANSFER,
+						ExistenceRequirement::AllowDeath,
+					)
+					.map_err(|_| XcmError::FailedToTransactAsset("insufficient balance"))?;
+				} else if location == Location::parent() {
+					let balance = parent_asset_balance(who)
+						.checked_sub(amount)
+						.ok_or(XcmError::FailedToTransactAsset("insufficient balance"))?;
+					set_parent_asset_balance(who, balance);
+				} else {
+					return Err(XcmError::AssetNotFound);
+				}
+				Ok(asset.clone().into())
+			}
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+}
+
+ord_parameter_types! {
+	pub const PoolsAccount: u64 = 1;
+}
+
+parameter_types! {
+	pub NativeLocation: Location = Location::here();
+	pub const LiquidityProvider: AccountId = 100;
+	pub const XcmAssetExchangerPalletId: PalletId = PalletId(*b"xcm/exch");
+	pub SwapFee: Permill = Permill::from_percent(1);
+}
+
+impl Config for Test {
+	type AccountIdToLocation = AccountIdToLocation;
+	type AssetTransactor = MockAssetTransactor;
+	type LiquidityProvider = LiquidityProvider;
+	type NativeLocation = NativeLocation;
+	type PalletId = XcmAssetExchangerPalletId;
+	type PoolsOrigin = EnsureSignedBy<PoolsAccount, AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type SwapFee = SwapFee;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<AccountId> for MockBenchmarkHelper {
+	fn setup_pool_asset(who: &AccountId, amount: u128) -> Location {
+		let _ = Balances::deposit_creating(who, amount);
+		set_parent_asset_balance(*who, amount);
+		Location::parent()
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	sp_tracing::try_init_simple();
+	let mut t = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+
+	let balances = vec![(1, 100), (LiquidityProvider::get(), 1_000_000)];
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		set_parent_asset_balance(LiquidityProvider::get(), 1_000_000);
+	});
+	ext
+}
@@ -0,0 +1,317 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit testing
+use {
+	crate::mock::*,
+	crate::{AllowPaidExecutionAfterExchangeFrom, Error, LiquiditySourceExchanger, PoolReserves},
+	frame_support::{
+		assert_noop, assert_ok,
+		traits::{Everything, ProcessMessageError},
+	},
+	sp_runtime::DispatchError,
+	xcm::v4::{Asset, Assets, Instruction, Location, Weight, WeightLimit, Xcm},
+	xcm_executor::{
+		traits::{AssetExchange, Properties, ShouldExecute},
+		AssetsInHolding,
+	},
+};
+
+type Exchanger = LiquiditySourceExchanger<XcmAssetExchanger>;
+
+fn native_asset(amount: u128) -> Asset {
+	(Location::here(), amount).into()
+}
+
+fn parent_asset(amount: u128) -> Asset {
+	(Location::parent(), amount).into()
+}
+
+fn setup_pool(asset_amount: u128, native_amount: u128) {
+	assert_ok!(XcmAssetExchanger::create_pool(
+		RuntimeOrigin::signed(PoolsAccount::get()),
+		Location::parent(),
+	));
+	assert_ok!(XcmAssetExchanger::add_liquidity(
+		RuntimeOrigin::signed(PoolsAccount::get()),
+		Location::parent(),
+		asset_amount,
+		native_amount,
+	));
+}
+
+#[test]
+fn test_create_pool() {
+	new_test_ext().execute_with(|| {
+		// Call with bad origin
+		assert_noop!(
+			XcmAssetExchanger::create_pool(RuntimeOrigin::signed(2), Location::parent()),
+			DispatchError::BadOrigin
+		);
+
+		// The native currency cannot be paired with itself
+		assert_noop!(
+			XcmAssetExchanger::create_pool(
+				RuntimeOrigin::signed(PoolsAccount::get()),
+				Location::here()
+			),
+			Error::<Test>::InvalidPoolAsset
+		);
+
+		assert_ok!(XcmAssetExchanger::create_pool(
+			RuntimeOrigin::signed(PoolsAccount::get()),
+			Location::parent()
+		));
+		assert_eq!(
+			XcmAssetExchanger::pools(Location::parent()),
+			Some(PoolReserves::default())
+		);
+
+		// Cannot create the same pool twice
+		assert_noop!(
+			XcmAssetExchanger::create_pool(
+				RuntimeOrigin::signed(PoolsAccount::get()),
+				Location::parent()
+			),
+			Error::<Test>::PoolAlreadyExists
+		);
+	})
+}
+
+#[test]
+fn test_add_and_remove_liquidity() {
+	new_test_ext().execute_with(|| {
+		let provider = LiquidityProvider::get();
+		let pool_account = XcmAssetExchanger::pool_account();
+
+		assert_noop!(
+			XcmAssetExchanger::add_liquidity(
+				RuntimeOrigin::signed(PoolsAccount::get()),
+				Location::parent(),
+				1_000,
+				2_000,
+			),
+			Error::<Test>::PoolNotFound
+		);
+
+		setup_pool(1_000, 2_000);
+		assert_eq!(
+			XcmAssetExchanger::pools(Location::parent()),
+			Some(PoolReserves {
+				asset: 1_000,
+				native: 2_000
+			})
+		);
+		assert_eq!(parent_asset_balance(pool_account), 1_000);
+		assert_eq!(Balances::free_balance(pool_account), 2_000);
+		assert_eq!(parent_asset_balance(provider), 999_000);
+		assert_eq!(Balances::free_balance(provider), 998_000);
+
+		// Cannot remove more than the reserves
+		assert_noop!(
+			XcmAssetExchanger::remove_liquidity(
+				RuntimeOrigin::signed(PoolsAccount::get()),
+				Location::parent(),
+				1_001,
+				0,
+			),
+			Error::<Test>::InsufficientLiquidity
+		);
+
+		assert_ok!(XcmAssetExchanger::remove_liquidity(
+			RuntimeOrigin::signed(PoolsAccount::get()),
+			Location::parent(),
+			400,
+			500,
+		));
+		assert_eq!(
+			XcmAssetExchanger::pools(Location::parent()),
+			Some(PoolReserves {
+				asset: 600,
+				native: 1_500
+			})
+		);
+		assert_eq!(parent_asset_balance(provider), 999_400);
+		assert_eq!(Balances::free_balance(provider), 998_500);
+	})
+}
+
+#[test]
+fn test_exchange_exact_in() {
+	new_test_ext().execute_with(|| {
+		setup_pool(10_000, 10_000);
+		let pool_account = XcmAssetExchanger::pool_account();
+
+		// 1% fee: 990 * 10_000 / (10_000 + 990) = 900
+		let received = Exchanger::exchange_asset(
+			None,
+			parent_asset(1_000).into(),
+			&Assets::from(native_asset(800)),
+			true,
+		)
+		.expect("exchange should succeed");
+		assert_eq!(received, AssetsInHolding::from(native_asset(900)));
+		assert_eq!(
+			XcmAssetExchanger::pools(Location::parent()),
+			Some(PoolReserves {
+				asset: 11_000,
+				native: 9_100
+			})
+		);
+		assert_eq!(parent_asset_balance(pool_account), 11_000);
+		assert_eq!(Balances::free_balance(pool_account), 9_100);
+
+		// Not enough output, the holding is given back untouched
+		let give: AssetsInHolding = parent_asset(1_000).into();
+		assert_eq!(
+			Exchanger::exchange_asset(None, give.clone(), &Assets::from(native_asset(1_000)), true),
+			Err(give)
+		);
+		assert_eq!(parent_asset_balance(pool_account), 11_000);
+	})
+}
+
+#[test]
+fn test_exchange_exact_out() {
+	new_test_ext().execute_with(|| {
+		setup_pool(10_000, 10_000);
+
+		// 10_000 * 900 / 9_100 = 989.01 -> 990 after fee, 990 / 0.99 = 1_000
+		let mut expected: AssetsInHolding = native_asset(900).into();
+		expected.subsume(parent_asset(500));
+		assert_eq!(
+			Exchanger::exchange_asset(
+				None,
+				parent_asset(1_500).into(),
+				&Assets::from(native_asset(900)),
+				false,
+			),
+			Ok(expected)
+		);
+		assert_eq!(
+			XcmAssetExchanger::pools(Location::parent()),
+			Some(PoolReserves {
+				asset: 11_000,
+				native: 9_100
+			})
+		);
+
+		// Cannot drain the pool
+		let give: AssetsInHolding = parent_asset(1_000_000).into();
+		assert_eq!(
+			Exchanger::exchange_asset(
+				None,
+				give.clone(),
+				&Assets::from(native_asset(9_100)),
+				false
+			),
+			Err(give)
+		);
+	})
+}
+
+#[test]
+fn test_exchange_unsupported_pairs() {
+	new_test_ext().execute_with(|| {
+		// No pool
+		let give: AssetsInHolding = parent_asset(1_000).into();
+		assert_eq!(
+			Exchanger::exchange_asset(None, give.clone(), &Assets::from(native_asset(1)), true),
+			Err(give)
+		);
+
+		setup_pool(10_000, 10_000);
+
+		// Native for native
+		let give: AssetsInHolding = native_asset(1_000).into();
+		assert_eq!(
+			Exchanger::exchange_asset(None, give.clone(), &Assets::from(native_asset(1)), true),
+			Err(give)
+		);
+
+		// Several assets given
+		let mut give: AssetsInHolding = native_asset(1_000).into();
+		give.subsume(parent_asset(1_000));
+		assert_eq!(
+			Exchanger::exchange_asset(None, give.clone(), &Assets::from(native_asset(1)), true),
+			Err(give)
+		);
+	})
+}
+
+#[test]
+fn test_barrier_allows_paid_execution_after_exchange() {
+	let max_weight = Weight::from_parts(1_000, 1_000);
+	let mut properties = Properties {
+		weight_credit: Weight::zero(),
+		message_id: None,
+	};
+	let mut message = |instructions: Vec<Instruction<()>>| {
+		let mut xcm = Xcm::<()>(instructions);
+		AllowPaidExecutionAfterExchangeFrom::<Everything>::should_execute(
+			&Location::parent(),
+			xcm.inner_mut(),
+			max_weight,
+			&mut properties,
+		)
+	};
+
+	assert_eq!(
+		message(vec![
+			Instruction::WithdrawAsset(parent_asset(1_000).into()),
+			Instruction::ClearOrigin,
+			Instruction::ExchangeAsset {
+				give: Assets::from(parent_asset(1_000)).into(),
+				want: native_asset(100).into(),
+				maximal: true,
+			},
+			Instruction::BuyExecution {
+				fees: native_asset(100),
+				weight_limit: WeightLimit::Unlimited,
+			},
+		]),
+		Ok(())
+	);
+
+	// Not enough weight bought
+	assert_eq!(
+		message(vec![
+			Instruction::WithdrawAsset(parent_asset(1_000).into()),
+			Instruction::ExchangeAsset {
+				give: Assets::from(parent_asset(1_000)).into(),
+				want: native_asset(100).into(),
+				maximal: true,
+			},
+			Instruction::BuyExecution {
+				fees: native_asset(100),
+				weight_limit: WeightLimit::Limited(Weight::from_parts(1, 1)),
+			},
+		]),
+		Err(ProcessMessageError::Overweight(max_weight))
+	);
+
+	// No exchange before paying for execution
+	assert_eq!(
+		message(vec![
+			Instruction::WithdrawAsset(parent_asset(1_000).into()),
+			Instruction::BuyExecution {
+				fees: parent_asset(100),
+				weight_limit: WeightLimit::Unlimited,
+			},
+		]),
+		Err(ProcessMessageError::BadFormat)
+	);
+}
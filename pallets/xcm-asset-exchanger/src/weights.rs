@@ -0,0 +1,74 @@
+// Copyright 2024 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_asset_exchanger
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap() -> Weight;
+}
+
+// Hand-written upper bounds for tests and runtimes without benchmarked weights. The liquidity
+// and swap calls are weighed as one EVM foreign asset call plus one native transfer.
+impl WeightInfo for () {
+	/// Storage: `XcmAssetExchanger::Pools` (r:1 w:1)
+	fn create_pool() -> Weight {
+		Weight::from_parts(11_000_000, 4115)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmAssetExchanger::Pools` (r:1 w:1)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EVM::AccountCodes` (r:1 w:0)
+	/// Storage: `EVM::AccountCodesMetadata` (r:1 w:0)
+	/// Storage: `EVM::AccountStorages` (r:2 w:2)
+	fn add_liquidity() -> Weight {
+		Weight::from_parts(160_000_000, 7782)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `XcmAssetExchanger::Pools` (r:1 w:1)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EVM::AccountCodes` (r:1 w:0)
+	/// Storage: `EVM::AccountCodesMetadata` (r:1 w:0)
+	/// Storage: `EVM::AccountStorages` (r:2 w:2)
+	fn remove_liquidity() -> Weight {
+		Weight::from_parts(160_000_000, 7886)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `XcmAssetExchanger::Pools` (r:1 w:1)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EVM::AccountCodes` (r:1 w:0)
+	/// Storage: `EVM::AccountCodesMetadata` (r:1 w:0)
+	/// Storage: `EVM::AccountStorages` (r:2 w:2)
+	fn swap() -> Weight {
+		Weight::from_parts(160_000_000, 7886)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -16,8 +16,12 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Call, Config, Currency, CurrencyPayment, HrmpOperation, Pallet, TransactWeights};
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use crate::{
+	Call, Config, Currency, CurrencyPayment, HrmpOperation, Pallet, TransactStatus,
+	TransactStatusInfo, TransactWeights,
+};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
 use frame_support::weights::Weight;
 use frame_system::RawOrigin;
 use sp_std::boxed::Box;
@@ -279,6 +283,53 @@ benchmarks! {
 			overall_weight: None
 		}
 	)
+
+	set_hrmp_auto_accept {
+		let para_id = 1000u32.into();
+	}: _(RawOrigin::Root, para_id, true)
+	verify {
+		assert!(crate::HrmpAutoAccept::<T>::contains_key(para_id));
+	}
+
+	transact_status_response {
+		let origin = T::ResponseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let responder = T::ResponseOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let query_id = 0u64;
+		crate::TransactStatusQueries::<T>::insert(
+			query_id,
+			TransactStatusInfo {
+				owner: None,
+				dest: responder,
+				status: TransactStatus::Pending,
+				timeout: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+	}: _<T::RuntimeOrigin>(origin, query_id, Response::DispatchResult(MaybeErrorCode::Success))
+	verify {
+		assert_eq!(
+			crate::TransactStatusQueries::<T>::get(query_id).map(|info| info.status),
+			Some(TransactStatus::Succeeded)
+		);
+	}
+
+	prune_transact_status {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		let query_id = 0u64;
+		crate::TransactStatusQueries::<T>::insert(
+			query_id,
+			TransactStatusInfo {
+				owner: Some(user.clone()),
+				dest: Location::parent(),
+				status: TransactStatus::Succeeded,
+				timeout: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+	}: _(RawOrigin::Signed(user), query_id)
+	verify {
+		assert!(!crate::TransactStatusQueries::<T>::contains_key(query_id));
+	}
 }
 
 #[cfg(test)]
@@ -64,6 +64,11 @@
 //! 	(and DescendOrigin + WithdrawAsset + BuyExecution + Transact messages allowed) in the
 //! 	destination chain. Additionally, a ML-based derivation mechanism needs to be implemented
 //! 	in the destination chain.
+//!
+//! The pallet also manages the HRMP channels of this chain. Besides the manual operations issued
+//! through hrmp_manage, it implements the executor handlers for the HRMP notifications sent by the
+//! relay chain: open requests coming from siblings in the HrmpAutoAccept allow-list are
+//! automatically accepted and reciprocated, and the status of the channels is tracked in storage.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -87,6 +92,10 @@ pub use crate::weights::WeightInfo;
 
 type CurrencyIdOf<T> = <T as Config>::CurrencyId;
 
+/// Margin added to the fee paying for a refund to the origin, so that the refund still goes
+/// through if the fee rate of this chain increases while it is in flight
+const REFUND_TO_ORIGIN_FEE_MARGIN: sp_runtime::Percent = sp_runtime::Percent::from_percent(50);
+
 #[pallet]
 pub mod pallet {
 
@@ -97,20 +106,25 @@ pub mod pallet {
 	use cumulus_primitives_core::{relay_chain::HrmpChannelId, ParaId};
 	use frame_support::traits::EitherOfDiverse;
 	use frame_support::{
-		dispatch::DispatchResult, pallet_prelude::*, weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+		dispatch::{DispatchResult, GetDispatchInfo},
+		pallet_prelude::*,
+		weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
-	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, Convert};
+	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, Convert, Saturating};
 	use sp_std::boxed::Box;
 	use sp_std::convert::TryFrom;
 	use sp_std::prelude::*;
 	use sp_std::vec;
 	use sp_std::vec::Vec;
 	use xcm::{latest::prelude::*, VersionedLocation};
-	use xcm_executor::traits::{TransactAsset, WeightBounds};
+	use xcm_executor::traits::{
+		HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+		TransactAsset, WeightBounds,
+	};
 	use xcm_primitives::{
-		FilterMaxAssetFee, HrmpAvailableCalls, HrmpEncodeCall, Reserve, UtilityAvailableCalls,
-		UtilityEncodeCall, XcmTransact,
+		FilterMaxAssetFee, HrmpAvailableCalls, HrmpEncodeCall, NotifyQueryHandler, Reserve,
+		UtilityAvailableCalls, UtilityEncodeCall, WeightToAssetFee, XcmTransact,
 	};
 
 	#[pallet::pallet]
@@ -185,6 +199,26 @@ pub mod pallet {
 		/// The way to filter the max fee to use for HRMP management operations
 		type MaxHrmpFee: FilterMaxAssetFee;
 
+		/// The weight of the HRMP calls automatically issued in the relay chain when handling
+		/// the open requests of the siblings in the HrmpAutoAccept allow-list
+		type HrmpAutomationTransactWeight: Get<Weight>;
+
+		/// Computes the fee charged by this chain, in the fee asset, to execute the message
+		/// refunding the surplus of the transact fees back to the caller
+		type WeightToAssetFee: WeightToAssetFee;
+
+		/// Registers the queries tracking the status of the transacts requesting to be reported
+		type NotifyQueryHandler: NotifyQueryHandler<Call<Self>, BlockNumberFor<Self>>;
+
+		/// The origin of the responses to the transact status queries
+		type ResponseOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = Location,
+		>;
+
+		/// The number of blocks after which a transact status query expires
+		type TransactStatusQueryTimeout: Get<BlockNumberFor<Self>>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -253,6 +287,28 @@ pub mod pallet {
 		},
 	}
 
+	/// Status of an HRMP channel involving this chain
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		scale_info::TypeInfo,
+	)]
+	pub enum HrmpChannelStatus {
+		/// A sibling requested to open the channel, the request is pending acceptance
+		Requested,
+		/// The recipient accepted the channel, it opens at the next relay session. The relay does
+		/// not notify the opening itself, so this is the last status recorded for a channel.
+		Accepted,
+		/// We requested to open the channel, the request is pending acceptance
+		OpenRequested,
+	}
+
 	#[derive(
 		Default,
 		Clone,
@@ -296,6 +352,64 @@ pub mod pallet {
 		pub overall_weight: Option<WeightLimit>,
 	}
 
+	/// Defines where the surplus of the fees of a transact is refunded
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum TransactRefund {
+		/// The surplus is deposited in the account paying the fees in the destination chain
+		InDestination,
+		/// The surplus is reserve transferred back to the caller account in this chain. The
+		/// fee asset pays for the execution of the refund in this chain.
+		ToOrigin,
+	}
+
+	/// The status of a transact reported back by its destination chain
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		scale_info::TypeInfo,
+	)]
+	pub enum TransactStatus {
+		/// The destination chain has not reported the result of the transact yet
+		Pending,
+		/// The call was successfully dispatched in the destination chain
+		Succeeded,
+		/// The message or the dispatch of the call failed in the destination chain
+		Failed,
+		/// The destination chain did not report the result of the transact before the timeout
+		TimedOut,
+	}
+
+	/// Stores the status of a transact and the account that requested it
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo,
+	)]
+	pub struct TransactStatusInfo<AccountId, BlockNumber> {
+		/// The account requesting the transact, if any
+		pub owner: Option<AccountId>,
+		/// The chain where the transact is executed
+		pub dest: Location,
+		pub status: TransactStatus,
+		/// The block after which a pending transact is considered timed out, and its status
+		/// can be pruned by anyone
+		pub timeout: BlockNumber,
+	}
+
+	impl<AccountId, BlockNumber: PartialOrd> TransactStatusInfo<AccountId, BlockNumber> {
+		/// The status of the transact at block `now`, accounting for the timeout
+		pub fn status_at(&self, now: BlockNumber) -> TransactStatus {
+			match self.status {
+				TransactStatus::Pending if now > self.timeout => TransactStatus::TimedOut,
+				status => status,
+			}
+		}
+	}
+
 	/// The amount of ref_time and proof_size to use for fee calculation if
 	/// we are dealing with an Unlimited variant inside 'overall_weight' field
 	/// of 'TransactWeights' struct.
@@ -327,6 +441,26 @@ pub mod pallet {
 	#[pallet::getter(fn relay_indices)]
 	pub type RelayIndices<T: Config> = StorageValue<_, RelayChainIndices, ValueQuery>;
 
+	/// Siblings whose HRMP channel open requests are automatically accepted and reciprocated
+	#[pallet::storage]
+	pub type HrmpAutoAccept<T: Config> = StorageMap<_, Twox64Concat, ParaId, (), OptionQuery>;
+
+	/// Stores the status of the HRMP channels involving this chain
+	#[pallet::storage]
+	pub type HrmpChannels<T: Config> =
+		StorageMap<_, Blake2_128Concat, HrmpChannelId, HrmpChannelStatus>;
+
+	/// Stores the status of the transacts whose result is reported back, by query id
+	#[pallet::storage]
+	#[pallet::getter(fn transact_status)]
+	pub type TransactStatusQueries<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, TransactStatusInfo<T::AccountId, BlockNumberFor<T>>>;
+
+	/// The query id of the last transact whose result is reported back. Read by the precompiles
+	/// to return it to the caller
+	#[pallet::storage]
+	pub type LastTransactStatusQuery<T: Config> = StorageValue<_, QueryId, OptionQuery>;
+
 	/// An error that can occur while executing the mapping pallet's logic.
 	#[pallet::error]
 	pub enum Error<T> {
@@ -357,6 +491,11 @@ pub mod pallet {
 		TooMuchFeeUsed,
 		ErrorValidating,
 		RefundNotSupportedWithTransactInfo,
+		UnableToComputeRefundFee,
+		ReportNotSupportedWithTransactInfo,
+		UnknownTransactStatusQuery,
+		UnexpectedResponder,
+		TransactStatusNotPrunable,
 	}
 
 	#[pallet::event]
@@ -415,6 +554,40 @@ pub mod pallet {
 		HrmpManagementSent {
 			action: HrmpOperation,
 		},
+		/// Changed whether the HRMP channel open requests of a sibling are automatically accepted
+		HrmpAutoAcceptSet {
+			para_id: ParaId,
+			auto_accept: bool,
+		},
+		/// The status of an HRMP channel changed
+		HrmpChannelStatusChanged {
+			channel_id: HrmpChannelId,
+			status: HrmpChannelStatus,
+		},
+		/// An HRMP channel was closed or its open request cancelled
+		HrmpChannelRemoved {
+			channel_id: HrmpChannelId,
+		},
+		/// An automated HRMP operation could not be sent
+		HrmpAutomationFailed {
+			action: HrmpOperation,
+			error: DispatchError,
+		},
+		/// The result of a transact will be reported back under the given query id
+		TransactStatusQueryRegistered {
+			query_id: QueryId,
+			owner: Option<T::AccountId>,
+			dest: Location,
+		},
+		/// The destination chain reported the result of a transact
+		TransactStatusReported {
+			query_id: QueryId,
+			status: TransactStatus,
+		},
+		/// The status of a transact was removed
+		TransactStatusPruned {
+			query_id: QueryId,
+		},
 	}
 
 	#[pallet::genesis_config]
@@ -484,37 +657,459 @@ pub mod pallet {
 			// Deposit event
 			Self::deposit_event(Event::<T>::DeRegisteredDerivative { index });
 
-			Ok(())
+			Ok(())
+		}
+
+		/// Transact the inner call through a derivative account in a destination chain,
+		/// using 'fee_location' to pay for the fees. This fee_location is given as a multilocation
+		///
+		/// The caller needs to have the index registered in this pallet. The fee multiasset needs
+		/// to be a reserve asset for the destination transactor::multilocation.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			Pallet::<T>::weight_of_initiate_reserve_withdraw()
+			.saturating_add(T::WeightInfo::transact_through_derivative())
+		)]
+		pub fn transact_through_derivative(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: T::Transactor,
+			// derivative index to be used
+			index: u16,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// inner call to be executed in destination. This wiol
+			// be wrapped into utility.as_derivative
+			inner_call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_derivative(
+				who,
+				dest,
+				index,
+				fee,
+				inner_call,
+				weight_info,
+				refund.then_some(TransactRefund::InDestination),
+			)
+		}
+
+		/// Transact the call through the sovereign account in a destination chain,
+		/// 'fee_payer' pays for the fee
+		///
+		/// SovereignAccountDispatcherOrigin callable only
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			Pallet::<T>::weight_of_initiate_reserve_withdraw()
+			.saturating_add(T::WeightInfo::transact_through_sovereign())
+		)]
+		pub fn transact_through_sovereign(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedLocation>,
+			// account paying for fees
+			fee_payer: Option<T::AccountId>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// origin kind to be used
+			origin_kind: OriginKind,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			T::SovereignAccountDispatcherOrigin::ensure_origin(origin)?;
+
+			Self::do_transact_through_sovereign(
+				dest,
+				fee_payer,
+				fee,
+				call,
+				origin_kind,
+				weight_info,
+				refund,
+				false,
+			)
+		}
+
+		/// Change the transact info of a location
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_transact_info())]
+		pub fn set_transact_info(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+			transact_extra_weight: Weight,
+			max_weight: Weight,
+			transact_extra_weight_signed: Option<Weight>,
+		) -> DispatchResult {
+			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
+			let location = Location::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+			let remote_info = RemoteTransactInfoWithMaxWeight {
+				transact_extra_weight,
+				max_weight,
+				transact_extra_weight_signed,
+			};
+
+			TransactInfoWithWeightLimit::<T>::insert(&location, &remote_info);
+
+			Self::deposit_event(Event::TransactInfoChanged {
+				location,
+				remote_info,
+			});
+			Ok(())
+		}
+
+		/// Remove the transact info of a location
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::remove_transact_info())]
+		pub fn remove_transact_info(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+		) -> DispatchResult {
+			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
+			let location = Location::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			// Remove transact info
+			TransactInfoWithWeightLimit::<T>::remove(&location);
+
+			Self::deposit_event(Event::TransactInfoRemoved { location });
+			Ok(())
+		}
+
+		/// Transact the call through the a signed origin in this chain
+		/// that should be converted to a transaction dispatch account in the destination chain
+		/// by any method implemented in the destination chains runtime
+		///
+		/// This time we are giving the currency as a currencyId instead of multilocation
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transact_through_signed())]
+		pub fn transact_through_signed(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedLocation>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_signed(
+				who,
+				dest,
+				fee,
+				call,
+				weight_info,
+				refund.then_some(TransactRefund::InDestination),
+				false,
+			)
+		}
+
+		/// Set the fee per second of an asset on its reserve chain
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_fee_per_second())]
+		pub fn set_fee_per_second(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedLocation>,
+			fee_per_second: u128,
+		) -> DispatchResult {
+			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
+			let asset_location =
+				Location::try_from(*asset_location).map_err(|()| Error::<T>::BadVersion)?;
+
+			DestinationAssetFeePerSecond::<T>::insert(&asset_location, &fee_per_second);
+
+			Self::deposit_event(Event::DestFeePerSecondChanged {
+				location: asset_location,
+				fee_per_second,
+			});
+			Ok(())
+		}
+
+		/// Remove the fee per second of an asset on its reserve chain
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_fee_per_second())]
+		pub fn remove_fee_per_second(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedLocation>,
+		) -> DispatchResult {
+			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
+			let asset_location =
+				Location::try_from(*asset_location).map_err(|()| Error::<T>::BadVersion)?;
+
+			DestinationAssetFeePerSecond::<T>::remove(&asset_location);
+
+			Self::deposit_event(Event::DestFeePerSecondRemoved {
+				location: asset_location,
+			});
+			Ok(())
+		}
+
+		/// Manage HRMP operations
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::hrmp_manage())]
+		pub fn hrmp_manage(
+			origin: OriginFor<T>,
+			action: HrmpOperation,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// weight information to be used
+			weight_info: TransactWeights,
+		) -> DispatchResult {
+			// WithdrawAsset
+			// BuyExecution
+			// SetAppendix(RefundSurplus, DepositAsset(sov account))
+			// Transact
+
+			// check permissions
+			match &action {
+				HrmpOperation::InitOpen(_) | HrmpOperation::Accept { .. } => {
+					<EitherOfDiverse<T::HrmpManipulatorOrigin, T::HrmpOpenOrigin>>::ensure_origin(
+						origin,
+					)?;
+				}
+				HrmpOperation::Close(_) | HrmpOperation::Cancel { .. } => {
+					T::HrmpManipulatorOrigin::ensure_origin(origin)?;
+				}
+			}
+
+			Self::do_hrmp_manage(action.clone(), fee, weight_info)?;
+
+			Self::deposit_event(Event::HrmpManagementSent { action });
+
+			Ok(())
+		}
+
+		/// Set whether the HRMP channel open requests of a sibling are automatically accepted
+		/// and reciprocated
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_hrmp_auto_accept())]
+		pub fn set_hrmp_auto_accept(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+			auto_accept: bool,
+		) -> DispatchResult {
+			T::HrmpManipulatorOrigin::ensure_origin(origin)?;
+
+			if auto_accept {
+				HrmpAutoAccept::<T>::insert(para_id, ());
+			} else {
+				HrmpAutoAccept::<T>::remove(para_id);
+			}
+
+			Self::deposit_event(Event::HrmpAutoAcceptSet {
+				para_id,
+				auto_accept,
+			});
+			Ok(())
+		}
+
+		/// Same as transact_through_derivative, but the surplus of the fees is always refunded
+		/// as specified by 'refund'
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			Pallet::<T>::weight_of_initiate_reserve_withdraw()
+			.saturating_add(T::WeightInfo::transact_through_derivative())
+		)]
+		pub fn transact_through_derivative_with_refund(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: T::Transactor,
+			// derivative index to be used
+			index: u16,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// inner call to be executed in destination. This will
+			// be wrapped into utility.as_derivative
+			inner_call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// where the surplus of the fees is refunded
+			refund: TransactRefund,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_derivative(
+				who,
+				dest,
+				index,
+				fee,
+				inner_call,
+				weight_info,
+				Some(refund),
+			)
+		}
+
+		/// Same as transact_through_signed, but the surplus of the fees is always refunded
+		/// as specified by 'refund'
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::transact_through_signed())]
+		pub fn transact_through_signed_with_refund(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedLocation>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// where the surplus of the fees is refunded
+			refund: TransactRefund,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_signed(who, dest, fee, call, weight_info, Some(refund), false)
+		}
+
+		/// Same as transact_through_sovereign, but the destination chain reports the result
+		/// of the transact back under a query id tracked in TransactStatusQueries
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::transact_through_sovereign()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 4))
+		)]
+		pub fn transact_through_sovereign_with_report(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedLocation>,
+			// account paying for fees
+			fee_payer: Option<T::AccountId>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// origin kind to be used
+			origin_kind: OriginKind,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			T::SovereignAccountDispatcherOrigin::ensure_origin(origin)?;
+
+			Self::do_transact_through_sovereign(
+				dest,
+				fee_payer,
+				fee,
+				call,
+				origin_kind,
+				weight_info,
+				refund,
+				true,
+			)
+		}
+
+		/// Same as transact_through_signed, but the destination chain reports the result
+		/// of the transact back under a query id tracked in TransactStatusQueries
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::transact_through_signed()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 4))
+		)]
+		pub fn transact_through_signed_with_report(
+			origin: OriginFor<T>,
+			// destination to which the message should be sent
+			dest: Box<VersionedLocation>,
+			// fee to be used
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			// call to be executed in destination
+			call: Vec<u8>,
+			// weight information to be used
+			weight_info: TransactWeights,
+			// add RefundSurplus and DepositAsset appendix
+			refund: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_signed(
+				who,
+				dest,
+				fee,
+				call,
+				weight_info,
+				refund.then_some(TransactRefund::InDestination),
+				true,
+			)
+		}
+
+		/// Record the result of a transact reported by its destination chain. Only callable
+		/// as the notification of a transact status query
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::transact_status_response())]
+		pub fn transact_status_response(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::ResponseOrigin::ensure_origin(origin)?;
+
+			let status = match response {
+				Response::DispatchResult(MaybeErrorCode::Success) => TransactStatus::Succeeded,
+				_ => TransactStatus::Failed,
+			};
+
+			TransactStatusQueries::<T>::try_mutate(query_id, |maybe_info| -> DispatchResult {
+				let info = maybe_info
+					.as_mut()
+					.ok_or(Error::<T>::UnknownTransactStatusQuery)?;
+				ensure!(info.dest == responder, Error::<T>::UnexpectedResponder);
+				info.status = status;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TransactStatusReported { query_id, status });
+			Ok(())
+		}
+
+		/// Remove the status of a transact. The owner can remove it once it is reported, and
+		/// anyone once its query timed out. Free of fees if the status is removed
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::prune_transact_status())]
+		pub fn prune_transact_status(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let info = TransactStatusQueries::<T>::get(query_id)
+				.ok_or(Error::<T>::UnknownTransactStatusQuery)?;
+			let timed_out = frame_system::Pallet::<T>::block_number() > info.timeout;
+			let reported_to_owner =
+				info.status != TransactStatus::Pending && info.owner.as_ref() == Some(&who);
+			ensure!(
+				timed_out || reported_to_owner,
+				Error::<T>::TransactStatusNotPrunable
+			);
+
+			TransactStatusQueries::<T>::remove(query_id);
+
+			Self::deposit_event(Event::<T>::TransactStatusPruned { query_id });
+			Ok(Pays::No.into())
 		}
+	}
 
-		/// Transact the inner call through a derivative account in a destination chain,
-		/// using 'fee_location' to pay for the fees. This fee_location is given as a multilocation
-		///
-		/// The caller needs to have the index registered in this pallet. The fee multiasset needs
-		/// to be a reserve asset for the destination transactor::multilocation.
-		#[pallet::call_index(2)]
-		#[pallet::weight(
-			Pallet::<T>::weight_of_initiate_reserve_withdraw()
-			.saturating_add(T::WeightInfo::transact_through_derivative())
-		)]
-		pub fn transact_through_derivative(
-			origin: OriginFor<T>,
-			// destination to which the message should be sent
+	impl<T: Config> Pallet<T> {
+		fn do_transact_through_derivative(
+			who: T::AccountId,
 			dest: T::Transactor,
-			// derivative index to be used
 			index: u16,
-			// fee to be used
 			fee: CurrencyPayment<CurrencyIdOf<T>>,
-			// inner call to be executed in destination. This wiol
-			// be wrapped into utility.as_derivative
 			inner_call: Vec<u8>,
-			// weight information to be used
 			weight_info: TransactWeights,
-			// add RefundSurplus and DepositAsset appendix
-			refund: bool,
+			refund: Option<TransactRefund>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
 			let fee_location = Self::currency_to_multilocation(fee.currency)
 				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
 
@@ -539,7 +1134,7 @@ pub mod pallet {
 					let weight_info = Self::take_weight_from_transact_info(
 						dest.clone(),
 						weight_info.transact_required_weight_at_most,
-						refund,
+						refund.is_some(),
 					)?;
 					Ok(WeightLimit::from(Some(weight_info)))
 				},
@@ -553,21 +1148,15 @@ pub mod pallet {
 
 			// Calculate fee based on FeePerSecond
 			let fee = Self::calculate_fee(
-				fee_location,
+				fee_location.clone(),
 				fee.fee_amount,
 				dest.clone(),
 				total_weight_fee_calculation,
 			)?;
 
-			// If refund is true, the appendix instruction will be a deposit back to the sovereign
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(T::SelfLocation::get(), &dest, 1u32)?,
-					])
-				})
-				.transpose()?;
+			// If refunding, the surplus is deposited back to the sovereign or sent to the caller
+			let appendix =
+				Self::refund_appendix(refund, &fee_location, T::SelfLocation::get(), &who, &dest)?;
 
 			Self::transact_in_dest_chain_asset_non_signed(
 				dest.clone(),
@@ -578,6 +1167,7 @@ pub mod pallet {
 				total_weight,
 				weight_info.transact_required_weight_at_most,
 				appendix,
+				None,
 			)?;
 
 			// Deposit event
@@ -591,39 +1181,27 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Transact the call through the sovereign account in a destination chain,
-		/// 'fee_payer' pays for the fee
-		///
-		/// SovereignAccountDispatcherOrigin callable only
-		#[pallet::call_index(3)]
-		#[pallet::weight(
-			Pallet::<T>::weight_of_initiate_reserve_withdraw()
-			.saturating_add(T::WeightInfo::transact_through_sovereign())
-		)]
-		pub fn transact_through_sovereign(
-			origin: OriginFor<T>,
-			// destination to which the message should be sent
+		fn do_transact_through_sovereign(
 			dest: Box<VersionedLocation>,
-			// account paying for fees
 			fee_payer: Option<T::AccountId>,
-			// fee to be used
 			fee: CurrencyPayment<CurrencyIdOf<T>>,
-			// call to be executed in destination
 			call: Vec<u8>,
-			// origin kind to be used
 			origin_kind: OriginKind,
-			// weight information to be used
 			weight_info: TransactWeights,
-			// add RefundSurplus and DepositAsset appendix
 			refund: bool,
+			report: bool,
 		) -> DispatchResult {
-			T::SovereignAccountDispatcherOrigin::ensure_origin(origin)?;
-
 			let fee_location = Self::currency_to_multilocation(fee.currency)
 				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
 
 			let dest = Location::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
 
+			// The reporting instructions are not accounted in the transact info
+			ensure!(
+				!report || weight_info.overall_weight.is_some(),
+				Error::<T>::ReportNotSupportedWithTransactInfo
+			);
+
 			// Calculate the total weight that the xcm message is going to spend in the
 			// destination chain
 			let total_weight = weight_info.overall_weight.map_or_else(
@@ -661,6 +1239,11 @@ pub mod pallet {
 				})
 				.transpose()?;
 
+			// The sovereign account is the querier in the destination chain
+			let report = report
+				.then(|| Self::new_transact_status_query(fee_payer.clone(), &dest, Here.into()))
+				.transpose()?;
+
 			// Grab the destination
 			Self::transact_in_dest_chain_asset_non_signed(
 				dest.clone(),
@@ -671,6 +1254,7 @@ pub mod pallet {
 				total_weight,
 				weight_info.transact_required_weight_at_most,
 				appendix,
+				report,
 			)?;
 
 			// Deposit event
@@ -683,77 +1267,26 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Change the transact info of a location
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::set_transact_info())]
-		pub fn set_transact_info(
-			origin: OriginFor<T>,
-			location: Box<VersionedLocation>,
-			transact_extra_weight: Weight,
-			max_weight: Weight,
-			transact_extra_weight_signed: Option<Weight>,
-		) -> DispatchResult {
-			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
-			let location = Location::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
-			let remote_info = RemoteTransactInfoWithMaxWeight {
-				transact_extra_weight,
-				max_weight,
-				transact_extra_weight_signed,
-			};
-
-			TransactInfoWithWeightLimit::<T>::insert(&location, &remote_info);
-
-			Self::deposit_event(Event::TransactInfoChanged {
-				location,
-				remote_info,
-			});
-			Ok(())
-		}
-
-		/// Remove the transact info of a location
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::remove_transact_info())]
-		pub fn remove_transact_info(
-			origin: OriginFor<T>,
-			location: Box<VersionedLocation>,
-		) -> DispatchResult {
-			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
-			let location = Location::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
-
-			// Remove transact info
-			TransactInfoWithWeightLimit::<T>::remove(&location);
-
-			Self::deposit_event(Event::TransactInfoRemoved { location });
-			Ok(())
-		}
-
-		/// Transact the call through the a signed origin in this chain
-		/// that should be converted to a transaction dispatch account in the destination chain
-		/// by any method implemented in the destination chains runtime
-		///
-		/// This time we are giving the currency as a currencyId instead of multilocation
-		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::transact_through_signed())]
-		pub fn transact_through_signed(
-			origin: OriginFor<T>,
-			// destination to which the message should be sent
+		fn do_transact_through_signed(
+			who: T::AccountId,
 			dest: Box<VersionedLocation>,
-			// fee to be used
 			fee: CurrencyPayment<CurrencyIdOf<T>>,
-			// call to be executed in destination
 			call: Vec<u8>,
-			// weight information to be used
 			weight_info: TransactWeights,
-			// add RefundSurplus and DepositAsset appendix
-			refund: bool,
+			refund: Option<TransactRefund>,
+			report: bool,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
 			let dest = Location::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
 
 			let fee_location = Self::currency_to_multilocation(fee.currency)
 				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
 
+			// The reporting instructions are not accounted in the transact info
+			ensure!(
+				!report || weight_info.overall_weight.is_some(),
+				Error::<T>::ReportNotSupportedWithTransactInfo
+			);
+
 			// Calculate the total weight that the xcm message is going to spend in the
 			// destination chain
 			let total_weight = weight_info.overall_weight.map_or_else(
@@ -761,7 +1294,7 @@ pub mod pallet {
 					let weight_info = Self::take_weight_from_transact_info_signed(
 						dest.clone(),
 						weight_info.transact_required_weight_at_most,
-						refund,
+						refund.is_some(),
 					)?;
 					Ok(WeightLimit::from(Some(weight_info)))
 				},
@@ -775,20 +1308,29 @@ pub mod pallet {
 
 			// Fee to be paid
 			let fee = Self::calculate_fee(
-				fee_location,
+				fee_location.clone(),
 				fee.fee_amount,
 				dest.clone(),
 				total_weight_fee_calculation,
 			)?;
 
-			// If refund is true, the appendix instruction will be a deposit back to the sender
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					let sender = T::AccountIdToLocation::convert(who.clone());
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(sender, &dest, 1u32)?,
-					])
+			// If refunding, the surplus is deposited back to the sender or sent to the caller
+			let appendix = Self::refund_appendix(
+				refund,
+				&fee_location,
+				T::AccountIdToLocation::convert(who.clone()),
+				&who,
+				&dest,
+			)?;
+
+			// The descended origin of the caller is the querier in the destination chain
+			let report = report
+				.then(|| {
+					Self::new_transact_status_query(
+						Some(who.clone()),
+						&dest,
+						T::AccountIdToLocation::convert(who.clone()),
+					)
 				})
 				.transpose()?;
 
@@ -802,6 +1344,7 @@ pub mod pallet {
 				total_weight,
 				weight_info.transact_required_weight_at_most,
 				appendix,
+				report,
 			)?;
 
 			// Deposit event
@@ -814,74 +1357,137 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Set the fee per second of an asset on its reserve chain
-		#[pallet::call_index(7)]
-		#[pallet::weight(T::WeightInfo::set_fee_per_second())]
-		pub fn set_fee_per_second(
-			origin: OriginFor<T>,
-			asset_location: Box<VersionedLocation>,
-			fee_per_second: u128,
-		) -> DispatchResult {
-			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
-			let asset_location =
-				Location::try_from(*asset_location).map_err(|()| Error::<T>::BadVersion)?;
+		/// Construct the appendix refunding the surplus of the fees. When refunding in the
+		/// destination, the surplus is deposited to `depositor`. When refunding to the origin,
+		/// it is reserve transferred back to `caller` in this chain.
+		fn refund_appendix(
+			refund: Option<TransactRefund>,
+			fee_location: &Location,
+			depositor: Location,
+			caller: &T::AccountId,
+			dest: &Location,
+		) -> Result<Option<Vec<Instruction<()>>>, DispatchError> {
+			match refund {
+				None => Ok(None),
+				Some(TransactRefund::InDestination) => Ok(Some(vec![
+					RefundSurplus,
+					Self::deposit_instruction(depositor, dest, 1u32)?,
+				])),
+				Some(TransactRefund::ToOrigin) => {
+					let beneficiary = T::AccountIdToLocation::convert(caller.clone());
+					Ok(Some(vec![
+						RefundSurplus,
+						Self::refund_to_origin_instruction(fee_location, beneficiary, dest)?,
+					]))
+				}
+			}
+		}
 
-			DestinationAssetFeePerSecond::<T>::insert(&asset_location, &fee_per_second);
+		/// Construct the instruction reserve transferring the holding of the destination back
+		/// to `beneficiary` in this chain. The execution in this chain is paid with the fee asset,
+		/// which must be reserved in the destination for the transfer back to be accepted here.
+		fn refund_to_origin_instruction(
+			fee_location: &Location,
+			beneficiary: Location,
+			dest: &Location,
+		) -> Result<Instruction<()>, DispatchError> {
+			Self::transfer_allowed(&(fee_location.clone(), 1u128).into(), dest)?;
+
+			// The message executed in this chain once the surplus is deposited
+			let local_message: Xcm<()> = Xcm(vec![
+				ReserveAssetDeposited((fee_location.clone(), 1u128).into()),
+				ClearOrigin,
+				BuyExecution {
+					fees: (fee_location.clone(), 1u128).into(),
+					weight_limit: Unlimited,
+				},
+				DepositAsset {
+					assets: Wild(AllCounted(1u32)),
+					beneficiary: beneficiary.clone(),
+				},
+			]);
+			let local_weight = T::Weigher::weight(&mut local_message.into())
+				.map_err(|()| Error::<T>::UnweighableMessage)?;
+			let local_fee = T::WeightToAssetFee::weight_to_asset_fee(local_weight, fee_location)
+				.ok_or(Error::<T>::UnableToComputeRefundFee)?;
+			let local_fee =
+				local_fee.saturating_add(REFUND_TO_ORIGIN_FEE_MARGIN.mul_ceil(local_fee));
+
+			let mut self_location = T::SelfLocation::get();
+			self_location
+				.reanchor(dest, &T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::CannotReanchor)?;
 
-			Self::deposit_event(Event::DestFeePerSecondChanged {
-				location: asset_location,
-				fee_per_second,
-			});
-			Ok(())
+			Ok(DepositReserveAsset {
+				assets: Wild(AllCounted(1u32)),
+				dest: self_location,
+				xcm: Xcm(vec![
+					BuyExecution {
+						fees: (fee_location.clone(), local_fee).into(),
+						weight_limit: Limited(local_weight),
+					},
+					DepositAsset {
+						assets: Wild(AllCounted(1u32)),
+						beneficiary,
+					},
+				]),
+			})
 		}
 
-		/// Remove the fee per second of an asset on its reserve chain
-		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::set_fee_per_second())]
-		pub fn remove_fee_per_second(
-			origin: OriginFor<T>,
-			asset_location: Box<VersionedLocation>,
-		) -> DispatchResult {
-			T::DerivativeAddressRegistrationOrigin::ensure_origin(origin)?;
-			let asset_location =
-				Location::try_from(*asset_location).map_err(|()| Error::<T>::BadVersion)?;
-
-			DestinationAssetFeePerSecond::<T>::remove(&asset_location);
+		/// Register a query tracking the status of a transact sent to `dest` on behalf of
+		/// `owner`, returning the information `dest` needs to report it back
+		fn new_transact_status_query(
+			owner: Option<T::AccountId>,
+			dest: &Location,
+			match_querier: Location,
+		) -> Result<QueryResponseInfo, DispatchError> {
+			let destination = T::UniversalLocation::get()
+				.invert_target(dest)
+				.map_err(|()| Error::<T>::DestinationNotInvertible)?;
+
+			// The query id and the response are filled in when notifying
+			let notify = Call::<T>::transact_status_response {
+				query_id: 0,
+				response: Response::Null,
+			};
+			let max_weight = notify.get_dispatch_info().weight;
+			let timeout = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::TransactStatusQueryTimeout::get());
+			let query_id = T::NotifyQueryHandler::new_notify_query(
+				dest.clone(),
+				notify,
+				timeout,
+				match_querier,
+			);
 
-			Self::deposit_event(Event::DestFeePerSecondRemoved {
-				location: asset_location,
+			TransactStatusQueries::<T>::insert(
+				query_id,
+				TransactStatusInfo {
+					owner: owner.clone(),
+					dest: dest.clone(),
+					status: TransactStatus::Pending,
+					timeout,
+				},
+			);
+			LastTransactStatusQuery::<T>::put(query_id);
+			Self::deposit_event(Event::<T>::TransactStatusQueryRegistered {
+				query_id,
+				owner,
+				dest: dest.clone(),
 			});
-			Ok(())
+
+			Ok(QueryResponseInfo {
+				destination,
+				query_id,
+				max_weight,
+			})
 		}
 
-		/// Manage HRMP operations
-		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::hrmp_manage())]
-		pub fn hrmp_manage(
-			origin: OriginFor<T>,
+		fn do_hrmp_manage(
 			action: HrmpOperation,
-			// fee to be used
 			fee: CurrencyPayment<CurrencyIdOf<T>>,
-			// weight information to be used
 			weight_info: TransactWeights,
 		) -> DispatchResult {
-			// WithdrawAsset
-			// BuyExecution
-			// SetAppendix(RefundSurplus, DepositAsset(sov account))
-			// Transact
-
-			// check permissions
-			match &action {
-				HrmpOperation::InitOpen(_) | HrmpOperation::Accept { .. } => {
-					<EitherOfDiverse<T::HrmpManipulatorOrigin, T::HrmpOpenOrigin>>::ensure_origin(
-						origin,
-					)?;
-				}
-				HrmpOperation::Close(_) | HrmpOperation::Cancel { .. } => {
-					T::HrmpManipulatorOrigin::ensure_origin(origin)?;
-				}
-			}
-
 			// process action
 			let call_bytes = match action.clone() {
 				HrmpOperation::InitOpen(params) => {
@@ -953,20 +1559,79 @@ pub mod pallet {
 				destination,
 				None,
 				fee,
-				call_bytes.clone(),
+				call_bytes,
 				OriginKind::Native,
 				total_weight,
 				weight_info.transact_required_weight_at_most,
 				Some(vec![RefundSurplus, deposit_appendix]),
+				None,
 			)?;
 
-			Self::deposit_event(Event::HrmpManagementSent { action });
+			// Track the status of the channel
+			if let Some(self_para_id) = Self::self_para_id() {
+				match action {
+					HrmpOperation::InitOpen(params) => Self::set_hrmp_channel_status(
+						HrmpChannelId {
+							sender: self_para_id,
+							recipient: params.para_id,
+						},
+						HrmpChannelStatus::OpenRequested,
+					),
+					HrmpOperation::Accept { para_id } => Self::set_hrmp_channel_status(
+						HrmpChannelId {
+							sender: para_id,
+							recipient: self_para_id,
+						},
+						HrmpChannelStatus::Accepted,
+					),
+					HrmpOperation::Close(channel_id) | HrmpOperation::Cancel { channel_id, .. } => {
+						Self::remove_hrmp_channel(channel_id)
+					}
+				}
+			}
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
+		/// Issues an HRMP operation paid with the relay chain token from the sovereign account.
+		/// Failures are reported through events.
+		fn do_automated_hrmp_manage(action: HrmpOperation) {
+			let result = Self::do_hrmp_manage(
+				action.clone(),
+				CurrencyPayment {
+					currency: Currency::AsMultiLocation(Box::new(Location::parent().into())),
+					fee_amount: None,
+				},
+				TransactWeights {
+					transact_required_weight_at_most: T::HrmpAutomationTransactWeight::get(),
+					overall_weight: None,
+				},
+			);
+			match result {
+				Ok(()) => Self::deposit_event(Event::HrmpManagementSent { action }),
+				Err(error) => Self::deposit_event(Event::HrmpAutomationFailed { action, error }),
+			}
+		}
+
+		fn self_para_id() -> Option<ParaId> {
+			T::UniversalLocation::get()
+				.iter()
+				.find_map(|junction| match junction {
+					Parachain(para_id) => Some(ParaId::from(*para_id)),
+					_ => None,
+				})
+		}
+
+		fn set_hrmp_channel_status(channel_id: HrmpChannelId, status: HrmpChannelStatus) {
+			HrmpChannels::<T>::insert(&channel_id, status);
+			Self::deposit_event(Event::HrmpChannelStatusChanged { channel_id, status });
+		}
+
+		fn remove_hrmp_channel(channel_id: HrmpChannelId) {
+			HrmpChannels::<T>::remove(&channel_id);
+			Self::deposit_event(Event::HrmpChannelRemoved { channel_id });
+		}
+
 		fn transact_in_dest_chain_asset_non_signed(
 			dest: Location,
 			fee_payer: Option<T::AccountId>,
@@ -976,6 +1641,7 @@ pub mod pallet {
 			total_weight: WeightLimit,
 			transact_required_weight_at_most: Weight,
 			with_appendix: Option<Vec<Instruction<()>>>,
+			with_report: Option<QueryResponseInfo>,
 		) -> DispatchResult {
 			if let Some(fee_payer) = fee_payer {
 				// Convert origin to multilocation
@@ -1001,6 +1667,7 @@ pub mod pallet {
 				transact_required_weight_at_most,
 				origin_kind,
 				with_appendix,
+				with_report,
 			)?;
 
 			// Send to sovereign
@@ -1021,6 +1688,7 @@ pub mod pallet {
 			total_weight: WeightLimit,
 			transact_required_weight_at_most: Weight,
 			with_appendix: Option<Vec<Instruction<()>>>,
+			with_report: Option<QueryResponseInfo>,
 		) -> DispatchResult {
 			// Convert origin to multilocation
 			let origin_as_mult = T::AccountIdToLocation::convert(fee_payer);
@@ -1039,6 +1707,7 @@ pub mod pallet {
 				transact_required_weight_at_most,
 				origin_kind,
 				with_appendix,
+				with_report,
 			)?;
 
 			// We append DescendOrigin as the first instruction in the message
@@ -1097,11 +1766,16 @@ pub mod pallet {
 			dispatch_weight: Weight,
 			origin_kind: OriginKind,
 			with_appendix: Option<Vec<Instruction<()>>>,
+			with_report: Option<QueryResponseInfo>,
 		) -> Result<Xcm<()>, DispatchError> {
-			let mut instructions = vec![
-				Self::withdraw_instruction(asset.clone(), &dest)?,
-				Self::buy_execution(asset, &dest, dest_weight)?,
-			];
+			let mut instructions = vec![];
+			// Errors happening before the transact is dispatched, including withdrawing and
+			// buying execution with the fee asset, are reported by the handler
+			if let Some(report) = with_report.clone() {
+				instructions.push(SetErrorHandler(Xcm(vec![ReportError(report)])));
+			}
+			instructions.push(Self::withdraw_instruction(asset.clone(), &dest)?);
+			instructions.push(Self::buy_execution(asset, &dest, dest_weight)?);
 			if let Some(appendix) = with_appendix {
 				instructions.push(Self::appendix_instruction(appendix)?);
 			}
@@ -1110,6 +1784,9 @@ pub mod pallet {
 				require_weight_at_most: dispatch_weight,
 				call: call.into(),
 			});
+			if let Some(report) = with_report {
+				instructions.push(ReportTransactStatus(report));
+			}
 			Ok(Xcm(instructions))
 		}
 
@@ -1317,4 +1994,63 @@ pub mod pallet {
 			}
 		}
 	}
+
+	impl<T: Config> HandleHrmpNewChannelOpenRequest for Pallet<T> {
+		fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> XcmResult {
+			let self_para_id = Self::self_para_id().ok_or(XcmError::Unanchored)?;
+			let sender = ParaId::from(sender);
+
+			Self::set_hrmp_channel_status(
+				HrmpChannelId {
+					sender,
+					recipient: self_para_id,
+				},
+				HrmpChannelStatus::Requested,
+			);
+
+			if HrmpAutoAccept::<T>::contains_key(sender) {
+				Self::do_automated_hrmp_manage(HrmpOperation::Accept { para_id: sender });
+
+				// Reciprocate with the same parameters, unless the channel already exists
+				let reciprocal_channel = HrmpChannelId {
+					sender: self_para_id,
+					recipient: sender,
+				};
+				if !HrmpChannels::<T>::contains_key(&reciprocal_channel) {
+					Self::do_automated_hrmp_manage(HrmpOperation::InitOpen(HrmpInitParams {
+						para_id: sender,
+						proposed_max_capacity: max_capacity,
+						proposed_max_message_size: max_message_size,
+					}));
+				}
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> HandleHrmpChannelAccepted for Pallet<T> {
+		fn handle(recipient: u32) -> XcmResult {
+			let self_para_id = Self::self_para_id().ok_or(XcmError::Unanchored)?;
+
+			Self::set_hrmp_channel_status(
+				HrmpChannelId {
+					sender: self_para_id,
+					recipient: recipient.into(),
+				},
+				HrmpChannelStatus::Accepted,
+			);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> HandleHrmpChannelClosing for Pallet<T> {
+		fn handle(_initiator: u32, sender: u32, recipient: u32) -> XcmResult {
+			Self::remove_hrmp_channel(HrmpChannelId {
+				sender: sender.into(),
+				recipient: recipient.into(),
+			});
+			Ok(())
+		}
+	}
 }
@@ -31,8 +31,8 @@ use sp_runtime::BuildStorage;
 use xcm::latest::{
 	opaque, Asset, Error as XcmError, Instruction, InteriorLocation,
 	Junction::{AccountKey20, GlobalConsensus, PalletInstance, Parachain},
-	Location, NetworkId, Result as XcmResult, SendError, SendResult, SendXcm, Xcm, XcmContext,
-	XcmHash,
+	Location, NetworkId, QueryId, Result as XcmResult, SendError, SendResult, SendXcm, Xcm,
+	XcmContext, XcmHash,
 };
 use xcm::{IntoVersion, VersionedXcm, WrapVersion};
 use xcm_primitives::{UtilityAvailableCalls, UtilityEncodeCall, XcmTransact};
@@ -380,6 +380,9 @@ impl SendXcm for TestSendXcm {
 
 parameter_types! {
 	pub MaxFee: Asset = (Location::parent(), 1_000_000_000_000u128).into();
+	pub const HrmpAutomationTransactWeight: Weight = Weight::from_parts(100u64, 0u64);
+	pub RelayLocation: Location = Location::parent();
+	pub const TransactStatusQueryTimeout: u64 = 100;
 	pub SelfLocationAbsolute: Location = Location {
 		parents: 1,
 		interior: [Parachain(ParachainId::get().into())].into(),
@@ -387,6 +390,34 @@ parameter_types! {
 }
 pub type MaxHrmpRelayFee = xcm_builder::Case<MaxFee>;
 
+/// Charges a flat fee in any asset
+pub struct WeightToAssetFee;
+impl xcm_primitives::WeightToAssetFee for WeightToAssetFee {
+	fn weight_to_asset_fee(_weight: Weight, _asset: &Location) -> Option<u128> {
+		Some(100)
+	}
+}
+
+// Simulates registering queries in pallet-xcm
+thread_local! {
+	pub static NEXT_QUERY_ID: RefCell<QueryId> = RefCell::new(0);
+}
+pub struct TestNotifyQueryHandler;
+impl xcm_primitives::NotifyQueryHandler<crate::Call<Test>, u64> for TestNotifyQueryHandler {
+	fn new_notify_query(
+		_responder: Location,
+		_notify: crate::Call<Test>,
+		_timeout: u64,
+		_match_querier: Location,
+	) -> QueryId {
+		NEXT_QUERY_ID.with(|q| {
+			let query_id = *q.borrow();
+			*q.borrow_mut() = query_id + 1;
+			query_id
+		})
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -407,6 +438,11 @@ impl Config for Test {
 	type HrmpManipulatorOrigin = EnsureRoot<u64>;
 	type HrmpOpenOrigin = EnsureRoot<u64>;
 	type MaxHrmpFee = MaxHrmpRelayFee;
+	type HrmpAutomationTransactWeight = HrmpAutomationTransactWeight;
+	type WeightToAssetFee = WeightToAssetFee;
+	type NotifyQueryHandler = TestNotifyQueryHandler;
+	type ResponseOrigin = frame_system::EnsureRootWithSuccess<u64, RelayLocation>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
 }
 
 pub(crate) struct ExtBuilder {
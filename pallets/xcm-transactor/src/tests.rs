@@ -16,13 +16,17 @@
 
 use crate::mock::*;
 use crate::*;
-use cumulus_primitives_core::relay_chain::HrmpChannelId;
+use cumulus_primitives_core::{relay_chain::HrmpChannelId, ParaId};
+use frame_support::dispatch::GetDispatchInfo;
 use frame_support::weights::Weight;
 use frame_support::{assert_noop, assert_ok, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
 use sp_runtime::traits::Convert;
 use sp_runtime::DispatchError;
 use sp_std::boxed::Box;
 use xcm::latest::prelude::*;
+use xcm_executor::traits::{
+	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+};
 use xcm_primitives::{UtilityAvailableCalls, UtilityEncodeCall};
 #[test]
 fn test_register_address() {
@@ -1745,3 +1749,708 @@ fn test_transact_through_signed_with_refund_works() {
 			]))));
 		})
 }
+
+#[test]
+fn test_transact_through_derivative_with_refund_to_origin_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// Root can register
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			// Root can set transact info
+			assert_ok!(XcmTransactor::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				0.into(),
+				10000.into(),
+				None
+			));
+
+			// Set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				1
+			));
+
+			assert_ok!(XcmTransactor::transact_through_derivative_with_refund(
+				RuntimeOrigin::signed(1u64),
+				Transactors::Relay,
+				1,
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: None
+				},
+				vec![1u8],
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(Limited(1000.into()))
+				},
+				TransactRefund::ToOrigin
+			));
+
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+
+			// Surplus is reserve transferred back to the caller in this chain
+			assert!(sent_message.0.contains(&SetAppendix(Xcm(vec![
+				RefundSurplus,
+				DepositReserveAsset {
+					assets: Wild(AllCounted(1u32)),
+					dest: Location::new(0, [Junction::Parachain(100)]),
+					xcm: Xcm(vec![
+						BuyExecution {
+							fees: (Location::parent(), 150u128).into(),
+							weight_limit: Limited(Weight::zero()),
+						},
+						DepositAsset {
+							assets: Wild(AllCounted(1u32)),
+							beneficiary: AccountIdToLocation::convert(1),
+						},
+					]),
+				}
+			]))));
+		})
+}
+
+#[test]
+fn test_transact_through_signed_with_refund_to_origin_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// Set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				1
+			));
+
+			// Overall weight to use
+			let total_weight: Weight = 10_100u64.into();
+			assert_ok!(XcmTransactor::transact_through_signed_with_refund(
+				RuntimeOrigin::signed(1u64),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: None
+				},
+				vec![1u8],
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(Limited(total_weight))
+				},
+				TransactRefund::ToOrigin
+			));
+
+			let expected = vec![
+				crate::Event::DestFeePerSecondChanged {
+					location: Location::parent(),
+					fee_per_second: 1,
+				},
+				crate::Event::TransactedSigned {
+					fee_payer: 1u64,
+					dest: Location::parent(),
+					call: vec![1u8],
+				},
+			];
+			assert_eq!(events(), expected);
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+
+			// Surplus is reserve transferred back to the caller in this chain
+			assert!(sent_message.0.contains(&SetAppendix(Xcm(vec![
+				RefundSurplus,
+				DepositReserveAsset {
+					assets: Wild(AllCounted(1u32)),
+					dest: Location::new(0, [Junction::Parachain(100)]),
+					xcm: Xcm(vec![
+						BuyExecution {
+							fees: (Location::parent(), 150u128).into(),
+							weight_limit: Limited(Weight::zero()),
+						},
+						DepositAsset {
+							assets: Wild(AllCounted(1u32)),
+							beneficiary: AccountIdToLocation::convert(1),
+						},
+					]),
+				}
+			]))));
+		})
+}
+
+#[test]
+fn test_transact_through_signed_with_refund_to_origin_fails_if_dest_is_not_the_fee_reserve() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// The relay asset pays the fees in a sibling, it could not be transferred back
+			assert_noop!(
+				XcmTransactor::transact_through_signed_with_refund(
+					RuntimeOrigin::signed(1u64),
+					Box::new(xcm::VersionedLocation::V4(Location::new(
+						1,
+						[Junction::Parachain(1000)]
+					))),
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: Some(1000)
+					},
+					vec![1u8],
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: Some(Limited(10_100u64.into()))
+					},
+					TransactRefund::ToOrigin
+				),
+				Error::<Test>::AssetIsNotReserveInDestination
+			);
+		})
+}
+
+#[test]
+fn test_transact_through_signed_with_refund_to_origin_fails_overall_weight_not_set() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// Root can set transact info
+			assert_ok!(XcmTransactor::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				0.into(),
+				10000.into(),
+				Some(1.into())
+			));
+
+			// Set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				1
+			));
+
+			assert_noop!(
+				XcmTransactor::transact_through_signed_with_refund(
+					RuntimeOrigin::signed(1u64),
+					Box::new(xcm::VersionedLocation::V4(Location::parent())),
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: None
+					},
+					vec![1u8],
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: None
+					},
+					TransactRefund::ToOrigin
+				),
+				Error::<Test>::RefundNotSupportedWithTransactInfo
+			);
+		})
+}
+
+fn set_relay_fee_info() {
+	assert_ok!(XcmTransactor::set_transact_info(
+		RuntimeOrigin::root(),
+		Box::new(xcm::VersionedLocation::V4(Location::parent())),
+		10_000u64.into(),
+		20_000u64.into(),
+		None
+	));
+	assert_ok!(XcmTransactor::set_fee_per_second(
+		RuntimeOrigin::root(),
+		Box::new(xcm::VersionedLocation::V4(Location::parent())),
+		WEIGHT_REF_TIME_PER_SECOND as u128
+	));
+}
+
+#[test]
+fn test_set_hrmp_auto_accept() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XcmTransactor::set_hrmp_auto_accept(RuntimeOrigin::signed(1u64), 1u32.into(), true),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(XcmTransactor::set_hrmp_auto_accept(
+				RuntimeOrigin::root(),
+				1u32.into(),
+				true
+			));
+			assert!(HrmpAutoAccept::<Test>::contains_key(ParaId::from(1u32)));
+
+			assert_ok!(XcmTransactor::set_hrmp_auto_accept(
+				RuntimeOrigin::root(),
+				1u32.into(),
+				false
+			));
+			assert!(!HrmpAutoAccept::<Test>::contains_key(ParaId::from(1u32)));
+
+			assert_eq!(
+				events(),
+				vec![
+					crate::Event::HrmpAutoAcceptSet {
+						para_id: 1u32.into(),
+						auto_accept: true,
+					},
+					crate::Event::HrmpAutoAcceptSet {
+						para_id: 1u32.into(),
+						auto_accept: false,
+					},
+				]
+			);
+		})
+}
+
+#[test]
+fn test_hrmp_manage_tracks_channel_status() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			set_relay_fee_info();
+			let payment = CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::V4(
+					Location::parent(),
+				))),
+				fee_amount: None,
+			};
+			let weights = TransactWeights {
+				transact_required_weight_at_most: 100u64.into(),
+				overall_weight: None,
+			};
+			let outbound = HrmpChannelId {
+				sender: 100u32.into(),
+				recipient: 1u32.into(),
+			};
+
+			assert_ok!(XcmTransactor::hrmp_manage(
+				RuntimeOrigin::root(),
+				HrmpOperation::InitOpen(HrmpInitParams {
+					para_id: 1u32.into(),
+					proposed_max_capacity: 1,
+					proposed_max_message_size: 1
+				}),
+				payment.clone(),
+				weights.clone()
+			));
+			assert_eq!(
+				HrmpChannels::<Test>::get(&outbound),
+				Some(HrmpChannelStatus::OpenRequested)
+			);
+
+			assert_ok!(XcmTransactor::hrmp_manage(
+				RuntimeOrigin::root(),
+				HrmpOperation::Cancel {
+					channel_id: outbound.clone(),
+					open_requests: 1
+				},
+				payment,
+				weights
+			));
+			assert_eq!(HrmpChannels::<Test>::get(&outbound), None);
+		})
+}
+
+#[test]
+fn test_hrmp_open_request_not_in_allow_list_is_recorded() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			set_relay_fee_info();
+
+			assert_ok!(<XcmTransactor as HandleHrmpNewChannelOpenRequest>::handle(
+				1u32, 5u32, 10u32
+			));
+
+			let inbound = HrmpChannelId {
+				sender: 1u32.into(),
+				recipient: 100u32.into(),
+			};
+			assert_eq!(
+				HrmpChannels::<Test>::get(&inbound),
+				Some(HrmpChannelStatus::Requested)
+			);
+			assert!(mock::sent_xcm().is_empty());
+		})
+}
+
+#[test]
+fn test_hrmp_open_request_in_allow_list_is_accepted_and_reciprocated() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			set_relay_fee_info();
+			assert_ok!(XcmTransactor::set_hrmp_auto_accept(
+				RuntimeOrigin::root(),
+				1u32.into(),
+				true
+			));
+
+			assert_ok!(<XcmTransactor as HandleHrmpNewChannelOpenRequest>::handle(
+				1u32, 5u32, 10u32
+			));
+
+			let inbound = HrmpChannelId {
+				sender: 1u32.into(),
+				recipient: 100u32.into(),
+			};
+			let outbound = HrmpChannelId {
+				sender: 100u32.into(),
+				recipient: 1u32.into(),
+			};
+			assert_eq!(
+				HrmpChannels::<Test>::get(&inbound),
+				Some(HrmpChannelStatus::Accepted)
+			);
+			assert_eq!(
+				HrmpChannels::<Test>::get(&outbound),
+				Some(HrmpChannelStatus::OpenRequested)
+			);
+
+			// Accept the request, and open the reciprocal channel with the same parameters
+			let sent_messages = mock::sent_xcm();
+			assert_eq!(sent_messages.len(), 2);
+			assert!(sent_messages[0].1 .0.contains(&Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: HrmpAutomationTransactWeight::get(),
+				call: vec![0, 0, 1, 0, 0, 0].into(),
+			}));
+			assert!(sent_messages[1].1 .0.contains(&Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: HrmpAutomationTransactWeight::get(),
+				call: vec![0, 0, 1, 0, 0, 0, 10, 0, 0, 0, 5, 0, 0, 0].into(),
+			}));
+			assert!(events().contains(&crate::Event::HrmpManagementSent {
+				action: HrmpOperation::InitOpen(HrmpInitParams {
+					para_id: 1u32.into(),
+					proposed_max_capacity: 10,
+					proposed_max_message_size: 5
+				})
+			}));
+
+			// The sibling accepts our request
+			assert_ok!(<XcmTransactor as HandleHrmpChannelAccepted>::handle(1u32));
+			assert_eq!(
+				HrmpChannels::<Test>::get(&outbound),
+				Some(HrmpChannelStatus::Accepted)
+			);
+
+			// The sibling closes its channel
+			assert_ok!(<XcmTransactor as HandleHrmpChannelClosing>::handle(
+				1u32, 1u32, 100u32
+			));
+			assert_eq!(HrmpChannels::<Test>::get(&inbound), None);
+			assert!(events().contains(&crate::Event::HrmpChannelRemoved {
+				channel_id: inbound
+			}));
+		})
+}
+
+#[test]
+fn test_hrmp_automation_failure_emits_event() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// No transact info is set, so the fee cannot be computed
+			assert_ok!(XcmTransactor::set_hrmp_auto_accept(
+				RuntimeOrigin::root(),
+				1u32.into(),
+				true
+			));
+
+			assert_ok!(<XcmTransactor as HandleHrmpNewChannelOpenRequest>::handle(
+				1u32, 5u32, 10u32
+			));
+
+			assert!(events().contains(&crate::Event::HrmpAutomationFailed {
+				action: HrmpOperation::Accept {
+					para_id: 1u32.into()
+				},
+				error: Error::<Test>::TransactorInfoNotSet.into(),
+			}));
+			assert_eq!(
+				HrmpChannels::<Test>::get(&HrmpChannelId {
+					sender: 1u32.into(),
+					recipient: 100u32.into(),
+				}),
+				Some(HrmpChannelStatus::Requested)
+			);
+			assert!(mock::sent_xcm().is_empty());
+		})
+}
+
+fn transact_through_signed_with_report() {
+	assert_ok!(XcmTransactor::set_fee_per_second(
+		RuntimeOrigin::root(),
+		Box::new(xcm::VersionedLocation::V4(Location::parent())),
+		1
+	));
+	assert_ok!(XcmTransactor::transact_through_signed_with_report(
+		RuntimeOrigin::signed(1u64),
+		Box::new(xcm::VersionedLocation::V4(Location::parent())),
+		CurrencyPayment {
+			currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+			fee_amount: None
+		},
+		vec![1u8],
+		TransactWeights {
+			transact_required_weight_at_most: 100u64.into(),
+			overall_weight: Some(Limited(10_100u64.into()))
+		},
+		false
+	));
+}
+
+#[test]
+fn test_transact_through_signed_with_report_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			transact_through_signed_with_report();
+
+			assert_eq!(
+				XcmTransactor::transact_status(0),
+				Some(TransactStatusInfo {
+					owner: Some(1u64),
+					dest: Location::parent(),
+					status: TransactStatus::Pending,
+					timeout: 101,
+				})
+			);
+			assert_eq!(LastTransactStatusQuery::<Test>::get(), Some(0));
+			assert!(
+				events().contains(&crate::Event::TransactStatusQueryRegistered {
+					query_id: 0,
+					owner: Some(1u64),
+					dest: Location::parent(),
+				})
+			);
+
+			let report = QueryResponseInfo {
+				destination: Location::new(0, [Parachain(100)]),
+				query_id: 0,
+				max_weight: crate::Call::<Test>::transact_status_response {
+					query_id: 0,
+					response: Response::Null,
+				}
+				.get_dispatch_info()
+				.weight,
+			};
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+
+			// Errors are reported by the handler, set right after descending to the caller
+			// origin, and the transact status after the transact
+			assert_eq!(
+				sent_message.0.get(1),
+				Some(&SetErrorHandler(Xcm(vec![ReportError(report.clone())])))
+			);
+			assert!(matches!(sent_message.0.get(2), Some(WithdrawAsset(..))));
+			assert_eq!(sent_message.0.last(), Some(&ReportTransactStatus(report)));
+		})
+}
+
+#[test]
+fn test_transact_through_sovereign_with_report_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				1
+			));
+			assert_ok!(XcmTransactor::transact_through_sovereign_with_report(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				Some(1u64),
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: None
+				},
+				vec![1u8],
+				OriginKind::SovereignAccount,
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(Limited(10_100u64.into()))
+				},
+				false
+			));
+
+			assert_eq!(
+				XcmTransactor::transact_status(0).map(|info| info.status),
+				Some(TransactStatus::Pending)
+			);
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+			assert!(matches!(sent_message.0.first(), Some(SetErrorHandler(..))));
+			assert!(matches!(
+				sent_message.0.last(),
+				Some(ReportTransactStatus(QueryResponseInfo { query_id: 0, .. }))
+			));
+		})
+}
+
+#[test]
+fn test_transact_with_report_fails_overall_weight_not_set() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XcmTransactor::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				0.into(),
+				10000.into(),
+				Some(1.into())
+			));
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedLocation::V4(Location::parent())),
+				1
+			));
+
+			assert_noop!(
+				XcmTransactor::transact_through_signed_with_report(
+					RuntimeOrigin::signed(1u64),
+					Box::new(xcm::VersionedLocation::V4(Location::parent())),
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: None
+					},
+					vec![1u8],
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: None
+					},
+					false
+				),
+				Error::<Test>::ReportNotSupportedWithTransactInfo
+			);
+		})
+}
+
+#[test]
+fn test_transact_status_response_updates_status() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			transact_through_signed_with_report();
+			transact_through_signed_with_report();
+
+			// Only the response origin can report
+			assert_noop!(
+				XcmTransactor::transact_status_response(
+					RuntimeOrigin::signed(1u64),
+					0,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(XcmTransactor::transact_status_response(
+				RuntimeOrigin::root(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			));
+			assert_ok!(XcmTransactor::transact_status_response(
+				RuntimeOrigin::root(),
+				1,
+				Response::ExecutionResult(Some((2, XcmError::TooExpensive)))
+			));
+
+			assert_eq!(
+				XcmTransactor::transact_status(0).map(|info| info.status),
+				Some(TransactStatus::Succeeded)
+			);
+			assert_eq!(
+				XcmTransactor::transact_status(1).map(|info| info.status),
+				Some(TransactStatus::Failed)
+			);
+			let events = events();
+			assert!(events.contains(&crate::Event::TransactStatusReported {
+				query_id: 0,
+				status: TransactStatus::Succeeded,
+			}));
+			assert!(events.contains(&crate::Event::TransactStatusReported {
+				query_id: 1,
+				status: TransactStatus::Failed,
+			}));
+		})
+}
+
+#[test]
+fn test_prune_transact_status() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			transact_through_signed_with_report();
+			transact_through_signed_with_report();
+
+			// A pending status can not be pruned before its timeout
+			assert_noop!(
+				XcmTransactor::prune_transact_status(RuntimeOrigin::signed(1u64), 0),
+				Error::<Test>::TransactStatusNotPrunable
+			);
+
+			// The owner can prune a reported status
+			assert_ok!(XcmTransactor::transact_status_response(
+				RuntimeOrigin::root(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			));
+			assert_noop!(
+				XcmTransactor::prune_transact_status(RuntimeOrigin::signed(2u64), 0),
+				Error::<Test>::TransactStatusNotPrunable
+			);
+			assert_ok!(XcmTransactor::prune_transact_status(
+				RuntimeOrigin::signed(1u64),
+				0
+			));
+			assert_eq!(XcmTransactor::transact_status(0), None);
+			assert!(events().contains(&crate::Event::TransactStatusPruned { query_id: 0 }));
+
+			// The unreported status times out, and anyone can prune it
+			let info = XcmTransactor::transact_status(1).unwrap();
+			assert_eq!(info.status_at(101), TransactStatus::Pending);
+			assert_eq!(info.status_at(102), TransactStatus::TimedOut);
+			System::set_block_number(102);
+			assert_ok!(XcmTransactor::prune_transact_status(
+				RuntimeOrigin::signed(2u64),
+				1
+			));
+			assert_eq!(XcmTransactor::transact_status(1), None);
+		})
+}
+
+#[test]
+fn test_transact_status_response_fails_for_unknown_query() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XcmTransactor::transact_status_response(
+					RuntimeOrigin::root(),
+					0,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				Error::<Test>::UnknownTransactStatusQuery
+			);
+		})
+}
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `benchmarker`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `set_hrmp_auto_accept` is not part of this run, its weight is a single storage write.
+//! Neither are `transact_status_response` and `prune_transact_status`, which read and write
+//! one transact status query.
 
 // Executed Command:
 // ./target/release/moonbeam
@@ -61,6 +65,9 @@ pub trait WeightInfo {
 	fn transact_through_sovereign() -> Weight;
 	fn transact_through_signed() -> Weight;
 	fn hrmp_manage() -> Weight;
+	fn set_hrmp_auto_accept() -> Weight;
+	fn transact_status_response() -> Weight;
+	fn prune_transact_status() -> Weight;
 }
 
 /// Weights for xcm_transactor using the Substrate node and recommended hardware.
@@ -196,6 +203,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
 	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
 	fn hrmp_manage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `425`
@@ -203,7 +212,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 69_320_000 picoseconds.
 		Weight::from_parts(69_951_000, 23200)
 			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmTransactor HrmpAutoAccept (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpAutoAccept (max_values: None, max_size: None, mode: Measured)
+	fn set_hrmp_auto_accept() -> Weight {
+		Weight::from_parts(16_311_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn transact_status_response() -> Weight {
+		Weight::from_parts(15_262_000, 3607)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn prune_transact_status() -> Weight {
+		Weight::from_parts(15_000_000, 3607)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -339,6 +368,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
 	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmTransactor HrmpChannels (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpChannels (max_values: None, max_size: None, mode: Measured)
 	fn hrmp_manage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `425`
@@ -346,6 +377,26 @@ impl WeightInfo for () {
 		// Minimum execution time: 69_320_000 picoseconds.
 		Weight::from_parts(69_951_000, 23200)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmTransactor HrmpAutoAccept (r:0 w:1)
+	/// Proof Skipped: XcmTransactor HrmpAutoAccept (max_values: None, max_size: None, mode: Measured)
+	fn set_hrmp_auto_accept() -> Weight {
+		Weight::from_parts(16_311_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn transact_status_response() -> Weight {
+		Weight::from_parts(15_262_000, 3607)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn prune_transact_status() -> Weight {
+		Weight::from_parts(15_000_000, 3607)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
\ No newline at end of file
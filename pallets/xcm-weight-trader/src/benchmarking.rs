@@ -20,7 +20,7 @@ use super::*;
 
 use frame_benchmarking::{v2::*, BenchmarkError};
 use frame_support::traits::EnsureOrigin;
-use frame_system::EventRecord;
+use frame_system::{EventRecord, RawOrigin};
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
@@ -121,9 +121,14 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn remove_asset() -> Result<(), BenchmarkError> {
-		// Setup one asset
+	fn remove_asset(x: Linear<0, { T::MaxPriceFeeders::get() }>) -> Result<(), BenchmarkError> {
+		// Setup one asset with x price submissions
 		let location = setup_one_asset::<T>()?;
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0..x {
+			let feeder: T::AccountId = account("feeder", i, 0);
+			PriceSubmissions::<T>::insert(&location, feeder, (1_000u128, now));
+		}
 
 		let origin = T::RemoveSupportedAssetOrigin::try_successful_origin()
 			.map_err(|_| BenchmarkError::Weightless)?;
@@ -135,5 +140,68 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_price_feeders() -> Result<(), BenchmarkError> {
+		let origin = T::EditSupportedAssetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let feeders: Vec<T::AccountId> = (0..T::MaxPriceFeeders::get())
+			.map(|i| account("feeder", i, 0))
+			.collect();
+		let bounded_feeders: BoundedVec<_, _> = feeders
+			.clone()
+			.try_into()
+			.expect("MaxPriceFeeders feeders fit in the bounded vec");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, bounded_feeders);
+
+		assert_last_event::<T>(Event::PriceFeedersSet { feeders }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn feed_price(x: Linear<1, { T::MaxPriceFeeders::get() }>) -> Result<(), BenchmarkError> {
+		// Setup one asset
+		let location = setup_one_asset::<T>()?;
+
+		// Setup x feeders (at least the quorum), all of them except the caller already
+		// submitted a price in this block
+		let feeders: Vec<T::AccountId> = (0..x.max(T::MinPriceFeedersQuorum::get()))
+			.map(|i| account("feeder", i, 0))
+			.collect();
+		let edit_origin = T::EditSupportedAssetOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Pallet::<T>::set_price_feeders(
+			edit_origin,
+			feeders
+				.clone()
+				.try_into()
+				.expect("x feeders fit in the bounded vec"),
+		)
+		.expect("fail to setup feeders");
+		// The submissions are inserted directly, so the price is not already updated in this block
+		let now = frame_system::Pallet::<T>::block_number();
+		for feeder in feeders.iter().skip(1) {
+			PriceSubmissions::<T>::insert(&location, feeder, (1_000u128, now));
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(feeders[0].clone()),
+			location.clone(),
+			1_000,
+		);
+
+		assert_last_event::<T>(
+			Event::SupportedAssetPriceFed {
+				location,
+				relative_price: 1_000,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -36,7 +36,8 @@ use frame_support::pallet_prelude::*;
 use frame_support::traits::Contains;
 use frame_support::weights::WeightToFee;
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::{Convert, Zero};
+use sp_runtime::traits::{Convert, Saturating, Zero};
+use sp_runtime::Perbill;
 use sp_std::vec::Vec;
 use xcm::v4::{Asset, AssetId as XcmAssetId, Error as XcmError, Fungibility, Location, XcmContext};
 use xcm::{IntoVersion, VersionedAssetId};
@@ -72,15 +73,30 @@ pub mod pallet {
 		/// The native balance type.
 		type Balance: TryInto<u128>;
 
-		/// Origin that is allowed to edit a supported asset units per seconds
+		/// Origin that is allowed to edit a supported asset units per seconds and to set the
+		/// price feeders
 		type EditSupportedAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Maximum deviation of a fed price with respect to the current price, per update
+		type MaxPriceDeviation: Get<Perbill>;
+
+		/// Maximum number of price feeders
+		#[pallet::constant]
+		type MaxPriceFeeders: Get<u32>;
+
+		/// Minimum number of fresh feeder submissions required to update the price of an asset
+		#[pallet::constant]
+		type MinPriceFeedersQuorum: Get<u32>;
+
 		/// XCM Location for native curreny
 		type NativeLocation: Get<Location>;
 
 		/// Origin that is allowed to pause a supported asset
 		type PauseSupportedAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Number of blocks after which a fed price (or a price submission) is considered stale
+		type PriceStalenessThreshold: Get<BlockNumberFor<Self>>;
+
 		/// Origin that is allowed to remove a supported asset
 		type RemoveSupportedAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -111,6 +127,30 @@ pub mod pallet {
 	#[pallet::getter(fn supported_assets)]
 	pub type SupportedAssets<T: Config> = StorageMap<_, Blake2_128Concat, Location, (bool, u128)>;
 
+	/// Accounts that are allowed to submit asset prices
+	#[pallet::storage]
+	#[pallet::getter(fn price_feeders)]
+	pub type PriceFeeders<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxPriceFeeders>, ValueQuery>;
+
+	/// Last price submitted by each feeder per asset, with the block of the submission
+	#[pallet::storage]
+	pub type PriceSubmissions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Location,
+		Blake2_128Concat,
+		T::AccountId,
+		(u128, BlockNumberFor<T>),
+	>;
+
+	/// Block of the last price update of each asset whose price is fed.
+	/// Assets that are not in this map are not subject to staleness.
+	#[pallet::storage]
+	#[pallet::getter(fn last_price_update)]
+	pub type LastPriceUpdate<T: Config> =
+		StorageMap<_, Blake2_128Concat, Location, BlockNumberFor<T>>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The given asset was already added
@@ -125,6 +165,10 @@ pub mod pallet {
 		XcmLocationFiltered,
 		/// The relative price cannot be zero
 		PriceCannotBeZero,
+		/// The caller is not a price feeder
+		NotPriceFeeder,
+		/// There are less price feeders than the quorum required to update a price
+		NotEnoughPriceFeeders,
 	}
 
 	#[pallet::event]
@@ -146,6 +190,19 @@ pub mod pallet {
 		ResumeAssetSupport { location: Location },
 		/// Supported asset type for fee payment removed
 		SupportedAssetRemoved { location: Location },
+		/// The set of price feeders has been updated
+		PriceFeedersSet { feeders: Vec<T::AccountId> },
+		/// A price feeder submitted a price for a given asset
+		PriceSubmitted {
+			location: Location,
+			feeder: T::AccountId,
+			relative_price: u128,
+		},
+		/// The price of a given asset has been updated from the price feeders submissions
+		SupportedAssetPriceFed {
+			location: Location,
+			relative_price: u128,
+		},
 	}
 
 	#[pallet::call]
@@ -195,6 +252,10 @@ pub mod pallet {
 				.0;
 
 			SupportedAssets::<T>::insert(&location, (enabled, relative_price));
+			// A price set by governance is fresh
+			if LastPriceUpdate::<T>::contains_key(&location) {
+				LastPriceUpdate::<T>::insert(&location, frame_system::Pallet::<T>::block_number());
+			}
 
 			Self::deposit_event(Event::SupportedAssetEdited {
 				location,
@@ -236,8 +297,11 @@ pub mod pallet {
 			}
 		}
 
+		/// Remove a supported asset along with its price submissions.
+		/// At most `MaxPriceFeeders` submissions are removed, submissions of former feeders left
+		/// behind are cleaned up by `feed_price` if the asset is added again.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::remove_asset())]
+		#[pallet::weight(T::WeightInfo::remove_asset(T::MaxPriceFeeders::get()))]
 		pub fn remove_asset(origin: OriginFor<T>, location: Location) -> DispatchResult {
 			T::RemoveSupportedAssetOrigin::ensure_origin(origin)?;
 
@@ -247,11 +311,115 @@ pub mod pallet {
 			);
 
 			SupportedAssets::<T>::remove(&location);
+			LastPriceUpdate::<T>::remove(&location);
+			let _ = PriceSubmissions::<T>::clear_prefix(&location, T::MaxPriceFeeders::get(), None);
 
 			Self::deposit_event(Event::SupportedAssetRemoved { location });
 
 			Ok(())
 		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_price_feeders())]
+		pub fn set_price_feeders(
+			origin: OriginFor<T>,
+			feeders: BoundedVec<T::AccountId, T::MaxPriceFeeders>,
+		) -> DispatchResult {
+			T::EditSupportedAssetOrigin::ensure_origin(origin)?;
+
+			// An empty set disables the price feeds
+			ensure!(
+				feeders.is_empty() || feeders.len() as u32 >= T::MinPriceFeedersQuorum::get(),
+				Error::<T>::NotEnoughPriceFeeders
+			);
+
+			PriceFeeders::<T>::put(&feeders);
+
+			Self::deposit_event(Event::PriceFeedersSet {
+				feeders: feeders.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Submit the relative price of a supported asset.
+		/// The price of the asset is updated to the median of the fresh submissions, bounded by
+		/// `MaxPriceDeviation` with respect to the current price, once at least
+		/// `MinPriceFeedersQuorum` current feeders submitted a fresh price.
+		/// The price is updated at most once per block, later submissions of the same block
+		/// are only recorded.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::feed_price(T::MaxPriceFeeders::get()))]
+		pub fn feed_price(
+			origin: OriginFor<T>,
+			location: Location,
+			relative_price: u128,
+		) -> DispatchResult {
+			let feeder = ensure_signed(origin)?;
+
+			let feeders = PriceFeeders::<T>::get();
+			ensure!(feeders.contains(&feeder), Error::<T>::NotPriceFeeder);
+			ensure!(relative_price != 0, Error::<T>::PriceCannotBeZero);
+
+			let (enabled, current_price) =
+				SupportedAssets::<T>::get(&location).ok_or(Error::<T>::AssetNotFound)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			PriceSubmissions::<T>::insert(&location, &feeder, (relative_price, now));
+
+			Self::deposit_event(Event::PriceSubmitted {
+				location: location.clone(),
+				feeder,
+				relative_price,
+			});
+
+			// A single update per block bounds the deviation with respect to the price at the
+			// start of the block, whatever the number of submissions.
+			if LastPriceUpdate::<T>::get(&location) == Some(now) {
+				return Ok(());
+			}
+
+			// Collect the fresh submissions of the current feeders, and clean up the
+			// submissions of the former feeders.
+			let mut prices = Vec::with_capacity(feeders.len());
+			let mut former_feeders = Vec::new();
+			for (who, (price, submitted_at)) in PriceSubmissions::<T>::iter_prefix(&location) {
+				if !feeders.contains(&who) {
+					former_feeders.push(who);
+				} else if now.saturating_sub(submitted_at) <= T::PriceStalenessThreshold::get() {
+					prices.push(price);
+				}
+			}
+			for who in former_feeders {
+				PriceSubmissions::<T>::remove(&location, who);
+			}
+
+			if (prices.len() as u32) < T::MinPriceFeedersQuorum::get() {
+				return Ok(());
+			}
+
+			// The submission of the caller is always fresh, so there is at least one price
+			prices.sort_unstable();
+			let median = prices[(prices.len() - 1) / 2];
+
+			let max_deviation = T::MaxPriceDeviation::get().mul_floor(current_price);
+			let new_price = median
+				.clamp(
+					current_price.saturating_sub(max_deviation),
+					current_price.saturating_add(max_deviation),
+				)
+				.max(1);
+
+			SupportedAssets::<T>::insert(&location, (enabled, new_price));
+			LastPriceUpdate::<T>::insert(&location, now);
+
+			Self::deposit_event(Event::SupportedAssetPriceFed {
+				location,
+				relative_price: new_price,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -262,6 +430,14 @@ pub mod pallet {
 				None
 			}
 		}
+		/// Whether the price of an asset is fed and has not been updated for more than
+		/// `PriceStalenessThreshold` blocks.
+		pub fn is_price_stale(location: &Location) -> bool {
+			LastPriceUpdate::<T>::get(location).map_or(false, |last_update| {
+				frame_system::Pallet::<T>::block_number().saturating_sub(last_update)
+					> T::PriceStalenessThreshold::get()
+			})
+		}
 		pub fn query_acceptable_payment_assets(
 			xcm_version: xcm::Version,
 		) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
@@ -298,6 +474,11 @@ pub mod pallet {
 				.into_version(4)
 				.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)?
 			{
+				// The trader refuses to charge fees with an outdated price
+				if Self::is_price_stale(&asset_location) {
+					return Err(XcmPaymentApiError::WeightNotComputable);
+				}
+
 				Trader::<T>::compute_amount_to_charge(&weight, &asset_location).map_err(|e| match e
 				{
 					XcmError::AssetNotFound => XcmPaymentApiError::AssetNotFound,
@@ -379,6 +560,11 @@ impl<T: crate::Config> WeightTrader for Trader<T> {
 
 		match (first_asset.id, first_asset.fun) {
 			(XcmAssetId(location), Fungibility::Fungible(_)) => {
+				// We refuse to charge fees with an outdated price
+				if Pallet::<T>::is_price_stale(&location) {
+					return Err(XcmError::FeesNotMet);
+				}
+
 				let amount: u128 = Self::compute_amount_to_charge(&weight, &location)?;
 
 				// We don't need to proceed if the amount is 0
@@ -164,6 +164,10 @@ parameter_types! {
 	pub NativeLocation: Location = Location::here();
 	pub XcmFeesAccount: AccountId = 101;
 	pub NotFilteredLocation: Location = Location::parent();
+	pub const MaxPriceFeeders: u32 = 3;
+	pub MaxPriceDeviation: Perbill = Perbill::from_percent(10);
+	pub static MinPriceFeedersQuorum: u32 = 1;
+	pub const PriceStalenessThreshold: u64 = 10;
 }
 
 impl Config for Test {
@@ -173,8 +177,12 @@ impl Config for Test {
 	type AssetTransactor = MockAssetTransactor;
 	type Balance = Balance;
 	type EditSupportedAssetOrigin = EnsureSignedBy<EditAccount, AccountId>;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxPriceFeeders = MaxPriceFeeders;
+	type MinPriceFeedersQuorum = MinPriceFeedersQuorum;
 	type NativeLocation = NativeLocation;
 	type PauseSupportedAssetOrigin = EnsureSignedBy<PauseAccount, AccountId>;
+	type PriceStalenessThreshold = PriceStalenessThreshold;
 	type RemoveSupportedAssetOrigin = EnsureSignedBy<RemoveAccount, AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type ResumeSupportedAssetOrigin = EnsureSignedBy<ResumeAccount, AccountId>;
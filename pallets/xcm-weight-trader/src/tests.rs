@@ -702,3 +702,301 @@ fn test_query_weight_to_asset_fee() {
 		);
 	})
 }
+
+fn setup_price_feeders(feeders: Vec<<Test as frame_system::Config>::AccountId>) {
+	assert_ok!(XcmWeightTrader::set_price_feeders(
+		RuntimeOrigin::signed(EditAccount::get()),
+		feeders.try_into().expect("too many feeders")
+	));
+}
+
+#[test]
+fn test_set_price_feeders() {
+	new_test_ext().execute_with(|| {
+		// Call with bad origin
+		assert_noop!(
+			XcmWeightTrader::set_price_feeders(
+				RuntimeOrigin::signed(AddAccount::get()),
+				vec![10].try_into().expect("too many feeders")
+			),
+			DispatchError::BadOrigin
+		);
+
+		setup_price_feeders(vec![10, 11]);
+		assert_eq!(XcmWeightTrader::price_feeders().into_inner(), vec![10, 11]);
+
+		setup_price_feeders(vec![]);
+		assert!(XcmWeightTrader::price_feeders().is_empty());
+	})
+}
+
+#[test]
+fn test_feed_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_price_feeders(vec![10, 11, 12]);
+
+		// Call with an unknown asset
+		assert_noop!(
+			XcmWeightTrader::feed_price(RuntimeOrigin::signed(10), Location::parent(), 1_000),
+			Error::<Test>::AssetNotFound
+		);
+
+		// Setup (add a supported asset)
+		assert_ok!(XcmWeightTrader::add_asset(
+			RuntimeOrigin::signed(AddAccount::get()),
+			Location::parent(),
+			1_000,
+		));
+
+		// Call with a non feeder account
+		assert_noop!(
+			XcmWeightTrader::feed_price(RuntimeOrigin::signed(13), Location::parent(), 1_000),
+			Error::<Test>::NotPriceFeeder
+		);
+
+		// Call with invalid price
+		assert_noop!(
+			XcmWeightTrader::feed_price(RuntimeOrigin::signed(10), Location::parent(), 0),
+			Error::<Test>::PriceCannotBeZero
+		);
+
+		// A single submission is applied as is when it is within the allowed deviation
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			1_050
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_050),
+		);
+		assert_eq!(
+			XcmWeightTrader::last_price_update(Location::parent()),
+			Some(1)
+		);
+
+		// The lower median of [1_050, 2_000] is applied
+		System::set_block_number(2);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(11),
+			Location::parent(),
+			2_000
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_050),
+		);
+
+		// The median of [1_050, 1_500, 2_000] is bounded by the maximum deviation (10%)
+		System::set_block_number(3);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(12),
+			Location::parent(),
+			1_500
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_155),
+		);
+
+		// Submissions of former feeders are ignored and cleaned up
+		setup_price_feeders(vec![10]);
+		System::set_block_number(4);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			1_100
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_100),
+		);
+		assert_eq!(
+			crate::PriceSubmissions::<Test>::iter_prefix(Location::parent()).count(),
+			1
+		);
+
+		// Feeding a paused asset keeps it paused
+		assert_ok!(XcmWeightTrader::pause_asset_support(
+			RuntimeOrigin::signed(PauseAccount::get()),
+			Location::parent(),
+		));
+		System::set_block_number(5);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			1_000
+		));
+		assert_eq!(
+			XcmWeightTrader::supported_assets(Location::parent()),
+			Some((false, 1_000))
+		);
+
+		// Removing the asset removes its price feed
+		assert_ok!(XcmWeightTrader::remove_asset(
+			RuntimeOrigin::signed(RemoveAccount::get()),
+			Location::parent(),
+		));
+		assert_eq!(XcmWeightTrader::last_price_update(Location::parent()), None);
+		assert_eq!(
+			crate::PriceSubmissions::<Test>::iter_prefix(Location::parent()).count(),
+			0
+		);
+	})
+}
+
+#[test]
+fn test_feed_price_requires_a_quorum_and_updates_once_per_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinPriceFeedersQuorum::set(2);
+		assert_ok!(XcmWeightTrader::add_asset(
+			RuntimeOrigin::signed(AddAccount::get()),
+			Location::parent(),
+			1_000,
+		));
+
+		// The feeders must be able to reach the quorum
+		assert_noop!(
+			XcmWeightTrader::set_price_feeders(
+				RuntimeOrigin::signed(EditAccount::get()),
+				vec![10].try_into().expect("too many feeders")
+			),
+			Error::<Test>::NotEnoughPriceFeeders
+		);
+		setup_price_feeders(vec![10, 11, 12]);
+
+		// A single fresh submission does not reach the quorum
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			1_050
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_000),
+		);
+		assert_eq!(XcmWeightTrader::last_price_update(Location::parent()), None);
+
+		// The quorum is reached
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(11),
+			Location::parent(),
+			1_050
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_050),
+		);
+
+		// Later submissions of the same block are only recorded
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(12),
+			Location::parent(),
+			2_000
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_050),
+		);
+		assert_eq!(
+			crate::PriceSubmissions::<Test>::iter_prefix(Location::parent()).count(),
+			3
+		);
+
+		// The next block, the median of [1_050, 2_000, 2_000] is bounded with respect to the
+		// price at the start of the block
+		System::set_block_number(2);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			2_000
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(1_155),
+		);
+	})
+}
+
+#[test]
+fn test_trader_refuses_stale_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let weight_to_buy = Weight::from_parts(10_000, 0);
+		let dummy_xcm_context = XcmContext::with_message_id(XcmHash::default());
+		let parent_asset = VersionedAssetId::V4(XcmAssetId(Location::parent()));
+		let payment: Asset = Asset {
+			fun: Fungibility::Fungible(22_000_000_000_000),
+			id: XcmAssetId(Location::parent()),
+		};
+
+		// Setup (add a supported asset with a fed price)
+		assert_ok!(XcmWeightTrader::add_asset(
+			RuntimeOrigin::signed(AddAccount::get()),
+			Location::parent(),
+			500_000_000,
+		));
+		setup_price_feeders(vec![10, 11]);
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(10),
+			Location::parent(),
+			500_000_000
+		));
+		assert!(!XcmWeightTrader::is_price_stale(&Location::parent()));
+
+		// The price is still fresh after PriceStalenessThreshold blocks
+		System::set_block_number(11);
+		assert!(!XcmWeightTrader::is_price_stale(&Location::parent()));
+
+		// The price is stale after more than PriceStalenessThreshold blocks
+		System::set_block_number(12);
+		assert!(XcmWeightTrader::is_price_stale(&Location::parent()));
+		assert_eq!(
+			Trader::<Test>::new().buy_weight(
+				weight_to_buy,
+				payment.clone().into(),
+				&dummy_xcm_context
+			),
+			Err(XcmError::FeesNotMet)
+		);
+
+		// Fee estimations are refused as well
+		assert_eq!(
+			XcmWeightTrader::query_weight_to_asset_fee(weight_to_buy, parent_asset),
+			Err(XcmPaymentApiError::WeightNotComputable)
+		);
+
+		// Stale submissions are ignored, only the new submission is taken into account
+		assert_ok!(XcmWeightTrader::feed_price(
+			RuntimeOrigin::signed(11),
+			Location::parent(),
+			520_000_000
+		));
+		assert_eq!(
+			XcmWeightTrader::get_asset_relative_price(&Location::parent()),
+			Some(520_000_000),
+		);
+		assert!(!XcmWeightTrader::is_price_stale(&Location::parent()));
+
+		// A price set by governance is fresh too
+		System::set_block_number(30);
+		assert!(XcmWeightTrader::is_price_stale(&Location::parent()));
+		assert_ok!(XcmWeightTrader::edit_asset(
+			RuntimeOrigin::signed(EditAccount::get()),
+			Location::parent(),
+			500_000_000,
+		));
+		assert!(!XcmWeightTrader::is_price_stale(&Location::parent()));
+		let mut trader = Trader::<Test>::new();
+		assert_eq!(
+			trader.buy_weight(weight_to_buy, payment.into(), &dummy_xcm_context),
+			Ok(Asset {
+				fun: Fungibility::Fungible(2_000_000_000_000),
+				id: XcmAssetId(Location::parent()),
+			}
+			.into())
+		);
+	})
+}
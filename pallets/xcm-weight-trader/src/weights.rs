@@ -27,7 +27,9 @@ pub trait WeightInfo {
 	fn edit_asset() -> Weight;
 	fn pause_asset_support() -> Weight;
 	fn resume_asset_support() -> Weight;
-	fn remove_asset() -> Weight;
+	fn remove_asset(x: u32) -> Weight;
+	fn set_price_feeders() -> Weight;
+	fn feed_price(x: u32) -> Weight;
 }
 
 // For tests only
@@ -44,7 +46,13 @@ impl WeightInfo for () {
 	fn resume_asset_support() -> Weight {
 		Weight::default()
 	}
-	fn remove_asset() -> Weight {
+	fn remove_asset(_x: u32) -> Weight {
+		Weight::default()
+	}
+	fn set_price_feeders() -> Weight {
+		Weight::default()
+	}
+	fn feed_price(_x: u32) -> Weight {
 		Weight::default()
 	}
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The DelegationPools contract's address.
+address constant DELEGATION_POOLS_ADDRESS = 0x000000000000000000000000000000000000081C;
+
+/// @dev The DelegationPools contract's instance.
+DelegationPools constant DELEGATION_POOLS_CONTRACT = DelegationPools(
+    DELEGATION_POOLS_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Pallet Delegation Pools Interface
+/// @dev The interface through which solidity contracts will interact with Delegation Pools
+/// @custom:address 0x000000000000000000000000000000000000081C
+interface DelegationPools {
+    /// @dev A pending withdrawal of a pool member
+    struct UnbondingRequest {
+        bool isValid;
+        address candidate;
+        uint256 amount;
+        uint32 whenExecutable;
+    }
+
+    /// @dev Get the account holding and delegating the funds of a pool
+    /// @custom:selector 5a6a8012
+    /// @param poolId The pool identifier
+    /// @return The pool account
+    function poolAccount(uint32 poolId) external view returns (address);
+
+    /// @dev Get the value of a pool owned by its members
+    /// @custom:selector 807010ba
+    /// @param poolId The pool identifier
+    /// @return The funds held by the pool, delegated or not, excluding unbonding funds
+    function poolValue(uint32 poolId) external view returns (uint256);
+
+    /// @dev Get the shares of a pool member
+    /// @custom:selector f2afa87e
+    /// @param poolId The pool identifier
+    /// @param member The member address
+    /// @return The amount of shares owned by the member
+    function sharesOf(
+        uint32 poolId,
+        address member
+    ) external view returns (uint256);
+
+    /// @dev Get the pending withdrawal of a pool member
+    /// @custom:selector 0a8ac4a8
+    /// @param poolId The pool identifier
+    /// @param member The member address
+    /// @return The unbonding request, isValid is false if there is none
+    function unbondingRequest(
+        uint32 poolId,
+        address member
+    ) external view returns (UnbondingRequest memory);
+
+    /// @dev Join a pool
+    /// @custom:selector a3e8c3ee
+    /// @param poolId The pool identifier
+    /// @param amount The amount to stake through the pool
+    function join(uint32 poolId, uint256 amount) external;
+
+    /// @dev Burn shares and schedule the decrease of the corresponding stake
+    /// @custom:selector cf7afd88
+    /// @param poolId The pool identifier
+    /// @param shares The amount of shares to burn
+    function unbond(uint32 poolId, uint256 shares) external;
+
+    /// @dev Withdraw the funds of a due unbonding request
+    /// @custom:selector 548a6706
+    /// @param poolId The pool identifier
+    function withdrawUnbonded(uint32 poolId) external;
+}
@@ -0,0 +1,176 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to interact with delegation pools via the EVM
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::Get;
+use pallet_delegation_pools::{BalanceOf, PoolId};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::marker::PhantomData;
+
+/// A precompile to join, leave and query delegation pools.
+pub struct DelegationPoolsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DelegationPoolsPrecompile<Runtime>
+where
+	Runtime: pallet_delegation_pools::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_delegation_pools::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+	<Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+	#[precompile::public("poolAccount(uint32)")]
+	#[precompile::view]
+	fn pool_account(_handle: &mut impl PrecompileHandle, pool_id: PoolId) -> EvmResult<Address> {
+		let pool_account = pallet_delegation_pools::Pallet::<Runtime>::pool_account(pool_id);
+
+		Ok(Address(pool_account.into()))
+	}
+
+	#[precompile::public("poolValue(uint32)")]
+	#[precompile::view]
+	fn pool_value(handle: &mut impl PrecompileHandle, pool_id: PoolId) -> EvmResult<U256> {
+		// Pools: Twox64Concat(8) + PoolId(4)
+		// + PoolInfo(AccountId(20) + Vec(1 + MaxPoolCandidates * AccountId(20)) + 2 * Balance(16))
+		handle.record_db_read::<Runtime>(
+			65 + 20
+				* (<Runtime as pallet_delegation_pools::Config>::MaxPoolCandidates::get() as usize),
+		)?;
+
+		let pool = match pallet_delegation_pools::Pallet::<Runtime>::pools(pool_id) {
+			Some(pool) => pool,
+			None => return Ok(U256::zero()),
+		};
+
+		// System::Account: Blake2_128Concat(16) + AccountId(20) + AccountInfo(80)
+		handle.record_db_read::<Runtime>(116)?;
+		let pool_account = pallet_delegation_pools::Pallet::<Runtime>::pool_account(pool_id);
+		let value = pallet_delegation_pools::Pallet::<Runtime>::pool_value(&pool_account, &pool);
+
+		Ok(value.into())
+	}
+
+	#[precompile::public("sharesOf(uint32,address)")]
+	#[precompile::view]
+	fn shares_of(
+		handle: &mut impl PrecompileHandle,
+		pool_id: PoolId,
+		member: Address,
+	) -> EvmResult<U256> {
+		// PoolMembers: Twox64Concat(8) + PoolId(4) + Blake2128(16) + AccountId(20) + Balance(16)
+		handle.record_db_read::<Runtime>(64)?;
+
+		let member = Runtime::AddressMapping::into_account_id(member.0);
+		let shares = pallet_delegation_pools::Pallet::<Runtime>::shares(pool_id, &member);
+
+		Ok(shares.into())
+	}
+
+	#[precompile::public("unbondingRequest(uint32,address)")]
+	#[precompile::view]
+	fn unbonding_request(
+		handle: &mut impl PrecompileHandle,
+		pool_id: PoolId,
+		member: Address,
+	) -> EvmResult<UnbondingRequest> {
+		// UnbondingRequests: Twox64Concat(8) + PoolId(4) + Blake2128(16) + AccountId(20)
+		// + UnbondingRequest(AccountId(20) + Balance(16) + RoundIndex(4))
+		handle.record_db_read::<Runtime>(88)?;
+
+		let member = Runtime::AddressMapping::into_account_id(member.0);
+		let request =
+			match pallet_delegation_pools::Pallet::<Runtime>::unbonding_request(pool_id, &member) {
+				Some(request) => request,
+				None => return Ok(UnbondingRequest::default()),
+			};
+
+		Ok(UnbondingRequest {
+			is_valid: true,
+			candidate: Address(request.candidate.into()),
+			amount: request.amount.into(),
+			when_executable: request.when_executable,
+		})
+	}
+
+	#[precompile::public("join(uint32,uint256)")]
+	fn join(handle: &mut impl PrecompileHandle, pool_id: PoolId, amount: U256) -> EvmResult {
+		let amount = Self::u256_to_amount(amount).in_field("amount")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_delegation_pools::Call::<Runtime>::join { pool_id, amount };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unbond(uint32,uint256)")]
+	fn unbond(handle: &mut impl PrecompileHandle, pool_id: PoolId, shares: U256) -> EvmResult {
+		let shares = Self::u256_to_amount(shares).in_field("shares")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_delegation_pools::Call::<Runtime>::unbond { pool_id, shares };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("withdrawUnbonded(uint32)")]
+	fn withdraw_unbonded(handle: &mut impl PrecompileHandle, pool_id: PoolId) -> EvmResult {
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_delegation_pools::Call::<Runtime>::withdraw_unbonded { pool_id };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
+
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct UnbondingRequest {
+	is_valid: bool,
+	candidate: Address,
+	amount: U256,
+	when_executable: u32,
+}
@@ -0,0 +1,216 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::{
+	roll_to_round_begin, AccountId, Balances, DelegationPools, ExtBuilder, PCall, Precompiles,
+	PrecompilesValue, Runtime, RuntimeOrigin,
+};
+use crate::UnbondingRequest;
+use frame_support::assert_ok;
+use frame_support::traits::Currency;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext_with_pool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 20)])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(DelegationPools::create_pool(
+			RuntimeOrigin::signed(Bob.into()),
+			vec![Alice.into()],
+			10
+		));
+	});
+	ext
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::pool_account_selectors().contains(&0x5a6a8012));
+	assert!(PCall::pool_value_selectors().contains(&0x807010ba));
+	assert!(PCall::shares_of_selectors().contains(&0xf2afa87e));
+	assert!(PCall::unbonding_request_selectors().contains(&0x0a8ac4a8));
+	assert!(PCall::join_selectors().contains(&0xa3e8c3ee));
+	assert!(PCall::unbond_selectors().contains(&0xcf7afd88));
+	assert!(PCall::withdraw_unbonded_selectors().contains(&0x548a6706));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::pool_account_selectors());
+		tester.test_view_modifier(PCall::pool_value_selectors());
+		tester.test_view_modifier(PCall::shares_of_selectors());
+		tester.test_view_modifier(PCall::unbonding_request_selectors());
+		tester.test_default_modifier(PCall::join_selectors());
+		tester.test_default_modifier(PCall::unbond_selectors());
+		tester.test_default_modifier(PCall::withdraw_unbonded_selectors());
+	});
+}
+
+#[test]
+fn pool_account_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::pool_account { pool_id: 0 })
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(Address(DelegationPools::pool_account(0).into()));
+	});
+}
+
+#[test]
+fn pool_value_of_unknown_pool_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::pool_value { pool_id: 0 })
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn join_works() {
+	ext_with_pool().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::join {
+					pool_id: 0,
+					amount: 5.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::shares_of {
+					pool_id: 0,
+					member: Address(Charlie.into()),
+				},
+			)
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns(U256::from(5));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::pool_value { pool_id: 0 })
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns(U256::from(15));
+	});
+}
+
+#[test]
+fn unbond_and_withdraw_unbonded_works() {
+	ext_with_pool().execute_with(|| {
+		assert_ok!(DelegationPools::join(
+			RuntimeOrigin::signed(Charlie.into()),
+			0,
+			5
+		));
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::unbond {
+					pool_id: 0,
+					shares: 5.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::unbonding_request {
+					pool_id: 0,
+					member: Address(Charlie.into()),
+				},
+			)
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns(UnbondingRequest {
+				is_valid: true,
+				candidate: Address(Alice.into()),
+				amount: 5.into(),
+				when_executable: 3,
+			});
+
+		roll_to_round_begin(3);
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::withdraw_unbonded { pool_id: 0 },
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		assert_eq!(Balances::free_balance(&Charlie.into()), 1_000);
+		assert_eq!(
+			DelegationPools::unbonding_request(0, AccountId::from(Charlie)),
+			None
+		);
+	});
+}
+
+#[test]
+fn unbonding_request_is_invalid_without_request() {
+	ext_with_pool().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::unbonding_request {
+					pool_id: 0,
+					member: Address(Charlie.into()),
+				},
+			)
+			.expect_cost(0) // TODO: Test db read/write costs
+			.expect_no_logs()
+			.execute_returns(UnbondingRequest::default());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["DelegationPools.sol"],
+		PCall::supports_selector,
+	)
+}
@@ -16,6 +16,22 @@ ParachainStaking constant PARACHAIN_STAKING_CONTRACT = ParachainStaking(
 /// wraps the pallet
 /// @custom:address 0x0000000000000000000000000000000000000800
 interface ParachainStaking {
+    /// @dev Rewards paid to a collator and its delegators for a round
+    struct CollatorRoundSummary {
+        address collator;
+        uint32 points;
+        uint256 totalPaid;
+    }
+
+    /// @dev Archived summary of the staking rewards of a round
+    struct RoundSummary {
+        bool isValid;
+        uint256 roundIssuance;
+        uint256 totalStakingReward;
+        uint32 totalPoints;
+        CollatorRoundSummary[] collators;
+    }
+
     /// @dev Check whether the specified address is currently a staking delegator
     /// @custom:selector fd8ab482
     /// @param delegator the address that we want to confirm is a delegator
@@ -254,6 +270,7 @@ interface ParachainStaking {
     ) external;
 
     /// @dev Cancel pending delegation request (already made in support of input by caller)
+    /// A delegation can have several pending bond less requests, only the oldest one is cancelled
     /// @custom:selector c90eee83
     /// @param candidate The address of the candidate
     function cancelDelegationRequest(address candidate) external;
@@ -272,6 +289,30 @@ interface ParachainStaking {
         uint256 delegatorDelegationCount
     ) external;
 
+    /// @dev Returns where the staking rewards of a delegator are paid to
+    /// @custom:selector a913de35
+    /// @param delegator the delegator for which we are querying the reward destination
+    /// @return The destination kind (0 = delegator account, 1 = payee account,
+    /// 2 = compounded towards a candidate) and the payee or candidate address
+    function rewardDestination(
+        address delegator
+    ) external view returns (uint8, address);
+
+    /// @dev Sets where the staking rewards of the caller are paid to
+    /// @custom:selector 318248ef
+    /// @param kind 0 = delegator account, 1 = payee account, 2 = compounded towards a candidate
+    /// @param target The payee or candidate address, ignored for kind 0
+    function setRewardDestination(uint8 kind, address target) external;
+
+    /// @dev Summary of the staking rewards of a round, kept for a limited number of rounds.
+    /// `isValid` is false if the round is not (or no longer) archived
+    /// @custom:selector 7d99533e
+    /// @param round the round for which we are querying the summary
+    /// @return The round issuance, staking reward, total points and per-collator payouts
+    function roundSummary(
+        uint32 round
+    ) external view returns (RoundSummary memory);
+
     /// @dev Fetch the total staked amount of a delegator, regardless of the
     /// candidate.
     /// @custom:selector e6861713
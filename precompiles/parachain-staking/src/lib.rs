@@ -28,11 +28,17 @@ use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::sp_runtime::Percent;
 use frame_support::traits::{Currency, Get};
 use pallet_evm::AddressMapping;
+use pallet_parachain_staking::RewardDestination;
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
 
+/// Reward destination kinds as exposed in the Solidity interface.
+pub const REWARD_DESTINATION_DELEGATOR: u8 = 0;
+pub const REWARD_DESTINATION_ACCOUNT: u8 = 1;
+pub const REWARD_DESTINATION_CANDIDATE: u8 = 2;
+
 type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
 >>::Balance;
@@ -844,6 +850,96 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("rewardDestination(address)")]
+	#[precompile::view]
+	fn reward_destination(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+	) -> EvmResult<(u8, Address)> {
+		// DelegatorRewardDestination:
+		// Twox64Concat(8) + AccountId(20) + RewardDestination(1 + AccountId(20))
+		handle.record_db_read::<Runtime>(49)?;
+
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+
+		let destination =
+			<pallet_parachain_staking::Pallet<Runtime>>::reward_destination(&delegator);
+
+		Ok(match destination {
+			RewardDestination::Delegator => (REWARD_DESTINATION_DELEGATOR, Address(H160::zero())),
+			RewardDestination::Account(payee) => {
+				(REWARD_DESTINATION_ACCOUNT, Address(payee.into()))
+			}
+			RewardDestination::Candidate(candidate) => {
+				(REWARD_DESTINATION_CANDIDATE, Address(candidate.into()))
+			}
+		})
+	}
+
+	#[precompile::public("setRewardDestination(uint8,address)")]
+	fn set_reward_destination(
+		handle: &mut impl PrecompileHandle,
+		kind: u8,
+		target: Address,
+	) -> EvmResult {
+		let target = Runtime::AddressMapping::into_account_id(target.0);
+		let destination = match kind {
+			REWARD_DESTINATION_DELEGATOR => RewardDestination::Delegator,
+			REWARD_DESTINATION_ACCOUNT => RewardDestination::Account(target),
+			REWARD_DESTINATION_CANDIDATE => RewardDestination::Candidate(target),
+			_ => {
+				return Err(RevertReason::custom("Unknown reward destination kind")
+					.in_field("kind")
+					.into())
+			}
+		};
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call =
+			pallet_parachain_staking::Call::<Runtime>::set_reward_destination { destination };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("roundSummary(uint32)")]
+	#[precompile::view]
+	fn round_summary(handle: &mut impl PrecompileHandle, round: u32) -> EvmResult<RoundSummary> {
+		// RoundSummaries: Twox64Concat(8) + RoundIndex(4) + RoundSummary(36)
+		handle.record_db_read::<Runtime>(48)?;
+
+		let summary = match <pallet_parachain_staking::Pallet<Runtime>>::round_summary(round) {
+			Some(summary) => summary,
+			None => return Ok(RoundSummary::default()),
+		};
+
+		// CollatorRoundSummaries, one per selected candidate at most:
+		// Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(20)
+		// + CollatorRoundSummary(20)
+		handle.record_db_read::<Runtime>(
+			60 * (<Runtime as pallet_parachain_staking::Config>::MaxCandidates::get() as usize),
+		)?;
+
+		Ok(RoundSummary {
+			is_valid: true,
+			round_issuance: summary.round_issuance.into(),
+			total_staking_reward: summary.total_staking_reward.into(),
+			total_points: summary.total_points,
+			collators: pallet_parachain_staking::CollatorRoundSummaries::<Runtime>::iter_prefix(
+				round,
+			)
+			.map(|(collator, summary)| CollatorRoundSummary {
+				collator: Address(collator.into()),
+				points: summary.points,
+				total_paid: summary.total_paid.into(),
+			})
+			.collect(),
+		})
+	}
+
 	#[precompile::public("getDelegatorTotalStaked(address)")]
 	#[precompile::view]
 	fn get_delegator_total_staked(
@@ -890,3 +986,19 @@ where
 			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
 	}
 }
+
+#[derive(Debug, Eq, PartialEq, solidity::Codec)]
+pub struct CollatorRoundSummary {
+	collator: Address,
+	points: u32,
+	total_paid: U256,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct RoundSummary {
+	is_valid: bool,
+	round_issuance: U256,
+	total_staking_reward: U256,
+	total_points: u32,
+	collators: Vec<CollatorRoundSummary>,
+}
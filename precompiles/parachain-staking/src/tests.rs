@@ -22,7 +22,7 @@ use core::str::from_utf8;
 use frame_support::assert_ok;
 use frame_support::sp_runtime::Percent;
 use pallet_evm::Call as EvmCall;
-use pallet_parachain_staking::Event as StakingEvent;
+use pallet_parachain_staking::{Event as StakingEvent, RewardDestination};
 use precompile_utils::{prelude::*, testing::*};
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
@@ -81,6 +81,7 @@ fn selectors() {
 	assert!(PCall::cancel_delegation_request_selectors().contains(&0xc90eee83));
 	assert!(PCall::get_delegator_total_staked_selectors().contains(&0xe6861713));
 	assert!(PCall::get_candidate_total_counted_selectors().contains(&0xbc5a1043));
+	assert!(PCall::round_summary_selectors().contains(&0x7d99533e));
 }
 
 #[test]
@@ -121,6 +122,7 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::cancel_delegation_request_selectors());
 		tester.test_view_modifier(PCall::get_delegator_total_staked_selectors());
 		tester.test_view_modifier(PCall::get_candidate_total_counted_selectors());
+		tester.test_view_modifier(PCall::round_summary_selectors());
 	});
 }
 
@@ -1620,6 +1622,141 @@ fn set_auto_compound_fails_if_not_delegation() {
 		});
 }
 
+#[test]
+fn reward_destination_returns_delegator_if_not_set() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::reward_destination {
+						delegator: Address(Bob.into()),
+					},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns((0u8, Address(H160::zero())));
+		})
+}
+
+#[test]
+fn set_reward_destination_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let input_data = PCall::set_reward_destination {
+				kind: 1,
+				target: Address(Charlie.into()),
+			}
+			.into();
+
+			// Make sure the call goes through successfully
+			assert_ok!(RuntimeCall::Evm(evm_call(Bob, input_data)).dispatch(RuntimeOrigin::root()));
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::RewardDestinationSet {
+				delegator: Bob.into(),
+				destination: RewardDestination::Account(Charlie.into()),
+			}
+			.into();
+			// Assert that the events vector contains the one expected
+			assert!(events().contains(&expected));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::reward_destination {
+						delegator: Address(Bob.into()),
+					},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns((1u8, Address(Charlie.into())));
+		});
+}
+
+#[test]
+fn set_reward_destination_returns_error_if_unknown_kind() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Bob.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::set_reward_destination {
+						kind: 3,
+						target: Address(Alice.into()),
+					},
+				)
+				.execute_reverts(|output| {
+					from_utf8(&output)
+						.unwrap()
+						.contains("kind: Unknown reward destination kind")
+				});
+		});
+}
+
+#[test]
+fn round_summary_returns_invalid_if_not_archived() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::round_summary { round: 1 })
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(crate::RoundSummary::default());
+	});
+}
+
+#[test]
+fn round_summary_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		pallet_parachain_staking::RoundSummaries::<Runtime>::insert(
+			1,
+			pallet_parachain_staking::RoundSummary {
+				round_issuance: 100,
+				total_staking_reward: 70,
+				total_points: 40,
+			},
+		);
+		pallet_parachain_staking::CollatorRoundSummaries::<Runtime>::insert(
+			1,
+			crate::mock::AccountId::from(Alice),
+			pallet_parachain_staking::CollatorRoundSummary {
+				points: 40,
+				total_paid: 70,
+			},
+		);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::round_summary { round: 1 })
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(crate::RoundSummary {
+				is_valid: true,
+				round_issuance: 100.into(),
+				total_staking_reward: 70.into(),
+				total_points: 40,
+				collators: vec![crate::CollatorRoundSummary {
+					collator: Address(Alice.into()),
+					points: 40,
+					total_paid: 70.into(),
+				}],
+			});
+	});
+}
+
 #[test]
 fn get_delegator_total_staked_getter() {
 	ExtBuilder::default()
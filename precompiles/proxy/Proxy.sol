@@ -90,4 +90,21 @@ interface Proxy {
         ProxyType proxyType,
         uint32 delay
     ) external view returns (bool exists);
+
+    /// @dev Register a time-limited proxy account for the sender, allowed to execute
+    /// Ethereum transactions on its behalf through XCM. A deposit is reserved from the sender.
+    /// @custom:selector 1cff9287
+    /// @param delegate The account that the caller would like to make a proxy
+    /// @param proxyType The permissions allowed for this proxy account
+    /// @param duration The number of blocks after which the proxy expires
+    function addXcmProxy(
+        address delegate,
+        ProxyType proxyType,
+        uint32 duration
+    ) external;
+
+    /// @dev Removes a time-limited proxy account from the sender and releases its deposit
+    /// @custom:selector 85be7785
+    /// @param delegate The account that the caller would like to remove as a proxy
+    function removeXcmProxy(address delegate) external;
 }
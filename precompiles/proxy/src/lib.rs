@@ -20,6 +20,7 @@ use account::SYSTEM_ACCOUNT_SIZE;
 use evm::ExitReason;
 use fp_evm::{Context, PrecompileFailure, PrecompileHandle, Transfer};
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_balances::Call as BalancesCall;
 use pallet_evm::AddressMapping;
 use pallet_proxy::Call as ProxyCall;
@@ -32,6 +33,7 @@ use sp_runtime::{
 	traits::{ConstU32, Dispatchable, StaticLookup, Zero},
 };
 use sp_std::marker::PhantomData;
+use xcm_primitives::ManageXcmProxies;
 
 #[cfg(test)]
 pub mod mock;
@@ -61,7 +63,7 @@ where
 		match selector {
 			None => false,
 			Some(selector) => {
-				ProxyPrecompileCall::<Runtime>::is_proxy_selectors().contains(&selector)
+				ProxyPrecompileCall::<Runtime, ()>::is_proxy_selectors().contains(&selector)
 			}
 		}
 	}
@@ -94,16 +96,23 @@ where
 		match selector {
 			None => false,
 			Some(selector) => {
-				ProxyPrecompileCall::<Runtime>::is_proxy_selectors().contains(&selector)
-					|| ProxyPrecompileCall::<Runtime>::proxy_selectors().contains(&selector)
-					|| ProxyPrecompileCall::<Runtime>::proxy_force_type_selectors()
+				ProxyPrecompileCall::<Runtime, ()>::is_proxy_selectors().contains(&selector)
+					|| ProxyPrecompileCall::<Runtime, ()>::proxy_selectors().contains(&selector)
+					|| ProxyPrecompileCall::<Runtime, ()>::proxy_force_type_selectors()
+						.contains(&selector)
+					// Smart contract wallets manage the proxies acting on their behalf via xcm
+					|| ProxyPrecompileCall::<Runtime, ()>::add_xcm_proxy_selectors()
+						.contains(&selector)
+					|| ProxyPrecompileCall::<Runtime, ()>::remove_xcm_proxy_selectors()
 						.contains(&selector)
 			}
 		}
 	}
 
 	fn description() -> String {
-		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type'".into()
+		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type', \
+		'add_xcm_proxy', 'remove_xcm_proxy'"
+			.into()
 	}
 }
 
@@ -132,12 +141,15 @@ pub trait EvmProxyCallFilter: Sized + Send + Sync {
 	}
 }
 
-/// A precompile to wrap the functionality from pallet-proxy.
-pub struct ProxyPrecompile<Runtime>(PhantomData<Runtime>);
+/// A precompile to wrap the functionality from pallet-proxy, and the registration of the
+/// time-limited proxies usable through xcm.
+pub struct ProxyPrecompile<Runtime, XcmProxies = ()>(PhantomData<(Runtime, XcmProxies)>);
 
 #[precompile_utils::precompile]
-impl<Runtime> ProxyPrecompile<Runtime>
+impl<Runtime, XcmProxies> ProxyPrecompile<Runtime, XcmProxies>
 where
+	XcmProxies:
+		ManageXcmProxies<<Runtime as pallet_proxy::Config>::ProxyType, BlockNumberFor<Runtime>>,
 	Runtime:
 		pallet_proxy::Config + pallet_evm::Config + frame_system::Config + pallet_balances::Config,
 	<<Runtime as pallet_proxy::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
@@ -350,6 +362,61 @@ where
 		Ok(is_proxy)
 	}
 
+	/// Register a time-limited proxy for the sender, allowed to execute Ethereum transactions on
+	/// its behalf from another chain through xcm. A deposit is reserved from the sender.
+	///
+	/// Parameters:
+	/// * delegate: The account that the caller would like to make a proxy, usually the account
+	///   derived from a remote location.
+	/// * proxy_type: The permissions allowed for this proxy account.
+	/// * duration: The number of blocks after which the proxy expires.
+	#[precompile::public("addXcmProxy(address,uint8,uint32)")]
+	fn add_xcm_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: Address,
+		proxy_type: u8,
+		duration: u32,
+	) -> EvmResult {
+		let proxy_type = Runtime::ProxyType::decode(&mut proxy_type.to_le_bytes().as_slice())
+			.map_err(|_| {
+				RevertReason::custom("Failed decoding value to ProxyType").in_field("proxyType")
+			})?;
+
+		// XcmProxies: Blake2128(16) + H160(20) + Blake2128(16) + H160(20) + Definition(21)
+		handle.record_db_read::<Runtime>(93)?;
+		// Reserve of the deposit
+		handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+		handle.record_cost(2 * RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		XcmProxies::add_xcm_proxy(
+			handle.context().caller,
+			delegate.into(),
+			proxy_type,
+			duration.into(),
+		)
+		.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		Ok(())
+	}
+
+	/// Unregister a time-limited proxy of the sender and release its deposit.
+	///
+	/// Parameters:
+	/// * delegate: The account that the caller would like to remove as a proxy.
+	#[precompile::public("removeXcmProxy(address)")]
+	fn remove_xcm_proxy(handle: &mut impl PrecompileHandle, delegate: Address) -> EvmResult {
+		// XcmProxies: Blake2128(16) + H160(20) + Blake2128(16) + H160(20) + Definition(21)
+		handle.record_db_read::<Runtime>(93)?;
+		// Unreserve of the deposit
+		handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+		handle.record_cost(2 * RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		XcmProxies::remove_xcm_proxy(handle.context().caller, delegate.into())
+			.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		Ok(())
+	}
+
 	fn inner_proxy(
 		handle: &mut impl PrecompileHandle,
 		real: Address,
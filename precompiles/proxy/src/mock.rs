@@ -37,7 +37,7 @@ use sp_io;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::{
 	codec::{Decode, Encode, MaxEncodedLen},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
 
 pub type AccountId = MockAccount;
@@ -114,7 +114,7 @@ pub type Precompiles<R> = PrecompileSetBuilder<
 	(
 		PrecompileAt<
 			AddressU64<1>,
-			ProxyPrecompile<R>,
+			ProxyPrecompile<R, MockXcmProxies>,
 			(
 				SubcallWithMaxNesting<1>,
 				CallableByContract<crate::OnlyIsProxyAndProxy<R>>,
@@ -126,7 +126,7 @@ pub type Precompiles<R> = PrecompileSetBuilder<
 	),
 >;
 
-pub type PCall = ProxyPrecompileCall<Runtime>;
+pub type PCall = ProxyPrecompileCall<Runtime, MockXcmProxies>;
 
 pub struct EnsureAddressAlways;
 impl<OuterOrigin> EnsureAddressOrigin<OuterOrigin> for EnsureAddressAlways {
@@ -262,6 +262,38 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositFactor = ();
 }
 
+parameter_types! {
+	pub static XcmProxies: Vec<(H160, H160, ProxyType, u32)> = vec![];
+}
+
+/// Keeps the xcm proxies registered through the precompile in memory.
+pub struct MockXcmProxies;
+impl xcm_primitives::ManageXcmProxies<ProxyType, u32> for MockXcmProxies {
+	fn add_xcm_proxy(
+		delegator: H160,
+		delegate: H160,
+		proxy_type: ProxyType,
+		duration: u32,
+	) -> DispatchResult {
+		let mut proxies = XcmProxies::get();
+		proxies.retain(|(d, p, _, _)| (*d, *p) != (delegator, delegate));
+		proxies.push((delegator, delegate, proxy_type, duration));
+		XcmProxies::set(proxies);
+		Ok(())
+	}
+
+	fn remove_xcm_proxy(delegator: H160, delegate: H160) -> DispatchResult {
+		let mut proxies = XcmProxies::get();
+		let len = proxies.len();
+		proxies.retain(|(d, p, _, _)| (*d, *p) != (delegator, delegate));
+		if proxies.len() == len {
+			return Err(DispatchError::Other("XcmProxyNotFound"));
+		}
+		XcmProxies::set(proxies);
+		Ok(())
+	}
+}
+
 /// Build test externalities, prepopulated with data for testing democracy precompiles
 pub(crate) struct ExtBuilder {
 	/// Endowed accounts with balances
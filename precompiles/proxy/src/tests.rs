@@ -18,7 +18,7 @@ use crate::{
 	assert_event_emitted, assert_event_not_emitted,
 	mock::{
 		AccountId, ExtBuilder, PCall, PrecompilesValue, ProxyType, Runtime, RuntimeCall,
-		RuntimeEvent, RuntimeOrigin,
+		RuntimeEvent, RuntimeOrigin, XcmProxies,
 	},
 };
 use frame_support::assert_ok;
@@ -59,6 +59,8 @@ fn selectors() {
 	assert!(PCall::proxy_selectors().contains(&0x0d3cff86));
 	assert!(PCall::proxy_force_type_selectors().contains(&0x4a36b2cd));
 	assert!(PCall::is_proxy_selectors().contains(&0xe26d38ed));
+	assert!(PCall::add_xcm_proxy_selectors().contains(&0x1cff9287));
+	assert!(PCall::remove_xcm_proxy_selectors().contains(&0x85be7785));
 }
 
 #[test]
@@ -73,6 +75,8 @@ fn modifiers() {
 		tester.test_payable_modifier(PCall::proxy_selectors());
 		tester.test_payable_modifier(PCall::proxy_force_type_selectors());
 		tester.test_view_modifier(PCall::is_proxy_selectors());
+		tester.test_default_modifier(PCall::add_xcm_proxy_selectors());
+		tester.test_default_modifier(PCall::remove_xcm_proxy_selectors());
 	});
 }
 
@@ -856,6 +860,119 @@ fn proxy_proxy_should_fail_if_called_by_smart_contract_for_a_non_eoa_account() {
 		})
 }
 
+#[test]
+fn test_add_xcm_proxy_succeeds() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::add_xcm_proxy {
+						delegate: Address(Bob.into()),
+						proxy_type: ProxyType::Something as u8,
+						duration: 10,
+					},
+				)
+				.execute_returns(());
+
+			assert_eq!(
+				XcmProxies::get(),
+				vec![(Alice.into(), Bob.into(), ProxyType::Something, 10)]
+			);
+		})
+}
+
+#[test]
+fn test_remove_xcm_proxy_fails_if_proxy_not_exist() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::remove_xcm_proxy {
+						delegate: Address(Bob.into()),
+					},
+				)
+				.execute_reverts(|o| o == b"XcmProxyNotFound");
+		})
+}
+
+#[test]
+fn test_remove_xcm_proxy_succeeds() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::add_xcm_proxy {
+						delegate: Address(Bob.into()),
+						proxy_type: ProxyType::Any as u8,
+						duration: 10,
+					},
+				)
+				.execute_returns(());
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::remove_xcm_proxy {
+						delegate: Address(Bob.into()),
+					},
+				)
+				.execute_returns(());
+
+			assert!(XcmProxies::get().is_empty());
+		})
+}
+
+#[test]
+fn test_xcm_proxies_can_be_managed_by_smart_contract() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// Set code to Alice address as it if was a smart contract.
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from(Alice), vec![10u8]);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::add_xcm_proxy {
+						delegate: Address(Bob.into()),
+						proxy_type: ProxyType::Something as u8,
+						duration: 10,
+					},
+				)
+				.execute_returns(());
+			assert_eq!(
+				XcmProxies::get(),
+				vec![(Alice.into(), Bob.into(), ProxyType::Something, 10)]
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::remove_xcm_proxy {
+						delegate: Address(Bob.into()),
+					},
+				)
+				.execute_returns(());
+			assert!(XcmProxies::get().is_empty());
+		})
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["Proxy.sol"], PCall::supports_selector)
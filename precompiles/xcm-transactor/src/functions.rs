@@ -21,9 +21,11 @@ use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	traits::ConstU32,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_evm::AddressMapping;
 use pallet_xcm_transactor::{
-	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactWeights,
+	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactRefund, TransactStatus,
+	TransactStatusInfo, TransactWeights,
 };
 use precompile_utils::prelude::*;
 use sp_core::{MaxEncodedLen, H160, U256};
@@ -38,7 +40,8 @@ use sp_weights::Weight;
 use xcm::latest::prelude::*;
 use xcm::latest::Location;
 use xcm_primitives::{
-	AccountIdToCurrencyId, UtilityAvailableCalls, UtilityEncodeCall, DEFAULT_PROOF_SIZE,
+	AccountIdToCurrencyId, EthereumXcmTransaction, EthereumXcmTransactionV3, TransactionAction,
+	UtilityAvailableCalls, UtilityEncodeCall, DEFAULT_PROOF_SIZE,
 };
 
 /// A precompile to wrap the functionality from xcm transactor
@@ -143,6 +146,49 @@ where
 		Ok(fee_per_second.into())
 	}
 
+	pub(crate) fn transact_status(
+		handle: &mut impl PrecompileHandle,
+		query_id: u64,
+	) -> EvmResult<u8> {
+		// fetch data from pallet
+		// storage item: TransactStatusQueries: Twox64(8) + QueryId(8) + TransactStatusInfo
+		handle.record_db_read::<Runtime>(Self::transact_status_info_max_encoded_len())?;
+		let info = pallet_xcm_transactor::Pallet::<Runtime>::transact_status(query_id)
+			.ok_or(revert("Transact status query not found"))?;
+
+		Ok(
+			match info.status_at(frame_system::Pallet::<Runtime>::block_number()) {
+				TransactStatus::Pending => 0,
+				TransactStatus::Succeeded => 1,
+				TransactStatus::Failed => 2,
+				TransactStatus::TimedOut => 3,
+			},
+		)
+	}
+
+	fn transact_status_info_max_encoded_len() -> usize {
+		16 + TransactStatusInfo::<Runtime::AccountId, BlockNumberFor<Runtime>>::max_encoded_len()
+	}
+
+	/// Dispatches a transact reporting its status, returning the id of the status query
+	fn dispatch_transact_with_report(
+		handle: &mut impl PrecompileHandle,
+		origin: Runtime::AccountId,
+		call: pallet_xcm_transactor::Call<Runtime>,
+	) -> EvmResult<u64> {
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			call,
+			Self::transact_status_info_max_encoded_len() as u64,
+		)?;
+
+		// storage item: LastTransactStatusQuery: QueryId(8)
+		handle.record_db_read::<Runtime>(8)?;
+		pallet_xcm_transactor::LastTransactStatusQuery::<Runtime>::get()
+			.ok_or(revert("Transact status query not registered"))
+	}
+
 	pub(crate) fn transact_through_derivative_multilocation(
 		handle: &mut impl PrecompileHandle,
 		transactor: u8,
@@ -539,6 +585,50 @@ where
 		Ok(encoded)
 	}
 
+	pub(crate) fn encode_ethereum_xcm_transact_v3(
+		handle: &mut impl PrecompileHandle,
+		pallet_index: u8,
+		transact_as: Option<Address>,
+		callee: Address,
+		value: U256,
+		input: BoundedBytes<GetDataLimit>,
+		gas_limit: U256,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: U256,
+		use_nonce: bool,
+		nonce: U256,
+		deadline: u32,
+		revert_on_failure: bool,
+	) -> EvmResult<UnboundedBytes> {
+		// There is no DB read in this function,
+		// we just account an arbitrary amount of gas to prevent spam
+		// TODO replace by proper benchmarks
+		handle.record_cost(1000)?;
+
+		let input: Vec<u8> = input.into();
+		let xcm_transaction = EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+			gas_limit,
+			action: TransactionAction::Call(callee.into()),
+			value,
+			input: input
+				.try_into()
+				.map_err(|_| RevertReason::value_is_too_large("input").in_field("input"))?,
+			access_list: None,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			nonce: if use_nonce { Some(nonce) } else { None },
+			// A zero deadline means the transaction does not expire
+			deadline: if deadline == 0 { None } else { Some(deadline) },
+			revert_on_failure,
+		});
+
+		let encoded = xcm_transaction
+			.encode_call(pallet_index, transact_as.map(Into::into))
+			.as_slice()
+			.into();
+		Ok(encoded)
+	}
+
 	pub(crate) fn transact_info_with_signed_v3(
 		handle: &mut impl PrecompileHandle,
 		multilocation: Location,
@@ -759,4 +849,285 @@ where
 
 		Ok(())
 	}
+
+	pub(crate) fn transact_through_derivative_multilocation_refund_to_origin_v3(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: Location,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+	) -> EvmResult {
+		let transactor = transactor
+			.try_into()
+			.map_err(|_| RevertReason::custom("Non-existent transactor").in_field("transactor"))?;
+
+		let inner_call: Vec<_> = inner_call.into();
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call =
+			pallet_xcm_transactor::Call::<Runtime>::transact_through_derivative_with_refund {
+				dest: transactor,
+				index,
+				fee: CurrencyPayment {
+					currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::V4(
+						fee_asset,
+					))),
+					fee_amount: Some(fee_amount),
+				},
+				inner_call,
+				weight_info: TransactWeights {
+					transact_required_weight_at_most: weight,
+					overall_weight: Some(overall_weight_limit),
+				},
+				refund: TransactRefund::ToOrigin,
+			};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transact_through_derivative_refund_to_origin_v3(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: Address,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+	) -> EvmResult {
+		// No DB access before try_dispatch but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amoun of gas
+		handle.record_cost(1000)?;
+
+		let transactor = transactor
+			.try_into()
+			.map_err(|_| RevertReason::custom("Non-existent transactor").in_field("transactor"))?;
+		let inner_call: Vec<_> = inner_call.into();
+
+		let to_address: H160 = fee_asset.into();
+		let to_account = Runtime::AddressMapping::into_account_id(to_address);
+
+		// We convert the address into a currency
+		let currency_id: <Runtime as pallet_xcm_transactor::Config>::CurrencyId =
+			Runtime::account_to_currency_id(to_account)
+				.ok_or(revert("cannot convert into currency id"))?;
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call =
+			pallet_xcm_transactor::Call::<Runtime>::transact_through_derivative_with_refund {
+				dest: transactor,
+				index,
+				fee: CurrencyPayment {
+					currency: Currency::AsCurrencyId(currency_id),
+					fee_amount: Some(fee_amount),
+				},
+				inner_call,
+				weight_info: TransactWeights {
+					transact_required_weight_at_most: weight,
+					overall_weight: Some(overall_weight_limit),
+				},
+				refund: TransactRefund::ToOrigin,
+			};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transact_through_signed_multilocation_refund_to_origin_v3(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Location,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+	) -> EvmResult {
+		let call: Vec<_> = call.into();
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::transact_through_signed_with_refund {
+			dest: Box::new(xcm::VersionedLocation::V4(dest)),
+			fee: CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::V4(
+					fee_asset,
+				))),
+				fee_amount: Some(fee_amount),
+			},
+			call,
+			weight_info: TransactWeights {
+				transact_required_weight_at_most: weight,
+				overall_weight: Some(overall_weight_limit),
+			},
+			refund: TransactRefund::ToOrigin,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transact_through_signed_refund_to_origin_v3(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Address,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+	) -> EvmResult {
+		// No DB access before try_dispatch but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amoun of gas
+		handle.record_cost(1000)?;
+
+		let to_address: H160 = fee_asset.into();
+		let to_account = Runtime::AddressMapping::into_account_id(to_address);
+
+		let call: Vec<_> = call.into();
+
+		// We convert the address into a currency
+		let currency_id: <Runtime as pallet_xcm_transactor::Config>::CurrencyId =
+			Runtime::account_to_currency_id(to_account)
+				.ok_or(revert("cannot convert into currency id"))?;
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::transact_through_signed_with_refund {
+			dest: Box::new(xcm::VersionedLocation::V4(dest)),
+			fee: CurrencyPayment {
+				currency: Currency::AsCurrencyId(currency_id),
+				fee_amount: Some(fee_amount),
+			},
+			call,
+			weight_info: TransactWeights {
+				transact_required_weight_at_most: weight,
+				overall_weight: Some(overall_weight_limit),
+			},
+			refund: TransactRefund::ToOrigin,
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transact_through_signed_multilocation_with_report_v3(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Location,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+		refund: bool,
+	) -> EvmResult<u64> {
+		let call: Vec<_> = call.into();
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::transact_through_signed_with_report {
+			dest: Box::new(xcm::VersionedLocation::from(dest)),
+			fee: CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::from(
+					fee_asset,
+				))),
+				fee_amount: Some(fee_amount),
+			},
+			weight_info: TransactWeights {
+				transact_required_weight_at_most: weight,
+				overall_weight: Some(overall_weight_limit),
+			},
+			refund,
+			call,
+		};
+
+		Self::dispatch_transact_with_report(handle, origin, call)
+	}
+
+	pub(crate) fn transact_through_signed_with_report_v3(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Address,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+		refund: bool,
+	) -> EvmResult<u64> {
+		// No DB access before try_dispatch but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amoun of gas
+		handle.record_cost(1000)?;
+
+		let to_address: H160 = fee_asset.into();
+		let to_account = Runtime::AddressMapping::into_account_id(to_address);
+
+		let call: Vec<_> = call.into();
+
+		// We convert the address into a currency
+		let currency_id: <Runtime as pallet_xcm_transactor::Config>::CurrencyId =
+			Runtime::account_to_currency_id(to_account)
+				.ok_or(revert("cannot convert into currency id"))?;
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_xcm_transactor::Call::<Runtime>::transact_through_signed_with_report {
+			dest: Box::new(xcm::VersionedLocation::from(dest)),
+			fee: CurrencyPayment {
+				currency: Currency::AsCurrencyId(currency_id),
+				fee_amount: Some(fee_amount),
+			},
+			weight_info: TransactWeights {
+				transact_required_weight_at_most: weight,
+				overall_weight: Some(overall_weight_limit),
+			},
+			refund,
+			call,
+		};
+
+		Self::dispatch_transact_with_report(handle, origin, call)
+	}
 }
@@ -328,6 +328,14 @@ parameter_types! {
 	};
 }
 
+/// Charges one unit of any asset per unit of ref time
+pub struct WeightToAssetFee;
+impl xcm_primitives::WeightToAssetFee for WeightToAssetFee {
+	fn weight_to_asset_fee(weight: Weight, _asset: &Location) -> Option<u128> {
+		Some(weight.ref_time() as u128)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -348,6 +356,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = frame_system::EnsureRoot<AccountId>;
 	type HrmpOpenOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxHrmpFee = ();
+	type HrmpAutomationTransactWeight = ();
+	type WeightToAssetFee = WeightToAssetFee;
+	type NotifyQueryHandler = ();
+	type ResponseOrigin = frame_support::traits::NeverEnsureOrigin<Location>;
+	type TransactStatusQueryTimeout = ();
 }
 
 // We need to use the encoding from the relay mock runtime
@@ -20,9 +20,10 @@ use crate::mock::{
 
 use frame_support::{assert_ok, weights::Weight};
 use precompile_utils::{prelude::*, testing::*};
-use sp_core::H160;
+use sp_core::{H160, U256};
 use sp_std::boxed::Box;
 use xcm::latest::Location;
+use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV3, TransactionAction};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
@@ -54,6 +55,28 @@ fn selectors() {
 	assert!(PCallV3::transact_through_derivative_selectors().contains(&0xca8c82d8));
 	assert!(PCallV3::transact_through_signed_multilocation_selectors().contains(&0x27b1d492));
 	assert!(PCallV3::transact_through_signed_selectors().contains(&0xb18270cf));
+	assert!(PCallV3::transact_status_selectors().contains(&0x30015ade));
+	assert!(
+		PCallV3::transact_through_signed_multilocation_with_report_selectors()
+			.contains(&0x641298e1)
+	);
+	assert!(PCallV3::transact_through_signed_with_report_selectors().contains(&0x684e072d));
+	assert!(
+		PCallV3::transact_through_derivative_multilocation_refund_to_origin_selectors()
+			.contains(&0xd72ca3ed)
+	);
+	assert!(
+		PCallV3::transact_through_derivative_refund_to_origin_selectors().contains(&0x737e49b5)
+	);
+	assert!(
+		PCallV3::transact_through_signed_multilocation_refund_to_origin_selectors()
+			.contains(&0x40625007)
+	);
+	assert!(PCallV3::transact_through_signed_refund_to_origin_selectors().contains(&0x75faf92c));
+	assert!(PCallV3::encode_ethereum_xcm_transact_v3_selectors().contains(&0x22d3891f));
+	assert!(
+		PCallV3::encode_ethereum_xcm_transact_through_proxy_v3_selectors().contains(&0xf40484e1)
+	);
 }
 
 #[test]
@@ -792,6 +815,235 @@ fn test_transact_through_signed_multilocation_v3() {
 		});
 }
 
+#[test]
+fn test_transact_derivative_multilocation_refund_to_origin_v3() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// register index
+			assert_ok!(XcmTransactor::register(
+				RuntimeOrigin::root(),
+				Alice.into(),
+				0
+			));
+
+			// we pay with our current self reserve.
+			let fee_payer_asset = Location::parent();
+
+			let bytes = vec![1u8, 2u8, 3u8];
+
+			let total_weight = Weight::from_parts(1_000_000_000u64, 82_000u64);
+			let require_weight_at_most = Weight::from_parts(4_000_000u64, 82_000u64);
+			// The surplus of the fees is sent back to Alice in this chain
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_through_derivative_multilocation_refund_to_origin {
+						transactor: 0,
+						index: 0,
+						fee_asset: fee_payer_asset,
+						weight: require_weight_at_most,
+						inner_call: bytes.into(),
+						fee_amount: u128::from(total_weight.ref_time()).into(),
+						overall_weight: total_weight,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn test_transact_through_signed_multilocation_refund_to_origin_v3() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// we pay with our current self reserve.
+			let fee_payer_asset = Location::parent();
+
+			let bytes = vec![1u8, 2u8, 3u8];
+
+			let total_weight = Weight::from_parts(1_000_000_000u64, 82_000u64);
+			let require_weight_at_most = Weight::from_parts(4_000_000u64, 82_000u64);
+			// The surplus of the fees is sent back to Alice in this chain
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_through_signed_multilocation_refund_to_origin {
+						dest: Location::parent(),
+						fee_asset: fee_payer_asset,
+						weight: require_weight_at_most,
+						call: bytes.into(),
+						fee_amount: u128::from(total_weight.ref_time()).into(),
+						overall_weight: total_weight,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn take_transact_status() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			pallet_xcm_transactor::TransactStatusQueries::<Runtime>::insert(
+				0,
+				pallet_xcm_transactor::TransactStatusInfo {
+					owner: Some(Alice.into()),
+					dest: Location::parent(),
+					status: pallet_xcm_transactor::TransactStatus::Succeeded,
+					timeout: 10,
+				},
+			);
+			pallet_xcm_transactor::TransactStatusQueries::<Runtime>::insert(
+				2,
+				pallet_xcm_transactor::TransactStatusInfo {
+					owner: Some(Alice.into()),
+					dest: Location::parent(),
+					status: pallet_xcm_transactor::TransactStatus::Pending,
+					timeout: 0,
+				},
+			);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_status { query_id: 0 },
+				)
+				.expect_no_logs()
+				.execute_returns(1u8);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_status { query_id: 1 },
+				)
+				.execute_reverts(|output| output == b"Transact status query not found");
+
+			// Pending past its timeout
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_status { query_id: 2 },
+				)
+				.expect_no_logs()
+				.execute_returns(3u8);
+		});
+}
+
+#[test]
+fn test_transact_signed_multilocation_with_report_returns_query_id() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// we pay with our current self reserve.
+			let fee_payer_asset = Location::parent();
+
+			let bytes = vec![1u8, 2u8, 3u8];
+
+			let total_weight = Weight::from_parts(1_000_000_000u64, 82_000u64);
+			let require_weight_at_most = Weight::from_parts(4_000_000u64, 82_000u64);
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_through_signed_multilocation_with_report {
+						dest: Location::parent(),
+						fee_asset: fee_payer_asset,
+						weight: require_weight_at_most,
+						call: bytes.into(),
+						fee_amount: u128::from(total_weight.ref_time()).into(),
+						overall_weight: total_weight,
+						refund: false,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(0u64);
+
+			assert_eq!(
+				pallet_xcm_transactor::TransactStatusQueries::<Runtime>::get(0)
+					.map(|info| info.owner),
+				Some(Some(Alice.into()))
+			);
+		});
+}
+
+#[test]
+fn test_encode_ethereum_xcm_transact_v3() {
+	ExtBuilder::default().build().execute_with(|| {
+		let xcm_transaction = EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+			gas_limit: U256::from(100_000),
+			action: TransactionAction::Call(Bob.into()),
+			value: U256::one(),
+			input: vec![1u8, 2u8].try_into().unwrap(),
+			access_list: None,
+			max_fee_per_gas: U256::from(10),
+			max_priority_fee_per_gas: U256::one(),
+			nonce: Some(U256::from(3)),
+			deadline: Some(50),
+			revert_on_failure: true,
+		});
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				TransactorV3,
+				PCallV3::encode_ethereum_xcm_transact_v3 {
+					pallet_index: 109,
+					callee: Address(Bob.into()),
+					value: U256::one(),
+					input: vec![1u8, 2u8].into(),
+					gas_limit: U256::from(100_000),
+					max_fee_per_gas: U256::from(10),
+					max_priority_fee_per_gas: U256::one(),
+					use_nonce: true,
+					nonce: U256::from(3),
+					deadline: 50,
+					revert_on_failure: true,
+				},
+			)
+			.expect_cost(1000)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(xcm_transaction.encode_call(109, None)));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				TransactorV3,
+				PCallV3::encode_ethereum_xcm_transact_through_proxy_v3 {
+					pallet_index: 109,
+					transact_as: Address(Charlie.into()),
+					callee: Address(Bob.into()),
+					value: U256::one(),
+					input: vec![1u8, 2u8].into(),
+					gas_limit: U256::from(100_000),
+					max_fee_per_gas: U256::from(10),
+					max_priority_fee_per_gas: U256::one(),
+					use_nonce: true,
+					nonce: U256::from(3),
+					deadline: 50,
+					revert_on_failure: true,
+				},
+			)
+			.expect_cost(1000)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(
+				xcm_transaction.encode_call(109, Some(Charlie.into())),
+			));
+	});
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented_v1() {
 	check_precompile_implements_solidity_interfaces(
@@ -59,6 +59,16 @@ interface XcmTransactorV3 {
         view
         returns (uint256 feePerSecond);
 
+    /// Get the status of a transact whose result is reported back to this chain
+    /// @custom:selector 30015ade
+    /// @param queryId The query id under which the destination chain reports the transact
+    /// @return status The status of the transact: 0 pending, 1 succeeded, 2 failed, 3 timed out
+    ///
+    function transactStatus(uint64 queryId)
+        external
+        view
+        returns (uint8 status);
+
     /// Transact through XCM using fee based on its multilocation
     /// @custom:selector bdacc26b
     /// @dev The token transfer burns/transfers the corresponding amount before sending
@@ -155,6 +165,152 @@ interface XcmTransactorV3 {
         bool refund
     ) external;
 
+    /// Transact through XCM using fee based on its multilocation, refunding the surplus
+    /// of the fees back to the caller in this chain
+    /// @custom:selector d72ca3ed
+    /// @dev The token transfer burns/transfers the corresponding amount before sending
+    /// @param transactor The transactor to be used
+    /// @param index The index to be used
+    /// @param feeAsset The asset in which we want to pay fees.
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain
+    /// @param innerCall The inner call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    function transactThroughDerivativeMultilocationRefundToOrigin(
+        uint8 transactor,
+        uint16 index,
+        Multilocation memory feeAsset,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory innerCall,
+        uint256 feeAmount,
+        Weight memory overallWeight
+    ) external;
+
+    /// Transact through XCM using fee based on its currency_id, refunding the surplus
+    /// of the fees back to the caller in this chain
+    /// @custom:selector 737e49b5
+    /// @dev The token transfer burns/transfers the corresponding amount before sending
+    /// @param transactor The transactor to be used
+    /// @param index The index to be used
+    /// @param currencyId Address of the currencyId of the asset to be used for fees
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain
+    /// @param innerCall The inner call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    function transactThroughDerivativeRefundToOrigin(
+        uint8 transactor,
+        uint16 index,
+        address currencyId,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory innerCall,
+        uint256 feeAmount,
+        Weight memory overallWeight
+    ) external;
+
+    /// Transact through XCM using fee based on its multilocation through signed origins,
+    /// refunding the surplus of the fees back to the caller in this chain
+    /// @custom:selector 40625007
+    /// @dev No token is burnt before sending the message. The caller must ensure the destination
+    /// is able to undertand the DescendOrigin message, and create a unique account from which
+    /// dispatch the call
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param feeLocation The asset multilocation that indentifies the fee payment currency
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain for the call to be made
+    /// @param call The call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    function transactThroughSignedMultilocationRefundToOrigin(
+        Multilocation memory dest,
+        Multilocation memory feeLocation,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory call,
+        uint256 feeAmount,
+        Weight memory overallWeight
+    ) external;
+
+    /// Transact through XCM using fee based on its erc20 address through signed origins,
+    /// refunding the surplus of the fees back to the caller in this chain
+    /// @custom:selector 75faf92c
+    /// @dev No token is burnt before sending the message. The caller must ensure the destination
+    /// is able to undertand the DescendOrigin message, and create a unique account from which
+    /// dispatch the call
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param feeLocationAddress The ERC20 address of the token we want to use to pay for fees
+    /// only callable if such an asset has been BRIDGED to our chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain for the call to be made
+    /// @param call The call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    function transactThroughSignedRefundToOrigin(
+        Multilocation memory dest,
+        address feeLocationAddress,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory call,
+        uint256 feeAmount,
+        Weight memory overallWeight
+    ) external;
+
+    /// Transact through XCM using fee based on its multilocation through signed origins,
+    /// reporting the result of the transact back to this chain
+    /// @custom:selector 641298e1
+    /// @dev No token is burnt before sending the message. The caller must ensure the destination
+    /// is able to undertand the DescendOrigin message, and create a unique account from which
+    /// dispatch the call
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param feeLocation The asset multilocation that indentifies the fee payment currency
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain for the call to be made
+    /// @param call The call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message, including the
+    /// reporting instructions. If uint64:MAX is passed through refTime field, Unlimited variant
+    /// will be used.
+    /// @param refund Indicates if RefundSurplus instruction will be appended
+    /// @return queryId The query id to pass to transactStatus
+    function transactThroughSignedMultilocationWithReport(
+        Multilocation memory dest,
+        Multilocation memory feeLocation,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory call,
+        uint256 feeAmount,
+        Weight memory overallWeight,
+        bool refund
+    ) external returns (uint64 queryId);
+
+    /// Transact through XCM using fee based on its erc20 address through signed origins,
+    /// reporting the result of the transact back to this chain
+    /// @custom:selector 684e072d
+    /// @dev No token is burnt before sending the message. The caller must ensure the destination
+    /// is able to undertand the DescendOrigin message, and create a unique account from which
+    /// dispatch the call
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param feeLocationAddress The ERC20 address of the token we want to use to pay for fees
+    /// only callable if such an asset has been BRIDGED to our chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain for the call to be made
+    /// @param call The call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message, including the
+    /// reporting instructions. If uint64:MAX is passed through refTime field, Unlimited variant
+    /// will be used.
+    /// @param refund Indicates if RefundSurplus instruction will be appended
+    /// @return queryId The query id to pass to transactStatus
+    function transactThroughSignedWithReport(
+        Multilocation memory dest,
+        address feeLocationAddress,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory call,
+        uint256 feeAmount,
+        Weight memory overallWeight,
+        bool refund
+    ) external returns (uint64 queryId);
+
     /// @dev Encode 'utility.as_derivative' relay call
     /// @custom:selector ff86378d
     /// @param transactor The transactor to be used
@@ -165,4 +321,64 @@ interface XcmTransactorV3 {
         external
         pure
         returns (bytes memory result);
+
+    /// @dev Encode 'ethereumXcm.transact' call with a V3 ethereum xcm transaction
+    /// @custom:selector 22d3891f
+    /// @param palletIndex The index of the ethereum xcm pallet in the destination runtime
+    /// @param callee The address of the account or contract to call
+    /// @param value The value to be transferred
+    /// @param input The input data for the contract call
+    /// @param gasLimit The gas limit of the evm execution
+    /// @param maxFeePerGas The EIP-1559 max fee per gas, zero if paid by the xcm executor
+    /// @param maxPriorityFeePerGas The EIP-1559 max priority fee per gas
+    /// @param useNonce Whether the transaction must match the sender's current nonce
+    /// @param nonce The expected sender's nonce, ignored if useNonce is false
+    /// @param deadline The block after which the transaction is rejected, zero for no deadline
+    /// @param revertOnFailure Whether a failed evm execution makes the call fail, so it can be
+    /// reported back through ReportTransactStatus
+    /// @return result The bytes associated with the encoded call
+    function encodeEthereumXcmTransactV3(
+        uint8 palletIndex,
+        address callee,
+        uint256 value,
+        bytes memory input,
+        uint256 gasLimit,
+        uint256 maxFeePerGas,
+        uint256 maxPriorityFeePerGas,
+        bool useNonce,
+        uint256 nonce,
+        uint32 deadline,
+        bool revertOnFailure
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'ethereumXcm.transact_through_proxy' call with a V3 ethereum xcm transaction
+    /// @custom:selector f40484e1
+    /// @param palletIndex The index of the ethereum xcm pallet in the destination runtime
+    /// @param transactAs The proxied account the transaction is applied as
+    /// @param callee The address of the account or contract to call
+    /// @param value The value to be transferred
+    /// @param input The input data for the contract call
+    /// @param gasLimit The gas limit of the evm execution
+    /// @param maxFeePerGas The EIP-1559 max fee per gas, zero if paid by the xcm executor
+    /// @param maxPriorityFeePerGas The EIP-1559 max priority fee per gas
+    /// @param useNonce Whether the transaction must match transactAs current nonce
+    /// @param nonce The expected transactAs nonce, ignored if useNonce is false
+    /// @param deadline The block after which the transaction is rejected, zero for no deadline
+    /// @param revertOnFailure Whether a failed evm execution makes the call fail, so it can be
+    /// reported back through ReportTransactStatus
+    /// @return result The bytes associated with the encoded call
+    function encodeEthereumXcmTransactThroughProxyV3(
+        uint8 palletIndex,
+        address transactAs,
+        address callee,
+        uint256 value,
+        bytes memory input,
+        uint256 gasLimit,
+        uint256 maxFeePerGas,
+        uint256 maxPriorityFeePerGas,
+        bool useNonce,
+        uint256 nonce,
+        uint32 deadline,
+        bool revertOnFailure
+    ) external pure returns (bytes memory result);
 }
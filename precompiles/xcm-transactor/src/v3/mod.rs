@@ -64,6 +64,12 @@ where
 		XcmTransactorWrapper::<Runtime>::fee_per_second(handle, location)
 	}
 
+	#[precompile::public("transactStatus(uint64)")]
+	#[precompile::view]
+	fn transact_status(handle: &mut impl PrecompileHandle, query_id: u64) -> EvmResult<u8> {
+		XcmTransactorWrapper::<Runtime>::transact_status(handle, query_id)
+	}
+
 	#[precompile::public(
 		"transactThroughDerivativeMultilocation(\
 		uint8,\
@@ -198,6 +204,192 @@ where
 		)
 	}
 
+	#[precompile::public(
+		"transactThroughDerivativeMultilocationRefundToOrigin(\
+		uint8,\
+		uint16,\
+		(uint8,bytes[]),\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64))"
+	)]
+	fn transact_through_derivative_multilocation_refund_to_origin(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: Location,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+	) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::transact_through_derivative_multilocation_refund_to_origin_v3(
+			handle,
+			transactor,
+			index,
+			fee_asset,
+			weight,
+			inner_call,
+			fee_amount.converted(),
+			overall_weight,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughDerivativeRefundToOrigin(\
+		uint8,\
+		uint16,\
+		address,\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64))"
+	)]
+	fn transact_through_derivative_refund_to_origin(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: Address,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+	) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::transact_through_derivative_refund_to_origin_v3(
+			handle,
+			transactor,
+			index,
+			fee_asset,
+			weight,
+			inner_call,
+			fee_amount.converted(),
+			overall_weight,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughSignedMultilocationRefundToOrigin(\
+		(uint8,bytes[]),\
+		(uint8,bytes[]),\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64))"
+	)]
+	fn transact_through_signed_multilocation_refund_to_origin(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Location,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+	) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::transact_through_signed_multilocation_refund_to_origin_v3(
+			handle,
+			dest,
+			fee_asset,
+			weight,
+			call,
+			fee_amount.converted(),
+			overall_weight,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughSignedRefundToOrigin(\
+		(uint8,bytes[]),\
+		address,\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64))"
+	)]
+	fn transact_through_signed_refund_to_origin(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Address,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+	) -> EvmResult {
+		XcmTransactorWrapper::<Runtime>::transact_through_signed_refund_to_origin_v3(
+			handle,
+			dest,
+			fee_asset,
+			weight,
+			call,
+			fee_amount.converted(),
+			overall_weight,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughSignedMultilocationWithReport(\
+		(uint8,bytes[]),\
+		(uint8,bytes[]),\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64),\
+		bool)"
+	)]
+	fn transact_through_signed_multilocation_with_report(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Location,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+		refund: bool,
+	) -> EvmResult<u64> {
+		XcmTransactorWrapper::<Runtime>::transact_through_signed_multilocation_with_report_v3(
+			handle,
+			dest,
+			fee_asset,
+			weight,
+			call,
+			fee_amount.converted(),
+			overall_weight,
+			refund,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughSignedWithReport(\
+		(uint8,bytes[]),\
+		address,\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64),\
+		bool)"
+	)]
+	fn transact_through_signed_with_report(
+		handle: &mut impl PrecompileHandle,
+		dest: Location,
+		fee_asset: Address,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+		refund: bool,
+	) -> EvmResult<u64> {
+		XcmTransactorWrapper::<Runtime>::transact_through_signed_with_report_v3(
+			handle,
+			dest,
+			fee_asset,
+			weight,
+			call,
+			fee_amount.converted(),
+			overall_weight,
+			refund,
+		)
+	}
+
 	#[precompile::public("encodeUtilityAsDerivative(uint8,uint16,bytes)")]
 	#[precompile::public("encode_utility_as_derivative(uint8,uint16,bytes)")]
 	#[precompile::view]
@@ -211,4 +403,98 @@ where
 			handle, transactor, index, inner_call,
 		)
 	}
+
+	#[precompile::public(
+		"encodeEthereumXcmTransactV3(\
+		uint8,\
+		address,\
+		uint256,\
+		bytes,\
+		uint256,\
+		uint256,\
+		uint256,\
+		bool,\
+		uint256,\
+		uint32,\
+		bool)"
+	)]
+	#[precompile::view]
+	fn encode_ethereum_xcm_transact_v3(
+		handle: &mut impl PrecompileHandle,
+		pallet_index: u8,
+		callee: Address,
+		value: U256,
+		input: BoundedBytes<GetDataLimit>,
+		gas_limit: U256,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: U256,
+		use_nonce: bool,
+		nonce: U256,
+		deadline: u32,
+		revert_on_failure: bool,
+	) -> EvmResult<UnboundedBytes> {
+		XcmTransactorWrapper::<Runtime>::encode_ethereum_xcm_transact_v3(
+			handle,
+			pallet_index,
+			None,
+			callee,
+			value,
+			input,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			use_nonce,
+			nonce,
+			deadline,
+			revert_on_failure,
+		)
+	}
+
+	#[precompile::public(
+		"encodeEthereumXcmTransactThroughProxyV3(\
+		uint8,\
+		address,\
+		address,\
+		uint256,\
+		bytes,\
+		uint256,\
+		uint256,\
+		uint256,\
+		bool,\
+		uint256,\
+		uint32,\
+		bool)"
+	)]
+	#[precompile::view]
+	fn encode_ethereum_xcm_transact_through_proxy_v3(
+		handle: &mut impl PrecompileHandle,
+		pallet_index: u8,
+		transact_as: Address,
+		callee: Address,
+		value: U256,
+		input: BoundedBytes<GetDataLimit>,
+		gas_limit: U256,
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: U256,
+		use_nonce: bool,
+		nonce: U256,
+		deadline: u32,
+		revert_on_failure: bool,
+	) -> EvmResult<UnboundedBytes> {
+		XcmTransactorWrapper::<Runtime>::encode_ethereum_xcm_transact_v3(
+			handle,
+			pallet_index,
+			Some(transact_as),
+			callee,
+			value,
+			input,
+			gas_limit,
+			max_fee_per_gas,
+			max_priority_fee_per_gas,
+			use_nonce,
+			nonce,
+			deadline,
+			revert_on_failure,
+		)
+	}
 }
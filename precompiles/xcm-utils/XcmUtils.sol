@@ -43,6 +43,20 @@ interface XcmUtils {
         view
         returns (uint256 unitsPerSecond);
 
+    /// Estimate the fees of executing a message locally and delivering a message to a destination
+    /// @custom:selector 822f3658
+    /// @param message scale encoded versioned xcm message to execute locally
+    /// @param dest The destination to which the remote message is delivered
+    /// @param remoteMessage scale encoded versioned xcm message to deliver, empty if none
+    /// @param feeAsset The asset in which the fees are paid
+    /// @return fees The local execution plus delivery fees, in the fee asset
+    function estimateXcmFees(
+        bytes memory message,
+        Multilocation memory dest,
+        bytes memory remoteMessage,
+        Multilocation memory feeAsset
+    ) external view returns (uint256 fees);
+
     /// Execute custom xcm message
     /// @dev This function CANNOT be called from a smart contract
     /// @custom:selector 34334a02
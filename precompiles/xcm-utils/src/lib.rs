@@ -19,7 +19,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use fp_evm::PrecompileHandle;
-use frame_support::traits::ConstU32;
+use frame_support::traits::{ConstU32, Get};
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	traits::OriginTrait,
@@ -35,7 +35,10 @@ use sp_std::marker::PhantomData;
 use sp_std::vec;
 use sp_std::vec::Vec;
 use sp_weights::Weight;
-use xcm::{latest::prelude::*, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use xcm::{
+	latest::prelude::*, IntoVersion, VersionedAssetId, VersionedAssets, VersionedXcm,
+	MAX_XCM_DECODE_DEPTH,
+};
 use xcm_executor::traits::ConvertOrigin;
 use xcm_executor::traits::WeightBounds;
 use xcm_executor::traits::WeightTrader;
@@ -62,7 +65,10 @@ pub struct AllExceptXcmExecute<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConf
 
 impl<Runtime, XcmConfig> SelectorFilter for AllExceptXcmExecute<Runtime, XcmConfig>
 where
-	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
+	Runtime: pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_xcm::Config
+		+ pallet_xcm_weight_trader::Config,
 	XcmOriginOf<XcmConfig>: OriginTrait,
 	XcmAccountIdOf<XcmConfig>: Into<H160>,
 	XcmConfig: xcm_executor::Config,
@@ -94,7 +100,10 @@ pub struct XcmUtilsPrecompile<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConfi
 #[precompile_utils::precompile]
 impl<Runtime, XcmConfig> XcmUtilsPrecompile<Runtime, XcmConfig>
 where
-	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
+	Runtime: pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_xcm::Config
+		+ pallet_xcm_weight_trader::Config,
 	XcmOriginOf<XcmConfig>: OriginTrait,
 	XcmAccountIdOf<XcmConfig>: Into<H160>,
 	XcmConfig: xcm_executor::Config,
@@ -204,6 +213,61 @@ where
 		Ok(result?.ref_time())
 	}
 
+	#[precompile::public("estimateXcmFees(bytes,(uint8,bytes[]),bytes,(uint8,bytes[]))")]
+	#[precompile::view]
+	fn estimate_xcm_fees(
+		handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+		dest: Location,
+		remote_message: BoundedBytes<GetXcmSizeLimit>,
+		fee_asset: Location,
+	) -> EvmResult<U256> {
+		// storage item: XcmWeightTrader::SupportedAssets
+		// max encoded len: hash (16) + Multilocation + bool (1) + u128 (16)
+		handle.record_db_read::<Runtime>(33 + Location::max_encoded_len())?;
+
+		// Fee of the local execution of the message
+		let message: Vec<u8> = message.into();
+		let mut xcm =
+			VersionedXcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::decode_all_with_depth_limit(
+				MAX_XCM_DECODE_DEPTH,
+				&mut message.as_slice(),
+			)
+			.map(Xcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::try_from)
+			.map_err(|_| RevertReason::custom("Failed decoding").in_field("message"))?
+			.map_err(|_| RevertReason::custom("Failed decoding").in_field("message"))?;
+		let weight =
+			XcmConfig::Weigher::weight(&mut xcm).map_err(|_| revert("failed weighting"))?;
+		let local_fee = pallet_xcm_weight_trader::Pallet::<Runtime>::query_weight_to_asset_fee(
+			weight,
+			VersionedAssetId::V4(AssetId(fee_asset.clone())),
+		)
+		.map_err(|_| {
+			RevertReason::custom("Asset not supported as fee payment").in_field("feeAsset")
+		})?;
+
+		// Fee of the delivery of the remote message, if any
+		let remote_message: Vec<u8> = remote_message.into();
+		if remote_message.is_empty() {
+			return Ok(local_fee.into());
+		}
+		let remote_xcm = VersionedXcm::<()>::decode_all_with_depth_limit(
+			MAX_XCM_DECODE_DEPTH,
+			&mut remote_message.as_slice(),
+		)
+		.map_err(|_| RevertReason::custom("Failed decoding").in_field("remoteMessage"))?;
+
+		// storage item: XcmpQueue::DeliveryFeeFactor
+		// max encoded len: hash (16) + ParaId (4) + FixedU128 (16)
+		handle.record_db_read::<Runtime>(36)?;
+		let delivery_fees =
+			pallet_xcm::Pallet::<Runtime>::query_delivery_fees(dest.into(), remote_xcm)
+				.map_err(|_| revert("Failed to compute delivery fees"))?;
+		let delivery_fee = Self::delivery_fee_in_asset(delivery_fees, &fee_asset)?;
+
+		Ok(U256::from(local_fee).saturating_add(delivery_fee.into()))
+	}
+
 	#[precompile::public("xcmExecute(bytes,uint64)")]
 	fn xcm_execute(
 		handle: &mut impl PrecompileHandle,
@@ -257,4 +321,44 @@ where
 
 		Ok(())
 	}
+
+	/// Sum of the delivery fees, converted into `fee_asset` when they are paid in the native
+	/// currency.
+	fn delivery_fee_in_asset(fees: VersionedAssets, fee_asset: &Location) -> EvmResult<u128> {
+		let fees: Assets = fees
+			.into_version(4)
+			.and_then(TryInto::try_into)
+			.map_err(|_| revert("Failed to convert delivery fees"))?;
+		let native_location = <Runtime as pallet_xcm_weight_trader::Config>::NativeLocation::get();
+
+		fees.into_inner().into_iter().try_fold(0u128, |total, fee| {
+			let amount = match (fee.id, fee.fun) {
+				(AssetId(location), Fungible(amount)) if location == *fee_asset => amount,
+				(AssetId(location), Fungible(amount)) if location == native_location => {
+					let relative_price =
+						pallet_xcm_weight_trader::Pallet::<Runtime>::get_asset_relative_price(
+							fee_asset,
+						)
+						.ok_or(
+							RevertReason::custom("Asset not supported as fee payment")
+								.in_field("feeAsset"),
+						)?;
+					if relative_price == 0 {
+						0
+					} else {
+						amount
+							.checked_mul(
+								10u128.pow(pallet_xcm_weight_trader::RELATIVE_PRICE_DECIMALS),
+							)
+							.map(|amount| amount / relative_price)
+							.ok_or(revert("Delivery fees overflow"))?
+					}
+				}
+				_ => return Err(revert("Delivery fees not payable in this asset")),
+			};
+			total
+				.checked_add(amount)
+				.ok_or(revert("Delivery fees overflow"))
+		})
+	}
 }
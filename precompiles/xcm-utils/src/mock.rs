@@ -57,6 +57,7 @@ construct_runtime!(
 		Evm: pallet_evm,
 		Timestamp: pallet_timestamp,
 		PolkadotXcm: pallet_xcm,
+		XcmWeightTrader: pallet_xcm_weight_trader,
 	}
 );
 
@@ -232,6 +233,37 @@ impl pallet_xcm::Config for Runtime {
 	type RemoteLockConsumerIdentifier = ();
 	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
 }
+
+parameter_types! {
+	pub XcmFeesAccount: AccountId = SelfReserveAccount.into();
+	pub const MaxPriceFeeders: u32 = 3;
+	pub MaxPriceDeviation: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+	pub const MinPriceFeedersQuorum: u32 = 1;
+	pub const PriceStalenessThreshold: u32 = 10;
+}
+impl pallet_xcm_weight_trader::Config for Runtime {
+	type AccountIdToLocation = AccountIdToLocation;
+	type AddSupportedAssetOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetLocationFilter = Everything;
+	type AssetTransactor = DummyAssetTransactor;
+	type Balance = Balance;
+	type EditSupportedAssetOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxPriceFeeders = MaxPriceFeeders;
+	type MinPriceFeedersQuorum = MinPriceFeedersQuorum;
+	type NativeLocation = SelfReserve;
+	type PauseSupportedAssetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PriceStalenessThreshold = PriceStalenessThreshold;
+	type RemoveSupportedAssetOrigin = frame_system::EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type ResumeSupportedAssetOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type WeightToFee = frame_support::weights::IdentityFee<Balance>;
+	type XcmFeesAccount = XcmFeesAccount;
+	#[cfg(feature = "runtime-benchmarks")]
+	type NotFilteredLocation = RelayLocation;
+}
+
 pub type Precompiles<R> = PrecompileSetBuilder<
 	R,
 	(
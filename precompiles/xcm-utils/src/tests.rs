@@ -16,7 +16,7 @@
 
 use crate::mock::{
 	sent_xcm, AccountId, Balances, ExtBuilder, PCall, ParentAccount, Precompiles, PrecompilesValue,
-	Runtime, SiblingParachainAccount, System,
+	Runtime, SiblingParachainAccount, System, XcmWeightTrader,
 };
 use frame_support::{traits::PalletInfo, weights::Weight};
 use parity_scale_codec::Encode;
@@ -33,6 +33,7 @@ fn test_selector_enum() {
 	assert!(PCall::multilocation_to_address_selectors().contains(&0x343b3e00));
 	assert!(PCall::weight_message_selectors().contains(&0x25d54154));
 	assert!(PCall::get_units_per_second_selectors().contains(&0x3f0f65db));
+	assert!(PCall::estimate_xcm_fees_selectors().contains(&0x822f3658));
 }
 
 #[test]
@@ -43,6 +44,7 @@ fn modifiers() {
 		tester.test_view_modifier(PCall::multilocation_to_address_selectors());
 		tester.test_view_modifier(PCall::weight_message_selectors());
 		tester.test_view_modifier(PCall::get_units_per_second_selectors());
+		tester.test_view_modifier(PCall::estimate_xcm_fees_selectors());
 	});
 }
 
@@ -116,6 +118,70 @@ fn test_get_units_per_second() {
 	});
 }
 
+#[test]
+fn test_estimate_xcm_fees() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The relay asset is worth half of the native asset
+		XcmWeightTrader::set_asset_price(Location::parent(), 2 * 10u128.pow(18));
+
+		let message: Vec<u8> =
+			xcm::VersionedXcm::<()>::V4(Xcm(vec![ClearOrigin, ClearOrigin])).encode();
+
+		// Local execution only
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::estimate_xcm_fees {
+					message: message.clone().into(),
+					dest: Location::parent(),
+					remote_message: Vec::<u8>::new().into(),
+					fee_asset: Location::parent(),
+				},
+			)
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(U256::from(1_000u128));
+
+		// Local execution and delivery (free in the mock router)
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::estimate_xcm_fees {
+					message: message.clone().into(),
+					dest: Location::parent(),
+					remote_message: message.into(),
+					fee_asset: Location::parent(),
+				},
+			)
+			.expect_cost(2)
+			.expect_no_logs()
+			.execute_returns(U256::from(1_000u128));
+	});
+}
+
+#[test]
+fn test_estimate_xcm_fees_unsupported_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message: Vec<u8> = xcm::VersionedXcm::<()>::V4(Xcm(vec![ClearOrigin])).encode();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::estimate_xcm_fees {
+					message: message.into(),
+					dest: Location::parent(),
+					remote_message: Vec::<u8>::new().into(),
+					fee_asset: Location::parent(),
+				},
+			)
+			.expect_no_logs()
+			.execute_reverts(|output| output == b"feeAsset: Asset not supported as fee payment");
+	});
+}
+
 #[test]
 fn test_executor_clear_origin() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -371,6 +371,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = frame_system::EnsureRoot<AccountId>;
 	type HrmpOpenOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxHrmpFee = ();
+	type HrmpAutomationTransactWeight = ();
+	type WeightToAssetFee = ();
+	type NotifyQueryHandler = ();
+	type ResponseOrigin = frame_support::traits::NeverEnsureOrigin<Location>;
+	type TransactStatusQueryTimeout = ();
 }
 
 pub struct XcmConfig;
@@ -0,0 +1,75 @@
+// Copyright 2025 Moonbeam Foundation.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API resolving the assets known by the runtime, whatever the registry they live in:
+//! the legacy assets of pallet-asset-manager, the evm foreign assets of
+//! pallet-moonbeam-foreign-assets and the erc20 tokens bridged by pallet-erc20-xcm-bridge.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Decode, Encode};
+use sp_core::H160;
+use sp_runtime::scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::VersionedLocation;
+
+/// The registry an asset is registered in
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum AssetKind {
+	/// Legacy foreign asset of pallet-asset-manager, backed by pallet-assets
+	LegacyForeignAsset,
+	/// Foreign asset of pallet-moonbeam-foreign-assets, backed by an erc20 contract
+	ForeignAsset,
+	/// Erc20 contract deployed on the chain, transferable through pallet-erc20-xcm-bridge
+	Erc20,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum AssetStatus {
+	/// All operations are enabled
+	Active,
+	/// The asset is frozen, but deposit from XCM still work
+	FrozenXcmDepositAllowed,
+	/// The asset is frozen, and deposit from XCM will fail
+	FrozenXcmDepositForbidden,
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AssetRegistryEntry {
+	pub kind: AssetKind,
+	/// Location of the asset in xcm
+	pub location: VersionedLocation,
+	/// Address of the erc20 contract, or precompile, exposing the asset in the evm
+	pub address: H160,
+	pub decimals: u8,
+	pub symbol: Vec<u8>,
+	pub status: AssetStatus,
+	/// Whether the asset can be used to pay xcm execution fees
+	pub fee_payment_supported: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetRegistryApi {
+		/// Resolve an asset from its xcm location
+		fn asset_by_location(location: VersionedLocation) -> Option<AssetRegistryEntry>;
+		/// Resolve an asset from its evm address
+		fn asset_by_address(address: H160) -> Option<AssetRegistryEntry>;
+	}
+}
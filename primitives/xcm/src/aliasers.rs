@@ -0,0 +1,179 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::traits::{Contains, ContainsPair};
+use sp_std::marker::PhantomData;
+use xcm::latest::{Junction, Location};
+use xcm_executor::traits::ConvertLocation;
+
+/// Allows an `AccountKey20` account of a trusted sibling parachain to alias the local account
+/// with the same key. Only siblings sharing our account derivation (i.e. Ethereum-compatible
+/// chains) should be trusted, as the same key is then controlled by the same private key.
+pub struct AliasSiblingAccountKey20<TrustedSiblings>(PhantomData<TrustedSiblings>);
+impl<TrustedSiblings> ContainsPair<Location, Location> for AliasSiblingAccountKey20<TrustedSiblings>
+where
+	TrustedSiblings: Contains<u32>,
+{
+	fn contains(origin: &Location, target: &Location) -> bool {
+		match (origin.unpack(), target.unpack()) {
+			(
+				(
+					1,
+					[Junction::Parachain(para_id), Junction::AccountKey20 {
+						key: origin_key, ..
+					}],
+				),
+				(
+					0,
+					[Junction::AccountKey20 {
+						key: target_key, ..
+					}],
+				),
+			) => origin_key == target_key && TrustedSiblings::contains(para_id),
+			_ => false,
+		}
+	}
+}
+
+/// Allows the origins accepted by `Origins` to alias the local account derived from their
+/// location through `LocationToAccountId`.
+pub struct AliasToDerivedAccountKey20<Origins, LocationToAccountId, AccountId>(
+	PhantomData<(Origins, LocationToAccountId, AccountId)>,
+);
+impl<Origins, LocationToAccountId, AccountId> ContainsPair<Location, Location>
+	for AliasToDerivedAccountKey20<Origins, LocationToAccountId, AccountId>
+where
+	Origins: Contains<Location>,
+	LocationToAccountId: ConvertLocation<AccountId>,
+	AccountId: Into<[u8; 20]>,
+{
+	fn contains(origin: &Location, target: &Location) -> bool {
+		match target.unpack() {
+			(0, [Junction::AccountKey20 { key, .. }]) => {
+				Origins::contains(origin)
+					&& LocationToAccountId::convert_location(origin)
+						.map(|account| account.into() == *key)
+						.unwrap_or(false)
+			}
+			_ => false,
+		}
+	}
+}
+
+/// Matches the `AccountId32` accounts of the relay chain.
+pub struct IsRelayChainAccountId32;
+impl Contains<Location> for IsRelayChainAccountId32 {
+	fn contains(location: &Location) -> bool {
+		matches!(location.unpack(), (1, [Junction::AccountId32 { .. }]))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::traits::Everything;
+	use xcm::latest::prelude::*;
+
+	pub struct TrustedSiblings;
+	impl Contains<u32> for TrustedSiblings {
+		fn contains(para_id: &u32) -> bool {
+			*para_id == 2000
+		}
+	}
+
+	/// Derives the account from the first 20 bytes of an `AccountId32`
+	pub struct TruncateAccountId32;
+	impl ConvertLocation<[u8; 20]> for TruncateAccountId32 {
+		fn convert_location(location: &Location) -> Option<[u8; 20]> {
+			match location.unpack() {
+				(_, [AccountId32 { id, .. }]) => {
+					let mut account = [0u8; 20];
+					account.copy_from_slice(&id[..20]);
+					Some(account)
+				}
+				_ => None,
+			}
+		}
+	}
+
+	fn sibling_account(para_id: u32, key: [u8; 20]) -> Location {
+		Location::new(1, [Parachain(para_id), AccountKey20 { network: None, key }])
+	}
+
+	fn local_account(key: [u8; 20]) -> Location {
+		Location::new(0, [AccountKey20 { network: None, key }])
+	}
+
+	#[test]
+	fn trusted_sibling_account_can_alias_same_local_account() {
+		type Aliaser = AliasSiblingAccountKey20<TrustedSiblings>;
+		assert!(Aliaser::contains(
+			&sibling_account(2000, [1u8; 20]),
+			&local_account([1u8; 20])
+		));
+		// Different key
+		assert!(!Aliaser::contains(
+			&sibling_account(2000, [1u8; 20]),
+			&local_account([2u8; 20])
+		));
+		// Untrusted sibling
+		assert!(!Aliaser::contains(
+			&sibling_account(2001, [1u8; 20]),
+			&local_account([1u8; 20])
+		));
+		// The sibling chain itself
+		assert!(!Aliaser::contains(
+			&Location::new(1, [Parachain(2000)]),
+			&local_account([1u8; 20])
+		));
+	}
+
+	#[test]
+	fn origin_can_alias_derived_account() {
+		type Aliaser =
+			AliasToDerivedAccountKey20<IsRelayChainAccountId32, TruncateAccountId32, [u8; 20]>;
+		let relay_account = Location::new(
+			1,
+			[AccountId32 {
+				network: None,
+				id: [3u8; 32],
+			}],
+		);
+		assert!(Aliaser::contains(&relay_account, &local_account([3u8; 20])));
+		// Not the derived account
+		assert!(!Aliaser::contains(
+			&relay_account,
+			&local_account([4u8; 20])
+		));
+		// Not a relay chain account
+		assert!(!Aliaser::contains(
+			&Location::new(
+				0,
+				[AccountId32 {
+					network: None,
+					id: [3u8; 32],
+				}]
+			),
+			&local_account([3u8; 20])
+		));
+		// Target must be a local account
+		type AnyOrigin = AliasToDerivedAccountKey20<Everything, TruncateAccountId32, [u8; 20]>;
+		assert!(!AnyOrigin::contains(
+			&relay_account,
+			&sibling_account(2000, [3u8; 20])
+		));
+	}
+}
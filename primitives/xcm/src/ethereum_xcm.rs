@@ -14,16 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
+use alloc::{format, string::String};
 use ethereum::{
 	AccessList, AccessListItem, EIP1559Transaction, EIP2930Transaction, LegacyTransaction,
-	TransactionAction, TransactionSignature, TransactionV2,
+	TransactionSignature, TransactionV2,
 };
 use ethereum_types::{H160, H256, U256};
 use frame_support::{traits::ConstU32, BoundedVec};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, DispatchResult, SaturatedConversion};
 use sp_std::vec::Vec;
 
+pub use ethereum::TransactionAction;
+
 // polkadot/blob/19f6665a6162e68cd2651f5fe3615d6676821f90/xcm/src/v3/mod.rs#L1193
 // Defensively we increase this value to allow UMP fragments through xcm-transactor to prepare our
 // runtime for a relay upgrade where the xcm instruction weights are not ZERO hardcoded. If that
@@ -42,6 +46,39 @@ pub trait EnsureProxy<AccountId> {
 	fn ensure_ok(delegator: AccountId, delegatee: AccountId) -> Result<(), &'static str>;
 }
 
+/// Filter of the Ethereum transactions that a proxy registered through `ManageXcmProxies` is
+/// allowed to execute on behalf of its delegator.
+/// This trait should be implemented by the proxy type.
+pub trait XcmProxyCallFilter {
+	fn is_xcm_proxy_call_allowed(&self, transaction: &EthereumXcmTransaction) -> bool;
+}
+
+/// Registration of the time-limited proxies allowing `delegate` to execute, through
+/// `transact_through_proxy`, the Ethereum transactions of `delegator`.
+pub trait ManageXcmProxies<ProxyType, BlockNumber> {
+	/// Register `delegate` as a proxy of `delegator` for `duration` blocks, reserving a deposit
+	/// from `delegator`. An existing proxy between both accounts is replaced.
+	fn add_xcm_proxy(
+		delegator: H160,
+		delegate: H160,
+		proxy_type: ProxyType,
+		duration: BlockNumber,
+	) -> DispatchResult;
+
+	/// Unregister `delegate` as a proxy of `delegator` and release the deposit.
+	fn remove_xcm_proxy(delegator: H160, delegate: H160) -> DispatchResult;
+}
+
+impl<ProxyType, BlockNumber> ManageXcmProxies<ProxyType, BlockNumber> for () {
+	fn add_xcm_proxy(_: H160, _: H160, _: ProxyType, _: BlockNumber) -> DispatchResult {
+		Err(DispatchError::Other("XCM proxies are not supported"))
+	}
+
+	fn remove_xcm_proxy(_: H160, _: H160) -> DispatchResult {
+		Err(DispatchError::Other("XCM proxies are not supported"))
+	}
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
 /// Manually sets a gas fee.
 pub struct ManualEthereumXcmFee {
@@ -66,8 +103,78 @@ pub enum EthereumXcmFee {
 pub enum EthereumXcmTransaction {
 	V1(EthereumXcmTransactionV1),
 	V2(EthereumXcmTransactionV2),
+	V3(EthereumXcmTransactionV3),
+	Create2(EthereumXcmCreate2Transaction),
+}
+
+impl EthereumXcmTransaction {
+	/// SCALE-encodes this transaction as a `pallet-ethereum-xcm` call, ready to be sent in a
+	/// remote Transact. The call is `transact_through_proxy` if `transact_as` is set, `transact`
+	/// otherwise.
+	pub fn encode_call(&self, pallet_index: u8, transact_as: Option<H160>) -> Vec<u8> {
+		let mut encoded_call = Vec::new();
+		encoded_call.push(pallet_index);
+		match transact_as {
+			Some(transact_as) => {
+				encoded_call.push(ETHEREUM_XCM_TRANSACT_THROUGH_PROXY_CALL_INDEX);
+				encoded_call.append(&mut transact_as.encode());
+			}
+			None => encoded_call.push(ETHEREUM_XCM_TRANSACT_CALL_INDEX),
+		}
+		encoded_call.append(&mut self.encode());
+		encoded_call
+	}
+}
+
+impl EthereumXcmTransaction {
+	/// Gas limit to be consumed by EVM execution.
+	pub fn gas_limit(&self) -> U256 {
+		match self {
+			EthereumXcmTransaction::V1(v1_tx) => v1_tx.gas_limit,
+			EthereumXcmTransaction::V2(v2_tx) => v2_tx.gas_limit,
+			EthereumXcmTransaction::V3(v3_tx) => v3_tx.gas_limit,
+			EthereumXcmTransaction::Create2(create2_tx) => create2_tx.gas_limit,
+		}
+	}
+
+	/// Recipient, value and input of this transaction if it calls an account, `None` if it
+	/// deploys a contract.
+	pub fn call(&self) -> Option<(H160, U256, &[u8])> {
+		let (action, value, input) = match self {
+			EthereumXcmTransaction::V1(v1_tx) => (&v1_tx.action, v1_tx.value, &v1_tx.input),
+			EthereumXcmTransaction::V2(v2_tx) => (&v2_tx.action, v2_tx.value, &v2_tx.input),
+			EthereumXcmTransaction::V3(v3_tx) => (&v3_tx.action, v3_tx.value, &v3_tx.input),
+			EthereumXcmTransaction::Create2(_) => return None,
+		};
+		match action {
+			TransactionAction::Call(to) => Some((*to, value, &input[..])),
+			TransactionAction::Create => None,
+		}
+	}
+
+	/// Size of the init code if this transaction deploys a contract.
+	pub fn init_code_len(&self) -> Option<usize> {
+		match self {
+			EthereumXcmTransaction::V1(v1_tx) if v1_tx.action == TransactionAction::Create => {
+				Some(v1_tx.input.len())
+			}
+			EthereumXcmTransaction::V2(v2_tx) if v2_tx.action == TransactionAction::Create => {
+				Some(v2_tx.input.len())
+			}
+			EthereumXcmTransaction::V3(v3_tx) if v3_tx.action == TransactionAction::Create => {
+				Some(v3_tx.input.len())
+			}
+			EthereumXcmTransaction::Create2(create2_tx) => Some(create2_tx.init_code.len()),
+			_ => None,
+		}
+	}
 }
 
+/// Call index of `pallet-ethereum-xcm::transact`.
+pub const ETHEREUM_XCM_TRANSACT_CALL_INDEX: u8 = 0;
+/// Call index of `pallet-ethereum-xcm::transact_through_proxy`.
+pub const ETHEREUM_XCM_TRANSACT_THROUGH_PROXY_CALL_INDEX: u8 = 1;
+
 /// Value for `r` and `s` for the invalid signature included in Xcm transact's Ethereum transaction.
 pub fn rs_id() -> H256 {
 	H256::from_low_u64_be(1u64)
@@ -79,7 +186,7 @@ pub struct EthereumXcmTransactionV1 {
 	pub gas_limit: U256,
 	/// Fee configuration of choice.
 	pub fee_payment: EthereumXcmFee,
-	/// Either a Call (the callee, account or contract address) or Create (unsupported for v1).
+	/// Either a Call (the callee, account or contract address) or Create).
 	pub action: TransactionAction,
 	/// Value to be transfered.
 	pub value: U256,
@@ -103,39 +210,80 @@ pub struct EthereumXcmTransactionV2 {
 	pub access_list: Option<Vec<(H160, Vec<H256>)>>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct EthereumXcmTransactionV3 {
+	/// Gas limit to be consumed by EVM execution.
+	pub gas_limit: U256,
+	/// Either a Call (the callee, account or contract address) or Create).
+	pub action: TransactionAction,
+	/// Value to be transfered.
+	pub value: U256,
+	/// Input data for a contract call. Max. size 65_536 bytes.
+	pub input: BoundedVec<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>,
+	/// Map of addresses to be pre-paid to warm storage.
+	pub access_list: Option<Vec<(H160, Vec<H256>)>>,
+	/// Eip-1559 max fee per gas, must be at least the on-chain base fee at the time of
+	/// applying the xcm. Zero means the fee is paid by the xcm executor.
+	pub max_fee_per_gas: U256,
+	/// Eip-1559 max priority fee per gas. Must not be greater than `max_fee_per_gas`.
+	pub max_priority_fee_per_gas: U256,
+	/// If set, the transaction is rejected unless it matches the sender's current nonce.
+	pub nonce: Option<U256>,
+	/// If set, the transaction is rejected when applied after this block number.
+	pub deadline: Option<u32>,
+	/// If set, a failed EVM execution makes the call fail, so the failure can be reported back
+	/// to the origin through `ReportTransactStatus`.
+	pub revert_on_failure: bool,
+}
+
+/// Deterministic contract deployment, the contract address is derived from the deployer, the
+/// salt and the init code as the CREATE2 opcode does.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct EthereumXcmCreate2Transaction {
+	/// Gas limit to be consumed by EVM execution.
+	pub gas_limit: U256,
+	/// Value to be transfered to the created contract.
+	pub value: U256,
+	/// Salt used to derive the contract address.
+	pub salt: H256,
+	/// Contract init code. Max. size 65_536 bytes.
+	pub init_code: BoundedVec<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>,
+	/// Map of addresses to be pre-paid to warm storage.
+	pub access_list: Option<Vec<(H160, Vec<H256>)>>,
+}
+
+impl EthereumXcmCreate2Transaction {
+	/// Address of the contract deployed by `deployer`, as defined in EIP-1014.
+	pub fn contract_address(&self, deployer: H160) -> H160 {
+		let init_code_hash = sp_io::hashing::keccak_256(&self.init_code);
+		let mut preimage = Vec::with_capacity(1 + 20 + 32 + 32);
+		preimage.push(0xffu8);
+		preimage.extend_from_slice(deployer.as_bytes());
+		preimage.extend_from_slice(self.salt.as_bytes());
+		preimage.extend_from_slice(&init_code_hash);
+		H160::from_slice(&sp_io::hashing::keccak_256(&preimage)[12..])
+	}
+}
+
 pub trait XcmToEthereum {
-	fn into_transaction_v2(
-		&self,
-		nonce: U256,
-		chain_id: u64,
-		allow_create: bool,
-	) -> Option<TransactionV2>;
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2>;
 }
 
 impl XcmToEthereum for EthereumXcmTransaction {
-	fn into_transaction_v2(
-		&self,
-		nonce: U256,
-		chain_id: u64,
-		allow_create: bool,
-	) -> Option<TransactionV2> {
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2> {
 		match self {
-			EthereumXcmTransaction::V1(v1_tx) => {
-				v1_tx.into_transaction_v2(nonce, chain_id, allow_create)
-			}
-			EthereumXcmTransaction::V2(v2_tx) => {
-				v2_tx.into_transaction_v2(nonce, chain_id, allow_create)
+			EthereumXcmTransaction::V1(v1_tx) => v1_tx.into_transaction_v2(nonce, chain_id),
+			EthereumXcmTransaction::V2(v2_tx) => v2_tx.into_transaction_v2(nonce, chain_id),
+			EthereumXcmTransaction::V3(v3_tx) => v3_tx.into_transaction_v2(nonce, chain_id),
+			EthereumXcmTransaction::Create2(create2_tx) => {
+				create2_tx.into_transaction_v2(nonce, chain_id)
 			}
 		}
 	}
 }
 
 impl XcmToEthereum for EthereumXcmTransactionV1 {
-	fn into_transaction_v2(&self, nonce: U256, chain_id: u64, _: bool) -> Option<TransactionV2> {
-		// We dont support creates for now
-		if self.action == TransactionAction::Create {
-			return None;
-		}
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2> {
 		let from_tuple_to_access_list = |t: &Vec<(H160, Vec<H256>)>| -> AccessList {
 			t.iter()
 				.map(|item| AccessListItem {
@@ -209,16 +357,7 @@ impl XcmToEthereum for EthereumXcmTransactionV1 {
 }
 
 impl XcmToEthereum for EthereumXcmTransactionV2 {
-	fn into_transaction_v2(
-		&self,
-		nonce: U256,
-		chain_id: u64,
-		allow_create: bool,
-	) -> Option<TransactionV2> {
-		if !allow_create && self.action == TransactionAction::Create {
-			// Create not allowed
-			return None;
-		}
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2> {
 		let from_tuple_to_access_list = |t: &Vec<(H160, Vec<H256>)>| -> AccessList {
 			t.iter()
 				.map(|item| AccessListItem {
@@ -249,6 +388,100 @@ impl XcmToEthereum for EthereumXcmTransactionV2 {
 	}
 }
 
+impl XcmToEthereum for EthereumXcmTransactionV3 {
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2> {
+		if self.max_priority_fee_per_gas > self.max_fee_per_gas {
+			// Invalid Eip-1559 fee configuration
+			return None;
+		}
+		let from_tuple_to_access_list = |t: &Vec<(H160, Vec<H256>)>| -> AccessList {
+			t.iter()
+				.map(|item| AccessListItem {
+					address: item.0.clone(),
+					storage_keys: item.1.clone(),
+				})
+				.collect::<Vec<AccessListItem>>()
+		};
+		// Eip-1559
+		Some(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id,
+			nonce,
+			max_fee_per_gas: self.max_fee_per_gas,
+			max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+			gas_limit: self.gas_limit,
+			action: self.action,
+			value: self.value,
+			input: self.input.to_vec(),
+			access_list: if let Some(ref access_list) = self.access_list {
+				from_tuple_to_access_list(access_list)
+			} else {
+				Vec::new()
+			},
+			odd_y_parity: true,
+			r: rs_id(),
+			s: rs_id(),
+		}))
+	}
+}
+
+impl XcmToEthereum for EthereumXcmCreate2Transaction {
+	fn into_transaction_v2(&self, nonce: U256, chain_id: u64) -> Option<TransactionV2> {
+		let from_tuple_to_access_list = |t: &Vec<(H160, Vec<H256>)>| -> AccessList {
+			t.iter()
+				.map(|item| AccessListItem {
+					address: item.0.clone(),
+					storage_keys: item.1.clone(),
+				})
+				.collect::<Vec<AccessListItem>>()
+		};
+		// Eip-1559, the CREATE2 address is forced when applying the transaction
+		Some(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id,
+			nonce,
+			max_fee_per_gas: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			gas_limit: self.gas_limit,
+			action: TransactionAction::Create,
+			value: self.value,
+			input: self.init_code.to_vec(),
+			access_list: if let Some(ref access_list) = self.access_list {
+				from_tuple_to_access_list(access_list)
+			} else {
+				Vec::new()
+			},
+			odd_y_parity: true,
+			r: rs_id(),
+			s: rs_id(),
+		}))
+	}
+}
+
+/// Extracts the revert reason from the data returned by a reverted EVM execution.
+/// The data should contain a UTF-8 encoded revert reason with a minimum size consisting of:
+/// error function selector (4 bytes) + offset (32 bytes) + reason string length (32 bytes)
+pub fn extract_revert_message(data: &[u8]) -> String {
+	const LEN_START: usize = 36;
+	const MESSAGE_START: usize = 68;
+	const BASE_MESSAGE: &str = "VM Exception while processing transaction: revert";
+	// Return base message if data is too short
+	if data.len() <= MESSAGE_START {
+		return BASE_MESSAGE.into();
+	}
+	// Extract message length and calculate end position
+	let message_len = U256::from(&data[LEN_START..MESSAGE_START]).saturated_into::<usize>();
+	let message_end = MESSAGE_START.saturating_add(message_len);
+	// Return base message if data is shorter than expected message end
+	if data.len() < message_end {
+		return BASE_MESSAGE.into();
+	}
+	// Extract and decode the message
+	let body = &data[MESSAGE_START..message_end];
+	match core::str::from_utf8(body) {
+		Ok(reason) => format!("{BASE_MESSAGE} {reason}"),
+		Err(_) => BASE_MESSAGE.into(),
+	}
+}
+
 /// The EthereumXcmTracingStatus storage key.
 pub const ETHEREUM_XCM_TRACING_STORAGE_KEY: &[u8] = b":ethereum_xcm_tracing";
 
@@ -293,10 +526,7 @@ mod tests {
 			s: H256::from_low_u64_be(1u64),
 		}));
 
-		assert_eq!(
-			xcm_transaction.into_transaction_v2(nonce, 111, false),
-			expected_tx
-		);
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
 	}
 
 	#[test]
@@ -324,10 +554,7 @@ mod tests {
 			signature: TransactionSignature::new(42, rs_id(), rs_id()).unwrap(),
 		}));
 
-		assert_eq!(
-			xcm_transaction.into_transaction_v2(nonce, 111, false),
-			expected_tx
-		);
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
 	}
 	#[test]
 	fn test_eip_2930_v1() {
@@ -369,10 +596,7 @@ mod tests {
 			s: H256::from_low_u64_be(1u64),
 		}));
 
-		assert_eq!(
-			xcm_transaction.into_transaction_v2(nonce, 111, false),
-			expected_tx
-		);
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
 	}
 
 	#[test]
@@ -401,9 +625,190 @@ mod tests {
 			s: H256::from_low_u64_be(1u64),
 		}));
 
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
+	}
+
+	#[test]
+	fn test_eip1559_v3() {
+		let xcm_transaction = EthereumXcmTransactionV3 {
+			gas_limit: U256::one(),
+			action: TransactionAction::Call(H160::default()),
+			value: U256::zero(),
+			input: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![1u8])
+				.unwrap(),
+			access_list: None,
+			max_fee_per_gas: U256::from(2),
+			max_priority_fee_per_gas: U256::one(),
+			nonce: Some(U256::from(5)),
+			deadline: Some(10),
+			revert_on_failure: false,
+		};
+		let nonce = U256::zero();
+		let expected_tx = Some(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id: 111,
+			nonce,
+			max_fee_per_gas: U256::from(2),
+			max_priority_fee_per_gas: U256::one(),
+			gas_limit: U256::one(),
+			action: TransactionAction::Call(H160::default()),
+			value: U256::zero(),
+			input: vec![1u8],
+			access_list: vec![],
+			odd_y_parity: true,
+			r: H256::from_low_u64_be(1u64),
+			s: H256::from_low_u64_be(1u64),
+		}));
+
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
+	}
+
+	#[test]
+	fn test_eip1559_v3_priority_fee_above_max_fee() {
+		let xcm_transaction = EthereumXcmTransactionV3 {
+			gas_limit: U256::one(),
+			action: TransactionAction::Call(H160::default()),
+			value: U256::zero(),
+			input: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![1u8])
+				.unwrap(),
+			access_list: None,
+			max_fee_per_gas: U256::one(),
+			max_priority_fee_per_gas: U256::from(2),
+			nonce: None,
+			deadline: None,
+			revert_on_failure: false,
+		};
+
+		assert_eq!(xcm_transaction.into_transaction_v2(U256::zero(), 111), None);
+	}
+
+	#[test]
+	fn test_encode_call_v3() {
+		let xcm_transaction = EthereumXcmTransaction::V3(EthereumXcmTransactionV3 {
+			gas_limit: U256::one(),
+			action: TransactionAction::Call(H160::default()),
+			value: U256::zero(),
+			input: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![1u8])
+				.unwrap(),
+			access_list: None,
+			max_fee_per_gas: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			nonce: None,
+			deadline: None,
+			revert_on_failure: false,
+		});
+
+		let mut expected = vec![109u8, ETHEREUM_XCM_TRANSACT_CALL_INDEX];
+		expected.append(&mut xcm_transaction.encode());
+		assert_eq!(xcm_transaction.encode_call(109, None), expected);
+
+		let transact_as = H160::repeat_byte(0x11);
+		let mut expected = vec![109u8, ETHEREUM_XCM_TRANSACT_THROUGH_PROXY_CALL_INDEX];
+		expected.append(&mut transact_as.encode());
+		expected.append(&mut xcm_transaction.encode());
+		assert_eq!(
+			xcm_transaction.encode_call(109, Some(transact_as)),
+			expected
+		);
+	}
+
+	#[test]
+	fn test_create_v1() {
+		let xcm_transaction = EthereumXcmTransactionV1 {
+			gas_limit: U256::one(),
+			fee_payment: EthereumXcmFee::Auto,
+			action: TransactionAction::Create,
+			value: U256::zero(),
+			input: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![1u8])
+				.unwrap(),
+			access_list: None,
+		};
+		let nonce = U256::zero();
+		let expected_tx = Some(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id: 111,
+			nonce,
+			max_fee_per_gas: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			gas_limit: U256::one(),
+			action: TransactionAction::Create,
+			value: U256::zero(),
+			input: vec![1u8],
+			access_list: vec![],
+			odd_y_parity: true,
+			r: H256::from_low_u64_be(1u64),
+			s: H256::from_low_u64_be(1u64),
+		}));
+
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
+	}
+
+	#[test]
+	fn test_create2() {
+		let xcm_transaction = EthereumXcmCreate2Transaction {
+			gas_limit: U256::one(),
+			value: U256::zero(),
+			salt: H256::zero(),
+			init_code: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![0u8])
+				.unwrap(),
+			access_list: None,
+		};
+		let nonce = U256::zero();
+		let expected_tx = Some(TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id: 111,
+			nonce,
+			max_fee_per_gas: U256::zero(),
+			max_priority_fee_per_gas: U256::zero(),
+			gas_limit: U256::one(),
+			action: TransactionAction::Create,
+			value: U256::zero(),
+			input: vec![0u8],
+			access_list: vec![],
+			odd_y_parity: true,
+			r: H256::from_low_u64_be(1u64),
+			s: H256::from_low_u64_be(1u64),
+		}));
+
+		assert_eq!(xcm_transaction.into_transaction_v2(nonce, 111), expected_tx);
+	}
+
+	#[test]
+	fn test_create2_contract_address() {
+		// Example 2 from EIP-1014
+		let xcm_transaction = EthereumXcmCreate2Transaction {
+			gas_limit: U256::one(),
+			value: U256::zero(),
+			salt: H256::zero(),
+			init_code: BoundedVec::<u8, ConstU32<MAX_ETHEREUM_XCM_INPUT_SIZE>>::try_from(vec![0u8])
+				.unwrap(),
+			access_list: None,
+		};
+		let deployer =
+			H160::from_slice(&hex::decode("deadbeef00000000000000000000000000000000").unwrap());
+
+		assert_eq!(
+			xcm_transaction.contract_address(deployer),
+			H160::from_slice(&hex::decode("b928f69bb1d91cd65274e3c79d8986362984fda3").unwrap()),
+		);
+	}
+
+	#[test]
+	fn test_extract_revert_message() {
+		// Error(string) with reason "error_msg"
+		let data = hex::decode(
+			"08c379a0\
+			0000000000000000000000000000000000000000000000000000000000000020\
+			0000000000000000000000000000000000000000000000000000000000000009\
+			6572726f725f6d73670000000000000000000000000000000000000000000000",
+		)
+		.unwrap();
+		assert_eq!(
+			extract_revert_message(&data),
+			"VM Exception while processing transaction: revert error_msg"
+		);
+
+		// Too short to hold a reason
 		assert_eq!(
-			xcm_transaction.into_transaction_v2(nonce, 111, false),
-			expected_tx
+			extract_revert_message(&data[..68]),
+			"VM Exception while processing transaction: revert"
 		);
 	}
 }
@@ -18,6 +18,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+mod aliasers;
+pub use aliasers::*;
+
 mod asset_id_conversions;
 pub use asset_id_conversions::*;
 
@@ -39,6 +44,9 @@ pub use origin_conversion::*;
 mod transactor_traits;
 pub use transactor_traits::*;
 
+mod xcm_execute_filter;
+pub use xcm_execute_filter::*;
+
 use xcm::latest::{Junction, Junctions, Location};
 
 pub fn split_location_into_chain_part_and_beneficiary(
@@ -18,7 +18,7 @@ use cumulus_primitives_core::{
 	ParaId,
 };
 use sp_std::vec::Vec;
-use xcm::latest::{Error as XcmError, Location};
+use xcm::latest::{Error as XcmError, Location, QueryId, Weight};
 
 // The utility calls that need to be implemented as part of
 // using a derivative account from a certain account
@@ -66,6 +66,43 @@ pub trait XcmTransact: UtilityEncodeCall {
 	fn destination(self) -> Location;
 }
 
+// Trait to compute the fee charged by this chain to execute some XCM weight.
+// It is used to pay for the messages bringing assets back to this chain
+pub trait WeightToAssetFee {
+	/// Returns the amount of `asset` to pay for `weight`, or None if `asset` is not accepted
+	fn weight_to_asset_fee(weight: Weight, asset: &Location) -> Option<u128>;
+}
+
+impl WeightToAssetFee for () {
+	fn weight_to_asset_fee(_weight: Weight, _asset: &Location) -> Option<u128> {
+		None
+	}
+}
+
+// Trait to register queries whose response is notified by dispatching a call.
+// It is used to track the status of the transacts sent to other chains
+pub trait NotifyQueryHandler<Call, BlockNumber> {
+	/// Registers a query expecting a response from `responder` before `timeout`. Once the
+	/// response arrives, `notify` is dispatched with the query id and the response.
+	fn new_notify_query(
+		responder: Location,
+		notify: Call,
+		timeout: BlockNumber,
+		match_querier: Location,
+	) -> QueryId;
+}
+
+impl<Call, BlockNumber> NotifyQueryHandler<Call, BlockNumber> for () {
+	fn new_notify_query(
+		_responder: Location,
+		_notify: Call,
+		_timeout: BlockNumber,
+		_match_querier: Location,
+	) -> QueryId {
+		0
+	}
+}
+
 pub enum AvailableStakeCalls {
 	Bond(
 		relay_chain::Balance,
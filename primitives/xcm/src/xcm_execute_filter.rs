@@ -0,0 +1,101 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::traits::Contains;
+use xcm::latest::{Instruction, Location, Xcm};
+
+/// Filter for the messages executed locally through `pallet_xcm::execute`.
+/// Only allows messages made of a safe subset of instructions: moving assets (withdrawals,
+/// transfers, deposits and reserve withdrawals), buying execution and setting topics.
+/// The messages forwarded to other chains are not inspected, they are filtered by the
+/// destination.
+pub struct SafeXcmExecuteFilter;
+
+impl<RuntimeCall> Contains<(Location, Xcm<RuntimeCall>)> for SafeXcmExecuteFilter {
+	fn contains((origin, message): &(Location, Xcm<RuntimeCall>)) -> bool {
+		let allowed = message.inner().iter().all(|instruction| {
+			matches!(
+				instruction,
+				Instruction::WithdrawAsset(..)
+					| Instruction::TransferAsset { .. }
+					| Instruction::TransferReserveAsset { .. }
+					| Instruction::DepositAsset { .. }
+					| Instruction::DepositReserveAsset { .. }
+					| Instruction::InitiateReserveWithdraw { .. }
+					| Instruction::BuyExecution { .. }
+					| Instruction::RefundSurplus
+					| Instruction::SetTopic(..)
+			)
+		});
+		if !allowed {
+			log::trace!(
+				target: "xcm::execute_filter",
+				"execution of {:?} by {:?} got filtered",
+				message,
+				origin,
+			);
+		}
+		allowed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use xcm::latest::prelude::*;
+
+	fn is_allowed(instructions: Vec<Instruction<()>>) -> bool {
+		SafeXcmExecuteFilter::contains(&(Location::here(), Xcm(instructions)))
+	}
+
+	#[test]
+	fn allows_local_transfers() {
+		let assets: Assets = (Location::parent(), 100u128).into();
+		assert!(is_allowed(vec![
+			WithdrawAsset(assets.clone()),
+			BuyExecution {
+				fees: (Location::parent(), 10u128).into(),
+				weight_limit: Unlimited,
+			},
+			DepositAsset {
+				assets: All.into(),
+				beneficiary: Location::here(),
+			},
+			SetTopic([0; 32]),
+		]));
+		assert!(is_allowed(vec![
+			WithdrawAsset(assets.clone()),
+			InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: Location::parent(),
+				xcm: Xcm(vec![ClearOrigin]),
+			},
+		]));
+	}
+
+	#[test]
+	fn filters_other_instructions() {
+		assert!(!is_allowed(vec![
+			WithdrawAsset((Location::parent(), 100u128).into()),
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::zero(),
+				call: Vec::new().into(),
+			},
+		]));
+		assert!(!is_allowed(vec![ClearOrigin]));
+	}
+}
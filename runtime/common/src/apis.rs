@@ -43,6 +43,74 @@ macro_rules! impl_runtime_apis_plus_common {
 			}
 		}
 
+		// Helper function resolving an asset location against the evm foreign assets, the legacy
+		// asset-manager assets and the erc20 xcm bridge, in that order.
+		fn asset_registry_entry_by_location(
+			location: xcm::latest::Location,
+		) -> Option<moonbeam_rpc_primitives_asset_registry::AssetRegistryEntry> {
+			use frame_support::traits::fungibles::metadata::Inspect as _;
+			use moonbeam_rpc_primitives_asset_registry::{
+				AssetKind, AssetRegistryEntry, AssetStatus,
+			};
+
+			let (kind, address, decimals, symbol, status) = if let Some((asset_id, asset_status)) =
+				EvmForeignAssets::assets_by_location(&location)
+			{
+				let address = EvmForeignAssets::contract_address_from_asset_id(asset_id);
+				let (decimals, symbol) = Erc20XcmBridge::erc20_metadata(address)?;
+				let status = match asset_status {
+					pallet_moonbeam_foreign_assets::AssetStatus::Active => AssetStatus::Active,
+					pallet_moonbeam_foreign_assets::AssetStatus::FrozenXcmDepositAllowed => {
+						AssetStatus::FrozenXcmDepositAllowed
+					}
+					pallet_moonbeam_foreign_assets::AssetStatus::FrozenXcmDepositForbidden => {
+						AssetStatus::FrozenXcmDepositForbidden
+					}
+				};
+				(AssetKind::ForeignAsset, address, decimals, symbol, status)
+			} else if let Some(asset_id) = xcm_config::AssetType::try_from(location.clone())
+				.ok()
+				.and_then(AssetManager::asset_type_id)
+			{
+				let mut address = [0u8; 20];
+				address[..4].copy_from_slice(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX);
+				address[4..].copy_from_slice(&asset_id.to_be_bytes());
+				// pallet-assets refuses to mint into an asset that is not live, so xcm deposits
+				// fail as well once the asset is frozen
+				let status = match pallet_assets::Asset::<Runtime>::get(asset_id)
+					.map(|details| details.status)
+				{
+					Some(pallet_assets::AssetStatus::Live) => AssetStatus::Active,
+					_ => AssetStatus::FrozenXcmDepositForbidden,
+				};
+				(
+					AssetKind::LegacyForeignAsset,
+					H160(address),
+					Assets::decimals(asset_id),
+					Assets::symbol(asset_id),
+					status,
+				)
+			} else if let Some(address) = Erc20XcmBridge::erc20_contract_address(&location) {
+				let (decimals, symbol) = Erc20XcmBridge::erc20_metadata(address)?;
+				(AssetKind::Erc20, address, decimals, symbol, AssetStatus::Active)
+			} else {
+				return None;
+			};
+
+			let fee_payment_supported = XcmWeightTrader::supported_assets(&location)
+				.map_or(false, |(enabled, _)| enabled);
+
+			Some(AssetRegistryEntry {
+				kind,
+				location: location.into(),
+				address,
+				decimals,
+				symbol,
+				status,
+				fee_payment_supported,
+			})
+		}
+
 		impl_runtime_apis! {
 			$($custom)*
 
@@ -842,6 +910,79 @@ macro_rules! impl_runtime_apis_plus_common {
 				}
 			}
 
+			impl pallet_moonbeam_foreign_assets_runtime_api::ForeignAssetsApi<
+				Block,
+				AccountId,
+				pallet_moonbeam_foreign_assets::ForeignAssetInfo<AccountId, Balance>,
+			> for Runtime {
+				fn foreign_assets(
+				) -> Vec<pallet_moonbeam_foreign_assets::ForeignAssetInfo<AccountId, Balance>> {
+					EvmForeignAssets::foreign_assets_info()
+				}
+
+				fn foreign_asset(
+					asset_id: u128,
+				) -> Option<pallet_moonbeam_foreign_assets::ForeignAssetInfo<AccountId, Balance>> {
+					EvmForeignAssets::foreign_asset_info(asset_id)
+				}
+
+				fn foreign_assets_by_owner(
+					owner: AccountId,
+				) -> Vec<pallet_moonbeam_foreign_assets::ForeignAssetInfo<AccountId, Balance>> {
+					EvmForeignAssets::foreign_assets_info_by_owner(owner)
+				}
+			}
+
+			impl pallet_moonbeam_lazy_migrations_runtime_api::LazyMigrationsApi<
+				Block,
+				pallet_moonbeam_lazy_migrations::MigrationId,
+				pallet_moonbeam_lazy_migrations::LazyMigrationStatus,
+			> for Runtime {
+				fn lazy_migrations() -> Vec<(
+					pallet_moonbeam_lazy_migrations::MigrationId,
+					pallet_moonbeam_lazy_migrations::LazyMigrationStatus,
+				)> {
+					MoonbeamLazyMigrations::lazy_migrations_status()
+				}
+
+				fn lazy_migration_status(
+					id: pallet_moonbeam_lazy_migrations::MigrationId,
+				) -> Option<pallet_moonbeam_lazy_migrations::LazyMigrationStatus> {
+					MoonbeamLazyMigrations::lazy_migrations_status()
+						.into_iter()
+						.find_map(|(migration_id, status)| (migration_id == id).then_some(status))
+				}
+			}
+
+			impl moonbeam_rpc_primitives_asset_registry::AssetRegistryApi<Block> for Runtime {
+				fn asset_by_location(
+					location: VersionedLocation,
+				) -> Option<moonbeam_rpc_primitives_asset_registry::AssetRegistryEntry> {
+					let location = xcm::latest::Location::try_from(location).ok()?;
+					asset_registry_entry_by_location(location)
+				}
+
+				fn asset_by_address(
+					address: H160,
+				) -> Option<moonbeam_rpc_primitives_asset_registry::AssetRegistryEntry> {
+					let location = if address.as_bytes()[..4] == FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX[..] {
+						let mut asset_id = [0u8; 16];
+						asset_id.copy_from_slice(&address.as_bytes()[4..]);
+						let asset_id = u128::from_be_bytes(asset_id);
+						match EvmForeignAssets::assets_by_id(asset_id) {
+							Some(location) => location,
+							None => AssetManager::asset_id_type(asset_id)
+								.and_then(Into::<Option<xcm::latest::Location>>::into)?,
+						}
+					} else if pallet_evm::AccountCodes::<Runtime>::contains_key(address) {
+						Erc20XcmBridge::erc20_location(address)?
+					} else {
+						return None;
+					};
+					asset_registry_entry_by_location(location)
+				}
+			}
+
 			#[cfg(feature = "runtime-benchmarks")]
 			impl frame_benchmarking::Benchmark<Block> for Runtime {
 
@@ -894,7 +1035,35 @@ macro_rules! impl_runtime_apis_plus_common {
 					}
 
 					impl moonbeam_xcm_benchmarks::Config for Runtime {}
-					impl moonbeam_xcm_benchmarks::generic::Config for Runtime {}
+					impl moonbeam_xcm_benchmarks::generic::Config for Runtime {
+						fn hrmp_notifications_setup(sibling: u32) -> Result<u32, BenchmarkError> {
+							// Automating the HRMP management of the sibling sends transacts
+							// to the relay, paid with the relay asset
+							let relay: xcm::VersionedLocation = Location::parent().into();
+							pallet_xcm_transactor::Pallet::<Runtime>::set_transact_info(
+								frame_system::RawOrigin::Root.into(),
+								Box::new(relay.clone()),
+								Weight::from_parts(300_000_000, 0),
+								Weight::from_parts(20_000_000_000, u64::MAX),
+								None,
+							)
+							.map_err(|_| BenchmarkError::Weightless)?;
+							pallet_xcm_transactor::Pallet::<Runtime>::set_fee_per_second(
+								frame_system::RawOrigin::Root.into(),
+								Box::new(relay),
+								1,
+							)
+							.map_err(|_| BenchmarkError::Weightless)?;
+							pallet_xcm_transactor::Pallet::<Runtime>::set_hrmp_auto_accept(
+								frame_system::RawOrigin::Root.into(),
+								sibling.into(),
+								true,
+							)
+							.map_err(|_| BenchmarkError::Weightless)?;
+
+							Ok(ParachainInfo::parachain_id().into())
+						}
+					}
 
 					use pallet_asset_manager::Config as PalletAssetManagerConfig;
 
@@ -1100,7 +1269,31 @@ macro_rules! impl_runtime_apis_plus_common {
 						}
 
 						fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-							Err(BenchmarkError::Skip)
+							// The worst case reads the trusted siblings from the dynamic
+							// parameters before accepting the alias.
+							let para_id = 2000u32;
+							let trusted_siblings = sp_std::vec![para_id]
+								.try_into()
+								.map_err(|_| BenchmarkError::Weightless)?;
+							pallet_parameters::Pallet::<Runtime>::set_parameter(
+								frame_system::RawOrigin::Root.into(),
+								RuntimeParameters::XcmConfig(
+									dynamic_params::xcm_config::Parameters::AliasTrustedSiblings(
+										dynamic_params::xcm_config::AliasTrustedSiblings,
+										Some(trusted_siblings),
+									),
+								),
+							)
+							.map_err(|_| BenchmarkError::Weightless)?;
+
+							let key = [1u8; 20];
+							Ok((
+								Location::new(
+									1,
+									[Parachain(para_id), Junction::AccountKey20 { network: None, key }],
+								),
+								Location::new(0, [Junction::AccountKey20 { network: None, key }]),
+							))
 						}
 					}
 
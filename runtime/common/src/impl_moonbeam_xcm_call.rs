@@ -31,7 +31,9 @@ macro_rules! impl_moonbeam_xcm_call {
 					match (call.clone(), raw_origin) {
 						(
 							RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact { .. }) |
-							RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact_through_proxy { .. }),
+							RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::transact_through_proxy { .. }) |
+							RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::add_xcm_proxy { .. }) |
+							RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::remove_xcm_proxy { .. }),
 							RawOrigin::Signed(account_id)
 						) => {
 							return RuntimeCall::dispatch(
@@ -83,8 +83,7 @@ macro_rules! impl_moonbeam_xcm_call_tracing {
 									EthereumXcmTracingStatus::Transaction(traced_transaction_hash) => {
 										let transaction_hash = xcm_transaction.into_transaction_v2(
 											EthereumXcm::nonce(),
-											<Runtime as pallet_evm::Config>::ChainId::get(),
-											false
+											<Runtime as pallet_evm::Config>::ChainId::get()
 										)
 										.expect("Invalid transaction conversion")
 										.hash();
@@ -114,6 +113,17 @@ macro_rules! impl_moonbeam_xcm_call_tracing {
 								None => dispatch_call()
 							};
 						},
+						RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::add_xcm_proxy { .. }) |
+						RuntimeCall::EthereumXcm(pallet_ethereum_xcm::Call::remove_xcm_proxy { .. }) => {
+							if let RawOrigin::Signed(account_id) = raw_origin {
+								return RuntimeCall::dispatch(
+									call,
+									pallet_ethereum_xcm::Origin::XcmEthereumTransaction(
+										account_id.into()
+									).into()
+								);
+							}
+						},
 						_ => {}
 					}
 				}
@@ -22,6 +22,7 @@
 use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_migrations::{GetMigrations, Migration};
+use pallet_parachain_staking::migrations::MigrateDelegationScheduledRequestsBound;
 use sp_std::{marker::PhantomData, prelude::*, vec};
 
 pub struct MigrateToLatestXcmVersion<Runtime>(PhantomData<Runtime>);
@@ -48,6 +49,30 @@ where
 	}
 }
 
+pub struct ParachainStakingMigrateScheduledRequestsBound<Runtime>(PhantomData<Runtime>);
+impl<Runtime> Migration for ParachainStakingMigrateScheduledRequestsBound<Runtime>
+where
+	Runtime: pallet_parachain_staking::Config,
+{
+	fn friendly_name(&self) -> &str {
+		"MM_ParachainStakingMigrateScheduledRequestsBound"
+	}
+
+	fn migrate(&self, _available_weight: Weight) -> Weight {
+		MigrateDelegationScheduledRequestsBound::<Runtime>::on_runtime_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		MigrateDelegationScheduledRequestsBound::<Runtime>::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		MigrateDelegationScheduledRequestsBound::<Runtime>::post_upgrade(state)
+	}
+}
+
 pub struct CommonMigrations<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> GetMigrations for CommonMigrations<Runtime>
@@ -200,7 +225,9 @@ where
 			// complete in runtime 3300
 			// Box::new(MigrateCodeToStateTrieV1::<Runtime>(Default::default())),
 			// Box::new(MigrateStakingParachainBondConfig::<Runtime>(Default::default())),
-
+			Box::new(ParachainStakingMigrateScheduledRequestsBound::<Runtime>(
+				Default::default(),
+			)),
 			// permanent migrations
 			Box::new(MigrateToLatestXcmVersion::<Runtime>(Default::default())),
 		]
@@ -24,3 +24,11 @@ impl Contains<Location> for AllowSiblingParachains {
 		matches!(location.unpack(), (1, [Parachain(_)]))
 	}
 }
+
+/// The relay chain and the sibling parachains themselves, not the locations inside them
+pub struct AllowRelayOrSiblingParachains;
+impl Contains<Location> for AllowRelayOrSiblingParachains {
+	fn contains(location: &Location) -> bool {
+		matches!(location.unpack(), (1, []) | (1, [Parachain(_)]))
+	}
+}
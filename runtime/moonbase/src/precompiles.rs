@@ -37,6 +37,7 @@ use pallet_evm_precompile_call_permit::CallPermitPrecompile;
 use pallet_evm_precompile_collective::CollectivePrecompile;
 use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
 use pallet_evm_precompile_crowdloan_rewards::CrowdloanRewardsPrecompile;
+use pallet_evm_precompile_delegation_pools::DelegationPoolsPrecompile;
 use pallet_evm_precompile_gmp::GmpPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
@@ -211,8 +212,9 @@ type MoonbasePrecompilesAt<R> = (
 	>,
 	PrecompileAt<
 		AddressU64<2059>,
-		ProxyPrecompile<R>,
+		ProxyPrecompile<R, crate::EthereumXcm>,
 		(
+			// Contracts can check and use proxies, and manage their xcm proxies.
 			CallableByContract<OnlyIsProxyAndProxy<R>>,
 			SubcallWithMaxNesting<0>,
 			// Batch is the only precompile allowed to call Proxy.
@@ -292,6 +294,11 @@ type MoonbasePrecompilesAt<R> = (
 		),
 	>,
 	PrecompileAt<AddressU64<2075>, ForeignAssetMigratorPrecompile<R>, ()>,
+	PrecompileAt<
+		AddressU64<2076>,
+		DelegationPoolsPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 pub struct DisabledLocalAssets<Runtime>(sp_std::marker::PhantomData<Runtime>);
@@ -0,0 +1,186 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Weights for `pallet_delegation_pools`
+//!
+//! These weights were not produced by a bencher run: they are estimated from the storage
+//! accessed by each call and must be regenerated with the benchmarks of the pallet.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for `pallet_delegation_pools`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_delegation_pools::WeightInfo for WeightInfo<T> {
+	/// Storage: `DelegationPools::NextPoolId` (r:1 w:1)
+	/// Proof: `DelegationPools::NextPoolId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::Pools` (r:0 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::PoolMembers` (r:0 w:1)
+	/// Proof: `DelegationPools::PoolMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		Weight::from_parts(96_802_417, 6104)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(68_115_904, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 4560).saturating_mul(x.into()))
+	}
+	/// Storage: `DelegationPools::Pools` (r:1 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::PoolMembers` (r:1 w:1)
+	/// Proof: `DelegationPools::PoolMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn join() -> Weight {
+		Weight::from_parts(130_417_000, 8088)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `DelegationPools::UnbondingRequests` (r:1 w:1)
+	/// Proof: `DelegationPools::UnbondingRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::Pools` (r:1 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::PoolMembers` (r:1 w:1)
+	/// Proof: `DelegationPools::PoolMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbond() -> Weight {
+		Weight::from_parts(72_990_000, 6078)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `DelegationPools::Pools` (r:1 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DelegationPools::UnbondingRequests` (r:1 w:1)
+	/// Proof: `DelegationPools::UnbondingRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 10]`.
+	fn withdraw_unbonded(x: u32, ) -> Weight {
+		Weight::from_parts(110_251_983, 8350)
+			// Standard Error: 27_413
+			.saturating_add(Weight::from_parts(12_944_120, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 79).saturating_mul(x.into()))
+	}
+	/// Storage: `DelegationPools::Pools` (r:1 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(18_396_000, 3812)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DelegationPools::Pools` (r:1 w:1)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(22_511_000, 4277)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DelegationPools::Pools` (r:1 w:0)
+	/// Proof: `DelegationPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bond_idle_funds() -> Weight {
+		Weight::from_parts(118_000_000, 8088)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
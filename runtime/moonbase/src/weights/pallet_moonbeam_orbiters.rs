@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `penalize_orbiter` is not part of this run: its weight is an estimate until it is benchmarked on
+//! the reference hardware. The listed storage accesses cover the orbiters whose deposit has to be
+//! topped up after the penalty.
 
 // Executed Command:
 // ./frame-omni-bencher
@@ -204,4 +208,23 @@ impl<T: frame_system::Config> pallet_moonbeam_orbiters::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1037), added: 3512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `MoonbeamOrbiters::CurrentRound` (r:1 w:0)
+	/// Proof: `MoonbeamOrbiters::CurrentRound` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `MoonbeamOrbiters::MinOrbiterDeposit` (r:1 w:0)
+	/// Proof: `MoonbeamOrbiters::MinOrbiterDeposit` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `MoonbeamOrbiters::ForfeitedOrbiterRewards` (r:0 w:1)
+	/// Proof: `MoonbeamOrbiters::ForfeitedOrbiterRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoonbeamOrbiters::RegisteredOrbiter` (r:0 w:1)
+	/// Proof: `MoonbeamOrbiters::RegisteredOrbiter` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `MoonbeamOrbiters::SuspendedOrbiters` (r:0 w:1)
+	/// Proof: `MoonbeamOrbiters::SuspendedOrbiters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn penalize_orbiter() -> Weight {
+		Weight::from_parts(34_687_000, 4502)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
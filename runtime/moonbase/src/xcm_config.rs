@@ -22,7 +22,7 @@ use super::{
 	governance, AccountId, AssetId, AssetManager, Balance, Balances, EmergencyParaXcm,
 	Erc20XcmBridge, EvmForeignAssets, MaintenanceMode, MessageQueue, ParachainInfo,
 	ParachainSystem, Perbill, PolkadotXcm, Runtime, RuntimeBlockWeights, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin, Treasury, XcmpQueue,
+	RuntimeOrigin, Treasury, XcmAssetExchanger, XcmTransactor, XcmpQueue,
 };
 use crate::OpenTechCommitteeInstance;
 use moonkit_xcm_primitives::AccountIdAssetIdConversion;
@@ -60,14 +60,15 @@ use xcm_executor::traits::{CallDispatcher, ConvertLocation, JustTry};
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
 use pallet_xcm::EnsureXcm;
 use xcm_primitives::{
-	AbsoluteAndRelativeReserve, AccountIdToCurrencyId, AccountIdToLocation, AsAssetType,
-	IsBridgedConcreteAssetFrom, MultiNativeAsset, SignedToAccountId20, UtilityAvailableCalls,
+	AbsoluteAndRelativeReserve, AccountIdToCurrencyId, AccountIdToLocation,
+	AliasSiblingAccountKey20, AliasToDerivedAccountKey20, AsAssetType, IsBridgedConcreteAssetFrom,
+	IsRelayChainAccountId32, MultiNativeAsset, SignedToAccountId20, UtilityAvailableCalls,
 	UtilityEncodeCall, XcmTransact,
 };
 
 use crate::governance::referenda::{FastGeneralAdminOrRoot, GeneralAdminOrRoot};
 use crate::runtime_params::dynamic_params;
-use moonbeam_runtime_common::xcm_origins::AllowSiblingParachains;
+use moonbeam_runtime_common::xcm_origins::{AllowRelayOrSiblingParachains, AllowSiblingParachains};
 use pallet_moonbeam_foreign_assets::{MapSuccessToGovernance, MapSuccessToXcm};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -215,10 +216,17 @@ pub type XcmBarrier = TrailingSetTopicAsId<(
 	TakeWeightCredit,
 	// Expected responses are OK.
 	AllowKnownQueryResponses<PolkadotXcm>,
+	// HRMP notifications from the relay chain are handled by the XcmTransactor pallet.
+	xcm_builder::AllowHrmpNotificationsFromRelayChain,
 	WithComputedOrigin<
 		(
 			// If the message is one that immediately attemps to pay for execution, then allow it.
 			AllowTopLevelPaidExecutionFrom<Everything>,
+			// Same, but the message first exchanges the assets used to pay for execution. Only
+			// the relay chain and sibling chains can swap against the pools before paying.
+			pallet_xcm_asset_exchanger::AllowPaidExecutionAfterExchangeFrom<
+				AllowRelayOrSiblingParachains,
+			>,
 			// Subscriptions for version tracking are OK.
 			AllowSubscriptionsFrom<Everything>,
 		),
@@ -274,6 +282,21 @@ type Reserves = (
 	MultiNativeAsset<AbsoluteAndRelativeReserve<SelfLocationAbsolute>>,
 );
 
+/// Sibling parachains trusted to alias local accounts, managed through dynamic parameters
+pub struct AliasTrustedSiblings;
+impl frame_support::traits::Contains<u32> for AliasTrustedSiblings {
+	fn contains(para_id: &u32) -> bool {
+		dynamic_params::xcm_config::AliasTrustedSiblings::get().contains(para_id)
+	}
+}
+
+pub type XcmAliasers = (
+	// The same AccountKey20 account on a trusted sibling parachain can act as the local account
+	AliasSiblingAccountKey20<AliasTrustedSiblings>,
+	// Relay chain accounts can act as the local account derived from their location
+	AliasToDerivedAccountKey20<IsRelayChainAccountId32, LocationToAccountId, AccountId>,
+);
+
 pub struct XcmExecutorConfig;
 impl xcm_executor::Config for XcmExecutorConfig {
 	type RuntimeCall = RuntimeCall;
@@ -302,16 +325,18 @@ impl xcm_executor::Config for XcmExecutorConfig {
 	type PalletInstancesInfo = crate::AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type AssetLocker = ();
-	type AssetExchanger = ();
+	// Incoming messages can exchange the assets they hold (e.g. to pay for execution fees)
+	// against the liquidity pools of the XcmAssetExchanger pallet.
+	type AssetExchanger = pallet_xcm_asset_exchanger::LiquiditySourceExchanger<XcmAssetExchanger>;
 	type FeeManager = ();
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
 	type SafeCallFilter = SafeCallFilter;
-	type Aliasers = Nothing;
+	type Aliasers = XcmAliasers;
 	type TransactionalProcessor = xcm_builder::FrameTransactionalProcessor;
-	type HrmpNewChannelOpenRequestHandler = ();
-	type HrmpChannelAcceptedHandler = ();
-	type HrmpChannelClosingHandler = ();
+	type HrmpNewChannelOpenRequestHandler = XcmTransactor;
+	type HrmpChannelAcceptedHandler = XcmTransactor;
+	type HrmpChannelClosingHandler = XcmTransactor;
 	type XcmRecorder = PolkadotXcm;
 }
 
@@ -586,6 +611,13 @@ parameter_types! {
 	pub MaxHrmpRelayFee: Asset = (Location::parent(), 1_000_000_000_000u128).into();
 }
 
+parameter_types! {
+	// Weight of the hrmp calls automatically issued in the relay chain
+	pub const HrmpAutomationTransactWeight: Weight = Weight::from_parts(1_000_000_000, 65_536);
+	// Number of blocks a transact status query waits for its response
+	pub const TransactStatusQueryTimeout: u32 = 7_200;
+}
+
 // For now we only allow to transact in the relay, although this might change in the future
 // Transactors just defines the chains in which we allow transactions to be issued through
 // xcm
@@ -634,6 +666,34 @@ impl XcmTransact for Transactors {
 pub type DerivativeAddressRegistrationOrigin =
 	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
 
+/// Computes the fees charged by this chain for XCM execution through the XcmWeightTrader pallet
+pub struct XcmWeightTraderFees;
+impl xcm_primitives::WeightToAssetFee for XcmWeightTraderFees {
+	fn weight_to_asset_fee(weight: Weight, asset: &Location) -> Option<u128> {
+		pallet_xcm_weight_trader::Pallet::<Runtime>::query_weight_to_asset_fee(
+			weight,
+			xcm::VersionedAssetId::V4(xcm::latest::AssetId(asset.clone())),
+		)
+		.ok()
+	}
+}
+
+/// Registers the transact status queries in pallet-xcm, which dispatches the notification
+/// once the response arrives
+pub struct TransactStatusQueryHandler;
+impl xcm_primitives::NotifyQueryHandler<pallet_xcm_transactor::Call<Runtime>, u32>
+	for TransactStatusQueryHandler
+{
+	fn new_notify_query(
+		responder: Location,
+		notify: pallet_xcm_transactor::Call<Runtime>,
+		timeout: u32,
+		match_querier: Location,
+	) -> xcm::latest::QueryId {
+		PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -657,6 +717,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = GeneralAdminOrRoot;
 	type HrmpOpenOrigin = FastGeneralAdminOrRoot;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
+	type HrmpAutomationTransactWeight = HrmpAutomationTransactWeight;
+	type WeightToAssetFee = XcmWeightTraderFees;
+	type NotifyQueryHandler = TransactStatusQueryHandler;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
 }
 
 parameter_types! {
@@ -673,13 +738,22 @@ parameter_types! {
 	// To be able to support almost all erc20 implementations,
 	// we provide a sufficiently hight gas limit.
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 400_000;
+
+	// Gas limit of erc721 transferFrom calls made by the bridge.
+	pub Erc721XcmBridgeTransferGasLimit: u64 = 400_000;
 }
 
 impl pallet_erc20_xcm_bridge::Config for Runtime {
 	type AccountIdConverter = LocationToH160;
 	type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
+	// Erc721 tokens share the erc20 prefix, they are matched as non-fungible assets
+	type Erc721MultilocationPrefix = Erc20XcmBridgePalletLocation;
+	type Erc721TransferGasLimit = Erc721XcmBridgeTransferGasLimit;
 	type EvmRunner = EvmRunnerPrecompileOrEthXcm<MoonbeamCall, Self>;
+	type GasLimitOrigin = GeneralAdminOrRoot;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = moonbase_weights::pallet_erc20_xcm_bridge::WeightInfo<Runtime>;
 }
 
 pub struct AccountIdToH160;
@@ -763,6 +837,13 @@ pub type RemoveSupportedAssetOrigin = EitherOfDiverse<
 	pallet_collective::EnsureProportionMoreThan<AccountId, OpenTechCommitteeInstance, 5, 9>,
 >;
 
+parameter_types! {
+	pub const MaxPriceFeeders: u32 = 8;
+	pub MaxPriceDeviation: Perbill = Perbill::from_percent(10);
+	pub const MinPriceFeedersQuorum: u32 = 2;
+	pub const PriceStalenessThreshold: crate::BlockNumber = 6 * crate::HOURS;
+}
+
 impl pallet_xcm_weight_trader::Config for Runtime {
 	type AccountIdToLocation = AccountIdToLocation<AccountId>;
 	type AddSupportedAssetOrigin = AddAndEditSupportedAssetOrigin;
@@ -770,8 +851,12 @@ impl pallet_xcm_weight_trader::Config for Runtime {
 	type AssetTransactor = AssetTransactors;
 	type Balance = Balance;
 	type EditSupportedAssetOrigin = AddAndEditSupportedAssetOrigin;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxPriceFeeders = MaxPriceFeeders;
+	type MinPriceFeedersQuorum = MinPriceFeedersQuorum;
 	type NativeLocation = SelfReserve;
 	type PauseSupportedAssetOrigin = AddAndEditSupportedAssetOrigin;
+	type PriceStalenessThreshold = PriceStalenessThreshold;
 	type ResumeSupportedAssetOrigin = AddAndEditSupportedAssetOrigin;
 	type RemoveSupportedAssetOrigin = RemoveSupportedAssetOrigin;
 	type RuntimeEvent = RuntimeEvent;
@@ -782,11 +867,59 @@ impl pallet_xcm_weight_trader::Config for Runtime {
 	type NotFilteredLocation = RelayLocation;
 }
 
+parameter_types! {
+	pub const XcmAssetExchangerPalletId: frame_support::PalletId =
+		frame_support::PalletId(*b"xcm/exch");
+	/// 0.3% of each swap is kept by the pool
+	pub XcmAssetExchangerSwapFee: sp_runtime::Permill = sp_runtime::Permill::from_parts(3_000);
+}
+
+impl pallet_xcm_asset_exchanger::Config for Runtime {
+	type AccountIdToLocation = AccountIdToLocation<AccountId>;
+	type AssetTransactor = AssetTransactors;
+	// The pools are funded by the treasury
+	type LiquidityProvider = XcmFeesAccount;
+	type NativeLocation = SelfReserve;
+	type PalletId = XcmAssetExchangerPalletId;
+	type PoolsOrigin = GeneralAdminOrRoot;
+	type RuntimeEvent = RuntimeEvent;
+	type SwapFee = XcmAssetExchangerSwapFee;
+	type WeightInfo = moonbase_weights::pallet_xcm_asset_exchanger::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = testing::XcmAssetExchangerBenchmarkHelper;
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 mod testing {
 	use super::*;
 	use xcm_builder::WithLatestLocationConverter;
 
+	pub struct XcmAssetExchangerBenchmarkHelper;
+	impl pallet_xcm_asset_exchanger::BenchmarkHelper<AccountId> for XcmAssetExchangerBenchmarkHelper {
+		fn setup_pool_asset(who: &AccountId, amount: u128) -> Location {
+			use frame_support::traits::fungible::Mutate;
+
+			let asset_id: AssetId = 1;
+			let location = RelayLocation::get();
+			EvmForeignAssets::register_foreign_asset(
+				asset_id,
+				location.clone(),
+				12,
+				b"POOL".to_vec().try_into().expect("fit in BoundedVec"),
+				b"Pool asset"
+					.to_vec()
+					.try_into()
+					.expect("fit in BoundedVec"),
+			)
+			.expect("fail to register foreign asset");
+			EvmForeignAssets::mint_into(asset_id, who.clone(), amount.into())
+				.expect("fail to mint foreign asset");
+			Balances::set_balance(who, amount);
+
+			location
+		}
+	}
+
 	/// This From exists for benchmarking purposes. It has the potential side-effect of calling
 	/// AssetManager::set_asset_type_asset_id() and should NOT be used in any production code.
 	impl From<Location> for CurrencyId {
@@ -2716,7 +2716,7 @@ fn precompile_existence() {
 		let precompile_addresses: std::collections::BTreeSet<_> = vec![
 			1, 2, 3, 4, 5, 6, 7, 8, 9, 256, 1024, 1025, 1026, 1027, 2048, 2049, 2050, 2051, 2052,
 			2053, 2054, 2055, 2056, 2057, 2058, 2059, 2060, 2061, 2062, 2063, 2064, 2065, 2066,
-			2067, 2068, 2069, 2070, 2071, 2072, 2073, 2074, 2075,
+			2067, 2068, 2069, 2070, 2071, 2072, 2073, 2074, 2075, 2076,
 		]
 		.into_iter()
 		.map(H160::from_low_u64_be)
@@ -3400,3 +3400,59 @@ mod fee_tests {
 		});
 	}
 }
+
+#[test]
+fn xcm_weigher_weighs_alias_origin() {
+	use moonbeam_xcm_benchmarks::weights::generic::{SubstrateWeight, WeightInfo};
+	use xcm_executor::traits::WeightBounds;
+
+	let mut message = Xcm::<RuntimeCall>(vec![AliasOrigin(Location::new(
+		0,
+		[AccountKey20 {
+			network: None,
+			key: [1u8; 20],
+		}],
+	))]);
+	let weight = <moonbase_runtime::xcm_config::XcmWeigher as WeightBounds<RuntimeCall>>::weight(
+		&mut message,
+	)
+	.expect("AliasOrigin must be weighable");
+	assert_eq!(
+		weight,
+		SubstrateWeight::<moonbase_runtime::Runtime>::alias_origin()
+	);
+}
+
+#[test]
+fn xcm_weigher_weighs_hrmp_notifications() {
+	use moonbeam_xcm_benchmarks::weights::generic::{SubstrateWeight, WeightInfo};
+	use xcm_executor::traits::WeightBounds;
+
+	let weigh = |instruction: Instruction<RuntimeCall>| {
+		<moonbase_runtime::xcm_config::XcmWeigher as WeightBounds<RuntimeCall>>::weight(&mut Xcm(
+			vec![instruction],
+		))
+		.expect("HRMP notifications must be weighable")
+	};
+
+	assert_eq!(
+		weigh(HrmpNewChannelOpenRequest {
+			sender: 2000,
+			max_message_size: 1024,
+			max_capacity: 8,
+		}),
+		SubstrateWeight::<moonbase_runtime::Runtime>::hrmp_new_channel_open_request()
+	);
+	assert_eq!(
+		weigh(HrmpChannelAccepted { recipient: 2000 }),
+		SubstrateWeight::<moonbase_runtime::Runtime>::hrmp_channel_accepted()
+	);
+	assert_eq!(
+		weigh(HrmpChannelClosing {
+			initiator: 2000,
+			sender: 2000,
+			recipient: 1000,
+		}),
+		SubstrateWeight::<moonbase_runtime::Runtime>::hrmp_channel_closing()
+	);
+}
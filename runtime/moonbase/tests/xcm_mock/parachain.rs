@@ -33,7 +33,7 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, Hash, IdentityLookup, MaybeEquivalence, Zero},
-	Permill,
+	Perbill, Permill,
 };
 use sp_std::{convert::TryFrom, prelude::*};
 use xcm::{latest::prelude::*, Version as XcmVersion, VersionedXcm};
@@ -270,6 +270,8 @@ pub type XcmBarrier = (
 	TakeWeightCredit,
 	// Expected responses are OK.
 	AllowKnownQueryResponses<PolkadotXcm>,
+	// HRMP notifications from the relay chain are handled by the XcmTransactor pallet.
+	xcm_builder::AllowHrmpNotificationsFromRelayChain,
 	WithComputedOrigin<
 		(
 			// If the message is one that immediately attemps to pay for execution, then allow it.
@@ -345,6 +347,23 @@ type Reserves = (
 	>,
 );
 
+/// Only the accounts of ParaB are trusted to alias the local accounts with the same key
+pub struct AliasTrustedSiblings;
+impl frame_support::traits::Contains<u32> for AliasTrustedSiblings {
+	fn contains(para_id: &u32) -> bool {
+		*para_id == 2
+	}
+}
+
+pub type XcmAliasers = (
+	xcm_primitives::AliasSiblingAccountKey20<AliasTrustedSiblings>,
+	xcm_primitives::AliasToDerivedAccountKey20<
+		xcm_primitives::IsRelayChainAccountId32,
+		LocationToAccountId,
+		AccountId,
+	>,
+);
+
 pub struct XcmConfig;
 impl Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -371,11 +390,11 @@ impl Config for XcmConfig {
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
 	type SafeCallFilter = Everything;
-	type Aliasers = Nothing;
+	type Aliasers = XcmAliasers;
 	type TransactionalProcessor = ();
-	type HrmpNewChannelOpenRequestHandler = ();
-	type HrmpChannelAcceptedHandler = ();
-	type HrmpChannelClosingHandler = ();
+	type HrmpNewChannelOpenRequestHandler = XcmTransactor;
+	type HrmpChannelAcceptedHandler = XcmTransactor;
+	type HrmpChannelClosingHandler = XcmTransactor;
 	type XcmRecorder = PolkadotXcm;
 }
 
@@ -769,6 +788,41 @@ parameter_types! {
 	pub MaxHrmpRelayFee: Asset = (Location::parent(), 1_000_000_000_000u128).into();
 }
 
+parameter_types! {
+	// Weight of the hrmp calls automatically issued in the relay chain
+	pub const HrmpAutomationTransactWeight: Weight = Weight::from_parts(500_000_000, 0);
+	// Number of blocks a transact status query waits for its response
+	pub const TransactStatusQueryTimeout: BlockNumber = 100;
+}
+
+/// Computes the fees charged by this chain for XCM execution through the XcmWeightTrader pallet
+pub struct XcmWeightTraderFees;
+impl xcm_primitives::WeightToAssetFee for XcmWeightTraderFees {
+	fn weight_to_asset_fee(weight: Weight, asset: &Location) -> Option<u128> {
+		pallet_xcm_weight_trader::Pallet::<Runtime>::query_weight_to_asset_fee(
+			weight,
+			xcm::VersionedAssetId::V4(xcm::latest::AssetId(asset.clone())),
+		)
+		.ok()
+	}
+}
+
+/// Registers the transact status queries in pallet-xcm, which dispatches the notification
+/// once the response arrives
+pub struct TransactStatusQueryHandler;
+impl xcm_primitives::NotifyQueryHandler<pallet_xcm_transactor::Call<Runtime>, BlockNumber>
+	for TransactStatusQueryHandler
+{
+	fn new_notify_query(
+		responder: Location,
+		notify: pallet_xcm_transactor::Call<Runtime>,
+		timeout: BlockNumber,
+		match_querier: Location,
+	) -> xcm::latest::QueryId {
+		PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -792,10 +846,19 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type HrmpManipulatorOrigin = EnsureRoot<AccountId>;
 	type HrmpOpenOrigin = EnsureRoot<AccountId>;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
+	type HrmpAutomationTransactWeight = HrmpAutomationTransactWeight;
+	type WeightToAssetFee = XcmWeightTraderFees;
+	type NotifyQueryHandler = TransactStatusQueryHandler;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
 }
 
 parameter_types! {
 	pub RelayLocation: Location = Location::parent();
+	pub const MaxPriceFeeders: u32 = 8;
+	pub MaxPriceDeviation: Perbill = Perbill::from_percent(10);
+	pub const MinPriceFeedersQuorum: u32 = 1;
+	pub const PriceStalenessThreshold: BlockNumber = 100;
 }
 
 impl pallet_xcm_weight_trader::Config for Runtime {
@@ -805,8 +868,12 @@ impl pallet_xcm_weight_trader::Config for Runtime {
 	type AssetTransactor = AssetTransactors;
 	type Balance = Balance;
 	type EditSupportedAssetOrigin = EnsureRoot<AccountId>;
+	type MaxPriceDeviation = MaxPriceDeviation;
+	type MaxPriceFeeders = MaxPriceFeeders;
+	type MinPriceFeedersQuorum = MinPriceFeedersQuorum;
 	type NativeLocation = SelfReserve;
 	type PauseSupportedAssetOrigin = EnsureRoot<AccountId>;
+	type PriceStalenessThreshold = PriceStalenessThreshold;
 	type RemoveSupportedAssetOrigin = EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type ResumeSupportedAssetOrigin = EnsureRoot<AccountId>;
@@ -984,6 +1051,15 @@ pub enum ProxyType {
 
 impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {}
 
+impl pallet_ethereum_xcm::XcmProxyCallFilter for ProxyType {
+	fn is_xcm_proxy_call_allowed(
+		&self,
+		_transaction: &pallet_ethereum_xcm::EthereumXcmTransaction,
+	) -> bool {
+		matches!(self, ProxyType::Any)
+	}
+}
+
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, _c: &RuntimeCall) -> bool {
 		match self {
@@ -1047,6 +1123,12 @@ impl pallet_ethereum_xcm::Config for Runtime {
 	type EnsureProxy = EthereumXcmEnsureProxy;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type XcmProxyOrigin = pallet_ethereum_xcm::EnsureXcmEthereumTransaction;
+	type XcmProxyType = ProxyType;
+	type XcmProxyCurrency = Balances;
+	type XcmProxyDeposit = frame_support::traits::ConstU128<100>;
+	type MaxXcmProxyDuration = frame_support::traits::ConstU32<100>;
+	type WeightInfo = ();
 }
 
 type Block = frame_system::mocking::MockBlockU32<Runtime>;
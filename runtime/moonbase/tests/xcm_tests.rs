@@ -29,6 +29,7 @@ use pallet_xcm_transactor::{
 };
 use sp_runtime::traits::{Convert, MaybeEquivalence};
 use sp_std::boxed::Box;
+use xcm::latest::prelude::{AliasOrigin, AllCounted, HrmpNewChannelOpenRequest, Outcome, Wild};
 use xcm::{
 	latest::prelude::{
 		AccountId32, AccountKey20, All, Asset, AssetId, Assets as XcmAssets, BuyExecution,
@@ -4187,6 +4188,104 @@ fn transact_through_signed_multilocation_custom_fee_and_weight_refund() {
 	});
 }
 
+#[test]
+fn transact_through_signed_with_report_tracks_transact_status() {
+	MockNet::reset();
+	let mut ancestry = Location::parent();
+
+	ParaA::execute_with(|| {
+		ancestry = parachain::UniversalLocation::get().into();
+	});
+
+	// Let's construct the Junction that we will append with DescendOrigin
+	let signed_origin: Junctions = [AccountKey20 {
+		network: None,
+		key: PARAALICE,
+	}]
+	.into();
+
+	let mut descend_origin_multilocation = parachain::SelfLocation::get();
+	descend_origin_multilocation
+		.append_with(signed_origin)
+		.unwrap();
+
+	// To convert it to what the relay will see instead of us
+	descend_origin_multilocation
+		.reanchor(&Location::parent(), &ancestry.interior)
+		.unwrap();
+
+	let derived = xcm_builder::Account32Hash::<
+		relay_chain::KusamaNetwork,
+		relay_chain::AccountId,
+	>::convert_location(&descend_origin_multilocation)
+	.unwrap();
+
+	Relay::execute_with(|| {
+		assert_ok!(RelayBalances::transfer_allow_death(
+			relay_chain::RuntimeOrigin::signed(RELAYALICE),
+			derived.clone(),
+			4000020000u128,
+		));
+	});
+
+	// Encode the call. Balances transact to para_a_account
+	// First index
+	let mut encoded: Vec<u8> = Vec::new();
+	let index = <relay_chain::Runtime as frame_system::Config>::PalletInfo::index::<
+		relay_chain::Balances,
+	>()
+	.unwrap() as u8;
+
+	encoded.push(index);
+
+	// Then call bytes
+	let mut call_bytes = pallet_balances::Call::<relay_chain::Runtime>::transfer_allow_death {
+		// 100 to sovereign
+		dest: para_a_account(),
+		value: 100u32.into(),
+	}
+	.encode();
+	encoded.append(&mut call_bytes);
+
+	// 4000000000 for transfer + the XCM instructions, including the reporting ones
+	let total_weight = 4000020000u64;
+	ParaA::execute_with(|| {
+		assert_ok!(XcmTransactor::transact_through_signed_with_report(
+			parachain::RuntimeOrigin::signed(PARAALICE.into()),
+			Box::new(xcm::VersionedLocation::V4(Location::parent())),
+			CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::V4(
+					Location::parent()
+				))),
+				fee_amount: Some(total_weight as u128)
+			},
+			encoded,
+			TransactWeights {
+				transact_required_weight_at_most: 4000000000.into(),
+				overall_weight: Some(Limited(total_weight.into()))
+			},
+			false
+		));
+		assert_eq!(
+			XcmTransactor::transact_status(0).map(|info| info.status),
+			Some(pallet_xcm_transactor::TransactStatus::Pending)
+		);
+	});
+
+	Relay::execute_with(|| {
+		// 100 transferred
+		assert_eq!(RelayBalances::free_balance(&para_a_account()), 100);
+	});
+
+	ParaA::execute_with(|| {
+		// The relay reported the successful dispatch of the transact
+		assert_eq!(
+			XcmTransactor::transact_status(0).map(|info| info.status),
+			Some(pallet_xcm_transactor::TransactStatus::Succeeded)
+		);
+	});
+}
+
 #[test]
 fn transact_through_signed_multilocation_para_to_para() {
 	MockNet::reset();
@@ -4936,6 +5035,269 @@ fn hrmp_close_works() {
 	});
 }
 
+#[test]
+fn hrmp_open_request_from_auto_accepted_sibling_is_accepted_and_reciprocated() {
+	MockNet::reset();
+
+	Relay::execute_with(|| {
+		assert_ok!(RelayBalances::transfer_allow_death(
+			relay_chain::RuntimeOrigin::signed(RELAYALICE),
+			para_a_account(),
+			1000u128
+		));
+		assert_ok!(RelayBalances::transfer_allow_death(
+			relay_chain::RuntimeOrigin::signed(RELAYALICE),
+			para_b_account(),
+			1_000_000_000u128
+		));
+	});
+
+	ParaA::execute_with(|| {
+		let total_fee = 1_000u128;
+		let total_weight: u64 = 1_000_000_000;
+		let tx_weight: u64 = 500_000_000;
+		assert_ok!(XcmTransactor::hrmp_manage(
+			parachain::RuntimeOrigin::root(),
+			HrmpOperation::InitOpen(HrmpInitParams {
+				para_id: 2u32.into(),
+				proposed_max_capacity: 1,
+				proposed_max_message_size: 1
+			}),
+			CurrencyPayment {
+				currency: Currency::AsMultiLocation(Box::new(xcm::VersionedLocation::V4(
+					Location::parent()
+				))),
+				fee_amount: Some(total_fee)
+			},
+			TransactWeights {
+				transact_required_weight_at_most: tx_weight.into(),
+				overall_weight: Some(Limited(total_weight.into()))
+			}
+		));
+	});
+
+	ParaB::execute_with(|| {
+		assert_ok!(XcmTransactor::set_transact_info(
+			parachain::RuntimeOrigin::root(),
+			Box::new(xcm::VersionedLocation::V4(Location::parent())),
+			// Relay charges 1000 for every instruction, and we have 3, so 3000
+			3000.into(),
+			20000000000.into(),
+			None
+		));
+		assert_ok!(XcmTransactor::set_fee_per_second(
+			parachain::RuntimeOrigin::root(),
+			Box::new(xcm::VersionedLocation::V4(Location::parent())),
+			WEIGHT_REF_TIME_PER_SECOND as u128 / 1000,
+		));
+		assert_ok!(XcmTransactor::set_hrmp_auto_accept(
+			parachain::RuntimeOrigin::root(),
+			1u32.into(),
+			true
+		));
+
+		// The relay chain notifies the open request of ParaA
+		let message = Xcm::<parachain::RuntimeCall>(vec![HrmpNewChannelOpenRequest {
+			sender: 1,
+			max_message_size: 1,
+			max_capacity: 1,
+		}]);
+		let mut hash = message.using_encoded(blake2_256);
+		assert!(
+			xcm_executor::XcmExecutor::<parachain::XcmConfig>::prepare_and_execute(
+				Location::parent(),
+				message,
+				&mut hash,
+				Weight::MAX,
+				Weight::zero(),
+			)
+			.ensure_complete()
+			.is_ok()
+		);
+	});
+
+	Relay::execute_with(|| {
+		let accepted_event: relay_chain::RuntimeEvent =
+			polkadot_runtime_parachains::hrmp::Event::OpenChannelAccepted {
+				sender: 1u32.into(),
+				recipient: 2u32.into(),
+			}
+			.into();
+		assert!(relay_chain::relay_events().contains(&accepted_event));
+
+		let reciprocal_event: relay_chain::RuntimeEvent =
+			polkadot_runtime_parachains::hrmp::Event::OpenChannelRequested {
+				sender: 2u32.into(),
+				recipient: 1u32.into(),
+				proposed_max_capacity: 1u32,
+				proposed_max_message_size: 1u32,
+			}
+			.into();
+		assert!(relay_chain::relay_events().contains(&reciprocal_event));
+	});
+}
+
+fn execute_on_para_a(origin: Location, message: Xcm<parachain::RuntimeCall>) -> Outcome {
+	let mut hash = message.using_encoded(blake2_256);
+	xcm_executor::XcmExecutor::<parachain::XcmConfig>::prepare_and_execute(
+		origin,
+		message,
+		&mut hash,
+		Weight::MAX,
+		Weight::MAX,
+	)
+}
+
+fn alias_and_transfer(
+	target: Location,
+	beneficiary: [u8; 20],
+	amount: u128,
+) -> Xcm<parachain::RuntimeCall> {
+	Xcm(vec![
+		AliasOrigin(target),
+		WithdrawAsset((parachain::SelfReserve::get(), amount).into()),
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: Location::new(
+				0,
+				[AccountKey20 {
+					network: None,
+					key: beneficiary,
+				}],
+			),
+		},
+	])
+}
+
+#[test]
+fn trusted_sibling_account_can_alias_same_local_account() {
+	MockNet::reset();
+
+	let para_alice = Location::new(
+		0,
+		[AccountKey20 {
+			network: None,
+			key: PARAALICE,
+		}],
+	);
+	let beneficiary = [2u8; 20];
+
+	ParaA::execute_with(|| {
+		// ParaC is not trusted
+		let origin = Location::new(
+			1,
+			[
+				Parachain(3),
+				AccountKey20 {
+					network: None,
+					key: PARAALICE,
+				},
+			],
+		);
+		assert!(execute_on_para_a(
+			origin,
+			alias_and_transfer(para_alice.clone(), beneficiary, 100)
+		)
+		.ensure_complete()
+		.is_err());
+
+		// ParaB accounts can only alias the account with the same key
+		let origin = Location::new(
+			1,
+			[
+				Parachain(2),
+				AccountKey20 {
+					network: None,
+					key: beneficiary,
+				},
+			],
+		);
+		assert!(execute_on_para_a(
+			origin,
+			alias_and_transfer(para_alice.clone(), beneficiary, 100)
+		)
+		.ensure_complete()
+		.is_err());
+
+		let origin = Location::new(
+			1,
+			[
+				Parachain(2),
+				AccountKey20 {
+					network: None,
+					key: PARAALICE,
+				},
+			],
+		);
+		assert!(
+			execute_on_para_a(origin, alias_and_transfer(para_alice, beneficiary, 100))
+				.ensure_complete()
+				.is_ok()
+		);
+		assert_eq!(ParaBalances::free_balance(&beneficiary.into()), 100);
+		assert_eq!(
+			ParaBalances::free_balance(&PARAALICE.into()),
+			INITIAL_BALANCE - 100
+		);
+	});
+}
+
+#[test]
+fn relay_account_can_alias_derived_account() {
+	MockNet::reset();
+
+	let relay_alice = Location::new(
+		1,
+		[AccountId32 {
+			network: None,
+			id: RELAYALICE.into(),
+		}],
+	);
+	let beneficiary = [2u8; 20];
+
+	ParaA::execute_with(|| {
+		let derived = parachain::LocationToAccountId::convert_location(&relay_alice).unwrap();
+		assert_ok!(ParaBalances::transfer_allow_death(
+			parachain::RuntimeOrigin::signed(PARAALICE.into()),
+			derived,
+			100
+		));
+
+		// Relay accounts cannot alias any other account
+		let message = alias_and_transfer(
+			Location::new(
+				0,
+				[AccountKey20 {
+					network: None,
+					key: PARAALICE,
+				}],
+			),
+			beneficiary,
+			100,
+		);
+		assert!(execute_on_para_a(relay_alice.clone(), message)
+			.ensure_complete()
+			.is_err());
+
+		let message = alias_and_transfer(
+			Location::new(
+				0,
+				[AccountKey20 {
+					network: None,
+					key: derived.into(),
+				}],
+			),
+			beneficiary,
+			100,
+		);
+		assert!(execute_on_para_a(relay_alice, message)
+			.ensure_complete()
+			.is_ok());
+		assert_eq!(ParaBalances::free_balance(&beneficiary.into()), 100);
+		assert_eq!(ParaBalances::free_balance(&derived), 0);
+	});
+}
+
 use parity_scale_codec::{Decode, Encode};
 use sp_io::hashing::blake2_256;
 
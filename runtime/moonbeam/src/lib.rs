@@ -707,6 +707,11 @@ impl xcm_primitives::EnsureProxy<AccountId> for EthereumXcmEnsureProxy {
 	}
 }
 
+parameter_types! {
+	// One storage item; key size 2 * (16 + 20), value size 1 + 4 + 16
+	pub const XcmProxyDeposit: Balance = currency::deposit(1, 93);
+}
+
 impl pallet_ethereum_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
@@ -716,6 +721,12 @@ impl pallet_ethereum_xcm::Config for Runtime {
 	type EnsureProxy = EthereumXcmEnsureProxy;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type XcmProxyOrigin = pallet_ethereum_xcm::EnsureXcmEthereumTransaction;
+	type XcmProxyType = ProxyType;
+	type XcmProxyCurrency = Balances;
+	type XcmProxyDeposit = XcmProxyDeposit;
+	type MaxXcmProxyDuration = ConstU32<{ 30 * DAYS }>;
+	type WeightInfo = moonbeam_weights::pallet_ethereum_xcm::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -807,6 +818,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type DelegationBondLessDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the reward is paid
 	type RewardPaymentDelay = ConstU32<2>;
+	/// Rounds of reward summaries kept on-chain
+	type MaxRoundArchive = ConstU32<120>;
 	/// Minimum collators selected per round, default at genesis and minimum forever after
 	type MinSelectedCandidates = ConstU32<8>;
 	/// Maximum top delegations per candidate
@@ -815,6 +828,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MaxBottomDelegationsPerCandidate = ConstU32<50>;
 	/// Maximum delegations per delegator
 	type MaxDelegationsPerDelegator = ConstU32<100>;
+	/// Maximum pending bond less requests per delegation
+	type MaxScheduledRequestsPerDelegation = ConstU32<3>;
 	/// Minimum stake required to be reserved to be a candidate
 	type MinCandidateStk = ConstU128<{ 5_000 * currency::GLMR * currency::SUPPLY_FACTOR }>;
 	/// Minimum stake required to be reserved to be a delegator
@@ -824,6 +839,7 @@ impl pallet_parachain_staking::Config for Runtime {
 	type PayoutCollatorReward = PayoutCollatorOrOrbiterReward;
 	type OnInactiveCollator = OnInactiveCollator;
 	type OnNewRound = OnNewRound;
+	type CandidateSelection = ();
 	type SlotProvider = RelayChainSlotProvider;
 	type WeightInfo = moonbeam_weights::pallet_parachain_staking::WeightInfo<Runtime>;
 	type MaxCandidates = ConstU32<200>;
@@ -1028,6 +1044,32 @@ impl pallet_evm_precompile_proxy::EvmProxyCallFilter for ProxyType {
 	}
 }
 
+// Xcm proxies apply the EVM filter to the transactions they execute, contract deployments being
+// only allowed to `Any` proxies.
+impl pallet_ethereum_xcm::XcmProxyCallFilter for ProxyType {
+	fn is_xcm_proxy_call_allowed(
+		&self,
+		transaction: &pallet_ethereum_xcm::EthereumXcmTransaction,
+	) -> bool {
+		use pallet_evm_precompile_proxy::EvmProxyCallFilter;
+
+		match transaction.call() {
+			Some((to, value, input)) => {
+				let call = pallet_evm_precompile_proxy::EvmSubCall {
+					to: to.into(),
+					value,
+					call_data: input.to_vec().into(),
+				};
+				let recipient_has_code =
+					pallet_evm::AccountCodes::<Runtime>::decode_len(to).unwrap_or(0) > 0;
+				self.is_evm_proxy_call_allowed(&call, recipient_has_code, u64::MAX)
+					.unwrap_or(false)
+			}
+			None => matches!(self, ProxyType::Any),
+		}
+	}
+}
+
 // Be careful: Each time this filter is modified, the EVM filter must also be modified consistently.
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
@@ -1140,6 +1182,8 @@ pub type ForeignAssetMigratorOrigin = EitherOfDiverse<
 
 impl pallet_moonbeam_lazy_migrations::Config for Runtime {
 	type ForeignAssetMigratorOrigin = ForeignAssetMigratorOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type LazyMigrations = (pallet_moonbeam_lazy_migrations::ForeignAssetsLazyMigration<Runtime>,);
 	type WeightInfo = moonbeam_weights::pallet_moonbeam_lazy_migrations::WeightInfo<Runtime>;
 }
 
@@ -1239,6 +1283,39 @@ impl pallet_proxy_genesis_companion::Config for Runtime {
 
 parameter_types! {
 	pub OrbiterReserveIdentifier: [u8; 4] = [b'o', b'r', b'b', b'i'];
+	pub const MinOrbiterPerformance: Perbill = Perbill::from_percent(50);
+	pub const OrbiterMissedBlocksPenalty: Perbill = Perbill::from_percent(5);
+}
+
+/// Block production performance of the collators, based on the points awarded by
+/// parachain-staking for the blocks authored during a round.
+pub struct CollatorPerformance;
+impl pallet_moonbeam_orbiters::CollatorPerformance<AccountId, pallet_parachain_staking::RoundIndex>
+	for CollatorPerformance
+{
+	fn awarded_points(round: pallet_parachain_staking::RoundIndex, collator: &AccountId) -> u32 {
+		ParachainStaking::awarded_pts(round, collator)
+	}
+	fn expected_points(round: pallet_parachain_staking::RoundIndex, collator: &AccountId) -> u32 {
+		// The round is checked when it ends, before the next round is stored
+		let round_info = ParachainStaking::round();
+		if round_info.current != round || ParachainStaking::at_stake(round, collator).is_none() {
+			return 0;
+		}
+		let collators_count =
+			pallet_parachain_staking::AtStake::<Runtime>::iter_key_prefix(round).count() as u32;
+		round_info
+			.length
+			.saturating_mul(pallet_parachain_staking::POINTS_PER_BLOCK)
+			.checked_div(collators_count)
+			.unwrap_or_default()
+	}
+	fn points_weight() -> Weight {
+		// reads: awarded points, round info and the collator snapshots of the round
+		<Runtime as frame_system::Config>::DbWeight::get().reads(3u64.saturating_add(
+			<Runtime as pallet_parachain_staking::Config>::MaxCandidates::get().into(),
+		))
+	}
 }
 
 type AddCollatorOrigin =
@@ -1250,13 +1327,17 @@ impl pallet_moonbeam_orbiters::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AccountLookup = AuthorMapping;
 	type AddCollatorOrigin = AddCollatorOrigin;
+	type CollatorPerformance = CollatorPerformance;
 	type Currency = Balances;
 	type DelCollatorOrigin = DelCollatorOrigin;
 	/// Maximum number of orbiters per collator
 	type MaxPoolSize = ConstU32<8>;
 	/// Maximum number of round to keep on storage
 	type MaxRoundArchive = ConstU32<4>;
+	type MinOrbiterPerformance = MinOrbiterPerformance;
+	type OrbiterMissedBlocksPenalty = OrbiterMissedBlocksPenalty;
 	type OrbiterReserveIdentifier = OrbiterReserveIdentifier;
+	type OrbiterSuspensionPeriod = ConstU32<4>;
 	type RotatePeriod = ConstU32<1>;
 	/// Round index type.
 	type RoundIndex = pallet_parachain_staking::RoundIndex;
@@ -1397,7 +1478,7 @@ construct_runtime! {
 		Migrations: pallet_migrations::{Pallet, Storage, Config<T>, Event<T>} = 34,
 		ProxyGenesisCompanion: pallet_proxy_genesis_companion::{Pallet, Config<T>} = 35,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 36,
-		MoonbeamLazyMigrations: pallet_moonbeam_lazy_migrations::{Pallet, Call, Storage} = 37,
+		MoonbeamLazyMigrations: pallet_moonbeam_lazy_migrations::{Pallet, Call, Storage, Event<T>} = 37,
 		Parameters: pallet_parameters = 38,
 
 		// Has been permanently removed for safety reasons.
@@ -1442,11 +1523,12 @@ construct_runtime! {
 		XcmTransactor: pallet_xcm_transactor::{Pallet, Call, Storage, Event<T>} = 107,
 		// Previously 108: pallet_assets::<Instance1>
 		EthereumXcm: pallet_ethereum_xcm::{Pallet, Call, Storage, Origin, Event<T>} = 109,
-		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet} = 110,
+		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet, Call, Storage, Event<T>} = 110,
 		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>} = 111,
 		EvmForeignAssets: pallet_moonbeam_foreign_assets::{Pallet, Call, Storage, Event<T>} = 114,
 		XcmWeightTrader: pallet_xcm_weight_trader::{Pallet, Call, Storage, Event<T>} = 115,
 		EmergencyParaXcm: pallet_emergency_para_xcm::{Pallet, Call, Storage, Event} = 116,
+		XcmAssetExchanger: pallet_xcm_asset_exchanger::{Pallet, Call, Storage, Event<T>} = 117,
 
 		// Utils
 		RelayStorageRoots: pallet_relay_storage_roots::{Pallet, Storage} = 112,
@@ -1500,6 +1582,7 @@ mod benches {
 		[pallet_asset_manager, AssetManager]
 		[pallet_xcm_transactor, XcmTransactor]
 		[pallet_moonbeam_foreign_assets, EvmForeignAssets]
+		[pallet_erc20_xcm_bridge, Erc20XcmBridge]
 		[pallet_moonbeam_orbiters, MoonbeamOrbiters]
 		[pallet_randomness, Randomness]
 		[pallet_conviction_voting, ConvictionVoting]
@@ -1508,10 +1591,12 @@ mod benches {
 		[pallet_whitelist, Whitelist]
 		[pallet_multisig, Multisig]
 		[pallet_moonbeam_lazy_migrations, MoonbeamLazyMigrations]
+		[pallet_ethereum_xcm, EthereumXcm]
 		[pallet_relay_storage_roots, RelayStorageRoots]
 		[pallet_precompile_benchmarks, PrecompileBenchmarks]
 		[pallet_parameters, Parameters]
 		[pallet_xcm_weight_trader, XcmWeightTrader]
+		[pallet_xcm_asset_exchanger, XcmAssetExchanger]
 		[pallet_collective_treasury_council, TreasuryCouncilCollective]
 		[pallet_collective_open_tech_committee, OpenTechCommitteeCollective]
 	);
@@ -206,8 +206,9 @@ type MoonbeamPrecompilesAt<R> = (
 	>,
 	PrecompileAt<
 		AddressU64<2059>,
-		ProxyPrecompile<R>,
+		ProxyPrecompile<R, crate::EthereumXcm>,
 		(
+			// Contracts can check and use proxies, and manage their xcm proxies.
 			CallableByContract<OnlyIsProxyAndProxy<R>>,
 			SubcallWithMaxNesting<0>,
 			// Batch is the only precompile allowed to call Proxy.
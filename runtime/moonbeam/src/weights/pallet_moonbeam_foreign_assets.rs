@@ -22,6 +22,16 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `update_metadata` and `upgrade_asset_template` are not part of this run, their weights are
+//! written by hand. The former writes the decimals, symbol and name slots of the contract,
+//! 35 writes when a 256 bytes symbol and name replace strings of the same length. The latter
+//! deploys the template once and copies its code over the one of the asset contract.
+//!
+//! The asset lifecycle calls were not benchmarked either. `destroy_foreign_asset` is bounded by
+//! the `DESTROY_ASSET_STORAGE_LIMIT` contract entries it removes, `clear_destroyed_asset_storage`
+//! by one read and one write per entry. `transfer_ownership` and `accept_ownership` only touch
+//! the asset deposit, the pending transfer and the balances of both owners.
 
 // Executed Command:
 // ./frame-omni-bencher
@@ -180,4 +190,95 @@ impl<T: frame_system::Config> pallet_moonbeam_foreign_assets::WeightInfo for Wei
 			.saturating_add(T::DbWeight::get().reads(17_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:2 w:35)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_metadata() -> Weight {
+		Weight::from_parts(48_000_000, 9042)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:2 w:2)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:2)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountStorages` (r:5 w:5)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::Suicided` (r:1 w:0)
+	/// Proof: `EVM::Suicided` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn upgrade_asset_template() -> Weight {
+		Weight::from_parts(893_562_000, 30277)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:65 w:64)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::CounterForAssetsById` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::CounterForAssetsById` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmForeignAssets::AssetsByLocation` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsByLocation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsTemplateVersion` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::AssetsTemplateVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn destroy_foreign_asset() -> Weight {
+		Weight::from_parts(225_083_000, 33178)
+			.saturating_add(T::DbWeight::get().reads(73_u64))
+			.saturating_add(T::DbWeight::get().writes(75_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsById` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsById` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:0)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:0 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(43_309_000, 8068)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EvmForeignAssets::PendingOwnershipTransfers` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::PendingOwnershipTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmForeignAssets::AssetsCreationDetails` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsCreationDetails` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(60_944_000, 8351)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EvmForeignAssets::AssetsPendingStorageClearing` (r:1 w:1)
+	/// Proof: `EvmForeignAssets::AssetsPendingStorageClearing` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountStorages` (r:1000 w:1000)
+	/// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn clear_destroyed_asset_storage(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3600)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(n.into()))
+	}
 }
@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `reset_lazy_migration` is not part of this run. Until it is benchmarked, its weight is an
+//! estimate bounded by the single `LazyMigrationStatuses` entry it reads and writes.
 
 // Executed Command:
 // ./frame-omni-bencher
@@ -223,4 +226,11 @@ impl<T: frame_system::Config> pallet_moonbeam_lazy_migrations::WeightInfo for We
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `MoonbeamLazyMigrations::LazyMigrationStatuses` (r:1 w:1)
+	/// Proof: `MoonbeamLazyMigrations::LazyMigrationStatuses` (`max_values`: None, `max_size`: Some(1331), added: 3806, mode: `MaxEncodedLen`)
+	fn reset_lazy_migration() -> Weight {
+		Weight::from_parts(10_000_000, 4796)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -864,4 +864,13 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegatorRewardDestination` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorRewardDestination` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(15_000_000, 4069)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
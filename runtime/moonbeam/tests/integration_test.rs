@@ -3145,3 +3145,59 @@ mod fee_tests {
 		});
 	}
 }
+
+#[test]
+fn xcm_weigher_weighs_alias_origin() {
+	use moonbeam_xcm_benchmarks::weights::generic::{SubstrateWeight, WeightInfo};
+	use xcm_executor::traits::WeightBounds;
+
+	let mut message = Xcm::<RuntimeCall>(vec![AliasOrigin(Location::new(
+		0,
+		[AccountKey20 {
+			network: None,
+			key: [1u8; 20],
+		}],
+	))]);
+	let weight = <moonbeam_runtime::xcm_config::XcmWeigher as WeightBounds<RuntimeCall>>::weight(
+		&mut message,
+	)
+	.expect("AliasOrigin must be weighable");
+	assert_eq!(
+		weight,
+		SubstrateWeight::<moonbeam_runtime::Runtime>::alias_origin()
+	);
+}
+
+#[test]
+fn xcm_weigher_weighs_hrmp_notifications() {
+	use moonbeam_xcm_benchmarks::weights::generic::{SubstrateWeight, WeightInfo};
+	use xcm_executor::traits::WeightBounds;
+
+	let weigh = |instruction: Instruction<RuntimeCall>| {
+		<moonbeam_runtime::xcm_config::XcmWeigher as WeightBounds<RuntimeCall>>::weight(&mut Xcm(
+			vec![instruction],
+		))
+		.expect("HRMP notifications must be weighable")
+	};
+
+	assert_eq!(
+		weigh(HrmpNewChannelOpenRequest {
+			sender: 2000,
+			max_message_size: 1024,
+			max_capacity: 8,
+		}),
+		SubstrateWeight::<moonbeam_runtime::Runtime>::hrmp_new_channel_open_request()
+	);
+	assert_eq!(
+		weigh(HrmpChannelAccepted { recipient: 2000 }),
+		SubstrateWeight::<moonbeam_runtime::Runtime>::hrmp_channel_accepted()
+	);
+	assert_eq!(
+		weigh(HrmpChannelClosing {
+			initiator: 2000,
+			sender: 2000,
+			recipient: 1000,
+		}),
+		SubstrateWeight::<moonbeam_runtime::Runtime>::hrmp_channel_closing()
+	);
+}
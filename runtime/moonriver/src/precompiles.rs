@@ -200,8 +200,9 @@ type MoonriverPrecompilesAt<R> = (
 	>,
 	PrecompileAt<
 		AddressU64<2059>,
-		ProxyPrecompile<R>,
+		ProxyPrecompile<R, crate::EthereumXcm>,
 		(
+			// Contracts can check and use proxies, and manage their xcm proxies.
 			CallableByContract<OnlyIsProxyAndProxy<R>>,
 			SubcallWithMaxNesting<0>,
 			// Batch is the only precompile allowed to call Proxy.
@@ -18,8 +18,10 @@
 
 use crate::{currency, Runtime};
 use frame_support::dynamic_params::{dynamic_pallet_params, dynamic_params};
+use frame_support::BoundedVec;
 use moonbeam_runtime_common::expose_u128_get;
 use moonbeam_runtime_common::types::BoundedU128;
+use sp_core::ConstU32;
 use sp_runtime::Perbill;
 
 #[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
@@ -48,6 +50,9 @@ pub mod dynamic_params {
 	pub mod xcm_config {
 		#[codec(index = 0)]
 		pub static ForeignAssetCreationDeposit: u128 = 100 * currency::MOVR;
+		/// Sibling parachains whose `AccountKey20` accounts can alias the same local account
+		#[codec(index = 1)]
+		pub static AliasTrustedSiblings: BoundedVec<u32, ConstU32<32>> = BoundedVec::new();
 	}
 }
 
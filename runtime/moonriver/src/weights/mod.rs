@@ -30,6 +30,8 @@ pub mod pallet_collective_open_tech_committee;
 pub mod pallet_collective_treasury_council;
 pub mod pallet_conviction_voting;
 pub mod pallet_crowdloan_rewards;
+pub mod pallet_erc20_xcm_bridge;
+pub mod pallet_ethereum_xcm;
 pub mod pallet_evm;
 pub mod pallet_identity;
 pub mod pallet_message_queue;
@@ -51,5 +53,6 @@ pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
+pub mod pallet_xcm_asset_exchanger;
 pub mod pallet_xcm_transactor;
 pub mod pallet_xcm_weight_trader;
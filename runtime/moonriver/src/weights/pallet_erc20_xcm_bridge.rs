@@ -0,0 +1,45 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_erc20_xcm_bridge`
+//!
+//! Hand-written, the pallet has no bencher output yet: both calls only access
+//! `Erc20TransferGasLimits`, so they are priced on their storage accesses.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for `pallet_erc20_xcm_bridge`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_erc20_xcm_bridge::WeightInfo for WeightInfo<T> {
+	/// Storage: `Erc20XcmBridge::Erc20TransferGasLimits` (r:0 w:1)
+	/// Proof: `Erc20XcmBridge::Erc20TransferGasLimits` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_erc20_transfer_gas_limit() -> Weight {
+		Weight::from_parts(7_698_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Erc20XcmBridge::Erc20TransferGasLimits` (r:1 w:1)
+	/// Proof: `Erc20XcmBridge::Erc20TransferGasLimits` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn remove_erc20_transfer_gas_limit() -> Weight {
+		Weight::from_parts(11_254_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `set_hrmp_auto_accept` is not part of this run, its weight is a single storage write.
+//! Neither are `transact_status_response` and `prune_transact_status`, which read and write
+//! one transact status query.
 
 // Executed Command:
 // ./frame-omni-bencher
@@ -196,6 +200,8 @@ impl<T: frame_system::Config> pallet_xcm_transactor::WeightInfo for WeightInfo<T
 	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmTransactor::HrmpChannels` (r:0 w:1)
+	/// Proof: `XcmTransactor::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn hrmp_manage() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `437`
@@ -203,6 +209,26 @@ impl<T: frame_system::Config> pallet_xcm_transactor::WeightInfo for WeightInfo<T
 		// Minimum execution time: 55_733_000 picoseconds.
 		Weight::from_parts(58_015_000, 3902)
 			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `XcmTransactor::HrmpAutoAccept` (r:0 w:1)
+	/// Proof: `XcmTransactor::HrmpAutoAccept` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_hrmp_auto_accept() -> Weight {
+		Weight::from_parts(7_305_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmTransactor::TransactStatusQueries` (r:1 w:1)
+	/// Proof: `XcmTransactor::TransactStatusQueries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transact_status_response() -> Weight {
+		Weight::from_parts(15_262_000, 3607)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmTransactor::TransactStatusQueries` (r:1 w:1)
+	/// Proof: `XcmTransactor::TransactStatusQueries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn prune_transact_status() -> Weight {
+		Weight::from_parts(15_000_000, 3607)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
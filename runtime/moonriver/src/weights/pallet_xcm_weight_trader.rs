@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! `remove_asset`, `set_price_feeders` and `feed_price` are not part of this run, their
+//! weights are written by hand from their storage accesses until the pallet is benchmarked
+//! again.
 
 // Executed Command:
 // ./frame-omni-bencher
@@ -63,14 +67,16 @@ impl<T: frame_system::Config> pallet_xcm_weight_trader::WeightInfo for WeightInf
 	}
 	/// Storage: `XcmWeightTrader::SupportedAssets` (r:1 w:1)
 	/// Proof: `XcmWeightTrader::SupportedAssets` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `XcmWeightTrader::LastPriceUpdate` (r:1 w:1)
+	/// Proof: `XcmWeightTrader::LastPriceUpdate` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	fn edit_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `102`
 		//  Estimated: `4100`
 		// Minimum execution time: 12_250_000 picoseconds.
 		Weight::from_parts(12_578_000, 4100)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `XcmWeightTrader::SupportedAssets` (r:1 w:1)
 	/// Proof: `XcmWeightTrader::SupportedAssets` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
@@ -96,13 +102,41 @@ impl<T: frame_system::Config> pallet_xcm_weight_trader::WeightInfo for WeightInf
 	}
 	/// Storage: `XcmWeightTrader::SupportedAssets` (r:1 w:1)
 	/// Proof: `XcmWeightTrader::SupportedAssets` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
-	fn remove_asset() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `102`
-		//  Estimated: `4100`
-		// Minimum execution time: 11_867_000 picoseconds.
-		Weight::from_parts(12_263_000, 4100)
+	/// Storage: `XcmWeightTrader::PriceSubmissions` (r:8 w:8)
+	/// Proof: `XcmWeightTrader::PriceSubmissions` (`max_values`: None, `max_size`: Some(674), added: 3149, mode: `MaxEncodedLen`)
+	/// Storage: `XcmWeightTrader::LastPriceUpdate` (r:0 w:1)
+	/// Proof: `XcmWeightTrader::LastPriceUpdate` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 8]`.
+	fn remove_asset(x: u32, ) -> Weight {
+		Weight::from_parts(12_160_000, 4100)
+			.saturating_add(Weight::from_parts(3_800_000, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3149).saturating_mul(x.into()))
+	}
+	/// Storage: `XcmWeightTrader::PriceFeeders` (r:0 w:1)
+	/// Proof: `XcmWeightTrader::PriceFeeders` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	fn set_price_feeders() -> Weight {
+		Weight::from_parts(8_530_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmWeightTrader::PriceFeeders` (r:1 w:0)
+	/// Proof: `XcmWeightTrader::PriceFeeders` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// Storage: `XcmWeightTrader::SupportedAssets` (r:1 w:1)
+	/// Proof: `XcmWeightTrader::SupportedAssets` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `XcmWeightTrader::PriceSubmissions` (r:8 w:1)
+	/// Proof: `XcmWeightTrader::PriceSubmissions` (`max_values`: None, `max_size`: Some(674), added: 3149, mode: `MaxEncodedLen`)
+	/// Storage: `XcmWeightTrader::LastPriceUpdate` (r:0 w:1)
+	/// Proof: `XcmWeightTrader::LastPriceUpdate` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 8]`.
+	fn feed_price(x: u32, ) -> Weight {
+		Weight::from_parts(22_417_936, 4100)
+			.saturating_add(Weight::from_parts(3_802_413, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 3149).saturating_mul(x.into()))
+	}
 }